@@ -24,11 +24,32 @@ use concrete_core::math::tensor::{
 use concrete_core::numeric::{CastFrom, CastInto, Numeric};
 
 mod bootstrap;
+mod fft;
 mod keyswitch;
+mod multisum;
 mod random;
 
 criterion_group!(bootstrap_b, bootstrap::bench_32, bootstrap::bench_64);
-criterion_group!(keyswitch_b, keyswitch::bench_32, keyswitch::bench_64);
+criterion_group!(
+    fft_b,
+    fft::bench_list_32,
+    fft::bench_list_64,
+    fft::bench_multiply_accumulate
+);
+criterion_group!(
+    keyswitch_b,
+    keyswitch::bench_32,
+    keyswitch::bench_64,
+    keyswitch::bench_variable_32,
+    keyswitch::bench_variable_64
+);
+criterion_group!(
+    multisum_b,
+    multisum::bench_32,
+    multisum::bench_64,
+    multisum::bench_binary_mul_32,
+    multisum::bench_binary_mul_64
+);
 criterion_group!(
     random_b,
     random::bench_8,
@@ -38,4 +59,4 @@ criterion_group!(
     random::bench_128
 );
 
-criterion_main!(bootstrap_b, keyswitch_b, random_b);
+criterion_main!(bootstrap_b, fft_b, keyswitch_b, multisum_b, random_b);
@@ -0,0 +1,94 @@
+use criterion::{black_box, BenchmarkId, Criterion};
+
+use concrete_core::crypto::{GlweDimension, UnsignedTorus};
+use concrete_core::math::fft::{Complex64, Fft, FourierPolynomial, FourierPolynomialList};
+use concrete_core::math::polynomial::{PolynomialCount, PolynomialList, PolynomialSize};
+use concrete_core::math::random::{fill_with_random_uniform, random_gaussian};
+
+pub fn bench_list<T: UnsignedTorus>(c: &mut Criterion) {
+    // The mask of a GLWE ciphertext.
+    let polynomial_size = PolynomialSize(1024);
+    let count = PolynomialCount(GlweDimension(2).0);
+    let mut fft = Fft::new(polynomial_size);
+    let mut list = PolynomialList::allocate(T::ZERO, count, polynomial_size);
+    fill_with_random_uniform(&mut list);
+    let mut fourier_list =
+        FourierPolynomialList::allocate(Complex64::new(0., 0.), count, polynomial_size);
+
+    let mut group = c.benchmark_group(format!("forward fft list u{}", T::BITS));
+    group.bench_function(BenchmarkId::new("one by one", polynomial_size.0), |b| {
+        b.iter(|| {
+            for (mut fourier_poly, poly) in fourier_list
+                .polynomial_iter_mut()
+                .zip(list.polynomial_iter())
+            {
+                fft.forward_as_torus(&mut fourier_poly, black_box(&poly));
+            }
+        })
+    });
+    group.bench_function(BenchmarkId::new("list", polynomial_size.0), |b| {
+        b.iter(|| fft.forward_as_torus_list(&mut fourier_list, black_box(&list)))
+    });
+    group.finish();
+}
+
+pub fn bench_list_32(c: &mut Criterion) {
+    bench_list::<u32>(c);
+}
+
+pub fn bench_list_64(c: &mut Criterion) {
+    bench_list::<u64>(c);
+}
+
+pub fn bench_multiply_accumulate(c: &mut Criterion) {
+    let polynomial_size = PolynomialSize(1024);
+    let random_poly = || {
+        let mut poly = FourierPolynomial::allocate(Complex64::new(0., 0.), polynomial_size);
+        for coef in poly.coefficient_iter_mut() {
+            let (re, im) = random_gaussian(0., 1.);
+            *coef = Complex64::new(re, im);
+        }
+        poly
+    };
+    let (a, b, c_poly, d) = (random_poly(), random_poly(), random_poly(), random_poly());
+    let mut acc = random_poly();
+    let mut product = random_poly();
+    let half = polynomial_size.0 / 2 + 2;
+
+    let mut group = c.benchmark_group("fourier multiply accumulate");
+    group.bench_function(
+        BenchmarkId::new("separate passes", polynomial_size.0),
+        |bench| {
+            bench.iter(|| {
+                for (res, (a_i, b_i)) in product
+                    .coefficient_iter_mut()
+                    .zip(a.coefficient_iter().zip(b.coefficient_iter()))
+                    .take(half)
+                {
+                    *res = black_box(a_i) * b_i;
+                }
+                for (acc_i, product_i) in acc
+                    .coefficient_iter_mut()
+                    .zip(product.coefficient_iter())
+                    .take(half)
+                {
+                    *acc_i += product_i;
+                }
+            })
+        },
+    );
+    group.bench_function(BenchmarkId::new("fused", polynomial_size.0), |bench| {
+        bench.iter(|| acc.update_with_multiply_accumulate(black_box(&a), black_box(&b)))
+    });
+    group.bench_function(BenchmarkId::new("two fused", polynomial_size.0), |bench| {
+        bench.iter(|| {
+            acc.update_with_two_multiply_accumulate(
+                black_box(&a),
+                black_box(&b),
+                black_box(&c_poly),
+                black_box(&d),
+            )
+        })
+    });
+    group.finish();
+}
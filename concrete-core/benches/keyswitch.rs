@@ -1,28 +1,14 @@
-use criterion::{criterion_group, criterion_main, Benchmark, BenchmarkId, Criterion};
+use criterion::{BenchmarkId, Criterion};
 use itertools::iproduct;
-use rand::Rng;
-
-use concrete_core::crypto::bootstrap::BootstrapKey;
-use concrete_core::crypto::cross::{bootstrap, cmux, constant_sample_extract, external_product};
-use concrete_core::crypto::encoding::{Plaintext, PlaintextList};
-use concrete_core::crypto::glwe::{GlweCiphertext, GlweList};
-use concrete_core::crypto::lwe::{LweCiphertext, LweKeyswitchKey};
-use concrete_core::crypto::secret::{GlweSecretKey, LweSecretKey};
-use concrete_core::crypto::{
-    CiphertextCount, GlweDimension, LweDimension, LweSize, PlaintextCount, UnsignedTorus,
-};
+
+use concrete_core::crypto::encoding::Plaintext;
+use concrete_core::crypto::lwe::{LweCiphertext, LweKeyswitchKey, VariableDecompKeySwitchingKey};
+use concrete_core::crypto::secret::LweSecretKey;
+use concrete_core::crypto::{LweDimension, UnsignedTorus};
 use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
-use concrete_core::math::dispersion::{DispersionParameter, LogStandardDev, Variance};
-use concrete_core::math::fft::{Complex64, Fft, FourierPolynomial};
-use concrete_core::math::polynomial::PolynomialSize;
-use concrete_core::math::random::{
-    fill_with_random_uniform, fill_with_random_uniform_boolean, random_uniform_n_msb,
-    RandomGenerable, UniformMsb,
-};
-use concrete_core::math::tensor::{
-    AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
-};
-use concrete_core::numeric::{CastFrom, CastInto, Numeric};
+use concrete_core::math::dispersion::LogStandardDev;
+use concrete_core::math::random::{random_uniform_n_msb, RandomGenerable, UniformMsb};
+use concrete_core::numeric::CastInto;
 
 pub fn bench<T: UnsignedTorus + RandomGenerable<UniformMsb>>(c: &mut Criterion) {
     // fix a set of parameters
@@ -101,3 +87,85 @@ pub fn bench_32(c: &mut Criterion) {
 pub fn bench_64(c: &mut Criterion) {
     bench::<u64>(c);
 }
+
+pub fn bench_variable<T: UnsignedTorus + RandomGenerable<UniformMsb>>(c: &mut Criterion) {
+    // the uniform decomposition, and the variable one giving one more level to half of the
+    // coefficients of the input key
+    let uniform = (DecompositionBaseLog(4), DecompositionLevelCount(3));
+    let refined = (DecompositionBaseLog(4), DecompositionLevelCount(4));
+    let dimension_before = LweDimension(1024);
+    let dimension_after = LweDimension(512);
+    let n_bit_msg = 8; // bit precision of the plaintext
+    let n_samples = 100; // number of samples used to measure the noise
+    let std_input = LogStandardDev::from_log_standard_dev(-30.);
+    let std_ksk = LogStandardDev::from_log_standard_dev(-25.);
+
+    let sk_before = LweSecretKey::generate(dimension_before);
+    let sk_after = LweSecretKey::generate(dimension_after);
+
+    let mut ksk = LweKeyswitchKey::allocate(
+        T::ZERO,
+        uniform.1,
+        uniform.0,
+        dimension_before,
+        dimension_after,
+    );
+    ksk.fill_with_keyswitch_key(&sk_before, &sk_after, std_ksk);
+    let decomp_params = (0..dimension_before.0)
+        .map(|i| if i % 2 == 0 { uniform } else { refined })
+        .collect();
+    let mut variable_ksk =
+        VariableDecompKeySwitchingKey::allocate(T::ZERO, decomp_params, dimension_after);
+    variable_ksk.fill_with_keyswitch_key(&sk_before, &sk_after, std_ksk);
+
+    // we measure the output noise of both keys on the same inputs
+    let mut ciphertext_before = LweCiphertext::allocate(T::ZERO, dimension_before.to_lwe_size());
+    let mut ciphertext_after = LweCiphertext::allocate(T::ZERO, dimension_after.to_lwe_size());
+    let mut uniform_noise = 0.;
+    let mut variable_noise = 0.;
+    for _ in 0..n_samples {
+        let message = Plaintext(random_uniform_n_msb::<T>(n_bit_msg));
+        sk_before.encrypt_lwe(&mut ciphertext_before, &message, std_input);
+        let mut decrypted = Plaintext(T::ZERO);
+        ksk.keyswitch_ciphertext(&mut ciphertext_after, &ciphertext_before);
+        sk_after.decrypt_lwe(&mut decrypted, &ciphertext_after);
+        let error: f64 = decrypted
+            .0
+            .wrapping_sub(message.0)
+            .into_signed()
+            .cast_into();
+        uniform_noise += error * error / n_samples as f64;
+        let mut decrypted = Plaintext(T::ZERO);
+        variable_ksk.keyswitch_ciphertext(&mut ciphertext_after, &ciphertext_before);
+        sk_after.decrypt_lwe(&mut decrypted, &ciphertext_after);
+        let error: f64 = decrypted
+            .0
+            .wrapping_sub(message.0)
+            .into_signed()
+            .cast_into();
+        variable_noise += error * error / n_samples as f64;
+    }
+    println!(
+        "p={} keyswitch output noise: uniform 2^{:.2}, variable 2^{:.2}",
+        T::BITS,
+        (uniform_noise.sqrt() / f64::powi(2., T::BITS as i32)).log2(),
+        (variable_noise.sqrt() / f64::powi(2., T::BITS as i32)).log2(),
+    );
+
+    let mut group = c.benchmark_group("compilo-variable-keyswitch");
+    group.bench_function(format!("p={}-uniform", T::BITS), |b| {
+        b.iter(|| ksk.keyswitch_ciphertext(&mut ciphertext_after, &ciphertext_before))
+    });
+    group.bench_function(format!("p={}-variable", T::BITS), |b| {
+        b.iter(|| variable_ksk.keyswitch_ciphertext(&mut ciphertext_after, &ciphertext_before))
+    });
+    group.finish();
+}
+
+pub fn bench_variable_32(c: &mut Criterion) {
+    bench_variable::<u32>(c);
+}
+
+pub fn bench_variable_64(c: &mut Criterion) {
+    bench_variable::<u64>(c);
+}
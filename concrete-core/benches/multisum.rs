@@ -0,0 +1,83 @@
+use criterion::{black_box, BenchmarkId, Criterion};
+
+use concrete_core::crypto::UnsignedTorus;
+use concrete_core::math::polynomial::{
+    BinaryPolynomialList, Polynomial, PolynomialCount, PolynomialList, PolynomialSize,
+};
+use concrete_core::math::random::{fill_with_random_uniform, fill_with_random_uniform_boolean};
+
+pub fn bench<T: UnsignedTorus>(c: &mut Criterion) {
+    let polynomial_size = PolynomialSize(1024);
+    let count = PolynomialCount(2);
+    let mut coef_list = PolynomialList::allocate(T::ZERO, count, polynomial_size);
+    fill_with_random_uniform(&mut coef_list);
+    let mut bin_list = PolynomialList::allocate(false, count, polynomial_size);
+    fill_with_random_uniform_boolean(&mut bin_list);
+    let packed_list = BinaryPolynomialList::from_boolean_list(&bin_list);
+    let mut output = Polynomial::allocate(T::ZERO, polynomial_size);
+
+    let mut group = c.benchmark_group(format!("binary multisum u{}", T::BITS));
+    group.bench_function(BenchmarkId::new("boolean", polynomial_size.0), |b| {
+        b.iter(|| {
+            output.update_with_wrapping_add_binary_multisum(
+                black_box(&coef_list),
+                black_box(&bin_list),
+            )
+        })
+    });
+    group.bench_function(BenchmarkId::new("packed", polynomial_size.0), |b| {
+        b.iter(|| {
+            output.update_with_wrapping_add_packed_binary_multisum(
+                black_box(&coef_list),
+                black_box(&packed_list),
+            )
+        })
+    });
+    group.finish();
+}
+
+pub fn bench_binary_mul<T: UnsignedTorus>(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("binary mul u{}", T::BITS));
+    for size in [512, 1024, 2048].iter() {
+        let polynomial_size = PolynomialSize(*size);
+        let mut poly = Polynomial::allocate(T::ZERO, polynomial_size);
+        fill_with_random_uniform(&mut poly);
+        let mut bin_poly = Polynomial::allocate(false, polynomial_size);
+        fill_with_random_uniform_boolean(&mut bin_poly);
+        // The same binary polynomial, lifted to integer coefficients for the dense product.
+        let lifted_poly = Polynomial::from_container(
+            bin_poly
+                .coefficient_iter()
+                .map(|bit| T::cast_from(*bit))
+                .collect::<Vec<_>>(),
+        );
+        let mut output = Polynomial::allocate(T::ZERO, polynomial_size);
+        group.bench_function(BenchmarkId::new("rotations", size), |b| {
+            b.iter(|| {
+                output.update_with_wrapping_add_binary_mul(black_box(&poly), black_box(&bin_poly))
+            })
+        });
+        group.bench_function(BenchmarkId::new("schoolbook", size), |b| {
+            b.iter(|| {
+                output.update_with_wrapping_add_mul(black_box(&poly), black_box(&lifted_poly))
+            })
+        });
+    }
+    group.finish();
+}
+
+pub fn bench_32(c: &mut Criterion) {
+    bench::<u32>(c);
+}
+
+pub fn bench_64(c: &mut Criterion) {
+    bench::<u64>(c);
+}
+
+pub fn bench_binary_mul_32(c: &mut Criterion) {
+    bench_binary_mul::<u32>(c);
+}
+
+pub fn bench_binary_mul_64(c: &mut Criterion) {
+    bench_binary_mul::<u64>(c);
+}
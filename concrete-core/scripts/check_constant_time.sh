@@ -0,0 +1,77 @@
+#!/usr/bin/env bash
+# Checks the machine code generated for the constant-time primitives.
+#
+# The library is built in release mode in a dedicated target directory, and
+# `constant_time_snippet.rs` is compiled against this build. The assembly of every function of
+# the snippet is then searched for jumps: the compiler is free to turn a mask back into a branch,
+# which would make the timing depend on the secret values. Calls are rejected as well, since the
+# code of the callee is not checked: the primitives must be inlined in the snippet.
+#
+# The result depends on the toolchain and on the target, so the check is only meaningful for the
+# ones the library is released with (x86_64 for now).
+set -euo pipefail
+
+SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+CRATE_DIR="$(dirname "${SCRIPT_DIR}")"
+TARGET_DIR="${CONSTANT_TIME_TARGET_DIR:-${CRATE_DIR}/target/constant_time}"
+WORK_DIR="$(mktemp -d)"
+trap 'rm -rf "${WORK_DIR}"' EXIT
+
+FUNCTIONS=(
+    ct_mask_from_bool_u64
+    ct_select_u64
+    ct_lt_u64
+    ct_compute_binary_multisum_u64
+    ct_decrypt_lwe_u64
+    ct_update_with_wrapping_add_binary_mul_u64
+    ct_update_with_wrapping_add_binary_multisum_u64
+    ct_decrypt_glwe_u64
+    modq_add
+    modq_sub
+    modq_mul
+    decrypt_modular_glwe
+)
+
+# Among the artifacts of the build, the library is the only one whose rlib is named after the
+# crate.
+LIBRARY="$(
+    cd "${CRATE_DIR}" &&
+        cargo build --release --lib --message-format=json --target-dir "${TARGET_DIR}" |
+        grep -o '"[^"]*/libconcrete_core[^"/]*\.rlib"' |
+            tr -d '"' |
+            head -n 1
+)"
+if [ -z "${LIBRARY}" ]; then
+    echo "The library was not reported by cargo." >&2
+    exit 1
+fi
+
+"${RUSTC:-rustc}" --edition 2018 --crate-type lib --emit asm \
+    -C opt-level=3 -C debug-assertions=off \
+    --extern "concrete_core=${LIBRARY}" \
+    -L "dependency=${TARGET_DIR}/release/deps" \
+    -o "${WORK_DIR}/snippet.s" \
+    "${SCRIPT_DIR}/constant_time_snippet.rs"
+
+status=0
+for function in "${FUNCTIONS[@]}"; do
+    # The instructions of the function, between its label and the end of its frame information.
+    body="$(
+        awk -v label="${function}:" '
+            $1 == label { found = 1; next }
+            found && $1 == ".cfi_endproc" { exit }
+            found && $1 !~ /^[.#]/ && NF > 0 { print }
+        ' "${WORK_DIR}/snippet.s"
+    )"
+    if [ -z "${body}" ]; then
+        echo "${function} was not found." >&2
+        status=1
+        continue
+    fi
+    if offending="$(echo "${body}" | awk '$1 ~ /^(j|call)/')" && [ -n "${offending}" ]; then
+        echo "${function} contains a jump or a call:" >&2
+        echo "${offending}" >&2
+        status=1
+    fi
+done
+exit "${status}"
@@ -0,0 +1,101 @@
+// Calls the constant-time primitives on secret values, for `check_constant_time.sh`.
+//
+// The functions are not mangled, so that they can be found in the assembly. The sizes are fixed,
+// so that the loops over the public dimensions are unrolled.
+use concrete_core::crypto::encoding::{Plaintext, PlaintextList};
+use concrete_core::crypto::glwe::GlweCiphertext;
+use concrete_core::crypto::lwe::LweCiphertext;
+use concrete_core::crypto::secret::{GlweSecretKey, LweSecretKey};
+use concrete_core::math::modular::ModQ;
+use concrete_core::math::polynomial::{Polynomial, PolynomialList, PolynomialSize};
+use concrete_core::numeric::UnsignedInteger;
+
+type Zq = ModQ<2305843009213693951>;
+
+#[no_mangle]
+pub fn ct_mask_from_bool_u64(bit: bool) -> u64 {
+    u64::ct_mask_from_bool(bit)
+}
+
+#[no_mangle]
+pub fn ct_select_u64(choice: bool, first: u64, second: u64) -> u64 {
+    u64::ct_select(choice, first, second)
+}
+
+#[no_mangle]
+pub fn ct_lt_u64(lhs: u64, rhs: u64) -> bool {
+    lhs.ct_lt(rhs)
+}
+
+#[no_mangle]
+pub fn ct_compute_binary_multisum_u64(ciphertext: &[u64; 5], key: &[bool; 4]) -> u64 {
+    let ciphertext = LweCiphertext::from_container(&ciphertext[..]);
+    ciphertext
+        .get_mask()
+        .ct_compute_binary_multisum(&LweSecretKey::from_container(&key[..]))
+}
+
+#[no_mangle]
+pub fn ct_decrypt_lwe_u64(ciphertext: &[u64; 5], key: &[bool; 4]) -> u64 {
+    let mut output = Plaintext(0);
+    LweSecretKey::from_container(&key[..])
+        .ct_decrypt_lwe(&mut output, &LweCiphertext::from_container(&ciphertext[..]));
+    output.0
+}
+
+#[no_mangle]
+pub fn ct_update_with_wrapping_add_binary_mul_u64(
+    output: &mut [u64; 4],
+    polynomial: &[u64; 4],
+    key: &[bool; 4],
+) {
+    Polynomial::from_container(&mut output[..]).ct_update_with_wrapping_add_binary_mul(
+        &Polynomial::from_container(&polynomial[..]),
+        &Polynomial::from_container(&key[..]),
+    );
+}
+
+#[no_mangle]
+pub fn ct_update_with_wrapping_add_binary_multisum_u64(
+    output: &mut [u64; 4],
+    polynomials: &[u64; 8],
+    key: &[bool; 8],
+) {
+    Polynomial::from_container(&mut output[..]).ct_update_with_wrapping_add_binary_multisum(
+        &PolynomialList::from_container(&polynomials[..], PolynomialSize(4)),
+        &PolynomialList::from_container(&key[..], PolynomialSize(4)),
+    );
+}
+
+#[no_mangle]
+pub fn ct_decrypt_glwe_u64(ciphertext: &[u64; 8], key: &[bool; 4], output: &mut [u64; 4]) {
+    let mut output = PlaintextList::from_container(&mut output[..]);
+    GlweSecretKey::from_container(&key[..], PolynomialSize(4)).ct_decrypt_glwe(
+        &mut output,
+        &GlweCiphertext::from_container(&ciphertext[..], PolynomialSize(4)),
+    );
+}
+
+#[no_mangle]
+pub fn modq_add(lhs: Zq, rhs: Zq) -> Zq {
+    lhs + rhs
+}
+
+#[no_mangle]
+pub fn modq_sub(lhs: Zq, rhs: Zq) -> Zq {
+    lhs - rhs
+}
+
+#[no_mangle]
+pub fn modq_mul(lhs: Zq, rhs: Zq) -> Zq {
+    lhs * rhs
+}
+
+#[no_mangle]
+pub fn decrypt_modular_glwe(ciphertext: &[Zq; 8], key: &[bool; 4], output: &mut [Zq; 4]) {
+    let mut output = PlaintextList::from_container(&mut output[..]);
+    GlweSecretKey::from_container(&key[..], PolynomialSize(4)).decrypt_modular_glwe(
+        &mut output,
+        &GlweCiphertext::from_container(&ciphertext[..], PolynomialSize(4)),
+    );
+}
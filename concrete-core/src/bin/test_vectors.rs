@@ -0,0 +1,254 @@
+//! Generates deterministic test vectors, used to check other implementations of the scheme (and
+//! other versions of this library) against `concrete-core`.
+//!
+//! # Usage
+//!
+//! ```text
+//! concrete-test-vectors <seed> <parameters.json> <output directory>
+//! ```
+//!
+//! The seed is a 128 bits integer, written in decimal or in hexadecimal with a `0x` prefix. The
+//! parameters are described by a JSON object:
+//!
+//! ```json
+//! {
+//!     "lwe_dimension": 630,
+//!     "glwe_dimension": 1,
+//!     "polynomial_size": 1024,
+//!     "lwe_log_std_dev": -15.0,
+//!     "glwe_log_std_dev": -25.0,
+//!     "ks_base_log": 2,
+//!     "ks_level_count": 8,
+//!     "ggsw_base_log": 7,
+//!     "ggsw_level_count": 3,
+//!     "plaintexts": [0, 4611686018427387904, 9223372036854775808]
+//! }
+//! ```
+//!
+//! # Artifacts
+//!
+//! Every artifact uses 64 bits integers, and is written in the wire format of the library: the
+//! `bincode` serialization of the object, with the default configuration of `bincode` 1.3
+//! (little endian integers, and lengths encoded as 64 bits integers). The artifacts are:
+//!
+//! + `lwe_secret_key.bin`: an LWE secret key of dimension `lwe_dimension`,
+//! + `glwe_secret_key.bin`: a GLWE secret key of dimension `glwe_dimension`,
+//! + `lwe_ciphertexts.bin`: the encryptions of the plaintexts under the LWE key obtained by
+//!   flattening the GLWE key,
+//! + `keyswitch_key.bin`: the key switching key from the flattened GLWE key to the LWE key,
+//! + `keyswitch_output.bin`: the ciphertexts switched to the LWE key,
+//! + `ggsw.bin`: the tensor of a GGSW encryption of the constant one under the GLWE key,
+//! + `glwe_ciphertext.bin`: the encryption of the first `polynomial_size` plaintexts (padded with
+//!   zeros) under the GLWE key,
+//! + `external_product_output.bin`: the external product of the two previous ciphertexts.
+//!
+//! The secret keys are obtained with a [`KeyDerivation`] of the seed, and all the other random
+//! samples are drawn inside [`with_seed`]. A `manifest.json` file lists the seed, the parameters,
+//! and the SHA-256 digest of every artifact.
+//!
+//! # Note
+//!
+//! The external product is computed with a floating point FFT. An implementation using a
+//! different FFT may obtain coefficients differing by small rounding errors, and should rather
+//! compare the decryptions of its output.
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use concrete_core::crypto::bootstrap::BootstrapKey;
+use concrete_core::crypto::encoding::PlaintextList;
+use concrete_core::crypto::ggsw::GgswCiphertext;
+use concrete_core::crypto::glwe::GlweCiphertext;
+use concrete_core::crypto::lwe::{LweKeyswitchKey, LweList};
+use concrete_core::crypto::secret::{KeyDerivation, KeyLabel, LweSecretKey};
+use concrete_core::crypto::{CiphertextCount, GlweDimension, LweDimension, PlaintextCount};
+use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_core::math::dispersion::LogStandardDev;
+use concrete_core::math::fft::{Complex64, Fft};
+use concrete_core::math::polynomial::PolynomialSize;
+use concrete_core::math::random::with_seed;
+use concrete_core::math::tensor::AsRefTensor;
+
+// The version of the layout of the output directory, written in the manifest.
+const FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Parameters {
+    lwe_dimension: usize,
+    glwe_dimension: usize,
+    polynomial_size: usize,
+    lwe_log_std_dev: f64,
+    glwe_log_std_dev: f64,
+    ks_base_log: usize,
+    ks_level_count: usize,
+    ggsw_base_log: usize,
+    ggsw_level_count: usize,
+    plaintexts: Vec<u64>,
+}
+
+#[derive(Debug, Serialize)]
+struct Manifest {
+    format_version: u32,
+    seed: String,
+    parameters: Parameters,
+    artifacts: BTreeMap<String, String>,
+}
+
+// Writes the artifacts to a directory, and keeps track of their digests.
+struct ArtifactWriter<'dir> {
+    directory: &'dir Path,
+    digests: BTreeMap<String, String>,
+}
+
+impl<'dir> ArtifactWriter<'dir> {
+    fn write(&mut self, name: &str, artifact: &impl Serialize) -> Result<(), Box<dyn Error>> {
+        let bytes = bincode::serialize(artifact)?;
+        std::fs::write(self.directory.join(name), &bytes)?;
+        self.digests
+            .insert(name.to_owned(), format!("{:x}", Sha256::digest(&bytes)));
+        Ok(())
+    }
+}
+
+fn parse_seed(seed: &str) -> Result<u128, Box<dyn Error>> {
+    let seed = if let Some(hex) = seed.strip_prefix("0x") {
+        u128::from_str_radix(&hex.replace('_', ""), 16)?
+    } else {
+        seed.replace('_', "").parse()?
+    };
+    Ok(seed)
+}
+
+fn generate(seed: u128, params: &Parameters, directory: &Path) -> Result<(), Box<dyn Error>> {
+    let lwe_dimension = LweDimension(params.lwe_dimension);
+    let glwe_dimension = GlweDimension(params.glwe_dimension);
+    let poly_size = PolynomialSize(params.polynomial_size);
+    let lwe_noise = LogStandardDev(params.lwe_log_std_dev);
+    let glwe_noise = LogStandardDev(params.glwe_log_std_dev);
+    let ks_level = DecompositionLevelCount(params.ks_level_count);
+    let ks_base_log = DecompositionBaseLog(params.ks_base_log);
+    let ggsw_level = DecompositionLevelCount(params.ggsw_level_count);
+    let ggsw_base_log = DecompositionBaseLog(params.ggsw_base_log);
+    for (name, base_log, level) in &[
+        ("keyswitch decomposition", ks_base_log, ks_level),
+        ("ggsw decomposition", ggsw_base_log, ggsw_level),
+    ] {
+        if base_log.0 * level.0 > 64 || base_log.0 == 0 || level.0 == 0 {
+            return Err(concrete_core::Error::InvalidParameter {
+                parameter: name,
+                reason: "must use between 1 and 64 bits".into(),
+            }
+            .into());
+        }
+    }
+    // Checks that the FFT supports the polynomial size before writing any artifact.
+    let mut fft = Fft::try_new(poly_size)?;
+    let mut writer = ArtifactWriter {
+        directory,
+        digests: BTreeMap::new(),
+    };
+
+    // The keys.
+    let derivation = KeyDerivation::new(seed);
+    let lwe_key = derivation.derive_lwe_key(KeyLabel(b"test vectors", 0), lwe_dimension);
+    let glwe_key =
+        derivation.derive_glwe_key(KeyLabel(b"test vectors", 0), glwe_dimension, poly_size);
+    let flat_key = glwe_key.clone().into_lwe_secret_key();
+    writer.write("lwe_secret_key.bin", &lwe_key)?;
+    writer.write("glwe_secret_key.bin", &glwe_key)?;
+
+    with_seed(seed, || -> Result<(), Box<dyn Error>> {
+        // The LWE ciphertexts.
+        let plaintexts = PlaintextList::from_container(params.plaintexts.clone());
+        let mut ciphertexts = LweList::allocate(
+            0u64,
+            flat_key.key_size().to_lwe_size(),
+            CiphertextCount(params.plaintexts.len()),
+        );
+        flat_key.encrypt_lwe_list(&mut ciphertexts, &plaintexts, glwe_noise);
+        writer.write("lwe_ciphertexts.bin", &ciphertexts)?;
+
+        // The key switch.
+        let mut ksk = LweKeyswitchKey::allocate(
+            0u64,
+            ks_level,
+            ks_base_log,
+            flat_key.key_size(),
+            lwe_dimension,
+        );
+        ksk.fill_with_keyswitch_key(&flat_key, &lwe_key, lwe_noise);
+        let mut switched = LweList::allocate(
+            0u64,
+            lwe_dimension.to_lwe_size(),
+            CiphertextCount(params.plaintexts.len()),
+        );
+        ksk.keyswitch_list(&mut switched, &ciphertexts);
+        writer.write("keyswitch_key.bin", &ksk)?;
+        writer.write("keyswitch_output.bin", &switched)?;
+
+        // The external product. The GGSW encryption of one is the bootstrap key of a single bit
+        // set to one.
+        let one_key = LweSecretKey::from_container(vec![true]);
+        let mut coef_bsk = BootstrapKey::allocate(
+            0u64,
+            glwe_dimension.to_glwe_size(),
+            poly_size,
+            ggsw_level,
+            ggsw_base_log,
+            LweDimension(1),
+        );
+        coef_bsk.fill_with_new_key(&one_key, &glwe_key, glwe_noise);
+        let mut fourier_bsk = BootstrapKey::allocate(
+            Complex64::new(0., 0.),
+            glwe_dimension.to_glwe_size(),
+            poly_size,
+            ggsw_level,
+            ggsw_base_log,
+            LweDimension(1),
+        );
+        fourier_bsk.fill_with_forward_fourier(&coef_bsk);
+        let mut messages = PlaintextList::allocate(0u64, PlaintextCount(poly_size.0));
+        for (message, plaintext) in messages.plaintext_iter_mut().zip(params.plaintexts.iter()) {
+            message.0 = *plaintext;
+        }
+        let mut glwe = GlweCiphertext::allocate(0u64, poly_size, glwe_dimension.to_glwe_size());
+        glwe_key.encrypt_glwe(&mut glwe, &messages, glwe_noise);
+        let ggsw_list: Vec<_> = fourier_bsk.ggsw_iter().collect();
+        let output = GgswCiphertext::reduce_external_product(&ggsw_list, &glwe, &mut fft);
+        writer.write("ggsw.bin", coef_bsk.as_tensor())?;
+        writer.write("glwe_ciphertext.bin", &glwe)?;
+        writer.write("external_product_output.bin", &output)?;
+        Ok(())
+    })?;
+
+    let manifest = Manifest {
+        format_version: FORMAT_VERSION,
+        seed: format!("{:#034x}", seed),
+        parameters: params.clone(),
+        artifacts: writer.digests,
+    };
+    let mut manifest = serde_json::to_string_pretty(&manifest)?;
+    manifest.push('\n');
+    std::fs::write(directory.join("manifest.json"), manifest)?;
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() != 4 {
+        eprintln!(
+            "Usage: {} <seed> <parameters.json> <output directory>",
+            args[0]
+        );
+        std::process::exit(2);
+    }
+    let seed = parse_seed(&args[1])?;
+    let params: Parameters = serde_json::from_reader(std::fs::File::open(&args[2])?)?;
+    let directory = Path::new(&args[3]);
+    std::fs::create_dir_all(directory)?;
+    generate(seed, &params, directory)
+}
@@ -0,0 +1,98 @@
+use crate::ck_dim_eq;
+use crate::crypto::UnsignedTorus;
+
+use super::{FheBool, GateBootstrappingKey};
+
+/// Comparisons of encrypted unsigned integers.
+///
+/// The integers are represented as slices of encrypted bits, the least significant bit first, and
+/// the two operands of a comparison must have the same number of bits. The comparisons are
+/// evaluated with boolean gates, by propagating the result of the comparison of the low bits
+/// towards the most significant bit, in the same way a carry is propagated in an adder.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::crypto::boolean::{FheBool, GateBootstrappingKey, HomomorphicComparison};
+/// use concrete_core::crypto::parameters::PbsParameters;
+/// use concrete_core::crypto::secret::{GlweSecretKey, LweSecretKey};
+/// use concrete_core::crypto::{GlweDimension, LweDimension};
+/// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+/// use concrete_core::math::dispersion::LogStandardDev;
+/// use concrete_core::math::polynomial::PolynomialSize;
+/// let lwe_sk = LweSecretKey::generate(LweDimension(630));
+/// let glwe_sk = GlweSecretKey::generate(GlweDimension(1), PolynomialSize(1024));
+/// let pbs_params =
+///     PbsParameters::new(DecompositionBaseLog(7), DecompositionLevelCount(3)).unwrap();
+/// let key = GateBootstrappingKey::generate(
+///     &lwe_sk,
+///     &glwe_sk,
+///     &pbs_params,
+///     LogStandardDev(-25.),
+///     DecompositionBaseLog(2),
+///     DecompositionLevelCount(8),
+///     LogStandardDev(-15.),
+/// );
+///
+/// let noise = LogStandardDev(-15.);
+/// let encrypt = |value: u8| -> Vec<FheBool<u64>> {
+///     (0..2)
+///         .map(|i| FheBool::encrypt((value >> i) & 1 == 1, &lwe_sk, noise))
+///         .collect()
+/// };
+/// let (two, three) = (encrypt(2), encrypt(3));
+/// assert!(three.greater_than(&two, &key).decrypt(&lwe_sk));
+/// assert!(two.less_than(&three, &key).decrypt(&lwe_sk));
+/// assert!(!two.equal(&three, &key).decrypt(&lwe_sk));
+/// ```
+pub trait HomomorphicComparison<Scalar> {
+    /// Returns an encryption of `true` if `self` is strictly greater than `other`.
+    fn greater_than(&self, other: &Self, key: &GateBootstrappingKey<Scalar>) -> FheBool<Scalar>;
+
+    /// Returns an encryption of `true` if `self` is strictly smaller than `other`.
+    fn less_than(&self, other: &Self, key: &GateBootstrappingKey<Scalar>) -> FheBool<Scalar>;
+
+    /// Returns an encryption of `true` if `self` is equal to `other`.
+    fn equal(&self, other: &Self, key: &GateBootstrappingKey<Scalar>) -> FheBool<Scalar>;
+}
+
+/// # Panics
+///
+/// The comparisons panic if the operands are empty.
+impl<Scalar> HomomorphicComparison<Scalar> for [FheBool<Scalar>]
+where
+    Scalar: UnsignedTorus,
+{
+    fn greater_than(&self, other: &Self, key: &GateBootstrappingKey<Scalar>) -> FheBool<Scalar> {
+        ck_dim_eq!(self.len() => other.len());
+        assert!(!self.is_empty(), "Cannot compare integers without bits.");
+        let mut bits = self.iter().zip(other.iter());
+        // On the least significant bit, a > b only if a = 1 and b = 0.
+        let (a, b) = bits.next().unwrap();
+        let mut greater = a.and(&!b, key);
+        // On the other bits, the comparison is decided by the current bit when the bits differ,
+        // and propagated from the lower bits otherwise.
+        for (a, b) in bits {
+            let decided = a.and(&!b, key);
+            let propagated = a.xnor(b, key).and(&greater, key);
+            greater = decided.or(&propagated, key);
+        }
+        greater
+    }
+
+    fn less_than(&self, other: &Self, key: &GateBootstrappingKey<Scalar>) -> FheBool<Scalar> {
+        other.greater_than(self, key)
+    }
+
+    fn equal(&self, other: &Self, key: &GateBootstrappingKey<Scalar>) -> FheBool<Scalar> {
+        ck_dim_eq!(self.len() => other.len());
+        assert!(!self.is_empty(), "Cannot compare integers without bits.");
+        let mut bits = self.iter().zip(other.iter());
+        let (a, b) = bits.next().unwrap();
+        let mut equal = a.xnor(b, key);
+        for (a, b) in bits {
+            equal = equal.and(&a.xnor(b, key), key);
+        }
+        equal
+    }
+}
@@ -0,0 +1,354 @@
+//! Boolean circuits
+//!
+//! This module implements the evaluation of boolean gates over encrypted bits, following the gate
+//! bootstrapping approach of TFHE. A bit is encrypted as an LWE ciphertext of $\pm\frac{1}{8}$,
+//! and every binary gate is evaluated by a linear combination of its inputs, followed by a
+//! bootstrap which resets the noise, and a keyswitch which brings the result back under the
+//! input secret key. The output of a gate can then be used as the input of any other gate.
+use std::ops::Not;
+
+use fftw::array::AlignedVec;
+
+use crate::ck_dim_eq;
+use crate::crypto::bootstrap::BootstrapKey;
+use crate::crypto::cross::bootstrap;
+use crate::crypto::encoding::{Cleartext, Plaintext};
+use crate::crypto::glwe::GlweCiphertext;
+use crate::crypto::lwe::{LweCiphertext, LweKeyswitchKey};
+use crate::crypto::parameters::PbsParameters;
+use crate::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::crypto::{LweDimension, LweSize, UnsignedTorus};
+use crate::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+use crate::math::dispersion::DispersionParameter;
+use crate::math::fft::Complex64;
+use crate::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor, MemoryFootprint};
+use crate::numeric::Numeric;
+
+#[cfg(test)]
+mod tests;
+
+mod comparison;
+pub use comparison::*;
+
+/// Returns the plaintext value $\frac{1}{8}$ encoding a `true` bit.
+fn encoded_true<Scalar: UnsignedTorus>() -> Scalar {
+    Scalar::ONE << (<Scalar as Numeric>::BITS - 3)
+}
+
+/// The public key used to evaluate boolean gates.
+///
+/// It contains a bootstrapping key in the fourier domain, which encrypts the LWE secret key under
+/// a GLWE secret key, and a keyswitching key, which switches from the flattened GLWE secret key
+/// back to the LWE secret key.
+pub struct GateBootstrappingKey<Scalar> {
+    bootstrap_key: BootstrapKey<AlignedVec<Complex64>>,
+    keyswitch_key: LweKeyswitchKey<Vec<Scalar>>,
+}
+
+impl<Scalar> MemoryFootprint for GateBootstrappingKey<Scalar> {
+    fn heap_size(&self) -> usize {
+        self.bootstrap_key.heap_size() + self.keyswitch_key.heap_size()
+    }
+}
+
+impl<Scalar> GateBootstrappingKey<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    /// Generates a new gate bootstrapping key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::boolean::GateBootstrappingKey;
+    /// use concrete_core::crypto::parameters::PbsParameters;
+    /// use concrete_core::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::crypto::{GlweDimension, LweDimension};
+    /// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let lwe_sk = LweSecretKey::generate(LweDimension(10));
+    /// let glwe_sk = GlweSecretKey::generate(GlweDimension(1), PolynomialSize(256));
+    /// let pbs_params =
+    ///     PbsParameters::new(DecompositionBaseLog(7), DecompositionLevelCount(3)).unwrap();
+    /// let key = GateBootstrappingKey::<u32>::generate(
+    ///     &lwe_sk,
+    ///     &glwe_sk,
+    ///     &pbs_params,
+    ///     LogStandardDev(-25.),
+    ///     DecompositionBaseLog(2),
+    ///     DecompositionLevelCount(8),
+    ///     LogStandardDev(-15.),
+    /// );
+    /// assert_eq!(key.keyswitch_key().after_key_size(), LweDimension(10));
+    /// assert_eq!(key.keyswitch_key().before_key_size(), LweDimension(256));
+    /// ```
+    pub fn generate(
+        lwe_secret_key: &LweSecretKey<Vec<bool>>,
+        glwe_secret_key: &GlweSecretKey<Vec<bool>>,
+        pbs_params: &PbsParameters,
+        bootstrap_noise: impl DispersionParameter,
+        keyswitch_base_log: DecompositionBaseLog,
+        keyswitch_level_count: DecompositionLevelCount,
+        keyswitch_noise: impl DispersionParameter,
+    ) -> Self {
+        let glwe_size = glwe_secret_key.key_size().to_glwe_size();
+        let polynomial_size = glwe_secret_key.polynomial_size();
+        let mut coef_bsk = BootstrapKey::allocate(
+            Scalar::ZERO,
+            glwe_size,
+            polynomial_size,
+            pbs_params.level_count(),
+            pbs_params.base_log(),
+            lwe_secret_key.key_size(),
+        );
+        coef_bsk.fill_with_new_key(lwe_secret_key, glwe_secret_key, bootstrap_noise);
+        let mut bootstrap_key = BootstrapKey::allocate_complex(
+            Complex64::new(0., 0.),
+            glwe_size,
+            polynomial_size,
+            pbs_params.level_count(),
+            pbs_params.base_log(),
+            lwe_secret_key.key_size(),
+        );
+        bootstrap_key.fill_with_forward_fourier(&coef_bsk);
+
+        let flattened_key = LweSecretKey::from_container(glwe_secret_key.as_tensor().as_slice());
+        let mut keyswitch_key = LweKeyswitchKey::allocate(
+            Scalar::ZERO,
+            keyswitch_level_count,
+            keyswitch_base_log,
+            LweDimension(glwe_secret_key.key_size().0 * polynomial_size.0),
+            lwe_secret_key.key_size(),
+        );
+        keyswitch_key.fill_with_keyswitch_key(&flattened_key, lwe_secret_key, keyswitch_noise);
+
+        GateBootstrappingKey {
+            bootstrap_key,
+            keyswitch_key,
+        }
+    }
+
+    /// Creates a gate bootstrapping key from existing keys.
+    ///
+    /// The bootstrapping key must encrypt the LWE secret key under a GLWE secret key, and the
+    /// keyswitching key must switch from the flattened GLWE secret key to the LWE secret key.
+    pub fn from_keys(
+        bootstrap_key: BootstrapKey<AlignedVec<Complex64>>,
+        keyswitch_key: LweKeyswitchKey<Vec<Scalar>>,
+    ) -> Self {
+        ck_dim_eq!(bootstrap_key.key_size() => keyswitch_key.after_key_size());
+        GateBootstrappingKey {
+            bootstrap_key,
+            keyswitch_key,
+        }
+    }
+
+    /// Returns the bootstrapping key.
+    pub fn bootstrap_key(&self) -> &BootstrapKey<AlignedVec<Complex64>> {
+        &self.bootstrap_key
+    }
+
+    /// Returns the keyswitching key.
+    pub fn keyswitch_key(&self) -> &LweKeyswitchKey<Vec<Scalar>> {
+        &self.keyswitch_key
+    }
+
+    /// Bootstraps a ciphertext to a fresh encryption of $\frac{1}{8}$ if its phase is in
+    /// $[0, \frac{1}{2}[$, and of $-\frac{1}{8}$ otherwise.
+    fn bootstrap(&self, input: &LweCiphertext<Vec<Scalar>>) -> FheBool<Scalar> {
+        let polynomial_size = self.bootstrap_key.polynomial_size();
+        let glwe_size = self.bootstrap_key.glwe_size();
+        let mut accumulator = GlweCiphertext::allocate(Scalar::ZERO, polynomial_size, glwe_size);
+        accumulator
+            .get_mut_body()
+            .as_mut_tensor()
+            .fill_with_element(encoded_true());
+        let mut extracted = LweCiphertext::allocate(
+            Scalar::ZERO,
+            LweSize(glwe_size.to_glwe_dimension().0 * polynomial_size.0 + 1),
+        );
+        bootstrap(&mut extracted, input, &self.bootstrap_key, &mut accumulator);
+        let mut output = LweCiphertext::allocate(Scalar::ZERO, self.keyswitch_key.lwe_size());
+        self.keyswitch_key
+            .keyswitch_ciphertext(&mut output, &extracted);
+        FheBool { ciphertext: output }
+    }
+}
+
+/// An encrypted boolean value.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::crypto::boolean::{FheBool, GateBootstrappingKey};
+/// use concrete_core::crypto::parameters::PbsParameters;
+/// use concrete_core::crypto::secret::{GlweSecretKey, LweSecretKey};
+/// use concrete_core::crypto::{GlweDimension, LweDimension};
+/// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+/// use concrete_core::math::dispersion::LogStandardDev;
+/// use concrete_core::math::polynomial::PolynomialSize;
+/// let lwe_sk = LweSecretKey::generate(LweDimension(630));
+/// let glwe_sk = GlweSecretKey::generate(GlweDimension(1), PolynomialSize(1024));
+/// let pbs_params =
+///     PbsParameters::new(DecompositionBaseLog(7), DecompositionLevelCount(3)).unwrap();
+/// let key = GateBootstrappingKey::generate(
+///     &lwe_sk,
+///     &glwe_sk,
+///     &pbs_params,
+///     LogStandardDev(-25.),
+///     DecompositionBaseLog(2),
+///     DecompositionLevelCount(8),
+///     LogStandardDev(-15.),
+/// );
+///
+/// let noise = LogStandardDev(-15.);
+/// let a = FheBool::<u32>::encrypt(true, &lwe_sk, noise);
+/// let b = FheBool::<u32>::encrypt(true, &lwe_sk, noise);
+/// let c = a.nand(&b, &key);
+/// assert!(!c.decrypt(&lwe_sk));
+/// assert!((!&c).decrypt(&lwe_sk));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FheBool<Scalar> {
+    ciphertext: LweCiphertext<Vec<Scalar>>,
+}
+
+impl<Scalar> MemoryFootprint for FheBool<Scalar> {
+    fn heap_size(&self) -> usize {
+        self.ciphertext.heap_size()
+    }
+}
+
+impl<Scalar> FheBool<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    /// Encrypts a bit under an LWE secret key.
+    pub fn encrypt(
+        bit: bool,
+        key: &LweSecretKey<Vec<bool>>,
+        noise: impl DispersionParameter,
+    ) -> Self {
+        let mut ciphertext = LweCiphertext::allocate(Scalar::ZERO, key.key_size().to_lwe_size());
+        let encoded = if bit {
+            encoded_true::<Scalar>()
+        } else {
+            encoded_true::<Scalar>().wrapping_neg()
+        };
+        key.encrypt_lwe(&mut ciphertext, &Plaintext(encoded), noise);
+        FheBool { ciphertext }
+    }
+
+    /// Decrypts the bit with an LWE secret key.
+    pub fn decrypt(&self, key: &LweSecretKey<Vec<bool>>) -> bool {
+        let mut decrypted = Plaintext(Scalar::ZERO);
+        key.decrypt_lwe(&mut decrypted, &self.ciphertext);
+        // The bit is true if the phase lies in the first half of the torus.
+        decrypted.0 >> (<Scalar as Numeric>::BITS - 1) == Scalar::ZERO
+    }
+
+    /// Wraps an existing LWE ciphertext of $\pm\frac{1}{8}$.
+    pub fn from_ciphertext(ciphertext: LweCiphertext<Vec<Scalar>>) -> Self {
+        FheBool { ciphertext }
+    }
+
+    /// Returns the underlying LWE ciphertext.
+    pub fn as_ciphertext(&self) -> &LweCiphertext<Vec<Scalar>> {
+        &self.ciphertext
+    }
+
+    /// Consumes the value and returns the underlying LWE ciphertext.
+    pub fn into_ciphertext(self) -> LweCiphertext<Vec<Scalar>> {
+        self.ciphertext
+    }
+
+    /// Evaluates the gate whose output is `true` when $c + s(a + b)$ lies in the first half of
+    /// the torus.
+    fn linear_gate(
+        &self,
+        other: &FheBool<Scalar>,
+        key: &GateBootstrappingKey<Scalar>,
+        constant: Scalar,
+        factor: Scalar,
+    ) -> FheBool<Scalar> {
+        let mut combination = self.ciphertext.clone();
+        combination.update_with_add(&other.ciphertext);
+        combination.update_with_scalar_mul(Cleartext(factor));
+        let body = combination.get_mut_body();
+        body.0 = body.0.wrapping_add(constant);
+        key.bootstrap(&combination)
+    }
+
+    /// Computes the homomorphic AND of two encrypted bits.
+    pub fn and(&self, other: &FheBool<Scalar>, key: &GateBootstrappingKey<Scalar>) -> Self {
+        let eighth = encoded_true::<Scalar>();
+        self.linear_gate(other, key, eighth.wrapping_neg(), Scalar::ONE)
+    }
+
+    /// Computes the homomorphic NAND of two encrypted bits.
+    pub fn nand(&self, other: &FheBool<Scalar>, key: &GateBootstrappingKey<Scalar>) -> Self {
+        let eighth = encoded_true::<Scalar>();
+        self.linear_gate(other, key, eighth, Scalar::ONE.wrapping_neg())
+    }
+
+    /// Computes the homomorphic OR of two encrypted bits.
+    pub fn or(&self, other: &FheBool<Scalar>, key: &GateBootstrappingKey<Scalar>) -> Self {
+        let eighth = encoded_true::<Scalar>();
+        self.linear_gate(other, key, eighth, Scalar::ONE)
+    }
+
+    /// Computes the homomorphic NOR of two encrypted bits.
+    pub fn nor(&self, other: &FheBool<Scalar>, key: &GateBootstrappingKey<Scalar>) -> Self {
+        let eighth = encoded_true::<Scalar>();
+        self.linear_gate(
+            other,
+            key,
+            eighth.wrapping_neg(),
+            Scalar::ONE.wrapping_neg(),
+        )
+    }
+
+    /// Computes the homomorphic XOR of two encrypted bits.
+    pub fn xor(&self, other: &FheBool<Scalar>, key: &GateBootstrappingKey<Scalar>) -> Self {
+        let quarter = encoded_true::<Scalar>() << 1;
+        self.linear_gate(other, key, quarter, Scalar::TWO)
+    }
+
+    /// Computes the homomorphic XNOR of two encrypted bits.
+    pub fn xnor(&self, other: &FheBool<Scalar>, key: &GateBootstrappingKey<Scalar>) -> Self {
+        let quarter = encoded_true::<Scalar>() << 1;
+        self.linear_gate(
+            other,
+            key,
+            quarter.wrapping_neg(),
+            Scalar::TWO.wrapping_neg(),
+        )
+    }
+}
+
+/// Negating an encrypted bit does not require a bootstrap.
+impl<Scalar> Not for &FheBool<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    type Output = FheBool<Scalar>;
+
+    fn not(self) -> FheBool<Scalar> {
+        let mut ciphertext = self.ciphertext.clone();
+        ciphertext.update_with_neg();
+        FheBool { ciphertext }
+    }
+}
+
+impl<Scalar> Not for FheBool<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    type Output = FheBool<Scalar>;
+
+    fn not(mut self) -> FheBool<Scalar> {
+        self.ciphertext.update_with_neg();
+        self
+    }
+}
@@ -0,0 +1,119 @@
+use crate::crypto::boolean::{FheBool, GateBootstrappingKey, HomomorphicComparison};
+use crate::crypto::parameters::PbsParameters;
+use crate::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::crypto::{GlweDimension, LweDimension, UnsignedTorus};
+use crate::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+use crate::math::dispersion::LogStandardDev;
+use crate::math::polynomial::PolynomialSize;
+
+fn test_gates<T: UnsignedTorus>() {
+    let lwe_sk = LweSecretKey::generate(LweDimension(630));
+    let glwe_sk = GlweSecretKey::generate(GlweDimension(1), PolynomialSize(1024));
+    let pbs_params =
+        PbsParameters::new(DecompositionBaseLog(7), DecompositionLevelCount(3)).unwrap();
+    let key = GateBootstrappingKey::<T>::generate(
+        &lwe_sk,
+        &glwe_sk,
+        &pbs_params,
+        LogStandardDev(-25.),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(8),
+        LogStandardDev(-15.),
+    );
+    let noise = LogStandardDev(-15.);
+
+    for &a in &[false, true] {
+        let enc_a = FheBool::<T>::encrypt(a, &lwe_sk, noise);
+        assert_eq!(enc_a.decrypt(&lwe_sk), a);
+        assert_eq!((!&enc_a).decrypt(&lwe_sk), !a);
+        for &b in &[false, true] {
+            let enc_b = FheBool::<T>::encrypt(b, &lwe_sk, noise);
+            assert_eq!(enc_a.and(&enc_b, &key).decrypt(&lwe_sk), a & b);
+            assert_eq!(enc_a.nand(&enc_b, &key).decrypt(&lwe_sk), !(a & b));
+            assert_eq!(enc_a.or(&enc_b, &key).decrypt(&lwe_sk), a | b);
+            assert_eq!(enc_a.nor(&enc_b, &key).decrypt(&lwe_sk), !(a | b));
+            assert_eq!(enc_a.xor(&enc_b, &key).decrypt(&lwe_sk), a ^ b);
+            assert_eq!(enc_a.xnor(&enc_b, &key).decrypt(&lwe_sk), !(a ^ b));
+        }
+    }
+
+    // The outputs of the gates can be fed to other gates.
+    let t = FheBool::<T>::encrypt(true, &lwe_sk, noise);
+    let f = FheBool::<T>::encrypt(false, &lwe_sk, noise);
+    let mut acc = t.xor(&f, &key);
+    for _ in 0..10 {
+        acc = acc.nand(&t, &key);
+    }
+    assert!(acc.decrypt(&lwe_sk));
+}
+
+#[test]
+fn test_gates_u32() {
+    test_gates::<u32>();
+}
+
+#[test]
+fn test_gates_u64() {
+    test_gates::<u64>();
+}
+
+fn test_comparisons<T: UnsignedTorus>() {
+    let lwe_sk = LweSecretKey::generate(LweDimension(630));
+    let glwe_sk = GlweSecretKey::generate(GlweDimension(1), PolynomialSize(1024));
+    let pbs_params =
+        PbsParameters::new(DecompositionBaseLog(7), DecompositionLevelCount(3)).unwrap();
+    let key = GateBootstrappingKey::<T>::generate(
+        &lwe_sk,
+        &glwe_sk,
+        &pbs_params,
+        LogStandardDev(-25.),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(8),
+        LogStandardDev(-15.),
+    );
+    let noise = LogStandardDev(-15.);
+    let encrypt = |value: u8| -> Vec<FheBool<T>> {
+        (0..2)
+            .map(|i| FheBool::encrypt((value >> i) & 1 == 1, &lwe_sk, noise))
+            .collect()
+    };
+
+    // All the pairs of 2 bits integers.
+    for a in 0..4u8 {
+        let enc_a = encrypt(a);
+        for b in 0..4u8 {
+            let enc_b = encrypt(b);
+            assert_eq!(
+                enc_a.greater_than(&enc_b, &key).decrypt(&lwe_sk),
+                a > b,
+                "{} > {}",
+                a,
+                b
+            );
+            assert_eq!(
+                enc_a.less_than(&enc_b, &key).decrypt(&lwe_sk),
+                a < b,
+                "{} < {}",
+                a,
+                b
+            );
+            assert_eq!(
+                enc_a.equal(&enc_b, &key).decrypt(&lwe_sk),
+                a == b,
+                "{} == {}",
+                a,
+                b
+            );
+        }
+    }
+}
+
+#[test]
+fn test_comparisons_u32() {
+    test_comparisons::<u32>();
+}
+
+#[test]
+fn test_comparisons_u64() {
+    test_comparisons::<u64>();
+}
@@ -0,0 +1,267 @@
+use fftw::array::AlignedVec;
+
+use crate::crypto::parameters::{
+    check_decomposition_bits, GlweParameters, LweParameters, PbsParameters,
+};
+use crate::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::crypto::UnsignedTorus;
+use crate::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+use crate::math::dispersion::{DispersionParameter, LogStandardDev};
+use crate::math::fft::{Complex64, Fft};
+use crate::math::tensor::{AsRefTensor, MemoryFootprint};
+use crate::Error;
+
+#[cfg(feature = "multithread")]
+use super::encrypt_key_bit;
+use super::BootstrapKey;
+
+/// A bootstrapping key produced by a [`BootstrapKeyBuilder`].
+#[derive(Debug)]
+pub enum BuiltBootstrapKey<Scalar> {
+    /// A key in the coefficient domain.
+    Standard(BootstrapKey<Vec<Scalar>>),
+    /// A key in the fourier domain, ready to be used in a bootstrap.
+    Fourier(BootstrapKey<AlignedVec<Complex64>>),
+}
+
+impl<Scalar> BuiltBootstrapKey<Scalar> {
+    /// Returns the key in the coefficient domain, if the builder produced one.
+    pub fn into_standard(self) -> Option<BootstrapKey<Vec<Scalar>>> {
+        match self {
+            BuiltBootstrapKey::Standard(key) => Some(key),
+            BuiltBootstrapKey::Fourier(_) => None,
+        }
+    }
+
+    /// Returns the key in the fourier domain, if the builder produced one.
+    pub fn into_fourier(self) -> Option<BootstrapKey<AlignedVec<Complex64>>> {
+        match self {
+            BuiltBootstrapKey::Standard(_) => None,
+            BuiltBootstrapKey::Fourier(key) => Some(key),
+        }
+    }
+}
+
+impl<Scalar> MemoryFootprint for BuiltBootstrapKey<Scalar> {
+    fn heap_size(&self) -> usize {
+        match self {
+            BuiltBootstrapKey::Standard(key) => key.heap_size(),
+            BuiltBootstrapKey::Fourier(key) => key.heap_size(),
+        }
+    }
+}
+
+/// A builder for bootstrapping keys.
+///
+/// The input key, output key, decomposition and noise must be set before calling
+/// [`BootstrapKeyBuilder::build`]. Optionally, the generation can be made deterministic with
+/// [`BootstrapKeyBuilder::seed`], spread over several threads with
+/// [`BootstrapKeyBuilder::parallel`] (which requires the `multithread` feature), and the key can
+/// be directly converted to the fourier domain with [`BootstrapKeyBuilder::fourier`].
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::crypto::bootstrap::BootstrapKeyBuilder;
+/// use concrete_core::crypto::secret::{GlweSecretKey, LweSecretKey};
+/// use concrete_core::crypto::{GlweDimension, GlweSize, LweDimension};
+/// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+/// use concrete_core::math::dispersion::LogStandardDev;
+/// use concrete_core::math::polynomial::PolynomialSize;
+/// let lwe_sk = LweSecretKey::generate(LweDimension(4));
+/// let glwe_sk = GlweSecretKey::generate(GlweDimension(6), PolynomialSize(256));
+/// let bsk = BootstrapKeyBuilder::new()
+///     .input_key(&lwe_sk)
+///     .output_key(&glwe_sk)
+///     .decomposition(DecompositionBaseLog(5), DecompositionLevelCount(3))
+///     .noise(LogStandardDev(-25.))
+///     .fourier(true)
+///     .build::<u32>()
+///     .unwrap()
+///     .into_fourier()
+///     .unwrap();
+/// assert_eq!(bsk.polynomial_size(), PolynomialSize(256));
+/// assert_eq!(bsk.glwe_size(), GlweSize(7));
+/// assert_eq!(bsk.key_size(), LweDimension(4));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BootstrapKeyBuilder<'keys, LweCont, GlweCont> {
+    input_key: Option<&'keys LweSecretKey<LweCont>>,
+    output_key: Option<&'keys GlweSecretKey<GlweCont>>,
+    decomposition: Option<(DecompositionBaseLog, DecompositionLevelCount)>,
+    noise: Option<LogStandardDev>,
+    seed: Option<u128>,
+    parallel: bool,
+    fourier: bool,
+}
+
+impl<'keys, LweCont, GlweCont> Default for BootstrapKeyBuilder<'keys, LweCont, GlweCont> {
+    fn default() -> Self {
+        BootstrapKeyBuilder {
+            input_key: None,
+            output_key: None,
+            decomposition: None,
+            noise: None,
+            seed: None,
+            parallel: false,
+            fourier: false,
+        }
+    }
+}
+
+impl<'keys, LweCont, GlweCont> BootstrapKeyBuilder<'keys, LweCont, GlweCont> {
+    /// Creates a new builder, with no parameter set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the LWE secret key encrypted in the bootstrapping key.
+    pub fn input_key(mut self, key: &'keys LweSecretKey<LweCont>) -> Self {
+        self.input_key = Some(key);
+        self
+    }
+
+    /// Sets the GLWE secret key used to encrypt the bootstrapping key.
+    pub fn output_key(mut self, key: &'keys GlweSecretKey<GlweCont>) -> Self {
+        self.output_key = Some(key);
+        self
+    }
+
+    /// Sets the decomposition used in the GGSW ciphertexts of the key.
+    pub fn decomposition(
+        mut self,
+        base_log: DecompositionBaseLog,
+        level_count: DecompositionLevelCount,
+    ) -> Self {
+        self.decomposition = Some((base_log, level_count));
+        self
+    }
+
+    /// Sets the noise used to encrypt the key.
+    pub fn noise(mut self, noise: impl DispersionParameter) -> Self {
+        self.noise = Some(LogStandardDev(noise.get_log_standard_dev()));
+        self
+    }
+
+    /// Makes the generation deterministic.
+    ///
+    /// The key is the same as the one generated by [`BootstrapKey::fill_with_new_seeded_key`]
+    /// with the same seed, whether the generation is parallel or not.
+    ///
+    /// The seed determines the masks and the noise of the encryptions, and must be treated as
+    /// secret material: knowing it allows to recover the secret key from the bootstrap key.
+    pub fn seed(mut self, seed: u128) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets whether the GGSW ciphertexts of the key are generated on several threads.
+    ///
+    /// When a seed is set, the result does not depend on the scheduling of the threads, and is
+    /// the same as the key generated sequentially with the same seed.
+    pub fn parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Sets whether the key is converted to the fourier domain.
+    pub fn fourier(mut self, fourier: bool) -> Self {
+        self.fourier = fourier;
+        self
+    }
+
+    /// Generates the bootstrapping key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::MissingParameter`] if one of the input key, output key, decomposition or
+    /// noise was not set, [`Error::InvalidParameter`] if the parameters are not consistent, and
+    /// [`Error::InvalidSize`] if the key is converted to the fourier domain and the fourier
+    /// transforms do not support the polynomials of the output key (see [`Fft::try_new`]).
+    pub fn build<Scalar>(&self) -> Result<BuiltBootstrapKey<Scalar>, Error>
+    where
+        LweSecretKey<LweCont>: AsRefTensor<Element = bool>,
+        GlweSecretKey<GlweCont>: AsRefTensor<Element = bool>,
+        LweCont: Sync,
+        GlweCont: Sync,
+        Scalar: UnsignedTorus + Send + Sync,
+    {
+        let input_key = self.input_key.ok_or(Error::MissingParameter {
+            parameter: "input key",
+        })?;
+        let output_key = self.output_key.ok_or(Error::MissingParameter {
+            parameter: "output key",
+        })?;
+        let (base_log, level_count) = self.decomposition.ok_or(Error::MissingParameter {
+            parameter: "decomposition",
+        })?;
+        let noise = self
+            .noise
+            .ok_or(Error::MissingParameter { parameter: "noise" })?;
+
+        let lwe_params = LweParameters::new(input_key.key_size(), noise)?;
+        let glwe_params =
+            GlweParameters::new(output_key.key_size(), output_key.polynomial_size(), noise)?;
+        let pbs_params = PbsParameters::new(base_log, level_count)?;
+        check_decomposition_bits::<Scalar>(base_log, level_count)?;
+        // Checks that the fourier transforms support the polynomials before generating the key.
+        if self.fourier {
+            Fft::try_new(output_key.polynomial_size())?;
+        }
+        if self.parallel && cfg!(not(feature = "multithread")) {
+            return Err(Error::InvalidParameter {
+                parameter: "parallel",
+                reason: "parallel generation requires the `multithread` feature".into(),
+            });
+        }
+
+        let mut coef_bsk =
+            BootstrapKey::allocate_with(Scalar::ZERO, &lwe_params, &glwe_params, &pbs_params);
+        if self.parallel {
+            #[cfg(feature = "multithread")]
+            par_fill_with_new_key(&mut coef_bsk, input_key, output_key, noise, self.seed);
+        } else if let Some(seed) = self.seed {
+            coef_bsk.fill_with_new_seeded_key(input_key, output_key, noise, seed);
+        } else {
+            coef_bsk.fill_with_new_key(input_key, output_key, noise);
+        }
+
+        if !self.fourier {
+            return Ok(BuiltBootstrapKey::Standard(coef_bsk));
+        }
+        let mut fourier_bsk = BootstrapKey::allocate_complex_with(
+            Complex64::new(0., 0.),
+            &lwe_params,
+            &glwe_params,
+            &pbs_params,
+        );
+        fourier_bsk.fill_with_forward_fourier(&coef_bsk);
+        Ok(BuiltBootstrapKey::Fourier(fourier_bsk))
+    }
+}
+
+/// Fills the GGSW ciphertexts of the key on the rayon thread pool.
+#[cfg(feature = "multithread")]
+fn par_fill_with_new_key<LweCont, GlweCont, Scalar>(
+    bsk: &mut BootstrapKey<Vec<Scalar>>,
+    lwe_secret_key: &LweSecretKey<LweCont>,
+    glwe_secret_key: &GlweSecretKey<GlweCont>,
+    noise: LogStandardDev,
+    seed: Option<u128>,
+) where
+    LweSecretKey<LweCont>: AsRefTensor<Element = bool>,
+    GlweSecretKey<GlweCont>: AsRefTensor<Element = bool>,
+    LweCont: Sync,
+    GlweCont: Sync,
+    Scalar: UnsignedTorus + Send + Sync,
+{
+    use rayon::iter::{ParallelBridge, ParallelIterator};
+
+    bsk.ggsw_iter_mut()
+        .zip(lwe_secret_key.as_tensor().iter())
+        .enumerate()
+        .par_bridge()
+        .for_each(|(index, (mut ggsw, bit))| {
+            encrypt_key_bit(&mut ggsw, *bit, glwe_secret_key, noise, seed, index)
+        });
+}
@@ -3,19 +3,28 @@ use fftw::array::AlignedVec;
 use serde::{Deserialize, Serialize};
 
 use crate::crypto::encoding::Plaintext;
+use crate::crypto::parameters::{GlweParameters, LweParameters, PbsParameters};
 use crate::crypto::{LweDimension, UnsignedTorus};
 use crate::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
 use crate::math::dispersion::DispersionParameter;
 use crate::math::fft::{Complex64, Fft, FourierPolynomial};
 use crate::math::polynomial::{Polynomial, PolynomialSize};
+use crate::math::random;
 use crate::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
 use crate::numeric::Numeric;
-use crate::{ck_dim_div, ck_dim_eq, tensor_traits};
+use crate::{ck_dim_div, ck_dim_eq, tensor_memory_footprint, tensor_traits};
 
 use super::ggsw::GgswCiphertext;
 use super::secret::{GlweSecretKey, LweSecretKey};
 use super::GlweSize;
 
+pub use builder::*;
+
+#[cfg(test)]
+mod tests;
+
+mod builder;
+
 /// A bootstrapping key
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct BootstrapKey<Cont> {
@@ -27,6 +36,7 @@ pub struct BootstrapKey<Cont> {
 }
 
 tensor_traits!(BootstrapKey);
+tensor_memory_footprint!(BootstrapKey);
 
 impl<Scalar> BootstrapKey<Vec<Scalar>> {
     /// Allocates a new bootstrapping key whose polynomials coefficients are all `value`.
@@ -78,6 +88,49 @@ impl<Scalar> BootstrapKey<Vec<Scalar>> {
             poly_size,
         }
     }
+
+    /// Allocates a new bootstrapping key switching from the `lwe_params` instance to the
+    /// `glwe_params` instance, whose polynomials coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::crypto::bootstrap::BootstrapKey;
+    /// use concrete_core::crypto::parameters::{GlweParameters, LweParameters, PbsParameters};
+    /// use concrete_core::crypto::{GlweDimension, GlweSize, LweDimension};
+    /// use concrete_core::math::decomposition::{DecompositionLevelCount, DecompositionBaseLog};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let lwe_params = LweParameters::new(LweDimension(4), LogStandardDev(-15.)).unwrap();
+    /// let glwe_params =
+    ///     GlweParameters::new(GlweDimension(6), PolynomialSize(8), LogStandardDev(-25.)).unwrap();
+    /// let pbs_params =
+    ///     PbsParameters::new(DecompositionBaseLog(5), DecompositionLevelCount(3)).unwrap();
+    /// let bsk = BootstrapKey::allocate_with(9u32, &lwe_params, &glwe_params, &pbs_params);
+    /// assert_eq!(bsk.polynomial_size(), PolynomialSize(8));
+    /// assert_eq!(bsk.glwe_size(), GlweSize(7));
+    /// assert_eq!(bsk.level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(bsk.base_log(), DecompositionBaseLog(5));
+    /// assert_eq!(bsk.key_size(), LweDimension(4));
+    /// ```
+    pub fn allocate_with(
+        value: Scalar,
+        lwe_params: &LweParameters,
+        glwe_params: &GlweParameters,
+        pbs_params: &PbsParameters,
+    ) -> BootstrapKey<Vec<Scalar>>
+    where
+        Scalar: Copy,
+    {
+        BootstrapKey::allocate(
+            value,
+            glwe_params.size(),
+            glwe_params.polynomial_size(),
+            pbs_params.level_count(),
+            pbs_params.base_log(),
+            lwe_params.dimension(),
+        )
+    }
 }
 
 impl BootstrapKey<AlignedVec<Complex64>> {
@@ -125,6 +178,52 @@ impl BootstrapKey<AlignedVec<Complex64>> {
             poly_size,
         }
     }
+
+    /// Allocates a new complex bootstrapping key switching from the `lwe_params` instance to the
+    /// `glwe_params` instance, whose polynomials coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::crypto::bootstrap::BootstrapKey;
+    /// use concrete_core::crypto::parameters::{GlweParameters, LweParameters, PbsParameters};
+    /// use concrete_core::crypto::{GlweDimension, GlweSize, LweDimension};
+    /// use concrete_core::math::decomposition::{DecompositionLevelCount, DecompositionBaseLog};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::fft::Complex64;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let lwe_params = LweParameters::new(LweDimension(4), LogStandardDev(-15.)).unwrap();
+    /// let glwe_params =
+    ///     GlweParameters::new(GlweDimension(6), PolynomialSize(8), LogStandardDev(-25.)).unwrap();
+    /// let pbs_params =
+    ///     PbsParameters::new(DecompositionBaseLog(5), DecompositionLevelCount(3)).unwrap();
+    /// let bsk = BootstrapKey::allocate_complex_with(
+    ///     Complex64::new(9., 8.),
+    ///     &lwe_params,
+    ///     &glwe_params,
+    ///     &pbs_params,
+    /// );
+    /// assert_eq!(bsk.polynomial_size(), PolynomialSize(8));
+    /// assert_eq!(bsk.glwe_size(), GlweSize(7));
+    /// assert_eq!(bsk.level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(bsk.base_log(), DecompositionBaseLog(5));
+    /// assert_eq!(bsk.key_size(), LweDimension(4));
+    /// ```
+    pub fn allocate_complex_with(
+        value: Complex64,
+        lwe_params: &LweParameters,
+        glwe_params: &GlweParameters,
+        pbs_params: &PbsParameters,
+    ) -> Self {
+        BootstrapKey::allocate_complex(
+            value,
+            glwe_params.size(),
+            glwe_params.polynomial_size(),
+            pbs_params.level_count(),
+            pbs_params.base_log(),
+            lwe_params.dimension(),
+        )
+    }
 }
 
 impl<Cont> BootstrapKey<Cont> {
@@ -339,7 +438,7 @@ impl<Cont> BootstrapKey<Cont> {
         GlweSecretKey<RlweCont>: AsRefTensor<Element = bool>,
         Scalar: UnsignedTorus,
     {
-        ck_dim_eq!(self.key_size().0 => lwe_secret_key.key_size().0);
+        ck_dim_eq!(self.key_size() => lwe_secret_key.key_size());
         self.as_mut_tensor()
             .fill_with_element(<Scalar as Numeric>::ZERO);
         for (mut rgsw, sk_scalar) in self.ggsw_iter_mut().zip(lwe_secret_key.as_tensor().iter()) {
@@ -352,6 +451,72 @@ impl<Cont> BootstrapKey<Cont> {
         }
     }
 
+    /// Generate a new bootstrap key from the input parameters and a seed, and fills the current
+    /// container with it.
+    ///
+    /// Each GGSW ciphertext of the key is encrypted with the samples of its own stream of the
+    /// seed, numbered after the position of the encrypted bit. The same key is hence obtained
+    /// whatever the order in which the ciphertexts are generated, which is what the parallel
+    /// generation of the [`BootstrapKeyBuilder`] relies on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::crypto::bootstrap::BootstrapKey;
+    /// use concrete_core::crypto::{GlweSize, LweSize, LweDimension, GlweDimension};
+    /// use concrete_core::math::decomposition::{DecompositionLevelCount, DecompositionBaseLog};
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// use concrete_core::crypto::secret::{LweSecretKey, GlweSecretKey};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(9));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let mut bsk = BootstrapKey::allocate(
+    ///     9u32,
+    ///     glwe_dim.to_glwe_size(),
+    ///     poly_size,
+    ///     dec_lc,
+    ///     dec_bl,
+    ///     lwe_dim
+    /// );
+    /// let mut other = bsk.clone();
+    /// let lwe_sk = LweSecretKey::generate(lwe_dim);
+    /// let glwe_sk = GlweSecretKey::generate(glwe_dim, poly_size);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    /// bsk.fill_with_new_seeded_key(&lwe_sk, &glwe_sk, noise, 42);
+    /// other.fill_with_new_seeded_key(&lwe_sk, &glwe_sk, noise, 42);
+    /// assert_eq!(bsk, other);
+    /// ```
+    pub fn fill_with_new_seeded_key<LweCont, RlweCont, Scalar>(
+        &mut self,
+        lwe_secret_key: &LweSecretKey<LweCont>,
+        glwe_secret_key: &GlweSecretKey<RlweCont>,
+        noise_parameters: impl DispersionParameter,
+        seed: u128,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<LweCont>: AsRefTensor<Element = bool>,
+        GlweSecretKey<RlweCont>: AsRefTensor<Element = bool>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.key_size() => lwe_secret_key.key_size());
+        self.as_mut_tensor()
+            .fill_with_element(<Scalar as Numeric>::ZERO);
+        for (index, (mut rgsw, sk_scalar)) in self
+            .ggsw_iter_mut()
+            .zip(lwe_secret_key.as_tensor().iter())
+            .enumerate()
+        {
+            encrypt_key_bit(
+                &mut rgsw,
+                *sk_scalar,
+                glwe_secret_key,
+                noise_parameters.clone(),
+                Some(seed),
+                index,
+            );
+        }
+    }
+
     /// Generate a new bootstrap key from the input parameters, and fills the current container
     /// with it.
     ///
@@ -393,7 +558,7 @@ impl<Cont> BootstrapKey<Cont> {
         GlweSecretKey<RlweCont>: AsRefTensor<Element = bool>,
         Scalar: UnsignedTorus,
     {
-        ck_dim_eq!(self.key_size().0 => lwe_secret_key.key_size().0);
+        ck_dim_eq!(self.key_size() => lwe_secret_key.key_size());
         for (mut rgsw, sk_scalar) in self.ggsw_iter_mut().zip(lwe_secret_key.as_tensor().iter()) {
             let encoded = if *sk_scalar {
                 Plaintext(Scalar::ONE)
@@ -542,16 +707,30 @@ impl<Cont> BootstrapKey<Cont> {
         // We create an fft transformer
         let mut fft = Fft::new(self.poly_size);
 
-        // We create an aligned buffer
-        let mut fft_buffer = FourierPolynomial::allocate(Complex64::new(0., 0.), self.poly_size);
+        // We create two aligned buffers
+        let mut fft_buffer_1 = FourierPolynomial::allocate(Complex64::new(0., 0.), self.poly_size);
+        let mut fft_buffer_2 = FourierPolynomial::allocate(Complex64::new(0., 0.), self.poly_size);
 
-        // We transform every polynomial into
-        for (mut fourier_poly, coef_poly) in self.fourier_poly_iter_mut().zip(coef_bsk.poly_iter())
-        {
-            fft.forward_as_torus(&mut fft_buffer, &coef_poly);
-            fourier_poly
+        // We transform the polynomials two by two, and the last one alone if their number is odd
+        let mut polys = self.fourier_poly_iter_mut().zip(coef_bsk.poly_iter());
+        while let Some((mut fourier_poly_1, coef_poly_1)) = polys.next() {
+            match polys.next() {
+                Some((mut fourier_poly_2, coef_poly_2)) => {
+                    fft.forward_two_as_torus(
+                        &mut fft_buffer_1,
+                        &mut fft_buffer_2,
+                        &coef_poly_1,
+                        &coef_poly_2,
+                    );
+                    fourier_poly_2
+                        .as_mut_tensor()
+                        .fill_with_one(fft_buffer_2.as_tensor(), |a| *a);
+                }
+                None => fft.forward_as_torus(&mut fft_buffer_1, &coef_poly_1),
+            }
+            fourier_poly_1
                 .as_mut_tensor()
-                .fill_with_one(fft_buffer.as_tensor(), |a| *a);
+                .fill_with_one(fft_buffer_1.as_tensor(), |a| *a);
         }
     }
 
@@ -697,3 +876,30 @@ impl<Cont> BootstrapKey<Cont> {
             .map(|chunk| Polynomial::from_container(chunk.into_container()))
     }
 }
+
+/// Encrypts the bit of the input key at `index` in a GGSW ciphertext of the key.
+///
+/// When a seed is given, the samples are drawn from the stream of the seed numbered after the
+/// position of the bit, which does not depend on the order in which the bits are encrypted.
+fn encrypt_key_bit<GlweCont, Scalar>(
+    ggsw: &mut GgswCiphertext<&mut [Scalar]>,
+    bit: bool,
+    glwe_secret_key: &GlweSecretKey<GlweCont>,
+    noise: impl DispersionParameter,
+    seed: Option<u128>,
+    index: usize,
+) where
+    GlweSecretKey<GlweCont>: AsRefTensor<Element = bool>,
+    Scalar: UnsignedTorus,
+{
+    let encoded = if bit {
+        Plaintext(Scalar::ONE)
+    } else {
+        Plaintext(Scalar::ZERO)
+    };
+    let encrypt = || glwe_secret_key.encrypt_constant_ggsw(ggsw, &encoded, noise);
+    match seed {
+        Some(seed) => random::with_seeded_stream(seed, index as u64, encrypt),
+        None => encrypt(),
+    }
+}
@@ -0,0 +1,195 @@
+use crate::crypto::bootstrap::{BootstrapKey, BootstrapKeyBuilder, BuiltBootstrapKey};
+use crate::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::crypto::{GlweDimension, LweDimension, UnsignedTorus};
+use crate::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+use crate::math::dispersion::LogStandardDev;
+use crate::math::fft::Complex64;
+use crate::math::polynomial::PolynomialSize;
+use crate::math::random;
+use crate::math::tensor::AsRefTensor;
+use crate::Error;
+
+fn test_builder_matches_explicit<T: UnsignedTorus + Send + Sync>() {
+    let lwe_dimension = LweDimension(10);
+    let glwe_dimension = GlweDimension(2);
+    let polynomial_size = PolynomialSize(256);
+    let base_log = DecompositionBaseLog(7);
+    let level_count = DecompositionLevelCount(3);
+    let noise = LogStandardDev(-25.);
+    let seed = random::random_uniform::<u128>();
+
+    let lwe_sk = LweSecretKey::generate(lwe_dimension);
+    let glwe_sk = GlweSecretKey::generate(glwe_dimension, polynomial_size);
+
+    let mut explicit = BootstrapKey::allocate(
+        T::ZERO,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        level_count,
+        base_log,
+        lwe_dimension,
+    );
+    explicit.fill_with_new_seeded_key(&lwe_sk, &glwe_sk, noise, seed);
+    let mut explicit_fourier = BootstrapKey::allocate_complex(
+        Complex64::new(0., 0.),
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        level_count,
+        base_log,
+        lwe_dimension,
+    );
+    explicit_fourier.fill_with_forward_fourier(&explicit);
+
+    let builder = BootstrapKeyBuilder::new()
+        .input_key(&lwe_sk)
+        .output_key(&glwe_sk)
+        .decomposition(base_log, level_count)
+        .noise(noise)
+        .seed(seed);
+    let built = builder.build::<T>().unwrap().into_standard().unwrap();
+    assert_eq!(built, explicit);
+
+    let built_fourier = builder
+        .fourier(true)
+        .build::<T>()
+        .unwrap()
+        .into_fourier()
+        .unwrap();
+    assert_eq!(built_fourier.glwe_size(), explicit_fourier.glwe_size());
+    assert!(built_fourier
+        .as_tensor()
+        .iter()
+        .eq(explicit_fourier.as_tensor().iter()));
+}
+
+#[test]
+fn test_builder_matches_explicit_u32() {
+    test_builder_matches_explicit::<u32>();
+}
+
+#[test]
+fn test_builder_matches_explicit_u64() {
+    test_builder_matches_explicit::<u64>();
+}
+
+#[cfg(feature = "multithread")]
+fn test_builder_parallel_matches_sequential<T: UnsignedTorus + Send + Sync>() {
+    let lwe_sk = LweSecretKey::generate(LweDimension(10));
+    let glwe_sk = GlweSecretKey::generate(GlweDimension(2), PolynomialSize(256));
+    let builder = BootstrapKeyBuilder::new()
+        .input_key(&lwe_sk)
+        .output_key(&glwe_sk)
+        .decomposition(DecompositionBaseLog(7), DecompositionLevelCount(3))
+        .noise(LogStandardDev(-25.))
+        .seed(random::random_uniform());
+    let sequential = builder.build::<T>().unwrap().into_standard().unwrap();
+    let parallel = builder
+        .parallel(true)
+        .build::<T>()
+        .unwrap()
+        .into_standard()
+        .unwrap();
+    assert_eq!(parallel, sequential);
+}
+
+#[cfg(feature = "multithread")]
+#[test]
+fn test_builder_parallel_matches_sequential_u32() {
+    test_builder_parallel_matches_sequential::<u32>();
+}
+
+#[cfg(feature = "multithread")]
+#[test]
+fn test_builder_parallel_matches_sequential_u64() {
+    test_builder_parallel_matches_sequential::<u64>();
+}
+
+fn assert_missing(result: Result<BuiltBootstrapKey<u32>, Error>, expected: &str) {
+    match result {
+        Err(Error::MissingParameter { parameter }) => assert_eq!(parameter, expected),
+        Err(error) => panic!("expected a missing {}, got {}", expected, error),
+        Ok(_) => panic!("expected a missing {}, got a key", expected),
+    }
+}
+
+#[test]
+fn test_builder_missing_parameters() {
+    let lwe_sk = LweSecretKey::generate(LweDimension(10));
+    let glwe_sk = GlweSecretKey::generate(GlweDimension(2), PolynomialSize(256));
+    let (base_log, level_count) = (DecompositionBaseLog(7), DecompositionLevelCount(3));
+    let noise = LogStandardDev(-25.);
+
+    let result = BootstrapKeyBuilder::<Vec<bool>, _>::new()
+        .output_key(&glwe_sk)
+        .decomposition(base_log, level_count)
+        .noise(noise)
+        .build();
+    assert_missing(result, "input key");
+    let result = BootstrapKeyBuilder::<_, Vec<bool>>::new()
+        .input_key(&lwe_sk)
+        .decomposition(base_log, level_count)
+        .noise(noise)
+        .build();
+    assert_missing(result, "output key");
+    let result = BootstrapKeyBuilder::new()
+        .input_key(&lwe_sk)
+        .output_key(&glwe_sk)
+        .noise(noise)
+        .build();
+    assert_missing(result, "decomposition");
+    let result = BootstrapKeyBuilder::new()
+        .input_key(&lwe_sk)
+        .output_key(&glwe_sk)
+        .decomposition(base_log, level_count)
+        .build();
+    assert_missing(result, "noise");
+}
+
+#[test]
+fn test_builder_inconsistent_parameters() {
+    let lwe_sk = LweSecretKey::generate(LweDimension(10));
+    let glwe_sk = GlweSecretKey::generate(GlweDimension(2), PolynomialSize(256));
+    let builder = BootstrapKeyBuilder::new()
+        .input_key(&lwe_sk)
+        .output_key(&glwe_sk)
+        .noise(LogStandardDev(-25.));
+
+    // The decomposition fits in 64 bits, but not in 32 bits.
+    let builder = builder.decomposition(DecompositionBaseLog(8), DecompositionLevelCount(5));
+    assert!(builder.build::<u64>().is_ok());
+    assert!(matches!(
+        builder.build::<u32>(),
+        Err(Error::InvalidParameter {
+            parameter: "decomposition",
+            ..
+        })
+    ));
+
+    let odd_sk = GlweSecretKey::generate(GlweDimension(2), PolynomialSize(100));
+    let builder = builder.output_key(&odd_sk);
+    assert!(matches!(
+        builder.build::<u64>(),
+        Err(Error::InvalidParameter {
+            parameter: "polynomial size",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_builder_unsupported_fourier_size() {
+    // A polynomial of 128 coefficients is a valid GLWE one, but is too small for the fourier
+    // transforms.
+    let lwe_sk = LweSecretKey::generate(LweDimension(10));
+    let glwe_sk = GlweSecretKey::generate(GlweDimension(2), PolynomialSize(128));
+    let builder = BootstrapKeyBuilder::new()
+        .input_key(&lwe_sk)
+        .output_key(&glwe_sk)
+        .decomposition(DecompositionBaseLog(7), DecompositionLevelCount(3))
+        .noise(LogStandardDev(-25.));
+    assert!(builder.build::<u64>().is_ok());
+    assert!(matches!(
+        builder.fourier(true).build::<u64>(),
+        Err(Error::InvalidSize(_))
+    ));
+}
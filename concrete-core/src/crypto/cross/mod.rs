@@ -36,10 +36,12 @@ pub fn external_product<RgswCont, RlweCont, InCont, FftCont1, FftCont2, FftCont3
     FourierPolynomial<FftCont3>: AsMutTensor<Element = Complex64>,
     Scalar: UnsignedTorus,
 {
-    ck_dim_eq!(glwe.polynomial_size().0 => ggsw.polynomial_size().0);
-    ck_dim_eq!(output.polynomial_size().0 => ggsw.polynomial_size().0);
-    ck_dim_eq!(glwe.size().0 => ggsw.glwe_size().0);
-    ck_dim_eq!(output.size().0 => ggsw.glwe_size().0);
+    #[cfg(feature = "metrics")]
+    let _timer = crate::metrics::EXTERNAL_PRODUCT.start();
+    ck_dim_eq!(glwe.polynomial_size() => ggsw.polynomial_size());
+    ck_dim_eq!(output.polynomial_size() => ggsw.polynomial_size());
+    ck_dim_eq!(glwe.size() => ggsw.glwe_size());
+    ck_dim_eq!(output.size() => ggsw.glwe_size());
 
     // We retrieve the parameters from the RGSW.
     let base_log = ggsw.decomposition_base_log().0;
@@ -229,6 +231,131 @@ pub fn cmux<RlweCont0, RlweCont1, RgswCont, FftCont1, FftCont2, FftCont3, Scalar
     external_product(fft, dec_i_fft, tmp_dec_i_fft, res_fft, glwe_0, ggsw, glwe_1);
 }
 
+/// Selection between two GLWE ciphertexts, conditioned on an encrypted bit.
+///
+/// This is a higher-level interface to the [`cmux`] operation: the condition is a GGSW ciphertext
+/// of a bit $b$, and the output encrypts the message of `if_true` when $b = 1$, and the message
+/// of `if_false` when $b = 0$. The output is computed as
+/// $\mathsf{if\_false} + b \cdot (\mathsf{if\_true} - \mathsf{if\_false})$, so its noise is
+/// the one of an external product added to the noise of the selected ciphertext. The inputs are
+/// left untouched.
+///
+/// The condition must encrypt either zero or one. With any other message, the output is a
+/// linear combination of the two inputs, and not a selection.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::crypto::bootstrap::BootstrapKey;
+/// use concrete_core::crypto::cross::HomomorphicMux;
+/// use concrete_core::crypto::encoding::PlaintextList;
+/// use concrete_core::crypto::glwe::GlweCiphertext;
+/// use concrete_core::crypto::secret::{GlweSecretKey, LweSecretKey};
+/// use concrete_core::crypto::{GlweDimension, LweDimension, PlaintextCount};
+/// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+/// use concrete_core::math::dispersion::LogStandardDev;
+/// use concrete_core::math::fft::Fft;
+/// use concrete_core::math::polynomial::PolynomialSize;
+/// use concrete_core::math::tensor::AsRefTensor;
+/// let (dimension, poly_size) = (GlweDimension(1), PolynomialSize(256));
+/// let glwe_key = GlweSecretKey::generate(dimension, poly_size);
+///
+/// // The GGSW encryption of one is the bootstrap key of a single bit set to one.
+/// let mut bsk = BootstrapKey::allocate(
+///     0u64,
+///     dimension.to_glwe_size(),
+///     poly_size,
+///     DecompositionLevelCount(3),
+///     DecompositionBaseLog(7),
+///     LweDimension(1),
+/// );
+/// bsk.fill_with_new_key(
+///     &LweSecretKey::from_container(vec![true]),
+///     &glwe_key,
+///     LogStandardDev(-50.),
+/// );
+/// let condition = bsk.ggsw_iter().next().unwrap();
+///
+/// let encrypt = |message: u64| {
+///     let messages = PlaintextList::allocate(message << 62, PlaintextCount(256));
+///     let mut glwe = GlweCiphertext::allocate(0u64, poly_size, dimension.to_glwe_size());
+///     glwe_key.encrypt_glwe(&mut glwe, &messages, LogStandardDev(-40.));
+///     glwe
+/// };
+/// let (if_true, if_false) = (encrypt(1), encrypt(2));
+///
+/// let mut fft = Fft::new(poly_size);
+/// let selected = HomomorphicMux::select(&condition, &if_true, &if_false, &mut fft);
+/// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(256));
+/// glwe_key.decrypt_glwe(&mut decrypted, &selected);
+/// for decrypted in decrypted.as_tensor().iter() {
+///     assert_eq!(decrypted.wrapping_add(1 << 61) >> 62, 1);
+/// }
+/// ```
+pub trait HomomorphicMux<Scalar> {
+    /// Returns an encryption of the message of `if_true` if the condition encrypts one, and of
+    /// the message of `if_false` if it encrypts zero.
+    fn select<TrueCont, FalseCont>(
+        &self,
+        if_true: &GlweCiphertext<TrueCont>,
+        if_false: &GlweCiphertext<FalseCont>,
+        fft: &mut Fft,
+    ) -> GlweCiphertext<Vec<Scalar>>
+    where
+        GlweCiphertext<TrueCont>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<FalseCont>: AsRefTensor<Element = Scalar>;
+}
+
+/// The condition is a GGSW ciphertext in the coefficient domain, whose fourier transform is
+/// computed on every selection.
+impl<Cont, Scalar> HomomorphicMux<Scalar> for GgswCiphertext<Cont>
+where
+    Self: AsRefTensor<Element = Scalar>,
+    Scalar: UnsignedTorus,
+{
+    fn select<TrueCont, FalseCont>(
+        &self,
+        if_true: &GlweCiphertext<TrueCont>,
+        if_false: &GlweCiphertext<FalseCont>,
+        fft: &mut Fft,
+    ) -> GlweCiphertext<Vec<Scalar>>
+    where
+        GlweCiphertext<TrueCont>: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<FalseCont>: AsRefTensor<Element = Scalar>,
+    {
+        ck_dim_eq!(self.glwe_size() => if_true.size(), if_false.size());
+        ck_dim_eq!(self.polynomial_size() => if_true.polynomial_size(), if_false.polynomial_size());
+        let poly_size = self.polynomial_size();
+        let fourier_ggsw = self.forward_fourier(fft);
+
+        // The cmux stores its result in the ciphertext selected by a zero bit, and overwrites
+        // the other one, so both are copied.
+        let mut result = GlweCiphertext::allocate(Scalar::ZERO, poly_size, self.glwe_size());
+        result.as_mut_tensor().copy_from(if_false.as_tensor());
+        let mut other = GlweCiphertext::allocate(Scalar::ZERO, poly_size, self.glwe_size());
+        other.as_mut_tensor().copy_from(if_true.as_tensor());
+
+        let mut dec_i_fft = FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
+        let mut tmp_dec_i_fft = FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
+        let mut res_fft = vec![
+            FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
+            self.glwe_size().0
+        ];
+        // The container of the fourier ciphertext is given, since the bound on `Self` would
+        // otherwise be used to resolve the one of the ciphertext argument.
+        cmux::<_, _, Vec<Complex64>, _, _, _, _>(
+            fft,
+            &mut dec_i_fft,
+            &mut tmp_dec_i_fft,
+            &mut res_fft,
+            &mut result,
+            &mut other,
+            &fourier_ggsw,
+        );
+        result
+    }
+}
+
 /// Fills the `output` ciphertext with the result of the blind rotation of the bootstrap key by
 /// the LWE ciphertext.
 pub fn blind_rotate<OutCont, LweCont, BskCont, FftCont1, FftCont2, FftCont3, Scalar>(
@@ -315,6 +442,91 @@ pub fn blind_rotate<OutCont, LweCont, BskCont, FftCont1, FftCont2, FftCont3, Sca
     }
 }
 
+/// Returns the blind rotation of a GLWE ciphertext by the phase of an LWE ciphertext.
+///
+/// If `rotation_lwe` encrypts a message whose phase $\varphi$ is rounded to
+/// $\tilde{\varphi} = \lfloor 2N \varphi \rceil$, the output encrypts the message of `glwe`
+/// multiplied by $X^{-\tilde{\varphi}}$. The input ciphertext is left untouched, which allows to
+/// chain the rotations, or to rotate the same accumulator with several LWE ciphertexts. Unlike
+/// [`blind_rotate`], this function allocates its own FFT buffers.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::crypto::bootstrap::BootstrapKey;
+/// use concrete_core::crypto::cross::blind_rotate_glwe;
+/// use concrete_core::crypto::glwe::GlweCiphertext;
+/// use concrete_core::crypto::lwe::LweCiphertext;
+/// use concrete_core::crypto::secret::{GlweSecretKey, LweSecretKey};
+/// use concrete_core::crypto::{GlweDimension, LweDimension};
+/// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+/// use concrete_core::math::dispersion::LogStandardDev;
+/// use concrete_core::math::fft::Complex64;
+/// use concrete_core::math::polynomial::PolynomialSize;
+/// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(256));
+/// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+/// let lwe_sk = LweSecretKey::generate(lwe_dim);
+/// let glwe_sk = GlweSecretKey::generate(glwe_dim, poly_size);
+/// let mut bsk = BootstrapKey::allocate(
+///     0u64,
+///     glwe_dim.to_glwe_size(),
+///     poly_size,
+///     dec_lc,
+///     dec_bl,
+///     lwe_dim,
+/// );
+/// bsk.fill_with_new_key(&lwe_sk, &glwe_sk, LogStandardDev::from_log_standard_dev(-25.));
+/// let mut fourier_bsk = BootstrapKey::allocate_complex(
+///     Complex64::new(0., 0.),
+///     glwe_dim.to_glwe_size(),
+///     poly_size,
+///     dec_lc,
+///     dec_bl,
+///     lwe_dim,
+/// );
+/// fourier_bsk.fill_with_forward_fourier(&bsk);
+/// let glwe = GlweCiphertext::allocate(0u64, poly_size, glwe_dim.to_glwe_size());
+/// let rotation = LweCiphertext::allocate(0u64, lwe_dim.to_lwe_size());
+/// let rotated = blind_rotate_glwe(&glwe, &rotation, &fourier_bsk);
+/// assert_eq!(rotated.size(), glwe.size());
+/// assert_eq!(rotated.polynomial_size(), poly_size);
+/// ```
+pub fn blind_rotate_glwe<GlweCont, LweCont, BskCont, Scalar>(
+    glwe: &GlweCiphertext<GlweCont>,
+    rotation_lwe: &LweCiphertext<LweCont>,
+    bootstrap_key: &BootstrapKey<BskCont>,
+) -> GlweCiphertext<Vec<Scalar>>
+where
+    GlweCiphertext<GlweCont>: AsRefTensor<Element = Scalar>,
+    LweCiphertext<LweCont>: AsRefTensor<Element = Scalar>,
+    BootstrapKey<BskCont>: AsRefTensor<Element = Complex64>,
+    Scalar: UnsignedTorus,
+{
+    ck_dim_eq!(glwe.polynomial_size() => bootstrap_key.polynomial_size());
+    ck_dim_eq!(glwe.size() => bootstrap_key.glwe_size());
+    ck_dim_eq!(rotation_lwe.lwe_size().to_lwe_dimension() => bootstrap_key.key_size());
+    let polynomial_size = glwe.polynomial_size();
+
+    let mut output = GlweCiphertext::allocate(Scalar::ZERO, polynomial_size, glwe.size());
+    output.as_mut_tensor().copy_from(glwe.as_tensor());
+
+    let mut fft = Fft::new(polynomial_size);
+    let mut dec_i_fft = FourierPolynomial::allocate(Complex64::new(0., 0.), polynomial_size);
+    let mut tmp_dec_i_fft = FourierPolynomial::allocate(Complex64::new(0., 0.), polynomial_size);
+    let mut res_fft =
+        vec![FourierPolynomial::allocate(Complex64::new(0., 0.), polynomial_size); glwe.size().0];
+    blind_rotate(
+        &mut fft,
+        &mut dec_i_fft,
+        &mut tmp_dec_i_fft,
+        &mut res_fft,
+        &mut output,
+        rotation_lwe,
+        bootstrap_key,
+    );
+    output
+}
+
 /// Extracts the constant term of a GLWE ciphertext into an LWE ciphertext.
 pub fn constant_sample_extract<LweCont, RlweCont, Scalar>(
     lwe: &mut LweCiphertext<LweCont>,
@@ -404,6 +616,8 @@ pub fn bootstrap<OutCont, InCont, BskCont, AccCont, Scalar>(
     GlweCiphertext<AccCont>: AsMutTensor<Element = Scalar>,
     Scalar: UnsignedTorus,
 {
+    #[cfg(feature = "metrics")]
+    let _timer = crate::metrics::BOOTSTRAP.start();
     let polynomial_size = bootstrap_key.polynomial_size();
     let dimension = bootstrap_key.glwe_size().0 - 1;
 
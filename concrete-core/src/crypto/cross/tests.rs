@@ -3,16 +3,21 @@ use std::fmt::Debug;
 use concrete_npe as npe;
 
 use crate::crypto::bootstrap::BootstrapKey;
-use crate::crypto::cross::{bootstrap, cmux, constant_sample_extract, external_product};
+use crate::crypto::cross::{
+    blind_rotate_glwe, bootstrap, cmux, constant_sample_extract, external_product, HomomorphicMux,
+};
 use crate::crypto::encoding::{Plaintext, PlaintextList};
-use crate::crypto::glwe::GlweCiphertext;
+use crate::crypto::ggsw::GgswCiphertext;
+use crate::crypto::glwe::{GlweCiphertext, GlweList};
 use crate::crypto::lwe::LweCiphertext;
 use crate::crypto::secret::{GlweSecretKey, LweSecretKey};
-use crate::crypto::{GlweDimension, LweDimension, LweSize, PlaintextCount, UnsignedTorus};
+use crate::crypto::{
+    CiphertextCount, GlweDimension, LweDimension, LweSize, PlaintextCount, UnsignedTorus,
+};
 use crate::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
 use crate::math::dispersion::{DispersionParameter, LogStandardDev, Variance};
 use crate::math::fft::{Complex64, Fft, FourierPolynomial};
-use crate::math::polynomial::PolynomialSize;
+use crate::math::polynomial::{MonomialDegree, PolynomialSize};
 use crate::math::random::{fill_with_random_uniform, fill_with_random_uniform_boolean};
 use crate::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor};
 use crate::numeric::{CastFrom, CastInto, Numeric};
@@ -245,6 +250,243 @@ fn test_external_product_generic<T: UnsignedTorus + npe::Cross>() {
     }
 }
 
+fn test_reduce_external_product<T: UnsignedTorus>() {
+    let polynomial_size = PolynomialSize(512);
+    let rlwe_dimension = GlweDimension(1);
+    let level = DecompositionLevelCount(4);
+    let base_log = DecompositionBaseLog(7);
+    let std_dev_bsk = LogStandardDev(-25.);
+    let std_dev_rlwe = LogStandardDev(-25.);
+    let rlwe_sk = GlweSecretKey::generate(rlwe_dimension, polynomial_size);
+    let mut fft = Fft::new(polynomial_size);
+
+    for bits in &[
+        vec![],
+        vec![true],
+        vec![false],
+        vec![true, true, true],
+        vec![true, false, true],
+    ] {
+        // the ggsw ciphertexts of the bits are the ones of a bootstrap key
+        let lwe_sk = LweSecretKey::from_container(bits.clone());
+        let mut coef_bsk = BootstrapKey::allocate(
+            T::ZERO,
+            rlwe_dimension.to_glwe_size(),
+            polynomial_size,
+            level,
+            base_log,
+            LweDimension(bits.len()),
+        );
+        coef_bsk.fill_with_new_key(&lwe_sk, &rlwe_sk, std_dev_bsk);
+        let mut fourier_bsk = BootstrapKey::allocate(
+            Complex64::new(0., 0.),
+            rlwe_dimension.to_glwe_size(),
+            polynomial_size,
+            level,
+            base_log,
+            LweDimension(bits.len()),
+        );
+        fourier_bsk.fill_with_forward_fourier(&coef_bsk);
+        let ggsw_list: Vec<_> = fourier_bsk.ggsw_iter().collect();
+
+        // encrypt 2 bits messages
+        let mut messages = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+        for (i, m) in messages.plaintext_iter_mut().enumerate() {
+            m.0 = T::cast_from((i % 4) as f64) << (T::BITS - 2);
+        }
+        let mut ciphertext =
+            GlweCiphertext::allocate(T::ZERO, polynomial_size, rlwe_dimension.to_glwe_size());
+        rlwe_sk.encrypt_glwe(&mut ciphertext, &messages, std_dev_rlwe);
+
+        let output = GgswCiphertext::reduce_external_product(&ggsw_list, &ciphertext, &mut fft);
+        let mut decrypted = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+        rlwe_sk.decrypt_glwe(&mut decrypted, &output);
+
+        let product = bits.iter().all(|b| *b);
+        for (i, d) in decrypted.plaintext_iter().enumerate() {
+            let decoded = d.0.wrapping_add(T::ONE << (T::BITS - 3)) >> (T::BITS - 2);
+            let expected = if product {
+                T::cast_from((i % 4) as f64)
+            } else {
+                T::ZERO
+            };
+            assert_eq!(decoded, expected);
+        }
+    }
+}
+
+fn test_batch_external_product<T: UnsignedTorus>() {
+    let polynomial_size = PolynomialSize(512);
+    let rlwe_dimension = GlweDimension(2);
+    let level = DecompositionLevelCount(4);
+    let base_log = DecompositionBaseLog(7);
+    let count = CiphertextCount(5);
+    let rlwe_sk = GlweSecretKey::generate(rlwe_dimension, polynomial_size);
+    let mut fft = Fft::new(polynomial_size);
+
+    // the ggsw ciphertext of a random bit is the one of a bootstrap key
+    let mut lwe_sk = LweSecretKey::from_container(vec![false]);
+    fill_with_random_uniform_boolean(&mut lwe_sk);
+    let mut coef_bsk = BootstrapKey::allocate(
+        T::ZERO,
+        rlwe_dimension.to_glwe_size(),
+        polynomial_size,
+        level,
+        base_log,
+        LweDimension(1),
+    );
+    coef_bsk.fill_with_new_key(&lwe_sk, &rlwe_sk, LogStandardDev(-25.));
+    let mut fourier_bsk = BootstrapKey::allocate(
+        Complex64::new(0., 0.),
+        rlwe_dimension.to_glwe_size(),
+        polynomial_size,
+        level,
+        base_log,
+        LweDimension(1),
+    );
+    fourier_bsk.fill_with_forward_fourier(&coef_bsk);
+    let coef_ggsw = coef_bsk.ggsw_iter().next().unwrap();
+    let fourier_ggsw: Vec<_> = fourier_bsk.ggsw_iter().collect();
+
+    let mut messages =
+        PlaintextList::allocate(T::ZERO, PlaintextCount(count.0 * polynomial_size.0));
+    fill_with_random_uniform(&mut messages);
+    let mut ciphertexts = GlweList::allocate(T::ZERO, polynomial_size, rlwe_dimension, count);
+    rlwe_sk.encrypt_glwe_list(&mut ciphertexts, &messages, LogStandardDev(-25.));
+
+    // the batch must give the same results as the products computed one by one
+    let mut output = GlweList::allocate(T::ONE, polynomial_size, rlwe_dimension, count);
+    GgswCiphertext::batch_external_product(&coef_ggsw, &ciphertexts, &mut output, &mut fft);
+    for (ciphertext, result) in ciphertexts.ciphertext_iter().zip(output.ciphertext_iter()) {
+        let expected =
+            GgswCiphertext::reduce_external_product(&fourier_ggsw, &ciphertext, &mut fft);
+        assert_eq!(
+            result.as_tensor().as_slice(),
+            expected.as_tensor().as_slice()
+        );
+    }
+}
+
+fn test_homomorphic_mux<T: UnsignedTorus>() {
+    let polynomial_size = PolynomialSize(512);
+    let rlwe_dimension = GlweDimension(2);
+    let rlwe_sk = GlweSecretKey::generate(rlwe_dimension, polynomial_size);
+    let mut fft = Fft::new(polynomial_size);
+
+    // the messages are encoded on the two most significant bits
+    let encrypt = |message: T| {
+        let messages = PlaintextList::allocate(message << (T::BITS - 2), PlaintextCount(512));
+        let mut ciphertext =
+            GlweCiphertext::allocate(T::ZERO, polynomial_size, rlwe_dimension.to_glwe_size());
+        rlwe_sk.encrypt_glwe(&mut ciphertext, &messages, LogStandardDev(-25.));
+        ciphertext
+    };
+    let if_true = encrypt(T::ONE);
+    let if_false = encrypt(T::TWO);
+
+    for &bit in [false, true].iter() {
+        let lwe_sk = LweSecretKey::from_container(vec![bit]);
+        let mut coef_bsk = BootstrapKey::allocate(
+            T::ZERO,
+            rlwe_dimension.to_glwe_size(),
+            polynomial_size,
+            DecompositionLevelCount(4),
+            DecompositionBaseLog(7),
+            LweDimension(1),
+        );
+        coef_bsk.fill_with_new_key(&lwe_sk, &rlwe_sk, LogStandardDev(-25.));
+        let condition = coef_bsk.ggsw_iter().next().unwrap();
+
+        let selected = condition.select(&if_true, &if_false, &mut fft);
+        let mut decrypted = PlaintextList::allocate(T::ZERO, PlaintextCount(512));
+        rlwe_sk.decrypt_glwe(&mut decrypted, &selected);
+        let expected = if bit { T::ONE } else { T::TWO };
+        for decrypted in decrypted.as_tensor().iter() {
+            let rounded = decrypted.wrapping_add(T::ONE << (T::BITS - 3)) >> (T::BITS - 2);
+            assert_eq!(rounded, expected);
+        }
+    }
+}
+
+fn test_blind_rotate_glwe<T: UnsignedTorus>() {
+    let polynomial_size = PolynomialSize(512);
+    let rlwe_dimension = GlweDimension(1);
+    let lwe_dimension = LweDimension(10);
+    let level = DecompositionLevelCount(4);
+    let base_log = DecompositionBaseLog(7);
+    let std_dev_bsk = LogStandardDev(-25.);
+    let std_dev = LogStandardDev(-25.);
+    let lwe_sk = LweSecretKey::generate(lwe_dimension);
+    let rlwe_sk = GlweSecretKey::generate(rlwe_dimension, polynomial_size);
+    let mut coef_bsk = BootstrapKey::allocate(
+        T::ZERO,
+        rlwe_dimension.to_glwe_size(),
+        polynomial_size,
+        level,
+        base_log,
+        lwe_dimension,
+    );
+    coef_bsk.fill_with_new_key(&lwe_sk, &rlwe_sk, std_dev_bsk);
+    let mut fourier_bsk = BootstrapKey::allocate(
+        Complex64::new(0., 0.),
+        rlwe_dimension.to_glwe_size(),
+        polynomial_size,
+        level,
+        base_log,
+        lwe_dimension,
+    );
+    fourier_bsk.fill_with_forward_fourier(&coef_bsk);
+
+    // encrypt 4 bits messages
+    let mut messages = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    for (i, m) in messages.plaintext_iter_mut().enumerate() {
+        m.0 = T::cast_from((i % 16) as f64) << (T::BITS - 4);
+    }
+    let mut glwe =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, rlwe_dimension.to_glwe_size());
+    rlwe_sk.encrypt_glwe(&mut glwe, &messages, std_dev);
+
+    // the rounding of a torus element to a power of X, as done by the blind rotation
+    let twice_n = 2 * polynomial_size.0;
+    let round = |x: T| -> i64 {
+        let tmp: f64 = x.cast_into() / (<T as Numeric>::MAX.cast_into() + 1.);
+        (tmp * twice_n as f64).round() as i64
+    };
+
+    for _ in 0..5 {
+        let mut plaintext = Tensor::allocate(T::ZERO, 1);
+        fill_with_random_uniform(&mut plaintext);
+        let mut rotation_lwe = LweCiphertext::allocate(T::ZERO, lwe_dimension.to_lwe_size());
+        lwe_sk.encrypt_lwe(
+            &mut rotation_lwe,
+            &Plaintext(*plaintext.get_element(0)),
+            std_dev,
+        );
+
+        // the exact rotation, computed with the secret key
+        let (body, mask) = rotation_lwe.get_body_and_mask();
+        let masked: i64 = mask
+            .mask_element_iter()
+            .zip(lwe_sk.as_tensor().iter())
+            .filter(|(_, s)| **s)
+            .map(|(a, _)| round(*a))
+            .sum();
+        let rotation = (round(body.0) - masked).rem_euclid(twice_n as i64) as usize;
+        let mut expected = PlaintextList::from_container(messages.as_tensor().as_slice().to_vec());
+        expected
+            .as_mut_polynomial()
+            .update_with_wrapping_monic_monomial_mul(MonomialDegree(twice_n - rotation));
+
+        let output = blind_rotate_glwe(&glwe, &rotation_lwe, &fourier_bsk);
+        let mut decrypted = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+        rlwe_sk.decrypt_glwe(&mut decrypted, &output);
+        for (d, e) in decrypted.plaintext_iter().zip(expected.plaintext_iter()) {
+            let decoded = d.0.wrapping_add(T::ONE << (T::BITS - 5)) >> (T::BITS - 4);
+            assert_eq!(decoded, e.0 >> (T::BITS - 4));
+        }
+    }
+}
+
 fn test_cmux_0<T: UnsignedTorus + npe::Cross>() {
     // fix different polynomial degrees
     let degrees = vec![512, 1024, 2048];
@@ -644,6 +886,46 @@ pub fn test_external_product_generic_u64() {
     test_external_product_generic::<u64>()
 }
 
+#[test]
+pub fn test_reduce_external_product_u32() {
+    test_reduce_external_product::<u32>();
+}
+
+#[test]
+pub fn test_reduce_external_product_u64() {
+    test_reduce_external_product::<u64>();
+}
+
+#[test]
+pub fn test_batch_external_product_u32() {
+    test_batch_external_product::<u32>();
+}
+
+#[test]
+pub fn test_batch_external_product_u64() {
+    test_batch_external_product::<u64>();
+}
+
+#[test]
+pub fn test_homomorphic_mux_u32() {
+    test_homomorphic_mux::<u32>();
+}
+
+#[test]
+pub fn test_homomorphic_mux_u64() {
+    test_homomorphic_mux::<u64>();
+}
+
+#[test]
+pub fn test_blind_rotate_glwe_u32() {
+    test_blind_rotate_glwe::<u32>();
+}
+
+#[test]
+pub fn test_blind_rotate_glwe_u64() {
+    test_blind_rotate_glwe::<u64>();
+}
+
 #[test]
 pub fn test_cmux0_u32() {
     test_cmux_0::<u32>();
@@ -1,9 +1,21 @@
 use crate::crypto::CleartextCount;
 use crate::math::tensor::{AsMutTensor, AsRefTensor, Tensor};
 use crate::numeric::Numeric;
-use crate::{ck_dim_div, tensor_traits};
+use crate::{ck_dim_div, tensor_memory_footprint, tensor_traits};
 
 /// A clear, non-encoded, value.
+///
+/// A cleartext is a value of the application, which must go through an [`Encoder`](super::Encoder)
+/// to become a [`Plaintext`](super::Plaintext) before being encrypted. The two types are distinct,
+/// so that an encoded value can not be mistaken for a cleartext:
+///
+/// ```compile_fail
+/// use concrete_core::crypto::encoding::*;
+/// let encoder = RealEncoder { offset: 0. as f64, delta: 10. };
+/// let encoded: Plaintext<u64> = encoder.encode(Cleartext(7.));
+/// // A plaintext can not be encoded a second time.
+/// let twice: Plaintext<u64> = encoder.encode(encoded);
+/// ```
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Cleartext<T: Numeric>(pub T);
 
@@ -13,6 +25,7 @@ pub struct CleartextList<Cont> {
 }
 
 tensor_traits!(CleartextList);
+tensor_memory_footprint!(CleartextList);
 
 impl<Scalar> CleartextList<Vec<Scalar>>
 where
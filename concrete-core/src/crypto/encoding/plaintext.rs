@@ -1,20 +1,71 @@
-use crate::crypto::PlaintextCount;
+#[cfg(feature = "npy")]
+use std::path::Path;
+
+use crate::crypto::{PlaintextCount, UnsignedTorus};
 use crate::math::polynomial::Polynomial;
 use crate::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
-use crate::numeric::Numeric;
-use crate::{ck_dim_div, tensor_traits};
+#[cfg(feature = "npy")]
+use crate::math::tensor::{NpyElement, NpyError};
+use crate::numeric::{Numeric, UnsignedInteger};
+use crate::{ck_dim_div, tensor_memory_footprint, tensor_traits};
 
 /// An plaintext (encoded) value.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct Plaintext<T: Numeric>(pub T);
 
+impl<T> Plaintext<T>
+where
+    T: UnsignedInteger,
+{
+    /// Decodes a message stored in the `n_bits` most significant bits of the plaintext.
+    ///
+    /// The plaintext is rounded to the closest multiple of $2^{q-n}$, where $q$ is the number of
+    /// bits of the integer type, and the resulting $n$ bits are returned. The rounding wraps
+    /// around, as is expected for torus elements.
+    ///
+    /// # Constant-time
+    ///
+    /// The rounding is computed with shifts, additions and masks only, and does not branch on
+    /// the value of the plaintext. Decoded values can then be compared with
+    /// [`UnsignedInteger::ct_eq`] and [`UnsignedInteger::ct_lt`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n_bits` is not smaller than the number of bits of the integer type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::encoding::Plaintext;
+    /// use concrete_core::numeric::UnsignedInteger;
+    /// let plaintext = Plaintext((3u32 << 28).wrapping_add(1 << 20));
+    /// assert_eq!(plaintext.ct_decode_msb(4), 3);
+    /// let plaintext = Plaintext((3u32 << 28).wrapping_sub(1 << 20));
+    /// assert!(plaintext.ct_decode_msb(4).ct_eq(3));
+    /// let plaintext = Plaintext(u32::MAX);
+    /// assert_eq!(plaintext.ct_decode_msb(4), 0);
+    /// ```
+    pub fn ct_decode_msb(&self, n_bits: usize) -> T {
+        assert!(
+            n_bits < T::BITS,
+            "Tried to decode {} bits out of a {} bits plaintext.",
+            n_bits,
+            T::BITS
+        );
+        let shift = T::BITS - n_bits;
+        let rounded = (self.0 >> (shift - 1)).wrapping_add(T::ONE) >> 1;
+        rounded & ((T::ONE << n_bits).wrapping_sub(T::ONE))
+    }
+}
+
 /// A list of plaintexts
 pub struct PlaintextList<Cont> {
     pub(crate) tensor: Tensor<Cont>,
 }
 
 tensor_traits!(PlaintextList);
+tensor_memory_footprint!(PlaintextList);
 
 impl<Scalar> PlaintextList<Vec<Scalar>>
 where
@@ -140,11 +191,53 @@ impl<Cont> PlaintextList<Cont> {
     where
         Self: AsRefTensor,
     {
+        ck_dim_div!(self.count().0 => count.0);
         self.as_tensor()
             .subtensor_iter(count.0)
             .map(|sub| PlaintextList::from_container(sub.into_container()))
     }
 
+    /// Creates an iterator over borrowed sub-lists of `chunk_size` plaintexts.
+    ///
+    /// Unlike [`PlaintextList::sublist_iter`], the number of plaintexts in the list does not
+    /// have to be a multiple of `chunk_size`: the last sub-list holds the remaining plaintexts,
+    /// and may be shorter than the others.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::{PlaintextCount, encoding::*};
+    /// let plain_list = PlaintextList::from_container(vec![1 as u8; 25]);
+    /// let counts: Vec<PlaintextCount> = plain_list
+    ///     .chunks_plaintext(PlaintextCount(10))
+    ///     .map(|chunk| chunk.count())
+    ///     .collect();
+    /// assert_eq!(
+    ///     counts,
+    ///     vec![PlaintextCount(10), PlaintextCount(10), PlaintextCount(5)]
+    /// );
+    /// ```
+    pub fn chunks_plaintext(
+        &self,
+        chunk_size: PlaintextCount,
+    ) -> impl Iterator<Item = PlaintextList<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        assert!(
+            chunk_size.0 > 0,
+            "Cannot split a plaintext list in chunks of zero plaintexts."
+        );
+        self.as_tensor()
+            .as_slice()
+            .chunks(chunk_size.0)
+            .map(PlaintextList::from_container)
+    }
+
     /// Creates an iterator over mutably borrowed sub-lists.
     ///
     /// # Example
@@ -206,4 +299,91 @@ impl<Cont> PlaintextList<Cont> {
     {
         Polynomial::from_container(self.as_mut_tensor().as_mut_slice())
     }
+
+    /// Returns the largest distance between the plaintexts of two lists, taken on the torus.
+    ///
+    /// See [`Tensor::max_wrapping_distance`] for the definition of the distance.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::encoding::*;
+    /// let plain_list = PlaintextList::from_container(vec![1 as u32, u32::MAX]);
+    /// let other_list = PlaintextList::from_container(vec![u32::MAX, 3]);
+    /// assert_eq!(plain_list.max_wrapping_distance(&other_list), 4);
+    /// ```
+    pub fn max_wrapping_distance<OtherCont, Scalar>(
+        &self,
+        other: &PlaintextList<OtherCont>,
+    ) -> Scalar
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        PlaintextList<OtherCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        self.as_tensor().max_wrapping_distance(other.as_tensor())
+    }
+
+    /// Returns whether all the plaintexts of two lists are at most at a given distance on the
+    /// torus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::encoding::*;
+    /// let plain_list = PlaintextList::from_container(vec![1 as u32, u32::MAX]);
+    /// let other_list = PlaintextList::from_container(vec![u32::MAX, 3]);
+    /// assert!(plain_list.all_within_wrapping_distance(&other_list, 4));
+    /// assert!(!plain_list.all_within_wrapping_distance(&other_list, 3));
+    /// ```
+    pub fn all_within_wrapping_distance<OtherCont, Scalar>(
+        &self,
+        other: &PlaintextList<OtherCont>,
+        bound: Scalar,
+    ) -> bool
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        PlaintextList<OtherCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        self.as_tensor()
+            .all_within_wrapping_distance(other.as_tensor(), bound)
+    }
+}
+
+#[cfg(feature = "npy")]
+impl<Cont> PlaintextList<Cont> {
+    /// Writes the plaintexts to a file in the npy format, as a one-dimensional array.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::encoding::PlaintextList;
+    /// use concrete_core::math::tensor::AsRefTensor;
+    /// let plaintexts = PlaintextList::from_container(vec![1u32 << 28, 2 << 28, 3 << 28]);
+    /// let path = std::env::temp_dir().join("concrete_plaintexts_example.npy");
+    /// plaintexts.write_npy(&path).unwrap();
+    /// let recovered: PlaintextList<Vec<u32>> = PlaintextList::read_npy(&path).unwrap();
+    /// assert_eq!(recovered.as_tensor(), plaintexts.as_tensor());
+    /// ```
+    pub fn write_npy<Scalar, P: AsRef<Path>>(&self, path: P) -> Result<(), NpyError>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: NpyElement,
+    {
+        self.as_tensor().write_npy(path)
+    }
+}
+
+#[cfg(feature = "npy")]
+impl<Scalar> PlaintextList<Vec<Scalar>>
+where
+    Scalar: NpyElement,
+{
+    /// Reads a list of plaintexts from a one-dimensional array stored in the npy format.
+    ///
+    /// See [`PlaintextList::write_npy`] for an example.
+    pub fn read_npy<P: AsRef<Path>>(path: P) -> Result<Self, NpyError> {
+        Tensor::read_npy(path).map(PlaintextList::from_tensor)
+    }
 }
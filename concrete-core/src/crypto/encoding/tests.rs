@@ -1,6 +1,7 @@
 use crate::crypto::encoding::{Cleartext, Encoder, Plaintext, RealEncoder};
 use crate::crypto::UnsignedTorus;
-use crate::test_tools::{any_utorus, random_utorus_between};
+use crate::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+use crate::test_tools::{any_utorus, random_usize_between, random_utorus_between};
 
 fn test_encoding_decoding<T: UnsignedTorus>() {
     //! Encodes and decodes random messages
@@ -46,3 +47,64 @@ fn test_encoding_decoding_u32() {
 fn test_encoding_decoding_u64() {
     test_encoding_decoding::<u64>()
 }
+
+fn test_ct_decode_msb<T: UnsignedTorus>() {
+    //! Checks that the constant-time decoding matches the rounding of the decomposition
+    let n_tests = 1000;
+    for _i in 0..n_tests {
+        let n_bits = random_usize_between(1..16);
+        let value: T = any_utorus();
+
+        // decodes with the regular rounding
+        let rounded = value
+            .round_to_closest_multiple(DecompositionBaseLog(n_bits), DecompositionLevelCount(1));
+        let expected = rounded >> (T::BITS - n_bits);
+
+        // test
+        let decoded = Plaintext(value).ct_decode_msb(n_bits);
+        assert_eq!(decoded, expected);
+    }
+}
+
+#[test]
+fn test_ct_decode_msb_u32() {
+    test_ct_decode_msb::<u32>()
+}
+
+#[test]
+fn test_ct_decode_msb_u64() {
+    test_ct_decode_msb::<u64>()
+}
+
+#[test]
+#[should_panic]
+fn test_ct_decode_msb_too_many_bits() {
+    Plaintext(0u32).ct_decode_msb(32);
+}
+
+fn test_ct_comparisons<T: UnsignedTorus>() {
+    //! Checks that the constant-time comparisons match the regular ones
+    let n_tests = 1000;
+    for _i in 0..n_tests {
+        let first: T = any_utorus();
+        let second: T = any_utorus();
+        assert_eq!(first.ct_eq(second), first == second);
+        assert_eq!(first.ct_lt(second), first < second);
+        assert!(first.ct_eq(first));
+        assert!(!first.ct_lt(first));
+        assert_eq!(
+            T::ct_select(first < second, first, second),
+            first.min(second)
+        );
+    }
+}
+
+#[test]
+fn test_ct_comparisons_u32() {
+    test_ct_comparisons::<u32>()
+}
+
+#[test]
+fn test_ct_comparisons_u64() {
+    test_ct_comparisons::<u64>()
+}
@@ -1,12 +1,14 @@
-use crate::crypto::glwe::GlweList;
-use crate::crypto::GlweSize;
+use crate::crypto::cross::external_product;
+use crate::crypto::glwe::{GlweCiphertext, GlweList};
+use crate::crypto::{CiphertextMetadata, GlweSize, LweDimension, UnsignedTorus};
 use crate::math::decomposition::{
     DecompositionBaseLog, DecompositionLevel, DecompositionLevelCount,
 };
-use crate::math::polynomial::PolynomialSize;
+use crate::math::fft::{Complex64, Fft, FourierPolynomial};
+use crate::math::polynomial::{Polynomial, PolynomialSize};
 use crate::math::tensor::AsMutSlice;
 use crate::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
-use crate::{ck_dim_div, tensor_traits};
+use crate::{ck_dim_div, ck_dim_eq, tensor_memory_footprint, tensor_traits};
 
 use super::GgswLevelMatrix;
 
@@ -19,6 +21,7 @@ pub struct GgswCiphertext<Cont> {
 }
 
 tensor_traits!(GgswCiphertext);
+tensor_memory_footprint!(GgswCiphertext);
 
 impl<Scalar> GgswCiphertext<Vec<Scalar>> {
     /// Allocates a new GGSW ciphertext whose coefficients are all `value`.
@@ -363,3 +366,289 @@ impl<Cont> GgswCiphertext<Cont> {
             })
     }
 }
+
+impl<Cont, Scalar> CiphertextMetadata for GgswCiphertext<Cont>
+where
+    Self: AsRefTensor<Element = Scalar>,
+    Scalar: UnsignedTorus,
+{
+    fn scalar_type_bits(&self) -> usize {
+        Scalar::BITS
+    }
+
+    fn lwe_dimension(&self) -> LweDimension {
+        LweDimension((self.rlwe_size.0 - 1) * self.poly_size.0)
+    }
+
+    fn polynomial_size(&self) -> Option<PolynomialSize> {
+        Some(self.poly_size)
+    }
+}
+
+impl<Cont> GgswCiphertext<Cont> {
+    /// Returns the fourier transform of a GGSW ciphertext given in the coefficient domain.
+    pub(crate) fn forward_fourier<Scalar>(&self, fft: &mut Fft) -> GgswCiphertext<Vec<Complex64>>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let poly_size = self.polynomial_size();
+        let mut fourier_ggsw = GgswCiphertext::allocate(
+            Complex64::new(0., 0.),
+            poly_size,
+            self.glwe_size(),
+            self.decomposition_level_count(),
+            self.decomposition_base_log(),
+        );
+        let mut fft_buffer_1 = FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
+        let mut fft_buffer_2 = FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
+        // The polynomials are transformed two by two, and the last one alone if their number is
+        // odd. The iterator borrows the fourier ciphertext until the end of the block.
+        {
+            let mut polys = fourier_ggsw
+                .as_mut_tensor()
+                .subtensor_iter_mut(poly_size.0)
+                .zip(self.as_tensor().subtensor_iter(poly_size.0));
+            while let Some((mut fourier_poly_1, coef_poly_1)) = polys.next() {
+                let coef_poly_1 = Polynomial::from_container(coef_poly_1.into_container());
+                match polys.next() {
+                    Some((mut fourier_poly_2, coef_poly_2)) => {
+                        fft.forward_two_as_torus(
+                            &mut fft_buffer_1,
+                            &mut fft_buffer_2,
+                            &coef_poly_1,
+                            &Polynomial::from_container(coef_poly_2.into_container()),
+                        );
+                        fourier_poly_2.copy_from(fft_buffer_2.as_tensor());
+                    }
+                    None => fft.forward_as_torus(&mut fft_buffer_1, &coef_poly_1),
+                }
+                fourier_poly_1.copy_from(fft_buffer_1.as_tensor());
+            }
+        }
+        fourier_ggsw
+    }
+
+    /// Computes the external products of a GGSW ciphertext with every GLWE ciphertext of a list,
+    /// and stores the results in `output`.
+    ///
+    /// The GGSW ciphertext is given in the coefficient domain. Its fourier transform is computed
+    /// once, and used for all the products, which saves the transforms of
+    /// $(k+1)^2 \cdot \ell$ polynomials for every ciphertext of the list. The previous content of
+    /// `output` is discarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::bootstrap::BootstrapKey;
+    /// use concrete_core::crypto::encoding::PlaintextList;
+    /// use concrete_core::crypto::ggsw::GgswCiphertext;
+    /// use concrete_core::crypto::glwe::GlweList;
+    /// use concrete_core::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::crypto::{CiphertextCount, GlweDimension, LweDimension, PlaintextCount};
+    /// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::fft::Fft;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// use concrete_core::math::tensor::AsRefTensor;
+    /// let (dimension, poly_size) = (GlweDimension(1), PolynomialSize(256));
+    /// let (level, base_log) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let glwe_key = GlweSecretKey::generate(dimension, poly_size);
+    ///
+    /// // The GGSW encryption of one is the bootstrap key of a single bit set to one.
+    /// let mut bsk = BootstrapKey::allocate(
+    ///     0u64,
+    ///     dimension.to_glwe_size(),
+    ///     poly_size,
+    ///     level,
+    ///     base_log,
+    ///     LweDimension(1),
+    /// );
+    /// bsk.fill_with_new_key(
+    ///     &LweSecretKey::from_container(vec![true]),
+    ///     &glwe_key,
+    ///     LogStandardDev(-50.),
+    /// );
+    /// let ggsw = bsk.ggsw_iter().next().unwrap();
+    ///
+    /// let messages = PlaintextList::allocate(1u64 << 62, PlaintextCount(3 * 256));
+    /// let mut glwe_list = GlweList::allocate(0u64, poly_size, dimension, CiphertextCount(3));
+    /// glwe_key.encrypt_glwe_list(&mut glwe_list, &messages, LogStandardDev(-40.));
+    ///
+    /// let mut output = GlweList::allocate(0u64, poly_size, dimension, CiphertextCount(3));
+    /// let mut fft = Fft::new(poly_size);
+    /// GgswCiphertext::batch_external_product(&ggsw, &glwe_list, &mut output, &mut fft);
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(3 * 256));
+    /// glwe_key.decrypt_glwe_list(&mut decrypted, &output);
+    /// for decrypted in decrypted.as_tensor().iter() {
+    ///     assert_eq!(decrypted.wrapping_add(1 << 61) >> 62, 1);
+    /// }
+    /// ```
+    pub fn batch_external_product<Scalar, InCont, OutCont>(
+        ggsw: &Self,
+        glwe_list: &GlweList<InCont>,
+        output: &mut GlweList<OutCont>,
+        fft: &mut Fft,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweList<InCont>: AsRefTensor<Element = Scalar>,
+        GlweList<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(glwe_list.ciphertext_count() => output.ciphertext_count());
+        ck_dim_eq!(glwe_list.glwe_size() => output.glwe_size(), ggsw.glwe_size());
+        ck_dim_eq!(glwe_list.polynomial_size() => output.polynomial_size(), ggsw.polynomial_size());
+        let poly_size = ggsw.polynomial_size();
+
+        // The fourier transform of the ggsw ciphertext, computed once.
+        let fourier_ggsw = ggsw.forward_fourier(fft);
+
+        // The buffers of the external products, shared by all the ciphertexts.
+        let mut input = GlweCiphertext::allocate(Scalar::ZERO, poly_size, ggsw.glwe_size());
+        let mut dec_i_fft = FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
+        let mut tmp_dec_i_fft = FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
+        let mut res_fft = vec![
+            FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
+            ggsw.glwe_size().0
+        ];
+
+        for (mut result, glwe) in output
+            .ciphertext_iter_mut()
+            .zip(glwe_list.ciphertext_iter())
+        {
+            // The external product mutates its input and adds its result to the output.
+            input.as_mut_tensor().copy_from(glwe.as_tensor());
+            result.as_mut_tensor().fill_with_element(Scalar::ZERO);
+            for res_fft_polynomial in res_fft.iter_mut() {
+                res_fft_polynomial
+                    .as_mut_tensor()
+                    .fill_with_element(Complex64::new(0., 0.));
+            }
+            // The container of the fourier ciphertext is given, since the bound on `Self`
+            // would otherwise be used to resolve the one of the ciphertext argument.
+            external_product::<Vec<Complex64>, _, _, _, _, _, _>(
+                fft,
+                &mut dec_i_fft,
+                &mut tmp_dec_i_fft,
+                &mut res_fft,
+                &mut result,
+                &fourier_ggsw,
+                &mut input,
+            );
+        }
+    }
+}
+
+impl<Cont> GgswCiphertext<Cont>
+where
+    Self: AsRefTensor<Element = Complex64>,
+{
+    /// Returns the result of the successive external products of a GLWE ciphertext with a list
+    /// of GGSW ciphertexts in the Fourier domain.
+    ///
+    /// The GLWE ciphertext is multiplied by the first GGSW ciphertext, the result by the second
+    /// one, and so on. If the GGSW ciphertexts encrypt the constants $b_0, \dots, b_{n-1}$, the
+    /// output encrypts the message of the input multiplied by $\prod_i b_i$, which is the
+    /// building block of the FHEW-style gates. The noise grows with every product, and an empty
+    /// list returns a copy of the input.
+    ///
+    /// The temporary buffers used by the external products are allocated once for the whole
+    /// list, and the FFT plan is borrowed so that it can be reused between calls.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::bootstrap::BootstrapKey;
+    /// use concrete_core::crypto::encoding::PlaintextList;
+    /// use concrete_core::crypto::ggsw::GgswCiphertext;
+    /// use concrete_core::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::crypto::{GlweDimension, LweDimension, PlaintextCount};
+    /// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::fft::{Complex64, Fft};
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// use concrete_core::math::tensor::AsRefTensor;
+    /// let (dimension, poly_size) = (GlweDimension(1), PolynomialSize(256));
+    /// let (level, base_log) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// let glwe_key = GlweSecretKey::generate(dimension, poly_size);
+    ///
+    /// // The GGSW ciphertexts of the bits of an LWE key are the ones of a bootstrap key.
+    /// let lwe_key = LweSecretKey::from_container(vec![true, true]);
+    /// let mut coef_bsk = BootstrapKey::allocate(
+    ///     0u64,
+    ///     dimension.to_glwe_size(),
+    ///     poly_size,
+    ///     level,
+    ///     base_log,
+    ///     LweDimension(2),
+    /// );
+    /// coef_bsk.fill_with_new_key(&lwe_key, &glwe_key, LogStandardDev(-50.));
+    /// let mut fourier_bsk = BootstrapKey::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     dimension.to_glwe_size(),
+    ///     poly_size,
+    ///     level,
+    ///     base_log,
+    ///     LweDimension(2),
+    /// );
+    /// fourier_bsk.fill_with_forward_fourier(&coef_bsk);
+    /// let ggsw_list: Vec<_> = fourier_bsk.ggsw_iter().collect();
+    ///
+    /// let messages = PlaintextList::allocate(1u64 << 62, PlaintextCount(256));
+    /// let mut glwe = GlweCiphertext::allocate(0u64, poly_size, dimension.to_glwe_size());
+    /// glwe_key.encrypt_glwe(&mut glwe, &messages, LogStandardDev(-40.));
+    ///
+    /// let mut fft = Fft::new(poly_size);
+    /// let output = GgswCiphertext::reduce_external_product(&ggsw_list, &glwe, &mut fft);
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(256));
+    /// glwe_key.decrypt_glwe(&mut decrypted, &output);
+    /// for decrypted in decrypted.as_tensor().iter() {
+    ///     assert_eq!(decrypted.wrapping_add(1 << 61) >> 62, 1);
+    /// }
+    /// ```
+    pub fn reduce_external_product<Scalar, GlweCont>(
+        ggsw_list: &[Self],
+        glwe: &GlweCiphertext<GlweCont>,
+        fft: &mut Fft,
+    ) -> GlweCiphertext<Vec<Scalar>>
+    where
+        GlweCiphertext<GlweCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let poly_size = glwe.polynomial_size();
+        let mut result = GlweCiphertext::allocate(Scalar::ZERO, poly_size, glwe.size());
+        result.as_mut_tensor().copy_from(glwe.as_tensor());
+        if ggsw_list.is_empty() {
+            return result;
+        }
+
+        let mut input = GlweCiphertext::allocate(Scalar::ZERO, poly_size, glwe.size());
+        let mut dec_i_fft = FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
+        let mut tmp_dec_i_fft = FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
+        let mut res_fft =
+            vec![FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size); glwe.size().0];
+
+        for ggsw in ggsw_list.iter() {
+            // The external product mutates its input and adds its result to the output, so the
+            // previous result becomes the input, and the output is reset.
+            std::mem::swap(&mut input, &mut result);
+            result.as_mut_tensor().fill_with_element(Scalar::ZERO);
+            for res_fft_polynomial in res_fft.iter_mut() {
+                for m in res_fft_polynomial.coefficient_iter_mut() {
+                    *m = Complex64::new(0., 0.);
+                }
+            }
+            external_product(
+                fft,
+                &mut dec_i_fft,
+                &mut tmp_dec_i_fft,
+                &mut res_fft,
+                &mut result,
+                ggsw,
+                &mut input,
+            );
+        }
+        result
+    }
+}
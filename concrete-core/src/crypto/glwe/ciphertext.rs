@@ -1,12 +1,20 @@
+use std::marker::PhantomData;
+
 use serde::{Deserialize, Serialize};
 
+use crate::crypto::lwe::pack_coefficients;
+use crate::crypto::parameters::GlweParameters;
 use crate::crypto::GlweDimension;
 use crate::crypto::GlweSize;
-use crate::math::polynomial::{PolynomialList, PolynomialSize};
+use crate::crypto::UnsignedTorus;
+use crate::crypto::{CiphertextMetadata, LweDimension};
+use crate::math::polynomial::{Polynomial, PolynomialList, PolynomialSize};
 use crate::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
-use crate::tensor_traits;
+use crate::math::torus::switch_modulus;
+use crate::numeric::{CastFrom, CastInto};
+use crate::{ck_dim_eq, tensor_memory_footprint, tensor_traits};
 
-use super::{GlweBody, GlweMask};
+use super::{CompressedGlweCiphertext, GlweBody, GlweMask};
 
 /// An GLWE ciphertext.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -16,6 +24,7 @@ pub struct GlweCiphertext<Cont> {
 }
 
 tensor_traits!(GlweCiphertext);
+tensor_memory_footprint!(GlweCiphertext);
 
 impl<Scalar> GlweCiphertext<Vec<Scalar>> {
     /// Allocates a new GLWE ciphertext, whose body and masks coefficients are all `value`.
@@ -41,6 +50,30 @@ impl<Scalar> GlweCiphertext<Vec<Scalar>> {
     {
         GlweCiphertext::from_container(vec![value; poly_size.0 * size.0], poly_size)
     }
+
+    /// Allocates a new GLWE ciphertext for the given set of GLWE parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::parameters::GlweParameters;
+    /// use concrete_core::crypto::{*, glwe::*};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let params =
+    ///     GlweParameters::new(GlweDimension(99), PolynomialSize(16), LogStandardDev(-25.)).unwrap();
+    /// let glwe_ciphertext = GlweCiphertext::allocate_with(0 as u8, &params);
+    /// assert_eq!(glwe_ciphertext.polynomial_size(), PolynomialSize(16));
+    /// assert_eq!(glwe_ciphertext.mask_size(), GlweDimension(99));
+    /// assert_eq!(glwe_ciphertext.size(), GlweSize(100));
+    /// ```
+    pub fn allocate_with(value: Scalar, params: &GlweParameters) -> GlweCiphertext<Vec<Scalar>>
+    where
+        GlweCiphertext<Vec<Scalar>>: AsMutTensor,
+        Scalar: Copy,
+    {
+        GlweCiphertext::allocate(value, params.polynomial_size(), params.size())
+    }
 }
 
 impl<Cont> GlweCiphertext<Cont> {
@@ -363,4 +396,337 @@ impl<Cont> GlweCiphertext<Cont> {
             poly_size,
         }
     }
+
+    /// Fills the current ciphertext with the result of the modulus switching of a ciphertext
+    /// with a larger modulus.
+    ///
+    /// Every coefficient of the input ciphertext is rounded to the closest value representable
+    /// with the integer type of the current ciphertext, which adds a small rounding noise. See
+    /// [`LweCiphertext::fill_with_modulus_switch`](crate::crypto::lwe::LweCiphertext::fill_with_modulus_switch)
+    /// for more details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::encoding::PlaintextList;
+    /// use concrete_core::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::crypto::secret::GlweSecretKey;
+    /// use concrete_core::crypto::{GlweDimension, PlaintextCount};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// use concrete_core::math::tensor::AsRefTensor;
+    ///
+    /// let secret_key = GlweSecretKey::generate(GlweDimension(2), PolynomialSize(16));
+    /// let noise = LogStandardDev::from_log_standard_dev(-50.);
+    /// let plaintexts = PlaintextList::allocate(3u128 << 120, PlaintextCount(16));
+    /// let mut cipher =
+    ///     GlweCiphertext::allocate(0 as u128, PolynomialSize(16), GlweDimension(2).to_glwe_size());
+    /// secret_key.encrypt_glwe(&mut cipher, &plaintexts, noise);
+    ///
+    /// let mut switched =
+    ///     GlweCiphertext::allocate(0 as u64, PolynomialSize(16), GlweDimension(2).to_glwe_size());
+    /// switched.fill_with_modulus_switch(&cipher);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0 as u64, PlaintextCount(16));
+    /// secret_key.decrypt_glwe(&mut decrypted, &switched);
+    /// for plaintext in decrypted.as_tensor().iter() {
+    ///     assert_eq!(plaintext.wrapping_add(1 << 55) >> 56, 3);
+    /// }
+    /// ```
+    pub fn fill_with_modulus_switch<InputCont, InputScalar, OutputScalar>(
+        &mut self,
+        input: &GlweCiphertext<InputCont>,
+    ) where
+        Self: AsMutTensor<Element = OutputScalar>,
+        GlweCiphertext<InputCont>: AsRefTensor<Element = InputScalar>,
+        InputScalar: UnsignedTorus + CastInto<OutputScalar>,
+        OutputScalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.polynomial_size() => input.polynomial_size());
+        self.as_mut_tensor()
+            .fill_with_one(input.as_tensor(), |coef| switch_modulus(*coef));
+    }
+
+    /// Adds a plaintext polynomial to the body of the ciphertext, leaving the mask untouched.
+    ///
+    /// The resulting ciphertext encrypts the sum of the original plaintext and of the input
+    /// polynomial, without any additional noise. This is typically used to initialize the lookup
+    /// table of a bootstrap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::encoding::PlaintextList;
+    /// use concrete_core::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::crypto::secret::GlweSecretKey;
+    /// use concrete_core::crypto::{GlweDimension, PlaintextCount};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialSize};
+    /// use concrete_core::math::tensor::AsRefTensor;
+    ///
+    /// let secret_key = GlweSecretKey::generate(GlweDimension(2), PolynomialSize(4));
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let plaintexts = PlaintextList::from_container(vec![1u32 << 28, 2 << 28, 3 << 28, 4 << 28]);
+    /// let mut cipher = GlweCiphertext::allocate(0u32, PolynomialSize(4), GlweDimension(2).to_glwe_size());
+    /// secret_key.encrypt_glwe(&mut cipher, &plaintexts, noise);
+    ///
+    /// let added = Polynomial::from_container(vec![1u32 << 28; 4]);
+    /// cipher.add_plaintext_to_body(&added);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0u32, PlaintextCount(4));
+    /// secret_key.decrypt_glwe(&mut decrypted, &cipher);
+    /// let rounded: Vec<u32> = decrypted
+    ///     .as_tensor()
+    ///     .iter()
+    ///     .map(|d| d.wrapping_add(1 << 27) >> 28)
+    ///     .collect();
+    /// assert_eq!(rounded, vec![2, 3, 4, 5]);
+    /// ```
+    pub fn add_plaintext_to_body<PlainCont, Scalar>(
+        &mut self,
+        plaintext_polynomial: &Polynomial<PlainCont>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        Polynomial<PlainCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        self.get_mut_body()
+            .as_mut_polynomial()
+            .update_with_wrapping_add(plaintext_polynomial);
+    }
+
+    /// Adds a precomputed correction polynomial to the body of the ciphertext, and leaves the
+    /// mask unchanged.
+    ///
+    /// This is the mask absorption step of the conversions between key-dependent and
+    /// key-independent representations: the contribution of a part of the mask, evaluated
+    /// beforehand, is moved into the body. The update is the same as the one of
+    /// [`GlweCiphertext::add_plaintext_to_body`], but the correction is usually not a message,
+    /// and changes what the ciphertext decrypts to under a given key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialSize};
+    /// use concrete_core::math::tensor::{AsRefSlice, AsRefTensor};
+    ///
+    /// let mut cipher =
+    ///     GlweCiphertext::from_container(vec![1u32, 2, 3, 4, 5, 6], PolynomialSize(2));
+    /// let correction = Polynomial::from_container(vec![10u32, 0u32.wrapping_sub(6)]);
+    /// cipher.absorb_mask_correction(&correction);
+    /// assert_eq!(cipher.get_mask().as_tensor().as_slice(), &[1, 2, 3, 4]);
+    /// assert_eq!(cipher.get_body().as_tensor().as_slice(), &[15, 0]);
+    /// ```
+    pub fn absorb_mask_correction<PolyCont, Scalar>(&mut self, correction: &Polynomial<PolyCont>)
+    where
+        Self: AsMutTensor<Element = Scalar>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.polynomial_size() => correction.polynomial_size());
+        self.get_mut_body()
+            .as_mut_polynomial()
+            .update_with_wrapping_add(correction);
+    }
+
+    /// Compresses the ciphertext by rounding every coefficient to its `kept_bits` most
+    /// significant bits.
+    ///
+    /// The coefficients of the mask and of the body are bit-packed, and the compressed ciphertext
+    /// takes $\lceil (k+1) \cdot N \cdot kept\\_bits / 8 \rceil$ bytes. As for
+    /// [`LweCiphertext::compress`](crate::crypto::lwe::LweCiphertext::compress), every
+    /// coefficient gets an additional rounding error, uniform over the range of the dropped bits.
+    /// Since each coefficient of the decrypted polynomial accumulates the errors of $k \cdot N$
+    /// mask coefficients, the number of bits to keep can be obtained with
+    /// [`minimum_kept_bits`](crate::crypto::lwe::minimum_kept_bits) called with
+    /// `LweDimension(k * N)`.
+    ///
+    /// A number of kept bits larger than the number of bits of `Scalar`, as `minimum_kept_bits`
+    /// returns for a high precision, keeps every bit of the coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `kept_bits` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::encoding::PlaintextList;
+    /// use concrete_core::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::crypto::lwe::minimum_kept_bits;
+    /// use concrete_core::crypto::secret::GlweSecretKey;
+    /// use concrete_core::crypto::{GlweDimension, LweDimension, PlaintextCount};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// use concrete_core::math::tensor::{AsRefTensor, MemoryFootprint};
+    ///
+    /// let secret_key = GlweSecretKey::generate(GlweDimension(1), PolynomialSize(1024));
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    /// let plaintexts = PlaintextList::from_container(vec![3u64 << 60; 1024]);
+    /// let mut cipher =
+    ///     GlweCiphertext::allocate(0u64, PolynomialSize(1024), GlweDimension(1).to_glwe_size());
+    /// secret_key.encrypt_glwe(&mut cipher, &plaintexts, noise);
+    ///
+    /// let kept_bits = minimum_kept_bits(LweDimension(1024), 4);
+    /// let compressed = cipher.compress(kept_bits);
+    /// assert_eq!(compressed.heap_size(), 2 * 1024 * kept_bits / 8);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(1024));
+    /// secret_key.decrypt_glwe(&mut decrypted, &compressed.decompress());
+    /// for plaintext in decrypted.as_tensor().iter() {
+    ///     assert_eq!(plaintext.wrapping_add(1 << 59) >> 60, 3);
+    /// }
+    /// ```
+    pub fn compress<Scalar>(&self, kept_bits: usize) -> CompressedGlweCiphertext<Scalar>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let kept_bits = kept_bits.min(Scalar::BITS);
+        CompressedGlweCiphertext {
+            data: pack_coefficients(self.as_tensor().as_slice(), kept_bits),
+            kept_bits,
+            glwe_size: self.size(),
+            poly_size: self.poly_size,
+            _scalar: PhantomData,
+        }
+    }
+}
+
+// The LWE dimension is the one of the flattened GLWE secret key, under which the sample
+// extraction outputs its LWE ciphertexts.
+impl<Cont, Scalar> CiphertextMetadata for GlweCiphertext<Cont>
+where
+    Self: AsRefTensor<Element = Scalar>,
+    Scalar: UnsignedTorus,
+{
+    fn scalar_type_bits(&self) -> usize {
+        Scalar::BITS
+    }
+
+    fn lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.mask_size().0 * self.poly_size.0)
+    }
+
+    fn polynomial_size(&self) -> Option<PolynomialSize> {
+        Some(self.poly_size)
+    }
+}
+
+/// Returns the product of a ciphertext with a public integer scalar.
+///
+/// Every polynomial of the mask and of the body is multiplied by `scalar`, and the output
+/// encrypts the input plaintext polynomial multiplied by the scalar. As for its LWE counterpart
+/// [`lwe::scalar_mul`](crate::crypto::lwe::scalar_mul), the noise variance of every coefficient
+/// is multiplied by $scalar^2$, and the same conditions apply: the messages multiplied by the
+/// scalar must fit in the plaintext space, and the amplified noise must stay below the decoding
+/// precision. Otherwise, the ciphertext must be bootstrapped before being used.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::crypto::encoding::PlaintextList;
+/// use concrete_core::crypto::glwe::{scalar_mul, GlweCiphertext};
+/// use concrete_core::crypto::secret::GlweSecretKey;
+/// use concrete_core::crypto::{GlweDimension, PlaintextCount};
+/// use concrete_core::math::dispersion::LogStandardDev;
+/// use concrete_core::math::polynomial::PolynomialSize;
+/// use concrete_core::math::tensor::AsRefTensor;
+///
+/// let secret_key = GlweSecretKey::generate(GlweDimension(2), PolynomialSize(4));
+/// let noise = LogStandardDev::from_log_standard_dev(-25.);
+/// let plaintexts = PlaintextList::from_container(vec![1u32 << 26, 2 << 26, 3 << 26, 4 << 26]);
+/// let mut cipher = GlweCiphertext::allocate(0u32, PolynomialSize(4), GlweDimension(2).to_glwe_size());
+/// secret_key.encrypt_glwe(&mut cipher, &plaintexts, noise);
+///
+/// let product = scalar_mul(&cipher, 5);
+///
+/// let mut decrypted = PlaintextList::allocate(0u32, PlaintextCount(4));
+/// secret_key.decrypt_glwe(&mut decrypted, &product);
+/// let rounded: Vec<u32> = decrypted
+///     .as_tensor()
+///     .iter()
+///     .map(|d| d.wrapping_add(1 << 25) >> 26)
+///     .collect();
+/// assert_eq!(rounded, vec![5, 10, 15, 20]);
+/// ```
+pub fn scalar_mul<Scalar>(
+    ciphertext: &GlweCiphertext<Vec<Scalar>>,
+    scalar: u64,
+) -> GlweCiphertext<Vec<Scalar>>
+where
+    Scalar: UnsignedTorus + CastFrom<u64>,
+{
+    let mut output = ciphertext.clone();
+    output
+        .as_mut_tensor()
+        .update_with_wrapping_scalar_mul(&Scalar::cast_from(scalar));
+    output
+}
+
+/// Multiplies a GLWE ciphertext by a plaintext polynomial, and returns the result in a new
+/// ciphertext.
+///
+/// Every polynomial of the mask and of the body is multiplied by `plaintext`, modulo $(X^N+1)$,
+/// and the output encrypts the input plaintext polynomial multiplied by `plaintext`. As the
+/// plaintext is known, the product is linear in the ciphertext, and does not need any
+/// relinearization. The products are computed with the schoolbook algorithm, in $O(N^2)$.
+///
+/// Each coefficient of the output error is a combination of the $N$ coefficients of the input
+/// error, and its variance is multiplied by $\sum_i p_i^2$, where the $p_i$ are the coefficients
+/// of `plaintext` in centered representation. The plaintext should hence have small coefficients,
+/// as for instance a monomial, which only rotates the encrypted coefficients.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::crypto::encoding::PlaintextList;
+/// use concrete_core::crypto::glwe::{plaintext_mul, GlweCiphertext};
+/// use concrete_core::crypto::secret::GlweSecretKey;
+/// use concrete_core::crypto::{GlweDimension, PlaintextCount};
+/// use concrete_core::math::dispersion::LogStandardDev;
+/// use concrete_core::math::polynomial::{Polynomial, PolynomialSize};
+/// use concrete_core::math::tensor::AsRefTensor;
+///
+/// let secret_key = GlweSecretKey::generate(GlweDimension(2), PolynomialSize(4));
+/// let noise = LogStandardDev::from_log_standard_dev(-25.);
+/// let plaintexts = PlaintextList::from_container(vec![1u32 << 26, 2 << 26, 3 << 26, 4 << 26]);
+/// let mut cipher = GlweCiphertext::allocate(0u32, PolynomialSize(4), GlweDimension(2).to_glwe_size());
+/// secret_key.encrypt_glwe(&mut cipher, &plaintexts, noise);
+///
+/// // We multiply by 2X.
+/// let product = plaintext_mul(&cipher, &Polynomial::from_container(vec![0u32, 2, 0, 0]));
+///
+/// let mut decrypted = PlaintextList::allocate(0u32, PlaintextCount(4));
+/// secret_key.decrypt_glwe(&mut decrypted, &product);
+/// let rounded: Vec<u32> = decrypted
+///     .as_tensor()
+///     .iter()
+///     .map(|d| d.wrapping_add(1 << 25) >> 26)
+///     .collect();
+/// // The leading coefficient wraps around with a negative sign: -8 is 56 modulo 64.
+/// assert_eq!(rounded, vec![56, 2, 4, 6]);
+/// ```
+pub fn plaintext_mul<Scalar>(
+    ciphertext: &GlweCiphertext<Vec<Scalar>>,
+    plaintext: &Polynomial<Vec<Scalar>>,
+) -> GlweCiphertext<Vec<Scalar>>
+where
+    Scalar: UnsignedTorus,
+{
+    ck_dim_eq!(ciphertext.polynomial_size() => plaintext.polynomial_size());
+    let mut output = GlweCiphertext::allocate(
+        Scalar::ZERO,
+        ciphertext.polynomial_size(),
+        ciphertext.size(),
+    );
+    for (mut output_poly, input_poly) in output
+        .as_mut_polynomial_list()
+        .polynomial_iter_mut()
+        .zip(ciphertext.as_polynomial_list().polynomial_iter())
+    {
+        output_poly.fill_with_wrapping_mul(&input_poly, plaintext);
+    }
+    output
 }
@@ -0,0 +1,208 @@
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::crypto::lwe::{check_packed_coefficients, unpack_coefficients};
+use crate::crypto::{GlweSize, UnsignedTorus};
+use crate::math::polynomial::PolynomialSize;
+use crate::math::tensor::MemoryFootprint;
+use crate::Error;
+
+use super::GlweCiphertext;
+
+/// A GLWE ciphertext whose coefficients were rounded to a reduced precision and bit-packed.
+///
+/// This type is obtained with [`GlweCiphertext::compress`], and converted back to a full-width
+/// ciphertext with [`CompressedGlweCiphertext::decompress`].
+///
+/// The deserialization goes through [`CompressedGlweCiphertext::try_new`], and fails on packed
+/// data which could not have been produced by a compression.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    try_from = "UncheckedCompressedGlweCiphertext<Scalar>",
+    bound(deserialize = "Scalar: UnsignedTorus")
+)]
+pub struct CompressedGlweCiphertext<Scalar> {
+    pub(super) data: Vec<u8>,
+    pub(super) kept_bits: usize,
+    pub(super) glwe_size: GlweSize,
+    pub(super) poly_size: PolynomialSize,
+    pub(super) _scalar: PhantomData<Scalar>,
+}
+
+// The fields of a compressed ciphertext, as deserialized before being checked.
+#[derive(Deserialize)]
+#[serde(bound = "")]
+struct UncheckedCompressedGlweCiphertext<Scalar> {
+    data: Vec<u8>,
+    kept_bits: usize,
+    glwe_size: GlweSize,
+    poly_size: PolynomialSize,
+    _scalar: PhantomData<Scalar>,
+}
+
+impl<Scalar> TryFrom<UncheckedCompressedGlweCiphertext<Scalar>> for CompressedGlweCiphertext<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    type Error = Error;
+
+    fn try_from(unchecked: UncheckedCompressedGlweCiphertext<Scalar>) -> Result<Self, Error> {
+        Self::try_new(
+            unchecked.data,
+            unchecked.kept_bits,
+            unchecked.glwe_size,
+            unchecked.poly_size,
+        )
+    }
+}
+
+impl<Scalar> CompressedGlweCiphertext<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    /// Creates a compressed ciphertext from its packed coefficients.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `kept_bits` is zero or larger than the number of bits of `Scalar`, if
+    /// `glwe_size` or `poly_size` is zero, or if `data` does not contain exactly
+    /// `glwe_size * poly_size` packed coefficients.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::glwe::{CompressedGlweCiphertext, GlweCiphertext};
+    /// use concrete_core::crypto::GlweSize;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let ciphertext =
+    ///     GlweCiphertext::from_container(vec![0x1000_0000u32, 0xc000_0000], PolynomialSize(1));
+    /// let compressed =
+    ///     CompressedGlweCiphertext::try_new(vec![0xc1], 4, GlweSize(2), PolynomialSize(1))
+    ///         .unwrap();
+    /// assert_eq!(compressed, ciphertext.compress(4));
+    ///
+    /// let too_short =
+    ///     CompressedGlweCiphertext::<u32>::try_new(vec![], 4, GlweSize(2), PolynomialSize(1));
+    /// assert!(too_short.is_err());
+    /// ```
+    pub fn try_new(
+        data: Vec<u8>,
+        kept_bits: usize,
+        glwe_size: GlweSize,
+        poly_size: PolynomialSize,
+    ) -> Result<Self, Error> {
+        if glwe_size.0 == 0 {
+            return Err(Error::InvalidParameter {
+                parameter: "glwe size",
+                reason: "must be at least 1".into(),
+            });
+        }
+        if poly_size.0 == 0 {
+            return Err(Error::InvalidParameter {
+                parameter: "polynomial size",
+                reason: "must be at least 1".into(),
+            });
+        }
+        let count =
+            glwe_size
+                .0
+                .checked_mul(poly_size.0)
+                .ok_or_else(|| Error::InvalidParameter {
+                    parameter: "ciphertext size",
+                    reason: format!(
+                        "{} polynomials of size {} overflow",
+                        glwe_size.0, poly_size.0
+                    ),
+                })?;
+        check_packed_coefficients::<Scalar>(&data, kept_bits, count)?;
+        Ok(CompressedGlweCiphertext {
+            data,
+            kept_bits,
+            glwe_size,
+            poly_size,
+            _scalar: PhantomData,
+        })
+    }
+
+    /// Returns the size of the ciphertext once decompressed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::crypto::GlweSize;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let ciphertext = GlweCiphertext::allocate(0u32, PolynomialSize(10), GlweSize(3));
+    /// let compressed = ciphertext.compress(12);
+    /// assert_eq!(compressed.size(), GlweSize(3));
+    /// ```
+    pub fn size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    /// Returns the size of the polynomials of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::crypto::GlweSize;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let ciphertext = GlweCiphertext::allocate(0u32, PolynomialSize(10), GlweSize(3));
+    /// let compressed = ciphertext.compress(12);
+    /// assert_eq!(compressed.polynomial_size(), PolynomialSize(10));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the number of bits kept for every coefficient.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::crypto::GlweSize;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let ciphertext = GlweCiphertext::allocate(0u32, PolynomialSize(10), GlweSize(3));
+    /// let compressed = ciphertext.compress(12);
+    /// assert_eq!(compressed.kept_bits(), 12);
+    /// ```
+    pub fn kept_bits(&self) -> usize {
+        self.kept_bits
+    }
+
+    /// Returns a full-width ciphertext, whose dropped low bits are all set to zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let ciphertext =
+    ///     GlweCiphertext::from_container(vec![0x1234_5678u32, 0x0c00_0000], PolynomialSize(1));
+    /// let decompressed = ciphertext.compress(4).decompress();
+    /// assert_eq!(
+    ///     decompressed,
+    ///     GlweCiphertext::from_container(vec![0x1000_0000, 0x1000_0000], PolynomialSize(1))
+    /// );
+    /// ```
+    pub fn decompress(&self) -> GlweCiphertext<Vec<Scalar>> {
+        GlweCiphertext::from_container(
+            unpack_coefficients(
+                &self.data,
+                self.kept_bits,
+                self.glwe_size.0 * self.poly_size.0,
+            ),
+            self.poly_size,
+        )
+    }
+}
+
+impl<Scalar> MemoryFootprint for CompressedGlweCiphertext<Scalar> {
+    fn heap_size(&self) -> usize {
+        self.data.len()
+    }
+}
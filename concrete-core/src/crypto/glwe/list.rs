@@ -1,10 +1,13 @@
 use serde::{Deserialize, Serialize};
 
+use crate::crypto::encoding::PlaintextList;
+use crate::crypto::secret::GlweSecretKey;
 use crate::crypto::GlweDimension;
-use crate::crypto::{CiphertextCount, GlweSize};
+use crate::crypto::{CiphertextCount, GlweSize, UnsignedTorus};
+use crate::math::dispersion::DispersionParameter;
 use crate::math::polynomial::PolynomialSize;
-use crate::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
-use crate::{ck_dim_div, tensor_traits};
+use crate::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
+use crate::{ck_dim_div, tensor_memory_footprint, tensor_traits, Error};
 
 use super::GlweCiphertext;
 
@@ -17,6 +20,7 @@ pub struct GlweList<Cont> {
 }
 
 tensor_traits!(GlweList);
+tensor_memory_footprint!(GlweList);
 
 impl<Scalar> GlweList<Vec<Scalar>>
 where
@@ -60,6 +64,97 @@ where
     }
 }
 
+impl<Scalar> GlweList<Vec<Scalar>>
+where
+    Scalar: UnsignedTorus,
+{
+    /// Allocates a [`GlweList`] and fills it with the encryptions of `plaintexts` under `key`.
+    ///
+    /// The plaintexts are split in chunks of `poly_size` values, each chunk being encrypted in a
+    /// separate ciphertext.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the dimension or the polynomial size of the key differ from `glwe_dim` and
+    /// `poly_size`, or if the number of plaintexts is not a multiple of `poly_size`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::encoding::PlaintextList;
+    /// use concrete_core::crypto::glwe::GlweList;
+    /// use concrete_core::crypto::secret::GlweSecretKey;
+    /// use concrete_core::crypto::{CiphertextCount, GlweDimension};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let key = GlweSecretKey::generate(GlweDimension(256), PolynomialSize(2));
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let plaintexts = PlaintextList::from_container(vec![1000 as u32, 2000, 3000, 4000]);
+    /// let ciphertexts = GlweList::allocate_and_encrypt(
+    ///     &key,
+    ///     &plaintexts,
+    ///     PolynomialSize(2),
+    ///     GlweDimension(256),
+    ///     noise,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(ciphertexts.ciphertext_count(), CiphertextCount(2));
+    ///
+    /// // Three plaintexts can not fill polynomials of size two.
+    /// let plaintexts = PlaintextList::from_container(vec![1000 as u32, 2000, 3000]);
+    /// let ciphertexts = GlweList::allocate_and_encrypt(
+    ///     &key,
+    ///     &plaintexts,
+    ///     PolynomialSize(2),
+    ///     GlweDimension(256),
+    ///     noise,
+    /// );
+    /// assert!(ciphertexts.is_err());
+    /// ```
+    pub fn allocate_and_encrypt<KeyCont, PlainCont>(
+        key: &GlweSecretKey<KeyCont>,
+        plaintexts: &PlaintextList<PlainCont>,
+        poly_size: PolynomialSize,
+        glwe_dim: GlweDimension,
+        noise: impl DispersionParameter,
+    ) -> Result<Self, Error>
+    where
+        GlweSecretKey<KeyCont>: AsRefTensor<Element = bool>,
+        PlaintextList<PlainCont>: AsRefTensor<Element = Scalar>,
+    {
+        for &(parameter, expected, found) in &[
+            ("glwe dimension", glwe_dim.0, key.key_size().0),
+            ("polynomial size", poly_size.0, key.polynomial_size().0),
+        ] {
+            if expected != found {
+                return Err(Error::DimensionMismatch {
+                    parameter,
+                    expected,
+                    found,
+                });
+            }
+        }
+        if poly_size.0 == 0 || !plaintexts.count().0.is_multiple_of(poly_size.0) {
+            return Err(Error::InvalidParameter {
+                parameter: "plaintexts",
+                reason: format!(
+                    "{} plaintexts can not be split in polynomials of size {}",
+                    plaintexts.count().0,
+                    poly_size.0
+                ),
+            });
+        }
+        let mut list = GlweList::allocate(
+            Scalar::ZERO,
+            poly_size,
+            glwe_dim,
+            CiphertextCount(plaintexts.count().0 / poly_size.0),
+        );
+        key.encrypt_glwe_list(&mut list, plaintexts, noise);
+        Ok(list)
+    }
+}
+
 impl<Cont> GlweList<Cont> {
     /// Creates a list from a container of values.
     ///
@@ -257,4 +352,56 @@ impl<Cont> GlweList<Cont> {
             .subtensor_iter_mut(chunks_size)
             .map(move |sub| GlweCiphertext::from_container(sub.into_container(), poly_size))
     }
+
+    /// Swaps the `i`-th and `j`-th ciphertexts of the list in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` or `j` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::glwe::GlweList;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// use concrete_core::crypto::{CiphertextCount, GlweDimension};
+    /// use concrete_core::math::tensor::{AsMutTensor, AsRefTensor};
+    /// let mut list = GlweList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(20),
+    ///     CiphertextCount(30)
+    /// );
+    /// for (i, mut ciphertext) in list.ciphertext_iter_mut().enumerate() {
+    ///     ciphertext.as_mut_tensor().fill_with_element(i as u8);
+    /// }
+    /// list.swap_ciphertexts(3, 7);
+    /// let mut ciphertexts = list.ciphertext_iter();
+    /// assert!(ciphertexts.nth(3).unwrap().as_tensor().iter().all(|a| *a == 7));
+    /// assert!(ciphertexts.nth(3).unwrap().as_tensor().iter().all(|a| *a == 3));
+    /// ```
+    pub fn swap_ciphertexts(&mut self, i: usize, j: usize)
+    where
+        Self: AsMutTensor,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.rlwe_size.0, self.poly_size.0);
+        let count = self.ciphertext_count().0;
+        assert!(
+            i < count && j < count,
+            "Tried to swap ciphertexts {} and {} in a list of {} ciphertexts.",
+            i,
+            j,
+            count
+        );
+        if i == j {
+            return;
+        }
+        let (low, high) = if i < j { (i, j) } else { (j, i) };
+        let size = self.rlwe_size.0 * self.poly_size.0;
+        let (head, tail) = self
+            .as_mut_tensor()
+            .as_mut_slice()
+            .split_at_mut(high * size);
+        head[low * size..(low + 1) * size].swap_with_slice(&mut tail[..size]);
+    }
 }
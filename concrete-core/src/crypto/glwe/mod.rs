@@ -2,6 +2,7 @@
 
 pub use body::*;
 pub use ciphertext::*;
+pub use compressed::*;
 pub use list::*;
 pub use mask::*;
 
@@ -10,5 +11,6 @@ mod tests;
 
 mod body;
 mod ciphertext;
+mod compressed;
 mod list;
 mod mask;
@@ -1,9 +1,19 @@
 use crate::crypto::encoding::PlaintextList;
-use crate::crypto::glwe::GlweList;
+use crate::crypto::glwe::{
+    plaintext_mul, scalar_mul, CompressedGlweCiphertext, GlweCiphertext, GlweList,
+};
+use crate::crypto::lwe::minimum_kept_bits;
 use crate::crypto::secret::GlweSecretKey;
-use crate::crypto::UnsignedTorus;
-use crate::math::dispersion::LogStandardDev;
+use crate::crypto::{
+    CiphertextCount, GlweDimension, GlweSize, LweDimension, PlaintextCount, UnsignedTorus,
+};
+use crate::math::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use crate::math::modular::ModQ;
+use crate::math::polynomial::{Polynomial, PolynomialSize};
 use crate::math::random;
+use crate::math::random::{RandomGenerable, UniformMsb};
+use crate::math::tensor::{AsMutTensor, AsRefTensor, IntoTensor, MemoryFootprint};
+use crate::numeric::CastFrom;
 use crate::test_tools;
 use crate::test_tools::assert_delta_std_dev;
 
@@ -43,3 +53,418 @@ fn test_glwe_encrypt_decrypt_u32() {
 fn test_glwe_encrypt_decrypt_u64() {
     test_glwe::<u64>();
 }
+
+#[test]
+fn test_glwe_encrypt_decrypt_u128() {
+    test_glwe::<u128>();
+}
+
+#[test]
+fn test_glwe_encrypt_decrypt_modular() {
+    // The largest prime smaller than 2^62.
+    const Q: u64 = (1 << 62) - 57;
+    let dimension = test_tools::random_glwe_dimension(20);
+    let polynomial_size = test_tools::random_polynomial_size(200);
+    let noise_parameter = LogStandardDev::from_log_standard_dev(-30.);
+
+    // encrypts random plaintexts
+    let sk = GlweSecretKey::generate(dimension, polynomial_size);
+    let plaintexts = PlaintextList::from_container(
+        (0..polynomial_size.0)
+            .map(|_| random::random_uniform::<ModQ<Q>>())
+            .collect::<Vec<_>>(),
+    );
+    let mut ciphertext =
+        GlweCiphertext::allocate(ModQ::<Q>::ZERO, polynomial_size, dimension.to_glwe_size());
+    sk.encrypt_modular_glwe(&mut ciphertext, &plaintexts, noise_parameter);
+    assert!(ciphertext.as_tensor().iter().all(|c| c.value() < Q));
+
+    // decrypts
+    let mut decryption =
+        PlaintextList::allocate(ModQ::<Q>::ZERO, PlaintextCount(polynomial_size.0));
+    sk.decrypt_modular_glwe(&mut decryption, &ciphertext);
+
+    // the error must be within 5 standard deviations
+    let bound = 5. * noise_parameter.get_standard_dev() * Q as f64;
+    for (dec, plain) in decryption
+        .as_tensor()
+        .iter()
+        .zip(plaintexts.as_tensor().iter())
+    {
+        assert!(((*dec - *plain).to_signed() as f64).abs() <= bound);
+    }
+}
+
+fn test_glwe_list_swap<T: UnsignedTorus>() {
+    let nb_ct = CiphertextCount(test_tools::random_ciphertext_count(20).0 + 2);
+    let dimension = test_tools::random_glwe_dimension(20);
+    let polynomial_size = test_tools::random_polynomial_size(20);
+    let mut list = GlweList::allocate(T::ZERO, polynomial_size, dimension, nb_ct);
+    random::fill_with_random_uniform(&mut list);
+    let original = list.clone();
+
+    let i = test_tools::random_usize_between(0..nb_ct.0);
+    let j = test_tools::random_usize_between(0..nb_ct.0);
+    list.swap_ciphertexts(i, j);
+    for (k, ciphertext) in list.ciphertext_iter().enumerate() {
+        let source = if k == i {
+            j
+        } else if k == j {
+            i
+        } else {
+            k
+        };
+        let expected = original.ciphertext_iter().nth(source).unwrap();
+        assert_eq!(ciphertext.as_tensor(), expected.as_tensor());
+    }
+
+    list.swap_ciphertexts(j, i);
+    assert_eq!(list, original);
+}
+
+#[test]
+fn test_glwe_list_swap_u32() {
+    test_glwe_list_swap::<u32>();
+}
+
+#[test]
+fn test_glwe_list_swap_u64() {
+    test_glwe_list_swap::<u64>();
+}
+
+#[test]
+fn test_glwe_list_swap_u128() {
+    test_glwe_list_swap::<u128>();
+}
+
+fn test_glwe_add_plaintext_to_body<T: UnsignedTorus>() {
+    let dimension = test_tools::random_glwe_dimension(20);
+    let polynomial_size = test_tools::random_polynomial_size(200);
+    let noise_parameter = LogStandardDev::from_log_standard_dev(-20.);
+
+    // encrypts random plaintexts
+    let sk = GlweSecretKey::generate(dimension, polynomial_size);
+    let plaintexts = PlaintextList::from_tensor(random::random_uniform_tensor(polynomial_size.0));
+    let mut ciphertext =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, dimension.to_glwe_size());
+    sk.encrypt_glwe(&mut ciphertext, &plaintexts, noise_parameter);
+
+    // adds a random polynomial to the body
+    let added = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+    );
+    ciphertext.add_plaintext_to_body(&added);
+
+    // decrypts
+    let mut decryption = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    sk.decrypt_glwe(&mut decryption, &ciphertext);
+
+    // test
+    let mut expected = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    expected
+        .as_mut_tensor()
+        .fill_with_two(plaintexts.as_tensor(), added.as_tensor(), |p, a| {
+            p.wrapping_add(*a)
+        });
+    assert_delta_std_dev(&expected, &decryption, noise_parameter);
+}
+
+#[test]
+fn test_glwe_add_plaintext_to_body_u32() {
+    test_glwe_add_plaintext_to_body::<u32>();
+}
+
+#[test]
+fn test_glwe_add_plaintext_to_body_u64() {
+    test_glwe_add_plaintext_to_body::<u64>();
+}
+
+fn test_glwe_scalar_mul<T: UnsignedTorus + CastFrom<u64>>() {
+    let dimension = test_tools::random_glwe_dimension(20);
+    let polynomial_size = test_tools::random_polynomial_size(200);
+    let noise_parameter = LogStandardDev::from_log_standard_dev(-20.);
+    let scalar = test_tools::any_usize() as u64 % 16 + 1;
+
+    // encrypts random plaintexts
+    let sk = GlweSecretKey::generate(dimension, polynomial_size);
+    let plaintexts = PlaintextList::from_tensor(random::random_uniform_tensor(polynomial_size.0));
+    let mut ciphertext =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, dimension.to_glwe_size());
+    sk.encrypt_glwe(&mut ciphertext, &plaintexts, noise_parameter);
+
+    // multiplies and decrypts
+    let product = scalar_mul(&ciphertext, scalar);
+    let mut decryption = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    sk.decrypt_glwe(&mut decryption, &product);
+
+    // test
+    let mut expected = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    expected
+        .as_mut_tensor()
+        .fill_with_one(plaintexts.as_tensor(), |p| {
+            p.wrapping_mul(T::cast_from(scalar))
+        });
+    let output_variance = Variance(noise_parameter.get_variance() * (scalar * scalar) as f64);
+    assert_delta_std_dev(&expected, &decryption, output_variance);
+}
+
+#[test]
+fn test_glwe_scalar_mul_u32() {
+    test_glwe_scalar_mul::<u32>();
+}
+
+#[test]
+fn test_glwe_scalar_mul_u64() {
+    test_glwe_scalar_mul::<u64>();
+}
+
+fn test_glwe_plaintext_mul<T: UnsignedTorus>() {
+    let dimension = test_tools::random_glwe_dimension(20);
+    let polynomial_size = test_tools::random_polynomial_size(200);
+    let noise_parameter = LogStandardDev::from_log_standard_dev(-20.);
+
+    // encrypts random plaintexts
+    let sk = GlweSecretKey::generate(dimension, polynomial_size);
+    let plaintexts = PlaintextList::from_tensor(random::random_uniform_tensor(polynomial_size.0));
+    let mut ciphertext =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, dimension.to_glwe_size());
+    sk.encrypt_glwe(&mut ciphertext, &plaintexts, noise_parameter);
+
+    // generates a plaintext polynomial with non-zero coefficients in [-2, 2]
+    let signed_coefficients: Vec<i64> = (0..polynomial_size.0)
+        .map(|_| [-2, -1, 1, 2][test_tools::any_usize() % 4])
+        .collect();
+    let multiplier = Polynomial::from_container(
+        signed_coefficients
+            .iter()
+            .map(|c| {
+                let abs = T::cast_from(c.abs() as f64);
+                if *c < 0 {
+                    abs.wrapping_neg()
+                } else {
+                    abs
+                }
+            })
+            .collect::<Vec<T>>(),
+    );
+
+    // multiplies and decrypts
+    let product = plaintext_mul(&ciphertext, &multiplier);
+    let mut decryption = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    sk.decrypt_glwe(&mut decryption, &product);
+
+    // test
+    let mut expected = Polynomial::allocate(T::ZERO, polynomial_size);
+    expected.fill_with_wrapping_mul(&plaintexts.as_polynomial(), &multiplier);
+    let expected = PlaintextList::from_container(expected.into_tensor().into_container());
+    let squared_norm: i64 = signed_coefficients.iter().map(|c| c * c).sum();
+    let output_variance = Variance(noise_parameter.get_variance() * squared_norm as f64);
+    assert_delta_std_dev(&expected, &decryption, output_variance);
+}
+
+#[test]
+fn test_glwe_plaintext_mul_u32() {
+    test_glwe_plaintext_mul::<u32>();
+}
+
+#[test]
+fn test_glwe_plaintext_mul_u64() {
+    test_glwe_plaintext_mul::<u64>();
+}
+
+fn test_glwe_ct_decrypt<T: UnsignedTorus>() {
+    // random settings
+    let nb_ct = test_tools::random_ciphertext_count(20);
+    let dimension = test_tools::random_glwe_dimension(20);
+    let polynomial_size = test_tools::random_polynomial_size(200);
+    let noise_parameter = LogStandardDev::from_log_standard_dev(-20.);
+
+    // generates a secret key and encrypts random plaintexts
+    let sk = GlweSecretKey::generate(dimension, polynomial_size);
+    let plaintexts =
+        PlaintextList::from_tensor(random::random_uniform_tensor(nb_ct.0 * polynomial_size.0));
+    let mut ciphertext = GlweList::allocate(T::ZERO, polynomial_size, dimension, nb_ct);
+    sk.encrypt_glwe_list(&mut ciphertext, &plaintexts, noise_parameter);
+
+    // decrypts with both paths
+    for ciphertext in ciphertext.ciphertext_iter() {
+        let mut decryption = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+        sk.decrypt_glwe(&mut decryption, &ciphertext);
+        let mut ct_decryption = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+        sk.ct_decrypt_glwe(&mut ct_decryption, &ciphertext);
+
+        // test
+        assert_eq!(decryption.as_tensor(), ct_decryption.as_tensor());
+    }
+}
+
+#[test]
+fn test_glwe_ct_decrypt_u32() {
+    test_glwe_ct_decrypt::<u32>();
+}
+
+#[test]
+fn test_glwe_ct_decrypt_u64() {
+    test_glwe_ct_decrypt::<u64>();
+}
+
+#[test]
+fn test_glwe_ct_decrypt_u128() {
+    test_glwe_ct_decrypt::<u128>();
+}
+
+fn test_glwe_ct_decrypt_timing<T: UnsignedTorus>() {
+    //! dudect-like test: measures the decryption time of a ciphertext with a null key and with a
+    //! random key, and checks with a Welch t-test that the two distributions can not be told apart.
+    let n_measures = 100_000;
+    let dimension = GlweDimension(1);
+    let polynomial_size = PolynomialSize(256);
+    let noise_parameter = LogStandardDev::from_log_standard_dev(-20.);
+
+    // the two classes of keys
+    let keys = [
+        GlweSecretKey::from_container(vec![false; polynomial_size.0], polynomial_size),
+        GlweSecretKey::generate(dimension, polynomial_size),
+    ];
+
+    let plaintexts = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    let mut ciphertext =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, dimension.to_glwe_size());
+    keys[1].encrypt_glwe(&mut ciphertext, &plaintexts, noise_parameter);
+    let mut decryption = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+
+    // online computation of the mean and variance of each class
+    let mut count = [0f64; 2];
+    let mut mean = [0f64; 2];
+    let mut m2 = [0f64; 2];
+    for _ in 0..n_measures {
+        let class = random::random_uniform_boolean::<bool>() as usize;
+        let start = std::time::Instant::now();
+        keys[class].ct_decrypt_glwe(&mut decryption, &ciphertext);
+        let time = start.elapsed().as_nanos() as f64;
+        count[class] += 1.;
+        let delta = time - mean[class];
+        mean[class] += delta / count[class];
+        m2[class] += delta * (time - mean[class]);
+    }
+    let var = [m2[0] / (count[0] - 1.), m2[1] / (count[1] - 1.)];
+    let t = (mean[0] - mean[1]) / (var[0] / count[0] + var[1] / count[1]).sqrt();
+
+    // test
+    assert!(t.abs() < 4.5, "Timing leakage detected: t = {}", t);
+}
+
+#[test]
+#[ignore]
+fn test_glwe_ct_decrypt_timing_u32() {
+    test_glwe_ct_decrypt_timing::<u32>();
+}
+
+#[test]
+#[ignore]
+fn test_glwe_ct_decrypt_timing_u64() {
+    test_glwe_ct_decrypt_timing::<u64>();
+}
+
+fn test_glwe_compression<T: UnsignedTorus + RandomGenerable<UniformMsb>>() {
+    // random settings
+    let dimension = test_tools::random_glwe_dimension(4);
+    let polynomial_size = test_tools::random_polynomial_size(512);
+    let precision = test_tools::random_usize_between(1..9);
+    let noise_parameter = LogStandardDev::from_log_standard_dev(-25.);
+    let kept_bits = minimum_kept_bits(LweDimension(dimension.0 * polynomial_size.0), precision);
+
+    // encrypts messages of `precision` bits
+    let sk = GlweSecretKey::generate(dimension, polynomial_size);
+    let plaintexts = PlaintextList::from_tensor(random::random_uniform_n_msb_tensor(
+        polynomial_size.0,
+        precision,
+    ));
+    let mut ciphertext =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, dimension.to_glwe_size());
+    sk.encrypt_glwe(&mut ciphertext, &plaintexts, noise_parameter);
+
+    // compresses and checks the size of the packed coefficients
+    let compressed = ciphertext.compress(kept_bits);
+    assert_eq!(compressed.size(), dimension.to_glwe_size());
+    assert_eq!(compressed.polynomial_size(), polynomial_size);
+    assert_eq!(
+        compressed.heap_size(),
+        ((dimension.0 + 1) * polynomial_size.0 * kept_bits).div_ceil(8)
+    );
+    assert!(compressed.heap_size() < ciphertext.heap_size());
+
+    // decrypts the decompressed ciphertext
+    let mut decryption = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    sk.decrypt_glwe(&mut decryption, &compressed.decompress());
+
+    // test
+    let shift = T::BITS - precision;
+    let decode = |p: &T| p.wrapping_add(T::ONE << (shift - 1)) >> shift;
+    for (expected, decrypted) in plaintexts
+        .as_tensor()
+        .iter()
+        .zip(decryption.as_tensor().iter())
+    {
+        assert_eq!(decode(expected), decode(decrypted));
+    }
+}
+
+#[test]
+fn test_glwe_compression_u32() {
+    test_glwe_compression::<u32>();
+}
+
+#[test]
+fn test_glwe_compression_u64() {
+    test_glwe_compression::<u64>();
+}
+
+fn test_glwe_compressed_deserialization<T: UnsignedTorus>() {
+    let glwe_size = test_tools::random_glwe_dimension(4).to_glwe_size();
+    let polynomial_size = test_tools::random_polynomial_size(512);
+    let kept_bits = test_tools::random_usize_between(1..T::BITS + 1);
+    let mut ciphertext = GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_size);
+    random::fill_with_random_uniform(&mut ciphertext);
+    let compressed = ciphertext.compress(kept_bits);
+
+    // a valid compressed ciphertext goes through the serialization
+    let bytes = bincode::serialize(&compressed).unwrap();
+    let deserialized: CompressedGlweCiphertext<T> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(deserialized, compressed);
+
+    // the fields are serialized in the same way as a tuple, which allows to forge invalid ones
+    let packed_len = (glwe_size.0 * polynomial_size.0 * kept_bits).div_ceil(8);
+    let invalid_fields = [
+        (vec![0u8; packed_len], 0, glwe_size, polynomial_size),
+        (
+            vec![0u8; packed_len],
+            T::BITS + 1,
+            glwe_size,
+            polynomial_size,
+        ),
+        (
+            vec![0u8; packed_len - 1],
+            kept_bits,
+            glwe_size,
+            polynomial_size,
+        ),
+        (vec![], kept_bits, GlweSize(0), polynomial_size),
+        (vec![], kept_bits, glwe_size, PolynomialSize(0)),
+        (vec![], kept_bits, GlweSize(usize::MAX), PolynomialSize(2)),
+    ];
+    for fields in invalid_fields.iter() {
+        let bytes = bincode::serialize(fields).unwrap();
+        assert!(bincode::deserialize::<CompressedGlweCiphertext<T>>(&bytes).is_err());
+    }
+}
+
+#[test]
+fn test_glwe_compressed_deserialization_u32() {
+    test_glwe_compressed_deserialization::<u32>();
+}
+
+#[test]
+fn test_glwe_compressed_deserialization_u64() {
+    test_glwe_compressed_deserialization::<u64>();
+}
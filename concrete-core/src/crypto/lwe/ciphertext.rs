@@ -1,13 +1,19 @@
+use std::marker::PhantomData;
+
 use serde::{Deserialize, Serialize};
 
 use crate::crypto::encoding::{Cleartext, CleartextList, Plaintext};
+use crate::crypto::parameters::LweParameters;
 use crate::crypto::secret::LweSecretKey;
-use crate::crypto::{LweDimension, LweSize, UnsignedTorus};
-use crate::math::tensor::{AsMutTensor, AsRefTensor, Tensor};
-use crate::numeric::{Numeric, UnsignedInteger};
-use crate::tensor_traits;
+use crate::crypto::{CiphertextMetadata, LweDimension, LweSize, UnsignedTorus};
+use crate::math::dispersion::DispersionParameter;
+use crate::math::polynomial::PolynomialSize;
+use crate::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
+use crate::math::torus::switch_modulus;
+use crate::numeric::{CastFrom, CastInto, Numeric, UnsignedInteger};
+use crate::{tensor_memory_footprint, tensor_traits};
 
-use super::LweList;
+use super::{pack_coefficients, CompressedLweCiphertext, LweList};
 
 /// A ciphertext encrypted using the LWE scheme.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
@@ -16,6 +22,7 @@ pub struct LweCiphertext<Cont> {
 }
 
 tensor_traits!(LweCiphertext);
+tensor_memory_footprint!(LweCiphertext);
 
 impl<Scalar> LweCiphertext<Vec<Scalar>>
 where
@@ -36,6 +43,23 @@ where
             tensor: Tensor::from_container(vec![value; size.0]),
         }
     }
+
+    /// Allocates a new ciphertext for the given set of LWE parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::crypto::parameters::LweParameters;
+    /// use concrete_core::crypto::{*, lwe::LweCiphertext};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// let params = LweParameters::new(LweDimension(3), LogStandardDev(-15.)).unwrap();
+    /// let ct = LweCiphertext::allocate_with(0 as u8, &params);
+    /// assert_eq!(ct.lwe_size(), LweSize(4));
+    /// assert_eq!(ct.get_mask().mask_size(), LweDimension(3));
+    /// ```
+    pub fn allocate_with(value: Scalar, params: &LweParameters) -> Self {
+        LweCiphertext::allocate(value, params.size())
+    }
 }
 
 impl<Cont> LweCiphertext<Cont> {
@@ -439,6 +463,294 @@ impl<Cont> LweCiphertext<Cont> {
         self.as_mut_tensor()
             .update_with_wrapping_scalar_mul(&scalar.0)
     }
+
+    /// Fills the current ciphertext with the result of the modulus switching of a ciphertext
+    /// with a larger modulus.
+    ///
+    /// Every coefficient of the input ciphertext is rounded to the closest value representable
+    /// with the integer type of the current ciphertext. The result encrypts the same message
+    /// under the same secret key, with an additional rounding noise of variance roughly
+    /// $\frac{n}{48} \cdot 2^{-2b}$, where $n$ is the dimension and $b$ the number of bits of the
+    /// output type. This allows to bring a high-precision `u128` ciphertext down to `u64`, for
+    /// instance before a bootstrap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::crypto::{*, secret::LweSecretKey, lwe::*, encoding::*};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    ///
+    /// let secret_key = LweSecretKey::generate(LweDimension(256));
+    /// let noise = LogStandardDev::from_log_standard_dev(-50.);
+    /// let mut cipher = LweCiphertext::allocate(0 as u128, LweSize(257));
+    /// secret_key.encrypt_lwe(&mut cipher, &Plaintext(5 << 124), noise);
+    ///
+    /// let mut switched = LweCiphertext::allocate(0 as u64, LweSize(257));
+    /// switched.fill_with_modulus_switch(&cipher);
+    ///
+    /// let mut decrypted = Plaintext(0 as u64);
+    /// secret_key.decrypt_lwe(&mut decrypted, &switched);
+    /// let rounded = (decrypted.0.wrapping_add(1 << 59)) >> 60;
+    /// assert_eq!(rounded, 5);
+    /// ```
+    pub fn fill_with_modulus_switch<InputCont, InputScalar, OutputScalar>(
+        &mut self,
+        input: &LweCiphertext<InputCont>,
+    ) where
+        Self: AsMutTensor<Element = OutputScalar>,
+        LweCiphertext<InputCont>: AsRefTensor<Element = InputScalar>,
+        InputScalar: UnsignedTorus + CastInto<OutputScalar>,
+        OutputScalar: UnsignedTorus,
+    {
+        self.as_mut_tensor()
+            .fill_with_one(input.as_tensor(), |coef| switch_modulus(*coef));
+    }
+
+    /// Compresses the ciphertext by rounding every coefficient to its `kept_bits` most
+    /// significant bits.
+    ///
+    /// The rounded coefficients are bit-packed, so that the compressed ciphertext takes
+    /// $\lceil (n+1) \cdot kept\\_bits / 8 \rceil$ bytes. Once decompressed, every coefficient
+    /// carries an additional error uniformly distributed in $[-2^{-k-1}, 2^{-k-1}[$ on the torus,
+    /// where $k$ is the number of kept bits, which adds up in the decryption. Use
+    /// [`minimum_kept_bits`](super::minimum_kept_bits) to pick a number of bits preserving the
+    /// correctness of the decoding.
+    ///
+    /// A number of kept bits larger than the number of bits of `Scalar`, as `minimum_kept_bits`
+    /// returns for a high precision, keeps every bit of the coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `kept_bits` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::encoding::Plaintext;
+    /// use concrete_core::crypto::lwe::{minimum_kept_bits, LweCiphertext};
+    /// use concrete_core::crypto::secret::LweSecretKey;
+    /// use concrete_core::crypto::{LweDimension, LweSize};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::tensor::MemoryFootprint;
+    ///
+    /// let secret_key = LweSecretKey::generate(LweDimension(630));
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let mut cipher = LweCiphertext::allocate(0u64, LweSize(631));
+    /// secret_key.encrypt_lwe(&mut cipher, &Plaintext(5 << 60), noise);
+    ///
+    /// let kept_bits = minimum_kept_bits(LweDimension(630), 4);
+    /// let compressed = cipher.compress(kept_bits);
+    /// assert!(compressed.heap_size() < cipher.heap_size() / 4);
+    ///
+    /// let mut decrypted = Plaintext(0u64);
+    /// secret_key.decrypt_lwe(&mut decrypted, &compressed.decompress());
+    /// assert_eq!(decrypted.0.wrapping_add(1 << 59) >> 60, 5);
+    /// ```
+    pub fn compress<Scalar>(&self, kept_bits: usize) -> CompressedLweCiphertext<Scalar>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let kept_bits = kept_bits.min(Scalar::BITS);
+        let coefficients = self.as_tensor().as_slice();
+        CompressedLweCiphertext {
+            data: pack_coefficients(coefficients, kept_bits),
+            kept_bits,
+            lwe_size: LweSize(coefficients.len()),
+            _scalar: PhantomData,
+        }
+    }
+
+    /// Returns a ciphertext encrypting a single bit of the current plaintext in its most
+    /// significant bit.
+    ///
+    /// The ciphertext is multiplied by $2^{q-1-p}$, where $q$ is the number of bits of `Scalar`
+    /// and $p$ is `bit_position`: the bits of the plaintext above the position are discarded by
+    /// the wrapping multiplication, and the bit at the position is moved to the most significant
+    /// bit. The output decrypts to $b \cdot 2^{q-1}$ plus some error, where $b$ is the bit of
+    /// index `bit_position` of the plaintext, once rounded to a multiple of $2^p$.
+    ///
+    /// The bits below the position are hence considered as noise. This makes the extraction
+    /// exact for the lowest bit of a message, or for a boolean encrypted in the most significant
+    /// bit, but not for the higher bits of a message spanning several bits. The noise of the
+    /// ciphertext is multiplied by $2^{q-1-p}$ as well, and the output is only correct if the
+    /// input noise stays below $2^{p-1}$, which is also the condition for the plaintext to be
+    /// decoded correctly at this position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_position` is not smaller than the number of bits of `Scalar`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::encoding::Plaintext;
+    /// use concrete_core::crypto::lwe::LweCiphertext;
+    /// use concrete_core::crypto::secret::LweSecretKey;
+    /// use concrete_core::crypto::{LweDimension, LweSize};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    ///
+    /// let secret_key = LweSecretKey::generate(LweDimension(256));
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let mut cipher = LweCiphertext::allocate(0u64, LweSize(257));
+    /// secret_key.encrypt_lwe(&mut cipher, &Plaintext(0b110 << 60), noise);
+    ///
+    /// // The lowest bit of the message, at position 61, is one.
+    /// let bit = cipher.extract_bit_encoding(61);
+    /// let mut decrypted = Plaintext(0u64);
+    /// secret_key.decrypt_lwe(&mut decrypted, &bit);
+    /// assert_eq!(decrypted.0.wrapping_add(1 << 62) >> 63, 1);
+    ///
+    /// // The bit at position 60 only contains noise.
+    /// let bit = cipher.extract_bit_encoding(60);
+    /// let mut decrypted = Plaintext(0u64);
+    /// secret_key.decrypt_lwe(&mut decrypted, &bit);
+    /// assert_eq!(decrypted.0.wrapping_add(1 << 62) >> 63, 0);
+    /// ```
+    pub fn extract_bit_encoding<Scalar>(&self, bit_position: usize) -> LweCiphertext<Vec<Scalar>>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        assert!(
+            bit_position < Scalar::BITS,
+            "The bit position must be smaller than {}, got {}.",
+            Scalar::BITS,
+            bit_position
+        );
+        let mut output = LweCiphertext::from_container(self.as_tensor().as_slice().to_vec());
+        output.update_with_scalar_mul(Cleartext(Scalar::ONE << (Scalar::BITS - 1 - bit_position)));
+        output
+    }
+
+    /// Replaces the ciphertext with a fresh encryption of the same plaintext, under the same key.
+    ///
+    /// The ciphertext is decrypted, and the result is encrypted again with a new random mask and
+    /// a new error drawn from `noise`. The output is no longer correlated with the ciphertexts the
+    /// input was computed from, which is not the case of a simple ciphertext addition.
+    ///
+    /// # Note
+    ///
+    /// The decrypted value still contains the error of the input, so the variance of the error of
+    /// the output is the sum of the previous variance and of the variance of `noise`. Contrary to
+    /// a bootstrap, the noise is not reset.
+    ///
+    /// The method does not take a random generator as argument, as the encryption functions of
+    /// the secret keys it relies on do not accept one. Like them, the random values are drawn
+    /// from the generator of the current thread, which can be seeded with
+    /// [`with_seed`](crate::math::random::with_seed) to make the output reproducible.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::encoding::Plaintext;
+    /// use concrete_core::crypto::lwe::LweCiphertext;
+    /// use concrete_core::crypto::secret::LweSecretKey;
+    /// use concrete_core::crypto::{LweDimension, LweSize};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    ///
+    /// let secret_key = LweSecretKey::generate(LweDimension(256));
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let mut cipher = LweCiphertext::allocate(0u64, LweSize(257));
+    /// secret_key.encrypt_lwe(&mut cipher, &Plaintext(1 << 62), noise);
+    ///
+    /// let previous = cipher.clone();
+    /// cipher.rerandomize(&secret_key, noise);
+    /// assert_ne!(cipher.get_mask(), previous.get_mask());
+    ///
+    /// let mut decrypted = Plaintext(0u64);
+    /// secret_key.decrypt_lwe(&mut decrypted, &cipher);
+    /// let distance = decrypted.0.wrapping_sub(1 << 62) as i64;
+    /// assert!(distance.abs() < 1 << 45);
+    /// ```
+    pub fn rerandomize<KeyCont, Scalar>(
+        &mut self,
+        key: &LweSecretKey<KeyCont>,
+        noise: impl DispersionParameter,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<KeyCont>: AsRefTensor<Element = bool>,
+        Scalar: UnsignedTorus,
+    {
+        let mut decrypted = Plaintext(Scalar::ZERO);
+        key.decrypt_lwe(&mut decrypted, self);
+        key.encrypt_lwe(self, &decrypted, noise);
+    }
+}
+
+impl<Cont, Scalar> CiphertextMetadata for LweCiphertext<Cont>
+where
+    Self: AsRefTensor<Element = Scalar>,
+    Scalar: UnsignedTorus,
+{
+    fn scalar_type_bits(&self) -> usize {
+        Scalar::BITS
+    }
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_size().to_lwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> Option<PolynomialSize> {
+        None
+    }
+}
+
+/// Returns the product of a ciphertext with a public integer scalar.
+///
+/// The output encrypts the product of the input plaintext with `scalar`, computed modulo
+/// $2^q$ where $q$ is the number of bits of `Scalar`. No key is needed, and no bootstrap is
+/// performed: the coefficients of the mask and the body are simply multiplied by the scalar. A
+/// negative scalar can be given with its two's complement representation, e.g.
+/// `(-3i64) as u64`.
+///
+/// The noise is multiplied by the scalar as well, hence its variance grows by a factor
+/// $scalar^2$ (see `single_scalar_mul` in the `concrete-npe` crate). The operation is safe as
+/// long as:
+///
+/// + the product of the message with the scalar still fits in the plaintext space, i.e. the
+///   encoding keeps at least $\log_2(scalar)$ bits of padding above the message,
+/// + the standard deviation of the input noise, multiplied by the scalar, stays small compared
+///   to the precision expected by the decoding.
+///
+/// Small scalars usually satisfy both conditions with the default parameters. With larger
+/// scalars, the result has to be bootstrapped to bring the noise back to a nominal level before
+/// it is decrypted or used in further computations.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::crypto::encoding::{Cleartext, Plaintext, RealEncoder, Encoder};
+/// use concrete_core::crypto::lwe::{scalar_mul, LweCiphertext};
+/// use concrete_core::crypto::secret::LweSecretKey;
+/// use concrete_core::crypto::{LweDimension, LweSize};
+/// use concrete_core::math::dispersion::LogStandardDev;
+///
+/// let secret_key = LweSecretKey::generate(LweDimension(256));
+/// let noise = LogStandardDev::from_log_standard_dev(-15.);
+/// let encoder = RealEncoder { offset: 0. as f32, delta: 10. };
+///
+/// let plain: Plaintext<u32> = encoder.encode(Cleartext(2. as f32));
+/// let mut cipher = LweCiphertext::allocate(0u32, LweSize(257));
+/// secret_key.encrypt_lwe(&mut cipher, &plain, noise);
+///
+/// let product = scalar_mul(&cipher, 3);
+///
+/// let mut decrypted = Plaintext(0u32);
+/// secret_key.decrypt_lwe(&mut decrypted, &product);
+/// let decoded = encoder.decode(decrypted);
+/// assert!((decoded.0 - 6.).abs() < 0.2);
+/// ```
+pub fn scalar_mul<Scalar>(
+    ciphertext: &LweCiphertext<Vec<Scalar>>,
+    scalar: u64,
+) -> LweCiphertext<Vec<Scalar>>
+where
+    Scalar: UnsignedTorus + CastFrom<u64>,
+{
+    let mut output = ciphertext.clone();
+    output.update_with_scalar_mul(Cleartext(Scalar::cast_from(scalar)));
+    output
 }
 
 /// The mask of an LWE encrypted ciphertext.
@@ -556,6 +868,39 @@ impl<Cont> LweMask<Cont> {
             },
         )
     }
+
+    /// Computes sum of the mask elements wighted by the key bits, in constant time.
+    ///
+    /// This computes the same result as [`LweMask::compute_binary_multisum`].
+    ///
+    /// # Constant-time
+    ///
+    /// The key bits are turned into masks applied to the mask elements, so that neither the
+    /// control flow nor the memory accesses depend on the key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::crypto::lwe::LweCiphertext;
+    /// use concrete_core::crypto::secret::LweSecretKey;
+    /// let ciphertext = LweCiphertext::from_container(vec![1u32,2,3,4,5]);
+    /// let mask = ciphertext.get_mask();
+    /// let key = LweSecretKey::from_container(vec![true, true, false, true]);
+    /// let multisum = mask.ct_compute_binary_multisum(&key);
+    /// assert_eq!(multisum, 7);
+    /// ```
+    pub fn ct_compute_binary_multisum<Scalar, KeyCont>(&self, key: &LweSecretKey<KeyCont>) -> Scalar
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        LweSecretKey<KeyCont>: AsRefTensor<Element = bool>,
+        Scalar: UnsignedTorus,
+    {
+        self.as_tensor().fold_with_one(
+            key.as_tensor(),
+            <Scalar as Numeric>::ZERO,
+            |ac, s_i, o_i| ac.wrapping_add(*s_i & Scalar::ct_mask_from_bool(*o_i)),
+        )
+    }
 }
 
 /// The body of an Lwe ciphertext.
@@ -0,0 +1,295 @@
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::crypto::{LweDimension, LweSize, UnsignedTorus};
+use crate::math::tensor::MemoryFootprint;
+use crate::Error;
+
+use super::LweCiphertext;
+
+/// An LWE ciphertext whose coefficients were rounded to a reduced precision and bit-packed.
+///
+/// This type is obtained with [`LweCiphertext::compress`], and can only be converted back to a
+/// full-width ciphertext with [`CompressedLweCiphertext::decompress`]. It is meant to reduce the
+/// size of the ciphertexts when they are stored or sent over the network: every coefficient
+/// only uses `kept_bits` bits instead of the number of bits of `Scalar`.
+///
+/// The deserialization goes through [`CompressedLweCiphertext::try_new`], and fails on packed
+/// data which could not have been produced by a compression.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    try_from = "UncheckedCompressedLweCiphertext<Scalar>",
+    bound(deserialize = "Scalar: UnsignedTorus")
+)]
+pub struct CompressedLweCiphertext<Scalar> {
+    pub(super) data: Vec<u8>,
+    pub(super) kept_bits: usize,
+    pub(super) lwe_size: LweSize,
+    pub(super) _scalar: PhantomData<Scalar>,
+}
+
+// The fields of a compressed ciphertext, as deserialized before being checked.
+#[derive(Deserialize)]
+#[serde(bound = "")]
+struct UncheckedCompressedLweCiphertext<Scalar> {
+    data: Vec<u8>,
+    kept_bits: usize,
+    lwe_size: LweSize,
+    _scalar: PhantomData<Scalar>,
+}
+
+impl<Scalar> TryFrom<UncheckedCompressedLweCiphertext<Scalar>> for CompressedLweCiphertext<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    type Error = Error;
+
+    fn try_from(unchecked: UncheckedCompressedLweCiphertext<Scalar>) -> Result<Self, Error> {
+        Self::try_new(unchecked.data, unchecked.kept_bits, unchecked.lwe_size)
+    }
+}
+
+impl<Scalar> CompressedLweCiphertext<Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    /// Creates a compressed ciphertext from its packed coefficients.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `kept_bits` is zero or larger than the number of bits of `Scalar`, if
+    /// `lwe_size` is zero, or if `data` does not contain exactly `lwe_size` packed coefficients
+    /// followed by zero bits up to the end of the last byte.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::lwe::{CompressedLweCiphertext, LweCiphertext};
+    /// use concrete_core::crypto::LweSize;
+    /// let ciphertext = LweCiphertext::from_container(vec![0x1200_0000u32, 0x3400_0000]);
+    /// let compressed = CompressedLweCiphertext::try_new(vec![0x12, 0x34], 8, LweSize(2)).unwrap();
+    /// assert_eq!(compressed, ciphertext.compress(8));
+    ///
+    /// assert!(CompressedLweCiphertext::<u32>::try_new(vec![0x12], 8, LweSize(2)).is_err());
+    /// assert!(CompressedLweCiphertext::<u32>::try_new(vec![0x12, 0x34], 0, LweSize(2)).is_err());
+    /// // Two coefficients of 4 bits fit in a byte, and 3 coefficients leave 4 padding bits.
+    /// assert!(CompressedLweCiphertext::<u32>::try_new(vec![0x12, 0x03], 4, LweSize(3)).is_ok());
+    /// assert!(CompressedLweCiphertext::<u32>::try_new(vec![0x12, 0x13], 4, LweSize(3)).is_err());
+    /// ```
+    pub fn try_new(data: Vec<u8>, kept_bits: usize, lwe_size: LweSize) -> Result<Self, Error> {
+        if lwe_size.0 == 0 {
+            return Err(Error::InvalidParameter {
+                parameter: "lwe size",
+                reason: "must be at least 1".into(),
+            });
+        }
+        check_packed_coefficients::<Scalar>(&data, kept_bits, lwe_size.0)?;
+        Ok(CompressedLweCiphertext {
+            data,
+            kept_bits,
+            lwe_size,
+            _scalar: PhantomData,
+        })
+    }
+
+    /// Returns the size of the ciphertext once decompressed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::lwe::LweCiphertext;
+    /// use concrete_core::crypto::LweSize;
+    /// let ciphertext = LweCiphertext::allocate(0u64, LweSize(11));
+    /// let compressed = ciphertext.compress(20);
+    /// assert_eq!(compressed.lwe_size(), LweSize(11));
+    /// ```
+    pub fn lwe_size(&self) -> LweSize {
+        self.lwe_size
+    }
+
+    /// Returns the number of bits kept for every coefficient.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::lwe::LweCiphertext;
+    /// use concrete_core::crypto::LweSize;
+    /// let ciphertext = LweCiphertext::allocate(0u64, LweSize(11));
+    /// let compressed = ciphertext.compress(20);
+    /// assert_eq!(compressed.kept_bits(), 20);
+    /// ```
+    pub fn kept_bits(&self) -> usize {
+        self.kept_bits
+    }
+
+    /// Returns a full-width ciphertext, whose dropped low bits are all set to zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::lwe::LweCiphertext;
+    /// let ciphertext = LweCiphertext::from_container(vec![0x1234_5678u32, 0x8000_0000, 0xffff_ffff]);
+    /// let decompressed = ciphertext.compress(8).decompress();
+    /// assert_eq!(
+    ///     decompressed,
+    ///     LweCiphertext::from_container(vec![0x1200_0000, 0x8000_0000, 0x0000_0000])
+    /// );
+    /// ```
+    pub fn decompress(&self) -> LweCiphertext<Vec<Scalar>> {
+        LweCiphertext::from_container(unpack_coefficients(
+            &self.data,
+            self.kept_bits,
+            self.lwe_size.0,
+        ))
+    }
+}
+
+impl<Scalar> MemoryFootprint for CompressedLweCiphertext<Scalar> {
+    fn heap_size(&self) -> usize {
+        self.data.len()
+    }
+}
+
+/// Returns the smallest number of bits to keep when compressing a ciphertext, for the decryption
+/// to stay correct at a given decoding precision.
+///
+/// Rounding a coefficient to $k$ bits adds an error uniformly distributed in
+/// $[-2^{-k-1}, 2^{-k-1}[$ on the torus. After decryption, the errors of the body and of the mask
+/// coefficients multiplied by a non-zero key bit add up, hence the decryption error grows by at
+/// most $(n+1) \cdot 2^{-k-1}$ for an LWE dimension $n$. A message decoded with `precision` bits
+/// is recovered as long as the total error stays below $2^{-precision-1}$. This function returns
+/// the smallest $k$ for which the compression error can not exceed half of this bound, the other
+/// half being left to the noise already present in the ciphertext:
+///
+/// $$k = precision + 1 + \lceil \log_2(n+1) \rceil$$
+///
+/// This bound holds for any secret key. For a GLWE ciphertext of dimension $k$ and polynomial
+/// size $N$, every coefficient of the decryption depends on $k \cdot N$ mask coefficients, and
+/// `LweDimension(k * N)` must be used instead.
+///
+/// The result may exceed the number of bits of the integer type, in which case the compression
+/// keeps all of them.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::crypto::lwe::minimum_kept_bits;
+/// use concrete_core::crypto::LweDimension;
+/// assert_eq!(minimum_kept_bits(LweDimension(630), 4), 15);
+/// assert_eq!(minimum_kept_bits(LweDimension(1023), 4), 15);
+/// assert_eq!(minimum_kept_bits(LweDimension(1024), 4), 16);
+/// ```
+pub fn minimum_kept_bits(lwe_dimension: LweDimension, precision: usize) -> usize {
+    let terms = lwe_dimension.0 + 1;
+    let mut log_terms = 0;
+    while (1 << log_terms) < terms {
+        log_terms += 1;
+    }
+    precision + 1 + log_terms
+}
+
+// Checks that `data` contains exactly `count` digits of `kept_bits` bits, with `kept_bits` in
+// $[1, q]$ where $q$ is the number of bits of `Scalar`, followed by zero padding bits, as produced
+// by `pack_coefficients`.
+pub(crate) fn check_packed_coefficients<Scalar: UnsignedTorus>(
+    data: &[u8],
+    kept_bits: usize,
+    count: usize,
+) -> Result<(), Error> {
+    if kept_bits == 0 || kept_bits > Scalar::BITS {
+        return Err(Error::InvalidParameter {
+            parameter: "kept bits",
+            reason: format!("must be in [1, {}], got {}", Scalar::BITS, kept_bits),
+        });
+    }
+    let expected = count
+        .checked_mul(kept_bits)
+        .ok_or_else(|| Error::InvalidParameter {
+            parameter: "ciphertext size",
+            reason: format!("{} coefficients of {} bits overflow", count, kept_bits),
+        })?
+        .div_ceil(8);
+    if data.len() != expected {
+        return Err(Error::DimensionMismatch {
+            parameter: "packed data length",
+            expected,
+            found: data.len(),
+        });
+    }
+    // The padding bits completing the last byte are always zero, so that every ciphertext has a
+    // single packed representation.
+    let used_bits = (count * kept_bits) % 8;
+    if used_bits != 0 && data[data.len() - 1] >> used_bits != 0 {
+        return Err(Error::InvalidParameter {
+            parameter: "packed data",
+            reason: format!(
+                "the {} padding bits of the last byte must be zero",
+                8 - used_bits
+            ),
+        });
+    }
+    Ok(())
+}
+
+// Rounds every coefficient to its `kept_bits` most significant bits, and packs the resulting
+// digits in a little endian bit stream.
+pub(crate) fn pack_coefficients<Scalar: UnsignedTorus>(
+    coefficients: &[Scalar],
+    kept_bits: usize,
+) -> Vec<u8> {
+    assert!(
+        kept_bits > 0 && kept_bits <= Scalar::BITS,
+        "The number of kept bits must be in [1, {}], got {}.",
+        Scalar::BITS,
+        kept_bits
+    );
+    let shift = Scalar::BITS - kept_bits;
+    let mut output = Vec::with_capacity((coefficients.len() * kept_bits).div_ceil(8));
+    let (mut current, mut filled) = (0u8, 0);
+    for coef in coefficients.iter() {
+        let digit = if shift == 0 {
+            *coef
+        } else {
+            coef.wrapping_add(Scalar::ONE << (shift - 1)) >> shift
+        };
+        for bit in 0..kept_bits {
+            if (digit >> bit) & Scalar::ONE == Scalar::ONE {
+                current |= 1 << filled;
+            }
+            filled += 1;
+            if filled == 8 {
+                output.push(current);
+                current = 0;
+                filled = 0;
+            }
+        }
+    }
+    if filled > 0 {
+        output.push(current);
+    }
+    output
+}
+
+// Reads `count` digits of `kept_bits` bits from a bit stream, and puts them back in the most
+// significant bits of the coefficients.
+pub(crate) fn unpack_coefficients<Scalar: UnsignedTorus>(
+    data: &[u8],
+    kept_bits: usize,
+    count: usize,
+) -> Vec<Scalar> {
+    let shift = Scalar::BITS - kept_bits;
+    (0..count)
+        .map(|index| {
+            let mut digit = Scalar::ZERO;
+            for bit in 0..kept_bits {
+                let position = index * kept_bits + bit;
+                if (data[position / 8] >> (position % 8)) & 1 == 1 {
+                    digit |= Scalar::ONE << bit;
+                }
+            }
+            digit << shift
+        })
+        .collect()
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::crypto::encoding::{Plaintext, PlaintextList};
+use crate::crypto::parameters::{check_decomposition_bits, LweKeyswitchParameters};
 use crate::crypto::secret::LweSecretKey;
 use crate::crypto::{CiphertextCount, LweDimension, LweSize, UnsignedTorus};
 use crate::math::decomposition::{
@@ -9,7 +10,7 @@ use crate::math::decomposition::{
 use crate::math::dispersion::DispersionParameter;
 use crate::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
 use crate::numeric::{CastFrom, SignedInteger};
-use crate::{ck_dim_div, ck_dim_eq, tensor_traits};
+use crate::{ck_dim_div, ck_dim_eq, tensor_memory_footprint, tensor_traits, Error};
 
 use super::{LweCiphertext, LweList};
 
@@ -33,6 +34,7 @@ pub struct LweKeyswitchKey<Cont> {
 }
 
 tensor_traits!(LweKeyswitchKey);
+tensor_memory_footprint!(LweKeyswitchKey);
 
 impl<Scalar> LweKeyswitchKey<Vec<Scalar>>
 where
@@ -81,6 +83,116 @@ where
             lwe_size: LweSize(output_size.0 + 1),
         }
     }
+
+    /// Allocates a keyswitching key from the `params` instance, whose masks and bodies are all
+    /// `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::crypto::lwe::LweKeyswitchKey;
+    /// use concrete_core::crypto::parameters::LweKeyswitchParameters;
+    /// use concrete_core::crypto::{LweDimension, LweSize};
+    /// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// let params = LweKeyswitchParameters::new(
+    ///     LweDimension(10),
+    ///     LweDimension(20),
+    ///     DecompositionBaseLog(16),
+    ///     DecompositionLevelCount(2),
+    ///     LogStandardDev(-15.),
+    /// )
+    /// .unwrap();
+    /// let ksk = LweKeyswitchKey::allocate_with(0u32, &params);
+    /// assert_eq!(ksk.decomposition_levels_count(), DecompositionLevelCount(2));
+    /// assert_eq!(ksk.decomposition_base_log(), DecompositionBaseLog(16));
+    /// assert_eq!(ksk.lwe_size(), LweSize(21));
+    /// assert_eq!(ksk.before_key_size(), LweDimension(10));
+    /// assert_eq!(ksk.after_key_size(), LweDimension(20));
+    /// ```
+    pub fn allocate_with(value: Scalar, params: &LweKeyswitchParameters) -> Self {
+        LweKeyswitchKey::allocate(
+            value,
+            params.level_count(),
+            params.base_log(),
+            params.input_dimension(),
+            params.output_dimension(),
+        )
+    }
+}
+
+impl<Scalar> LweKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: UnsignedTorus,
+{
+    /// Generates a keyswitching key from `input_key` to `output_key`, with the decomposition
+    /// and the noise of `params`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the dimensions of the keys are not the ones of `params`, or if the decomposition
+    /// covers more bits than the integer type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::crypto::lwe::LweKeyswitchKey;
+    /// use concrete_core::crypto::parameters::LweKeyswitchParameters;
+    /// use concrete_core::crypto::secret::LweSecretKey;
+    /// use concrete_core::crypto::LweDimension;
+    /// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// let params = LweKeyswitchParameters::new(
+    ///     LweDimension(10),
+    ///     LweDimension(20),
+    ///     DecompositionBaseLog(3),
+    ///     DecompositionLevelCount(5),
+    ///     LogStandardDev(-15.),
+    /// )
+    /// .unwrap();
+    /// let input_key = LweSecretKey::generate(LweDimension(10));
+    /// let output_key = LweSecretKey::generate(LweDimension(20));
+    /// let ksk = LweKeyswitchKey::<Vec<u32>>::generate_with(&params, &input_key, &output_key);
+    /// assert_eq!(ksk.unwrap().after_key_size(), LweDimension(20));
+    ///
+    /// // The keys must match the parameters.
+    /// let ksk = LweKeyswitchKey::<Vec<u32>>::generate_with(&params, &output_key, &input_key);
+    /// assert!(ksk.is_err());
+    /// ```
+    pub fn generate_with<InKeyCont, OutKeyCont>(
+        params: &LweKeyswitchParameters,
+        input_key: &LweSecretKey<InKeyCont>,
+        output_key: &LweSecretKey<OutKeyCont>,
+    ) -> Result<Self, Error>
+    where
+        LweSecretKey<InKeyCont>: AsRefTensor<Element = bool>,
+        LweSecretKey<OutKeyCont>: AsRefTensor<Element = bool>,
+    {
+        for &(parameter, expected, found) in &[
+            (
+                "keyswitch input dimension",
+                params.input_dimension().0,
+                input_key.key_size().0,
+            ),
+            (
+                "keyswitch output dimension",
+                params.output_dimension().0,
+                output_key.key_size().0,
+            ),
+        ] {
+            if expected != found {
+                return Err(Error::DimensionMismatch {
+                    parameter,
+                    expected,
+                    found,
+                });
+            }
+        }
+        check_decomposition_bits::<Scalar>(params.base_log(), params.level_count())?;
+        let mut ksk = LweKeyswitchKey::allocate_with(Scalar::ZERO, params);
+        ksk.fill_with_keyswitch_key(input_key, output_key, params.noise());
+        Ok(ksk)
+    }
 }
 
 impl<Cont> LweKeyswitchKey<Cont> {
@@ -459,8 +571,10 @@ impl<Cont> LweKeyswitchKey<Cont> {
         LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
-        ck_dim_eq!(self.before_key_size().0 => before.get_mask().mask_size().0);
-        ck_dim_eq!(self.after_key_size().0 => after.get_mask().mask_size().0);
+        #[cfg(feature = "metrics")]
+        let _timer = crate::metrics::KEYSWITCH.start();
+        ck_dim_eq!(self.before_key_size() => before.get_mask().mask_size());
+        ck_dim_eq!(self.after_key_size() => after.get_mask().mask_size());
 
         // We reset the output
         after.as_mut_tensor().fill_with(|| Scalar::ZERO);
@@ -506,7 +620,7 @@ impl<Cont> LweKeyswitchKey<Cont> {
         LweList<OutCont>: AsMutTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
-        ck_dim_eq!(input.count().0 => output.count().0);
+        ck_dim_eq!(input.count() => output.count());
         // for each ciphertext, call mono_key_switch
         for (input_cipher, mut output_cipher) in
             input.ciphertext_iter().zip(output.ciphertext_iter_mut())
@@ -657,7 +771,7 @@ impl<Cont> LweKeyBitDecomposition<Cont> {
     }
 }
 
-fn torus_small_sign_decompose<Scalar>(res: &mut [Scalar], val: Scalar, base_log: usize)
+pub(super) fn torus_small_sign_decompose<Scalar>(res: &mut [Scalar], val: Scalar, base_log: usize)
 where
     Scalar: UnsignedTorus,
     Scalar::Signed: SignedInteger,
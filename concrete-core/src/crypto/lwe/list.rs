@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::crypto::encoding::{CleartextList, PlaintextList};
 use crate::crypto::{CiphertextCount, CleartextCount, LweDimension, LweSize, UnsignedTorus};
 use crate::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
-use crate::{ck_dim_div, tensor_traits, zip, zip_args};
+use crate::{ck_dim_div, tensor_memory_footprint, tensor_traits, zip, zip_args};
 
 use super::LweCiphertext;
 
@@ -15,6 +15,7 @@ pub struct LweList<Cont> {
 }
 
 tensor_traits!(LweList);
+tensor_memory_footprint!(LweList);
 
 impl<Scalar> LweList<Vec<Scalar>>
 where
@@ -1,11 +1,15 @@
 //! LWE encryption scheme.
 pub use ciphertext::*;
+pub use compressed::*;
 pub use keyswitch::*;
 pub use list::*;
+pub use variable_keyswitch::*;
 
 #[cfg(test)]
 mod tests;
 
 mod ciphertext;
+mod compressed;
 mod keyswitch;
 mod list;
+mod variable_keyswitch;
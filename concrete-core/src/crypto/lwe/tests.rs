@@ -1,21 +1,29 @@
 use concrete_npe as npe;
 
 use crate::crypto::encoding::{Cleartext, CleartextList, Plaintext, PlaintextList};
-use crate::crypto::lwe::{LweCiphertext, LweKeyswitchKey, LweList};
+use crate::crypto::lwe::{
+    minimum_kept_bits, CompressedLweCiphertext, LweCiphertext, LweKeyswitchKey, LweList,
+    VariableDecompKeySwitchingKey,
+};
+use crate::crypto::parameters::LweKeyswitchParameters;
 use crate::crypto::secret::LweSecretKey;
-use crate::crypto::{CiphertextCount, CleartextCount, LweDimension, PlaintextCount, UnsignedTorus};
+use crate::crypto::{
+    CiphertextCount, CleartextCount, LweDimension, LweSize, PlaintextCount, UnsignedTorus,
+};
 use crate::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
 use crate::math::dispersion::{DispersionParameter, LogStandardDev, Variance};
 use crate::math::random;
 use crate::math::random::{
     fill_with_random_uniform, random_uniform_n_msb_tensor, RandomGenerable, UniformMsb,
 };
-use crate::math::tensor::{AsMutTensor, AsRefTensor, Tensor};
-use crate::numeric::{CastFrom, Numeric, SignedInteger};
+use crate::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor, MemoryFootprint, Tensor};
+use crate::math::torus::switch_modulus;
+use crate::numeric::{CastFrom, CastInto, Numeric, SignedInteger};
 use crate::test_tools::{
     assert_delta_std_dev, assert_noise_distribution, random_ciphertext_count, random_lwe_dimension,
-    random_utorus_between,
+    random_usize_between, random_utorus_between,
 };
+use crate::Error;
 
 fn test_keyswitch<T: UnsignedTorus + RandomGenerable<UniformMsb> + npe::LWE>() {
     //! create a KSK and key switch some LWE samples
@@ -98,6 +106,144 @@ fn test_keyswitch_u64() {
     test_keyswitch::<u64>();
 }
 
+#[test]
+fn test_keyswitch_u128() {
+    test_keyswitch::<u128>();
+}
+
+#[test]
+fn test_keyswitch_decomposition_too_wide_u32() {
+    let params = LweKeyswitchParameters::new(
+        LweDimension(10),
+        LweDimension(20),
+        DecompositionBaseLog(16),
+        DecompositionLevelCount(3),
+        LogStandardDev(-15.),
+    )
+    .unwrap();
+    let input_key = LweSecretKey::generate(LweDimension(10));
+    let output_key = LweSecretKey::generate(LweDimension(20));
+    let ksk = LweKeyswitchKey::<Vec<u32>>::generate_with(&params, &input_key, &output_key);
+    assert!(matches!(ksk, Err(Error::InvalidParameter { .. })));
+
+    // The same decomposition fits in 64 bits.
+    let ksk = LweKeyswitchKey::<Vec<u64>>::generate_with(&params, &input_key, &output_key);
+    assert!(ksk.is_ok());
+}
+
+fn test_variable_keyswitch<T: UnsignedTorus + RandomGenerable<UniformMsb>>() {
+    //! wraps a uniform KSK into a variable decomposition KSK and checks that both key switch
+    //! the same way
+    let n_bit_msg = 8;
+    let nb_ct = random_ciphertext_count(100);
+    let base_log = DecompositionBaseLog(3);
+    let level_count = DecompositionLevelCount(8);
+    let messages = PlaintextList::from_tensor(random_uniform_n_msb_tensor(nb_ct.0, n_bit_msg));
+    let std_input = LogStandardDev::from_log_standard_dev(-10.);
+    let std_ksk = LogStandardDev::from_log_standard_dev(-25.);
+
+    let dimension_after = LweDimension(600);
+    let sk_after = LweSecretKey::generate(dimension_after);
+    let dimension_before = LweDimension(1024);
+    let sk_before = LweSecretKey::generate(dimension_before);
+
+    // with uniform parameters, both keys share the same layout
+    let mut ksk = LweKeyswitchKey::allocate(
+        T::ZERO,
+        level_count,
+        base_log,
+        dimension_before,
+        dimension_after,
+    );
+    ksk.fill_with_keyswitch_key(&sk_before, &sk_after, std_ksk);
+    let variable_ksk = VariableDecompKeySwitchingKey::from_container(
+        ksk.as_tensor().as_slice(),
+        vec![(base_log, level_count); dimension_before.0],
+        dimension_after,
+    );
+
+    let mut ciphertexts_before = LweList::allocate(T::ZERO, dimension_before.to_lwe_size(), nb_ct);
+    sk_before.encrypt_lwe_list(&mut ciphertexts_before, &messages, std_input);
+
+    let mut ciphertexts_after = LweList::allocate(T::ZERO, dimension_after.to_lwe_size(), nb_ct);
+    let mut variable_after = LweList::allocate(T::ZERO, dimension_after.to_lwe_size(), nb_ct);
+    ksk.keyswitch_list(&mut ciphertexts_after, &ciphertexts_before);
+    variable_ksk.keyswitch_list(&mut variable_after, &ciphertexts_before);
+
+    assert_eq!(ciphertexts_after, variable_after);
+}
+
+#[test]
+fn test_variable_keyswitch_u32() {
+    test_variable_keyswitch::<u32>();
+}
+
+#[test]
+fn test_variable_keyswitch_u64() {
+    test_variable_keyswitch::<u64>();
+}
+
+#[test]
+fn test_variable_keyswitch_u128() {
+    test_variable_keyswitch::<u128>();
+}
+
+fn test_variable_keyswitch_decrypt<T: UnsignedTorus + RandomGenerable<UniformMsb>>() {
+    //! creates a KSK whose decomposition parameters differ from one coefficient of the input key
+    //! to the next, and checks that the switched LWE samples decrypt to the messages
+    let n_bit_msg = 8;
+    let nb_ct = random_ciphertext_count(100);
+    let messages = PlaintextList::from_tensor(random_uniform_n_msb_tensor(nb_ct.0, n_bit_msg));
+    let std_input = LogStandardDev::from_log_standard_dev(-25.);
+    let std_ksk = LogStandardDev::from_log_standard_dev(-25.);
+
+    let dimension_after = LweDimension(600);
+    let sk_after = LweSecretKey::generate(dimension_after);
+    let dimension_before = LweDimension(1024);
+    let sk_before = LweSecretKey::generate(dimension_before);
+
+    // every decomposition keeps at least 20 bits, with bases and level counts of all kinds
+    let decomp_params = [(3, 7), (4, 5), (6, 4), (8, 3)];
+    let decomp_params = (0..dimension_before.0)
+        .map(|i| {
+            let (base_log, level_count) = decomp_params[i % decomp_params.len()];
+            (
+                DecompositionBaseLog(base_log),
+                DecompositionLevelCount(level_count),
+            )
+        })
+        .collect();
+    let mut ksk = VariableDecompKeySwitchingKey::allocate(T::ZERO, decomp_params, dimension_after);
+    ksk.fill_with_keyswitch_key(&sk_before, &sk_after, std_ksk);
+
+    let mut ciphertexts_before = LweList::allocate(T::ZERO, dimension_before.to_lwe_size(), nb_ct);
+    sk_before.encrypt_lwe_list(&mut ciphertexts_before, &messages, std_input);
+    let mut ciphertexts_after = LweList::allocate(T::ZERO, dimension_after.to_lwe_size(), nb_ct);
+    ksk.keyswitch_list(&mut ciphertexts_after, &ciphertexts_before);
+
+    let mut dec_messages = PlaintextList::allocate(T::ZERO, PlaintextCount(nb_ct.0));
+    sk_after.decrypt_lwe_list(&mut dec_messages, &ciphertexts_after);
+
+    // the decrypted messages round to the encrypted ones
+    let half_step = T::ONE << (T::BITS - n_bit_msg - 1);
+    assert!(dec_messages.all_within_wrapping_distance(&messages, half_step - T::ONE));
+}
+
+#[test]
+fn test_variable_keyswitch_decrypt_u32() {
+    test_variable_keyswitch_decrypt::<u32>();
+}
+
+#[test]
+fn test_variable_keyswitch_decrypt_u64() {
+    test_variable_keyswitch_decrypt::<u64>();
+}
+
+#[test]
+fn test_variable_keyswitch_decrypt_u128() {
+    test_variable_keyswitch_decrypt::<u128>();
+}
+
 fn test_encrypt_decrypt<T: UnsignedTorus>() {
     //! encrypts a bunch of messages and decrypts them
     //! warning: std_dev is not randomized
@@ -143,6 +289,88 @@ fn test_encrypt_decrypt_u64() {
     test_encrypt_decrypt::<u64>()
 }
 
+#[test]
+fn test_encrypt_decrypt_u128() {
+    test_encrypt_decrypt::<u128>()
+}
+
+fn test_ct_decrypt<T: UnsignedTorus>() {
+    //! encrypts a bunch of messages and checks that both decryption paths agree
+    let nb_ct = random_ciphertext_count(100);
+    let dimension = random_lwe_dimension(1000);
+    let std_dev = LogStandardDev::from_log_standard_dev(-25.);
+
+    let sk = LweSecretKey::generate(dimension);
+    let messages = PlaintextList::from_tensor(random::random_uniform_tensor(nb_ct.0));
+    let mut ciphertexts = LweList::allocate(T::ZERO, dimension.to_lwe_size(), nb_ct);
+    sk.encrypt_lwe_list(&mut ciphertexts, &messages, std_dev);
+
+    for ciphertext in ciphertexts.ciphertext_iter() {
+        let mut decryption = Plaintext(T::ZERO);
+        sk.decrypt_lwe(&mut decryption, &ciphertext);
+        let mut ct_decryption = Plaintext(T::ZERO);
+        sk.ct_decrypt_lwe(&mut ct_decryption, &ciphertext);
+        assert_eq!(decryption, ct_decryption);
+    }
+}
+
+#[test]
+fn test_ct_decrypt_u32() {
+    test_ct_decrypt::<u32>()
+}
+
+#[test]
+fn test_ct_decrypt_u64() {
+    test_ct_decrypt::<u64>()
+}
+
+#[test]
+fn test_ct_decrypt_u128() {
+    test_ct_decrypt::<u128>()
+}
+
+fn test_modulus_switch<In, Out>()
+where
+    In: UnsignedTorus + CastInto<Out>,
+    Out: UnsignedTorus,
+{
+    //! encrypts messages with a large modulus, switches to a smaller one and decrypts them
+    let nb_ct = random_ciphertext_count(100);
+    let dimension = random_lwe_dimension(1000);
+    let std_dev = LogStandardDev::from_log_standard_dev(-25.);
+
+    let sk = LweSecretKey::generate(dimension);
+    let messages = PlaintextList::from_tensor(random::random_uniform_tensor::<In>(nb_ct.0));
+    let mut ciphertexts = LweList::allocate(In::ZERO, dimension.to_lwe_size(), nb_ct);
+    sk.encrypt_lwe_list(&mut ciphertexts, &messages, std_dev);
+
+    let mut expected = PlaintextList::allocate(Out::ZERO, PlaintextCount(nb_ct.0));
+    expected
+        .as_mut_tensor()
+        .fill_with_one(messages.as_tensor(), |m| switch_modulus(*m));
+    let mut decryptions = PlaintextList::allocate(Out::ZERO, PlaintextCount(nb_ct.0));
+    for (ciphertext, decryption) in ciphertexts
+        .ciphertext_iter()
+        .zip(decryptions.plaintext_iter_mut())
+    {
+        let mut switched = LweCiphertext::allocate(Out::ZERO, dimension.to_lwe_size());
+        switched.fill_with_modulus_switch(&ciphertext);
+        sk.decrypt_lwe(decryption, &switched);
+    }
+
+    assert_delta_std_dev(&expected, &decryptions, std_dev);
+}
+
+#[test]
+fn test_modulus_switch_u128_u64() {
+    test_modulus_switch::<u128, u64>();
+}
+
+#[test]
+fn test_modulus_switch_u64_u32() {
+    test_modulus_switch::<u64, u32>();
+}
+
 fn test_multisum_npe<T>()
 where
     T: UnsignedTorus + RandomGenerable<UniformMsb> + npe::LWE + CastFrom<usize>,
@@ -229,6 +457,11 @@ fn test_multisum_u64() {
     test_multisum_npe::<u64>();
 }
 
+#[test]
+fn test_multisum_u128() {
+    test_multisum_npe::<u128>();
+}
+
 fn test_scalar_mul<T>()
 where
     T: UnsignedTorus + RandomGenerable<UniformMsb> + npe::LWE + CastFrom<usize>,
@@ -303,6 +536,11 @@ fn test_scalar_mul_u64() {
     test_scalar_mul::<u64>();
 }
 
+#[test]
+fn test_scalar_mul_u128() {
+    test_scalar_mul::<u128>();
+}
+
 fn test_scalar_mul_random<T>()
 where
     T: UnsignedTorus + RandomGenerable<UniformMsb> + npe::LWE + CastFrom<usize>,
@@ -381,3 +619,259 @@ fn test_scalar_mul_random_u32() {
 fn test_scalar_mul_random_u64() {
     test_scalar_mul_random::<u64>()
 }
+
+#[test]
+fn test_scalar_mul_random_u128() {
+    test_scalar_mul_random::<u128>()
+}
+
+// Returns the plaintext rounded to its `precision` most significant bits.
+fn decode_msb<T: UnsignedTorus>(plaintext: T, precision: usize) -> T {
+    let shift = T::BITS - precision;
+    plaintext.wrapping_add(T::ONE << (shift - 1)) >> shift
+}
+
+fn test_compression<T: UnsignedTorus + RandomGenerable<UniformMsb>>() {
+    // random settings
+    let nb_ct = random_ciphertext_count(100);
+    let dimension = random_lwe_dimension(1000);
+    let precision = random_usize_between(1..9);
+    let std_dev = LogStandardDev::from_log_standard_dev(-25.);
+    let kept_bits = minimum_kept_bits(dimension, precision);
+
+    // encrypts messages of `precision` bits
+    let sk = LweSecretKey::generate(dimension);
+    let messages: Tensor<Vec<T>> = random_uniform_n_msb_tensor(nb_ct.0, precision);
+
+    for message in messages.iter() {
+        let mut ciphertext = LweCiphertext::allocate(T::ZERO, dimension.to_lwe_size());
+        sk.encrypt_lwe(&mut ciphertext, &Plaintext(*message), std_dev);
+
+        // compresses and checks the size of the packed coefficients
+        let compressed = ciphertext.compress(kept_bits);
+        assert_eq!(compressed.kept_bits(), kept_bits);
+        assert_eq!(compressed.lwe_size(), dimension.to_lwe_size());
+        assert_eq!(
+            compressed.heap_size(),
+            ((dimension.0 + 1) * kept_bits).div_ceil(8)
+        );
+        assert!(compressed.heap_size() < ciphertext.heap_size());
+
+        // decrypts the decompressed ciphertext
+        let decompressed = compressed.decompress();
+        assert_eq!(decompressed.lwe_size(), dimension.to_lwe_size());
+        let mut decryption = Plaintext(T::ZERO);
+        sk.decrypt_lwe(&mut decryption, &decompressed);
+        assert_eq!(
+            decode_msb(decryption.0, precision),
+            decode_msb(*message, precision)
+        );
+    }
+}
+
+#[test]
+fn test_compression_u32() {
+    test_compression::<u32>()
+}
+
+#[test]
+fn test_compression_u64() {
+    test_compression::<u64>()
+}
+
+#[test]
+fn test_compression_keeps_all_bits() {
+    // the minimum number of bits for 30 bits of precision exceeds the 32 bits of the integers
+    let dimension = LweDimension(1024);
+    let kept_bits = minimum_kept_bits(dimension, 30);
+    assert!(kept_bits > u32::BITS as usize);
+
+    let sk = LweSecretKey::generate(dimension);
+    let mut ciphertext = LweCiphertext::allocate(0u32, dimension.to_lwe_size());
+    sk.encrypt_lwe(
+        &mut ciphertext,
+        &Plaintext(0xdead_beef),
+        LogStandardDev::from_log_standard_dev(-25.),
+    );
+
+    // the compression keeps every bit, and is hence lossless
+    let compressed = ciphertext.compress(kept_bits);
+    assert_eq!(compressed.kept_bits(), u32::BITS as usize);
+    assert_eq!(compressed.decompress(), ciphertext);
+}
+
+fn test_compression_below_minimum<T: UnsignedTorus + RandomGenerable<UniformMsb>>() {
+    //! keeps only the bits of the message: the rounding errors of the mask add up to an error
+    //! several times larger than the decoding precision, and most decryptions fail
+    let nb_ct = 100;
+    let dimension = LweDimension(512);
+    let precision = 4;
+    let std_dev = LogStandardDev::from_log_standard_dev(-25.);
+    assert!(precision < minimum_kept_bits(dimension, precision));
+
+    let sk = LweSecretKey::generate(dimension);
+    let messages: Tensor<Vec<T>> = random_uniform_n_msb_tensor(nb_ct, precision);
+    let mut failures = 0;
+    for message in messages.iter() {
+        let mut ciphertext = LweCiphertext::allocate(T::ZERO, dimension.to_lwe_size());
+        sk.encrypt_lwe(&mut ciphertext, &Plaintext(*message), std_dev);
+        let decompressed = ciphertext.compress(precision).decompress();
+        let mut decryption = Plaintext(T::ZERO);
+        sk.decrypt_lwe(&mut decryption, &decompressed);
+        if decode_msb(decryption.0, precision) != decode_msb(*message, precision) {
+            failures += 1;
+        }
+    }
+    assert!(failures > nb_ct / 2);
+}
+
+#[test]
+fn test_compression_below_minimum_u32() {
+    test_compression_below_minimum::<u32>()
+}
+
+#[test]
+fn test_compression_below_minimum_u64() {
+    test_compression_below_minimum::<u64>()
+}
+
+fn test_compressed_deserialization<T: UnsignedTorus>() {
+    let lwe_size = random_lwe_dimension(1000).to_lwe_size();
+    let kept_bits = random_usize_between(1..T::BITS + 1);
+    let mut ciphertext = LweCiphertext::allocate(T::ZERO, lwe_size);
+    fill_with_random_uniform(&mut ciphertext);
+    let compressed = ciphertext.compress(kept_bits);
+
+    // a valid compressed ciphertext goes through the serialization
+    let bytes = bincode::serialize(&compressed).unwrap();
+    let deserialized: CompressedLweCiphertext<T> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(deserialized, compressed);
+
+    // the fields are serialized in the same way as a tuple, which allows to forge invalid ones
+    let packed_len = (lwe_size.0 * kept_bits).div_ceil(8);
+    let invalid_fields = [
+        (vec![0u8; packed_len], 0, lwe_size),
+        (vec![0u8; packed_len], T::BITS + 1, lwe_size),
+        (vec![0u8; packed_len - 1], kept_bits, lwe_size),
+        (vec![0u8; packed_len + 1], kept_bits, lwe_size),
+        (vec![], kept_bits, LweSize(0)),
+    ];
+    for fields in invalid_fields.iter() {
+        let bytes = bincode::serialize(fields).unwrap();
+        assert!(bincode::deserialize::<CompressedLweCiphertext<T>>(&bytes).is_err());
+    }
+}
+
+#[test]
+fn test_compressed_deserialization_u32() {
+    test_compressed_deserialization::<u32>()
+}
+
+#[test]
+fn test_compressed_deserialization_u64() {
+    test_compressed_deserialization::<u64>()
+}
+
+fn test_compressed_padding_bits<T: UnsignedTorus>() {
+    // 3 coefficients of 5 bits leave a single padding bit in the second byte
+    let ciphertext = LweCiphertext::from_container(vec![T::ONE << (T::BITS - 1); 3]);
+    let compressed = ciphertext.compress(5);
+    let bytes = bincode::serialize(&compressed).unwrap();
+    assert_eq!(
+        bincode::deserialize::<CompressedLweCiphertext<T>>(&bytes).unwrap(),
+        compressed
+    );
+
+    // the same coefficients with the padding bit set are rejected
+    let mut data = compressed.data.clone();
+    assert_eq!(data.len(), 2);
+    data[1] |= 0x80;
+    let bytes = bincode::serialize(&(data.clone(), 5usize, LweSize(3))).unwrap();
+    assert!(bincode::deserialize::<CompressedLweCiphertext<T>>(&bytes).is_err());
+    assert!(CompressedLweCiphertext::<T>::try_new(data, 5, LweSize(3)).is_err());
+}
+
+#[test]
+fn test_compressed_padding_bits_u32() {
+    test_compressed_padding_bits::<u32>()
+}
+
+#[test]
+fn test_compressed_padding_bits_u64() {
+    test_compressed_padding_bits::<u64>()
+}
+
+fn test_extract_bit_encoding<T: UnsignedTorus>() {
+    // random settings, with a position leaving enough room below for the noise
+    let nb_ct = random_ciphertext_count(100);
+    let dimension = random_lwe_dimension(1000);
+    let std_dev = LogStandardDev::from_log_standard_dev(-25.);
+    let sk = LweSecretKey::generate(dimension);
+
+    for _ in 0..nb_ct.0 {
+        let bit_position = random_usize_between(T::BITS - 16..T::BITS);
+        let message = random::random_uniform::<T>() << bit_position;
+        let mut ciphertext = LweCiphertext::allocate(T::ZERO, dimension.to_lwe_size());
+        sk.encrypt_lwe(&mut ciphertext, &Plaintext(message), std_dev);
+
+        // extracts and decrypts
+        let bit = ciphertext.extract_bit_encoding(bit_position);
+        let mut decryption = Plaintext(T::ZERO);
+        sk.decrypt_lwe(&mut decryption, &bit);
+
+        // test
+        assert_eq!(
+            decode_msb(decryption.0, 1),
+            (message >> bit_position) & T::ONE
+        );
+    }
+}
+
+#[test]
+fn test_extract_bit_encoding_u32() {
+    test_extract_bit_encoding::<u32>()
+}
+
+#[test]
+fn test_extract_bit_encoding_u64() {
+    test_extract_bit_encoding::<u64>()
+}
+
+fn test_rerandomize<T: UnsignedTorus>() {
+    // random settings
+    let nb_ct = random_ciphertext_count(100);
+    let dimension = random_lwe_dimension(1000);
+    let std_dev = LogStandardDev::from_log_standard_dev(-25.);
+    let sk = LweSecretKey::generate(dimension);
+    let messages = PlaintextList::from_tensor(random::random_uniform_tensor(nb_ct.0));
+    let mut ciphertexts = LweList::allocate(T::ZERO, dimension.to_lwe_size(), nb_ct);
+    sk.encrypt_lwe_list(&mut ciphertexts, &messages, std_dev);
+
+    // rerandomizes every ciphertext
+    let previous = ciphertexts.clone();
+    for mut ciphertext in ciphertexts.ciphertext_iter_mut() {
+        ciphertext.rerandomize(&sk, std_dev);
+    }
+    for (ciphertext, previous) in ciphertexts
+        .ciphertext_iter()
+        .zip(previous.ciphertext_iter())
+    {
+        assert_ne!(ciphertext.get_mask(), previous.get_mask());
+    }
+
+    // the error of the input is kept, so the variances add up
+    let mut decryptions = PlaintextList::allocate(T::ZERO, PlaintextCount(nb_ct.0));
+    sk.decrypt_lwe_list(&mut decryptions, &ciphertexts);
+    let output_variance = Variance(2. * std_dev.get_variance());
+    assert_delta_std_dev(&messages, &decryptions, output_variance);
+}
+
+#[test]
+fn test_rerandomize_u32() {
+    test_rerandomize::<u32>()
+}
+
+#[test]
+fn test_rerandomize_u64() {
+    test_rerandomize::<u64>()
+}
@@ -0,0 +1,396 @@
+use serde::{Deserialize, Serialize};
+
+use crate::crypto::encoding::{Plaintext, PlaintextList};
+use crate::crypto::secret::LweSecretKey;
+use crate::crypto::{LweDimension, LweSize, PlaintextCount, UnsignedTorus};
+use crate::math::decomposition::{
+    DecompositionBaseLog, DecompositionLevel, DecompositionLevelCount,
+};
+use crate::math::dispersion::DispersionParameter;
+use crate::math::tensor::{
+    AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, MemoryFootprint, Tensor,
+};
+use crate::numeric::CastFrom;
+use crate::{ck_dim_eq, tensor_traits};
+
+use super::keyswitch::{torus_small_sign_decompose, LweKeyBitDecomposition};
+use super::{LweCiphertext, LweList};
+
+/// An Lwe keyswitching key using a different decomposition for each coefficient of the input
+/// key.
+///
+/// This key works the same way as the [`LweKeyswitchKey`](super::LweKeyswitchKey), except that
+/// every bit $s\_i$ of the input key is associated with its own decomposition parameters
+/// $(B\_i, l\_i)$. The key then contains, for each input key bit, $l\_i$ lwe ciphertexts
+/// encrypting the levels of the decomposition of $s\_i$ in base $2^{B\_i}$. This allows to tune
+/// the decomposition coefficient by coefficient, to trade some key size for a smaller output
+/// noise where it matters.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VariableDecompKeySwitchingKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_params: Vec<(DecompositionBaseLog, DecompositionLevelCount)>,
+    lwe_size: LweSize,
+}
+
+tensor_traits!(VariableDecompKeySwitchingKey);
+
+impl<Scalar> MemoryFootprint for VariableDecompKeySwitchingKey<Vec<Scalar>> {
+    fn heap_size(&self) -> usize {
+        self.tensor.heap_size()
+            + self.decomp_params.len()
+                * std::mem::size_of::<(DecompositionBaseLog, DecompositionLevelCount)>()
+    }
+}
+
+impl<Scalar> VariableDecompKeySwitchingKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a keyswitching key whose values are all `value`.
+    ///
+    /// The size of the input key is given by the number of decomposition parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::crypto::{*, lwe::VariableDecompKeySwitchingKey};
+    /// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+    /// let ksk = VariableDecompKeySwitchingKey::allocate(
+    ///     0 as u8,
+    ///     vec![
+    ///         (DecompositionBaseLog(4), DecompositionLevelCount(3)),
+    ///         (DecompositionBaseLog(2), DecompositionLevelCount(5)),
+    ///     ],
+    ///     LweDimension(20)
+    /// );
+    /// assert_eq!(ksk.before_key_size(), LweDimension(2));
+    /// assert_eq!(ksk.after_key_size(), LweDimension(20));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        decomp_params: Vec<(DecompositionBaseLog, DecompositionLevelCount)>,
+        output_size: LweDimension,
+    ) -> Self {
+        let lwe_size = output_size.to_lwe_size();
+        let levels: usize = decomp_params.iter().map(|(_, level)| level.0).sum();
+        VariableDecompKeySwitchingKey {
+            tensor: Tensor::from_container(vec![value; levels * lwe_size.0]),
+            decomp_params,
+            lwe_size,
+        }
+    }
+}
+
+impl<Cont> VariableDecompKeySwitchingKey<Cont> {
+    /// Creates a keyswitching key from a container.
+    ///
+    /// # Notes
+    ///
+    /// This method does not create a keyswitching key, but merely wraps the container in the
+    /// proper type. It assumes that the container holds, for each set of decomposition
+    /// parameters, as many lwe ciphertexts of size `output_size + 1` as there are levels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::crypto::{*, lwe::VariableDecompKeySwitchingKey};
+    /// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+    /// let ksk = VariableDecompKeySwitchingKey::from_container(
+    ///     vec![0 as u8; 8 * 21],
+    ///     vec![
+    ///         (DecompositionBaseLog(4), DecompositionLevelCount(3)),
+    ///         (DecompositionBaseLog(2), DecompositionLevelCount(5)),
+    ///     ],
+    ///     LweDimension(20)
+    /// );
+    /// assert_eq!(ksk.before_key_size(), LweDimension(2));
+    /// assert_eq!(ksk.lwe_size(), LweSize(21));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        decomp_params: Vec<(DecompositionBaseLog, DecompositionLevelCount)>,
+        output_size: LweDimension,
+    ) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        let lwe_size = output_size.to_lwe_size();
+        ck_dim_eq!(
+            tensor.len() =>
+            decomp_params.iter().map(|(_, level)| level.0).sum::<usize>() * lwe_size.0
+        );
+        VariableDecompKeySwitchingKey {
+            tensor,
+            decomp_params,
+            lwe_size,
+        }
+    }
+
+    /// Returns the size of the input key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::crypto::{*, lwe::VariableDecompKeySwitchingKey};
+    /// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+    /// let ksk = VariableDecompKeySwitchingKey::allocate(
+    ///     0 as u8,
+    ///     vec![(DecompositionBaseLog(4), DecompositionLevelCount(3)); 10],
+    ///     LweDimension(20)
+    /// );
+    /// assert_eq!(ksk.before_key_size(), LweDimension(10));
+    /// ```
+    pub fn before_key_size(&self) -> LweDimension {
+        LweDimension(self.decomp_params.len())
+    }
+
+    /// Returns the size of the output key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::crypto::{*, lwe::VariableDecompKeySwitchingKey};
+    /// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+    /// let ksk = VariableDecompKeySwitchingKey::allocate(
+    ///     0 as u8,
+    ///     vec![(DecompositionBaseLog(4), DecompositionLevelCount(3)); 10],
+    ///     LweDimension(20)
+    /// );
+    /// assert_eq!(ksk.after_key_size(), LweDimension(20));
+    /// ```
+    pub fn after_key_size(&self) -> LweDimension {
+        self.lwe_size.to_lwe_dimension()
+    }
+
+    /// Returns the size of the ciphertexts encoding the levels of the decompositions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::crypto::{*, lwe::VariableDecompKeySwitchingKey};
+    /// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+    /// let ksk = VariableDecompKeySwitchingKey::allocate(
+    ///     0 as u8,
+    ///     vec![(DecompositionBaseLog(4), DecompositionLevelCount(3)); 10],
+    ///     LweDimension(20)
+    /// );
+    /// assert_eq!(ksk.lwe_size(), LweSize(21));
+    /// ```
+    pub fn lwe_size(&self) -> LweSize {
+        self.lwe_size
+    }
+
+    /// Returns the decomposition parameters used for each coefficient of the input key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::crypto::{*, lwe::VariableDecompKeySwitchingKey};
+    /// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+    /// let ksk = VariableDecompKeySwitchingKey::allocate(
+    ///     0 as u8,
+    ///     vec![
+    ///         (DecompositionBaseLog(4), DecompositionLevelCount(3)),
+    ///         (DecompositionBaseLog(2), DecompositionLevelCount(5)),
+    ///     ],
+    ///     LweDimension(20)
+    /// );
+    /// assert_eq!(
+    ///     ksk.decomposition_parameters()[1],
+    ///     (DecompositionBaseLog(2), DecompositionLevelCount(5))
+    /// );
+    /// ```
+    pub fn decomposition_parameters(&self) -> &[(DecompositionBaseLog, DecompositionLevelCount)] {
+        &self.decomp_params
+    }
+
+    /// Fills the current keyswitch key container with an actual keyswitching key constructed from
+    /// an input and an output key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::crypto::{*, secret::LweSecretKey, lwe::VariableDecompKeySwitchingKey};
+    /// use concrete_core::math::tensor::AsRefTensor;
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+    ///
+    /// let input_size = LweDimension(10);
+    /// let output_size = LweDimension(20);
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let input_key = LweSecretKey::generate(input_size);
+    /// let output_key = LweSecretKey::generate(output_size);
+    ///
+    /// let mut ksk = VariableDecompKeySwitchingKey::allocate(
+    ///     0 as u32,
+    ///     vec![(DecompositionBaseLog(3), DecompositionLevelCount(5)); 10],
+    ///     output_size
+    /// );
+    /// ksk.fill_with_keyswitch_key(&input_key, &output_key, noise);
+    ///
+    /// assert!(!ksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_keyswitch_key<InKeyCont, OutKeyCont, Scalar>(
+        &mut self,
+        before_key: &LweSecretKey<InKeyCont>,
+        after_key: &LweSecretKey<OutKeyCont>,
+        noise_parameters: impl DispersionParameter,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<InKeyCont>: AsRefTensor<Element = bool>,
+        LweSecretKey<OutKeyCont>: AsRefTensor<Element = bool>,
+        Scalar: UnsignedTorus + CastFrom<bool>,
+    {
+        ck_dim_eq!(self.before_key_size() => before_key.key_size());
+        ck_dim_eq!(self.after_key_size() => after_key.key_size());
+
+        let lwe_size = self.lwe_size;
+        let mut offset = 0;
+        for (input_key_bit, (base_log, level_count)) in before_key
+            .as_tensor()
+            .iter()
+            .zip(self.decomp_params.clone())
+        {
+            // We fill a buffer with the powers of the key bit
+            let mut messages = PlaintextList::allocate(Scalar::ZERO, PlaintextCount(level_count.0));
+            for (level, message) in (0..level_count.0).zip(messages.plaintext_iter_mut()) {
+                *message = Plaintext(
+                    Scalar::cast_from(*input_key_bit)
+                        .set_val_at_level(base_log, DecompositionLevel(level)),
+                );
+            }
+
+            // We encrypt the buffer in the block of the key bit
+            let block_size = level_count.0 * lwe_size.0;
+            let block = self
+                .as_mut_tensor()
+                .get_sub_mut(offset..offset + block_size);
+            let keyswitch_key_block =
+                LweKeyBitDecomposition::from_container(block.into_container(), lwe_size);
+            after_key.encrypt_lwe_list(
+                &mut keyswitch_key_block.into_lwe_list(),
+                &messages,
+                noise_parameters.clone(),
+            );
+            offset += block_size;
+        }
+    }
+
+    /// Switches the key of a single Lwe ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::{*, secret::LweSecretKey};
+    /// use concrete_core::crypto::{lwe::*, encoding::*};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+    ///
+    /// let input_size = LweDimension(1024);
+    /// let output_size = LweDimension(1024);
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    ///
+    /// let input_key = LweSecretKey::generate(input_size);
+    /// let output_key = LweSecretKey::generate(output_size);
+    ///
+    /// let decomp_params = (0..input_size.0)
+    ///     .map(|i| if i % 2 == 0 {
+    ///         (DecompositionBaseLog(3), DecompositionLevelCount(8))
+    ///     } else {
+    ///         (DecompositionBaseLog(4), DecompositionLevelCount(6))
+    ///     })
+    ///     .collect();
+    /// let mut ksk = VariableDecompKeySwitchingKey::allocate(0 as u64, decomp_params, output_size);
+    /// ksk.fill_with_keyswitch_key(&input_key, &output_key, noise);
+    ///
+    /// let plaintext: Plaintext<u64> = Plaintext(1 << 60);
+    /// let mut ciphertext = LweCiphertext::allocate(0 as u64, LweSize(1025));
+    /// let mut switched_ciphertext = LweCiphertext::allocate(0 as u64, LweSize(1025));
+    /// input_key.encrypt_lwe(&mut ciphertext, &plaintext, noise);
+    ///
+    /// ksk.keyswitch_ciphertext(&mut switched_ciphertext, &ciphertext);
+    ///
+    /// let mut decrypted = Plaintext(0 as u64);
+    /// output_key.decrypt_lwe(&mut decrypted, &switched_ciphertext);
+    /// assert_eq!(decrypted.0.wrapping_add(1 << 59) >> 60, 1);
+    /// ```
+    pub fn keyswitch_ciphertext<InCont, OutCont, Scalar>(
+        &self,
+        after: &mut LweCiphertext<OutCont>,
+        before: &LweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        #[cfg(feature = "metrics")]
+        let _timer = crate::metrics::KEYSWITCH.start();
+        ck_dim_eq!(self.before_key_size() => before.get_mask().mask_size());
+        ck_dim_eq!(self.after_key_size() => after.get_mask().mask_size());
+
+        // We reset the output
+        after.as_mut_tensor().fill_with(|| Scalar::ZERO);
+
+        // We copy the body
+        *after.get_mut_body() = *before.get_body();
+
+        // We allocate a buffer large enough to hold the largest decomposition.
+        let max_level = self
+            .decomp_params
+            .iter()
+            .map(|(_, level)| level.0)
+            .max()
+            .unwrap_or(0);
+        let mut decomp = Tensor::allocate(Scalar::ZERO, max_level);
+
+        let mut offset = 0;
+        for ((base_log, level_count), before_mask) in self
+            .decomp_params
+            .iter()
+            .zip(before.get_mask().mask_element_iter())
+        {
+            let block_size = level_count.0 * self.lwe_size.0;
+            let block = self.as_tensor().get_sub(offset..offset + block_size);
+            offset += block_size;
+
+            let mask_rounded = before_mask.round_to_closest_multiple(*base_log, *level_count);
+            let mut level_decomp = decomp.get_sub_mut(0..level_count.0);
+            torus_small_sign_decompose(level_decomp.as_mut_slice(), mask_rounded, base_log.0);
+
+            // loop over the number of levels
+            for (level_key_cipher, decomposed) in block
+                .subtensor_iter(self.lwe_size.0)
+                .zip(level_decomp.iter())
+            {
+                after
+                    .as_mut_tensor()
+                    .update_with_wrapping_sub_element_mul(&level_key_cipher, *decomposed);
+            }
+        }
+    }
+
+    /// Switches the key of a list of Lwe ciphertexts.
+    ///
+    /// See [`VariableDecompKeySwitchingKey::keyswitch_ciphertext`] for an example on a single
+    /// ciphertext.
+    pub fn keyswitch_list<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut LweList<OutCont>,
+        input: &LweList<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweList<InCont>: AsRefTensor<Element = Scalar>,
+        LweList<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(input.count() => output.count());
+        for (input_cipher, mut output_cipher) in
+            input.ciphertext_iter().zip(output.ciphertext_iter_mut())
+        {
+            self.keyswitch_ciphertext(&mut output_cipher, &input_cipher);
+        }
+    }
+}
@@ -7,18 +7,24 @@ use std::fmt::{Debug, Display};
 use serde::{Deserialize, Serialize};
 
 use crate::math::decomposition::SignedDecomposable;
+use crate::math::polynomial::PolynomialSize;
 use crate::math::random::{Gaussian, RandomGenerable, Uniform};
 use crate::math::torus::{FromTorus, IntoTorus};
 use crate::numeric::{CastFrom, CastInto, UnsignedInteger};
 
+pub mod boolean;
 pub mod bootstrap;
 pub mod cross;
 pub mod encoding;
 pub mod ggsw;
 pub mod glwe;
 pub mod lwe;
+pub mod parameters;
 pub mod secret;
 
+mod noise;
+pub use noise::*;
+
 /// A marker trait for unsigned integer types that can be used in ciphertexts, keys etc.
 pub trait UnsignedTorus:
     UnsignedInteger
@@ -38,6 +44,72 @@ pub trait UnsignedTorus:
 impl UnsignedTorus for u32 {}
 impl UnsignedTorus for u64 {}
 
+/// High-precision torus elements.
+///
+/// All the linear operations (encryption, decryption, additions, multiplications by cleartexts,
+/// keyswitches) are exact at this precision, which allows to encrypt messages of more than 32
+/// bits without resorting to a CRT decomposition.
+///
+/// The fourier transforms are not: the coefficients are converted to `f64` before being
+/// transformed, which only keeps their 53 most significant bits. External products and
+/// bootstraps can still be performed on `u128` ciphertexts, but their output only has about
+/// `f64` precision. To bootstrap a high-precision ciphertext, switch it down to `u64` first with
+/// [`LweCiphertext::fill_with_modulus_switch`](lwe::LweCiphertext::fill_with_modulus_switch).
+impl UnsignedTorus for u128 {}
+
+/// A uniform access to the parameters of the different ciphertext types.
+///
+/// The ciphertext types expose their dimensions through methods specific to each scheme. This
+/// trait gives the parameters common to all of them, which allows to write functions generic
+/// over the type of ciphertext.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::crypto::glwe::GlweCiphertext;
+/// use concrete_core::crypto::lwe::LweCiphertext;
+/// use concrete_core::crypto::{CiphertextMetadata, GlweSize, LweSize};
+/// use concrete_core::math::polynomial::PolynomialSize;
+///
+/// fn describe(ciphertext: &impl CiphertextMetadata) -> String {
+///     match ciphertext.polynomial_size() {
+///         Some(size) => format!(
+///             "{} bits, lwe dimension {}, polynomials of size {}",
+///             ciphertext.scalar_type_bits(),
+///             ciphertext.lwe_dimension().0,
+///             size.0
+///         ),
+///         None => format!(
+///             "{} bits, lwe dimension {}",
+///             ciphertext.scalar_type_bits(),
+///             ciphertext.lwe_dimension().0
+///         ),
+///     }
+/// }
+///
+/// let lwe = LweCiphertext::allocate(0u32, LweSize(631));
+/// assert_eq!(describe(&lwe), "32 bits, lwe dimension 630");
+/// let glwe = GlweCiphertext::allocate(0u64, PolynomialSize(1024), GlweSize(3));
+/// assert_eq!(
+///     describe(&glwe),
+///     "64 bits, lwe dimension 2048, polynomials of size 1024"
+/// );
+/// ```
+pub trait CiphertextMetadata {
+    /// Returns the number of bits of the integers representing the torus elements.
+    fn scalar_type_bits(&self) -> usize;
+
+    /// Returns the dimension of the LWE secret key the ciphertext is (or, for GLWE and GGSW
+    /// ciphertexts, the sample-extracted LWE ciphertexts are) encrypted under.
+    ///
+    /// For GLWE and GGSW ciphertexts, this is the dimension of the flattened GLWE secret key,
+    /// e.g. the GLWE dimension times the polynomial size.
+    fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the size of the polynomials of the ciphertext, or `None` for an LWE ciphertext.
+    fn polynomial_size(&self) -> Option<PolynomialSize>;
+}
+
 /// The number plaintexts in a plaintext list.
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct PlaintextCount(pub usize);
@@ -0,0 +1,112 @@
+use crate::crypto::parameters::LweParameters;
+use crate::math::dispersion::DispersionParameter;
+
+/// The number of standard deviations of the error which must fit below the decoding bound, for a
+/// ciphertext to be considered decryptable. Above eight standard deviations, the probability of a
+/// gaussian error is smaller than $2^{-49}$.
+const CONFIDENCE_LOG2: f64 = 3.;
+
+/// A static estimate of the noise budget of an LWE ciphertext.
+///
+/// A message encoded on the $p$ most significant bits of the torus is decrypted correctly as long
+/// as the error stays below $2^{-(p+1)}$. The budget is the number of bits between this bound and
+/// eight standard deviations of the error, and it decreases with every linear operation applied
+/// to the ciphertext. Once the budget is expired, the ciphertext must be bootstrapped before the
+/// error reaches the bound.
+///
+/// This is only a bookkeeping tool: the budget is computed from the parameters and the
+/// operations, and never from the ciphertexts themselves. The errors of the combined ciphertexts
+/// are assumed to be independent.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::crypto::parameters::LweParameters;
+/// use concrete_core::crypto::{LweDimension, NoiseBudget};
+/// use concrete_core::math::dispersion::LogStandardDev;
+/// let params = LweParameters::new(LweDimension(630), LogStandardDev(-15.)).unwrap();
+/// // Two bits of message leave 15 - 3 - 3 bits of budget.
+/// let fresh = NoiseBudget::after_encryption(&params, 2);
+/// assert_eq!(fresh.remaining_bits(), 9.);
+/// // The sum of four ciphertexts doubles the standard deviation.
+/// let double = fresh.after_addition(&fresh);
+/// let sum = double.after_addition(&double);
+/// assert_eq!(sum.remaining_bits(), 8.);
+/// // A multiplication by 512 consumes the remaining bits.
+/// assert!(!sum.after_scalar_mul(256).is_expired());
+/// assert!(sum.after_scalar_mul(512).is_expired());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NoiseBudget {
+    // The log2 of the largest error allowing a correct decryption.
+    log2_bound: f64,
+    // The variance of the error, on the torus.
+    variance: f64,
+}
+
+impl NoiseBudget {
+    /// Returns the budget of a fresh encryption of a message of `message_bits` bits, with the
+    /// noise of `params`.
+    pub fn after_encryption(params: &LweParameters, message_bits: usize) -> Self {
+        NoiseBudget {
+            log2_bound: -(message_bits as f64 + 1.),
+            variance: params.noise().get_variance(),
+        }
+    }
+
+    /// Returns the budget of the output of a boolean gate, whose error is given by
+    /// `output_noise`.
+    ///
+    /// The gates of the [`boolean`](crate::crypto::boolean) module end with a bootstrap, which
+    /// resets the error of the output independently of the inputs, followed by a keyswitch. The
+    /// `output_noise` is hence the dispersion of the error after the bootstrap and the keyswitch.
+    /// The bits are encoded as $\pm\frac{1}{8}$, which gives a decoding bound of
+    /// $\frac{1}{8}$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::crypto::NoiseBudget;
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// let budget = NoiseBudget::after_gate_evaluation(LogStandardDev(-12.));
+    /// assert_eq!(budget.remaining_bits(), 6.);
+    /// ```
+    pub fn after_gate_evaluation(output_noise: impl DispersionParameter) -> Self {
+        NoiseBudget {
+            log2_bound: -3.,
+            variance: output_noise.get_variance(),
+        }
+    }
+
+    /// Returns the budget of the sum, or the difference, of two ciphertexts.
+    ///
+    /// The variances of the errors add up, and the result can only be decoded at the precision
+    /// of the least precise operand.
+    pub fn after_addition(&self, other: &NoiseBudget) -> Self {
+        NoiseBudget {
+            log2_bound: self.log2_bound.min(other.log2_bound),
+            variance: self.variance + other.variance,
+        }
+    }
+
+    /// Returns the budget of the product of a ciphertext by an integer, which multiplies the
+    /// standard deviation of the error by the absolute value of the integer.
+    pub fn after_scalar_mul(&self, scalar: i64) -> Self {
+        NoiseBudget {
+            log2_bound: self.log2_bound,
+            variance: self.variance * (scalar as f64).powi(2),
+        }
+    }
+
+    /// Returns the number of bits of budget left, which may be negative once the budget is
+    /// expired.
+    pub fn remaining_bits(&self) -> f64 {
+        self.log2_bound - self.variance.log2() / 2. - CONFIDENCE_LOG2
+    }
+
+    /// Returns whether the budget is expired, in which case the ciphertext may not decrypt
+    /// correctly anymore.
+    pub fn is_expired(&self) -> bool {
+        self.remaining_bits() < 0.
+    }
+}
@@ -0,0 +1,404 @@
+//! Parameter bundles
+//!
+//! The constructors of the keys and ciphertexts take their dimensions as separate positional
+//! arguments, which makes it easy to mix them up. The types of this module gather the parameters
+//! which always go together, and check that their values are sensible when they are created:
+//!
+//! + [`LweParameters`] describes an LWE instance (dimension and noise).
+//! + [`GlweParameters`] describes a GLWE instance (dimension, polynomial size and noise).
+//! + [`PbsParameters`] describes the decomposition used in a bootstrapping key.
+//! + [`LweKeyswitchParameters`] describes a key switching key between two LWE instances.
+//!
+//! The keys and ciphertexts expose `*_with` constructors consuming those bundles.
+use crate::crypto::{GlweDimension, GlweSize, LweDimension, LweSize};
+use crate::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+use crate::math::dispersion::{DispersionParameter, LogStandardDev};
+use crate::math::polynomial::PolynomialSize;
+use crate::numeric::Numeric;
+use crate::Error;
+
+#[cfg(test)]
+mod tests;
+
+/// The largest number of bits a decomposition can cover, e.g. the precision of the widest
+/// supported torus type.
+const MAX_DECOMPOSITION_BITS: usize = 64;
+
+fn check_noise(noise: &LogStandardDev) -> Result<(), Error> {
+    if !noise.0.is_finite() || noise.0 >= 0. {
+        return Err(Error::InvalidParameter {
+            parameter: "noise",
+            reason: format!(
+                "the log2 of the standard deviation must be negative, got {}",
+                noise.0
+            ),
+        });
+    }
+    Ok(())
+}
+
+fn check_decomposition(
+    base_log: DecompositionBaseLog,
+    level_count: DecompositionLevelCount,
+) -> Result<(), Error> {
+    if base_log.0 == 0 {
+        return Err(Error::InvalidParameter {
+            parameter: "decomposition base log",
+            reason: "must be non-zero".into(),
+        });
+    }
+    if level_count.0 == 0 {
+        return Err(Error::InvalidParameter {
+            parameter: "decomposition level count",
+            reason: "must be non-zero".into(),
+        });
+    }
+    if base_log.0 * level_count.0 > MAX_DECOMPOSITION_BITS {
+        return Err(Error::InvalidParameter {
+            parameter: "decomposition",
+            reason: format!(
+                "base log times level count must not exceed {} bits, got {}",
+                MAX_DECOMPOSITION_BITS,
+                base_log.0 * level_count.0
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Checks that a decomposition fits in the bits of `Scalar`.
+///
+/// The bundles do not know the integer type of the keys, and only check the decomposition against
+/// [`MAX_DECOMPOSITION_BITS`]. The constructors which do know it call this function on top.
+pub(crate) fn check_decomposition_bits<Scalar: Numeric>(
+    base_log: DecompositionBaseLog,
+    level_count: DecompositionLevelCount,
+) -> Result<(), Error> {
+    if base_log.0 * level_count.0 > Scalar::BITS {
+        return Err(Error::InvalidParameter {
+            parameter: "decomposition",
+            reason: format!(
+                "base log times level count must not exceed the {} bits of the integer type",
+                Scalar::BITS
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// The parameters of an LWE instance.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::crypto::parameters::LweParameters;
+/// use concrete_core::crypto::{LweDimension, LweSize};
+/// use concrete_core::math::dispersion::LogStandardDev;
+/// let params = LweParameters::new(LweDimension(630), LogStandardDev(-15.)).unwrap();
+/// assert_eq!(params.dimension(), LweDimension(630));
+/// assert_eq!(params.size(), LweSize(631));
+/// assert_eq!(params.noise(), LogStandardDev(-15.));
+///
+/// assert!(LweParameters::new(LweDimension(0), LogStandardDev(-15.)).is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LweParameters {
+    dimension: LweDimension,
+    noise: LogStandardDev,
+}
+
+impl LweParameters {
+    /// Creates a new set of LWE parameters.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the dimension is zero, or if the standard deviation of the noise is not smaller
+    /// than one.
+    pub fn new(dimension: LweDimension, noise: impl DispersionParameter) -> Result<Self, Error> {
+        if dimension.0 == 0 {
+            return Err(Error::InvalidParameter {
+                parameter: "lwe dimension",
+                reason: "must be non-zero".into(),
+            });
+        }
+        let noise = LogStandardDev(noise.get_log_standard_dev());
+        check_noise(&noise)?;
+        Ok(LweParameters { dimension, noise })
+    }
+
+    /// Returns the dimension of the LWE mask.
+    pub fn dimension(&self) -> LweDimension {
+        self.dimension
+    }
+
+    /// Returns the size of an LWE ciphertext, e.g. the dimension + 1.
+    pub fn size(&self) -> LweSize {
+        self.dimension.to_lwe_size()
+    }
+
+    /// Returns the noise used when encrypting.
+    pub fn noise(&self) -> LogStandardDev {
+        self.noise
+    }
+}
+
+/// The parameters of a GLWE instance.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::crypto::parameters::GlweParameters;
+/// use concrete_core::crypto::{GlweDimension, GlweSize, LweDimension};
+/// use concrete_core::math::dispersion::LogStandardDev;
+/// use concrete_core::math::polynomial::PolynomialSize;
+/// let params =
+///     GlweParameters::new(GlweDimension(1), PolynomialSize(1024), LogStandardDev(-29.)).unwrap();
+/// assert_eq!(params.dimension(), GlweDimension(1));
+/// assert_eq!(params.size(), GlweSize(2));
+/// assert_eq!(params.polynomial_size(), PolynomialSize(1024));
+/// assert_eq!(params.to_lwe_parameters().dimension(), LweDimension(1024));
+///
+/// // The polynomial size must be a power of two.
+/// assert!(
+///     GlweParameters::new(GlweDimension(1), PolynomialSize(1000), LogStandardDev(-29.)).is_err()
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GlweParameters {
+    dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    noise: LogStandardDev,
+}
+
+impl GlweParameters {
+    /// Creates a new set of GLWE parameters.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the dimension is zero, if the polynomial size is not a power of two, or if the
+    /// standard deviation of the noise is not smaller than one.
+    pub fn new(
+        dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        noise: impl DispersionParameter,
+    ) -> Result<Self, Error> {
+        if dimension.0 == 0 {
+            return Err(Error::InvalidParameter {
+                parameter: "glwe dimension",
+                reason: "must be non-zero".into(),
+            });
+        }
+        if !polynomial_size.0.is_power_of_two() {
+            return Err(Error::InvalidParameter {
+                parameter: "polynomial size",
+                reason: format!("must be a power of two, got {}", polynomial_size.0),
+            });
+        }
+        let noise = LogStandardDev(noise.get_log_standard_dev());
+        check_noise(&noise)?;
+        Ok(GlweParameters {
+            dimension,
+            polynomial_size,
+            noise,
+        })
+    }
+
+    /// Returns the number of polynomials in the GLWE mask.
+    pub fn dimension(&self) -> GlweDimension {
+        self.dimension
+    }
+
+    /// Returns the size of a GLWE ciphertext, e.g. the dimension + 1.
+    pub fn size(&self) -> GlweSize {
+        self.dimension.to_glwe_size()
+    }
+
+    /// Returns the number of coefficients of the polynomials.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Returns the noise used when encrypting.
+    pub fn noise(&self) -> LogStandardDev {
+        self.noise
+    }
+
+    /// Returns the parameters of the LWE ciphertexts extracted from a GLWE ciphertext, which are
+    /// encrypted under the flattened GLWE secret key.
+    pub fn to_lwe_parameters(&self) -> LweParameters {
+        LweParameters {
+            dimension: LweDimension(self.dimension.0 * self.polynomial_size.0),
+            noise: self.noise,
+        }
+    }
+}
+
+/// The decomposition parameters of a bootstrapping key.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::crypto::parameters::PbsParameters;
+/// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+/// let params = PbsParameters::new(DecompositionBaseLog(7), DecompositionLevelCount(3)).unwrap();
+/// assert_eq!(params.base_log(), DecompositionBaseLog(7));
+/// assert_eq!(params.level_count(), DecompositionLevelCount(3));
+///
+/// assert!(PbsParameters::new(DecompositionBaseLog(0), DecompositionLevelCount(3)).is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PbsParameters {
+    base_log: DecompositionBaseLog,
+    level_count: DecompositionLevelCount,
+}
+
+impl PbsParameters {
+    /// Creates a new set of bootstrapping parameters.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the base log or the level count is zero, or if the decomposition covers more
+    /// than 64 bits.
+    ///
+    /// Only the 64 bits of the widest integer type are checked here, since the bundle does not
+    /// know the integer type of the keys. The constructors generating a key, such as
+    /// [`BootstrapKeyBuilder::build`](crate::crypto::bootstrap::BootstrapKeyBuilder::build),
+    /// check the decomposition against the bits of their integer type as well.
+    pub fn new(
+        base_log: DecompositionBaseLog,
+        level_count: DecompositionLevelCount,
+    ) -> Result<Self, Error> {
+        check_decomposition(base_log, level_count)?;
+        Ok(PbsParameters {
+            base_log,
+            level_count,
+        })
+    }
+
+    /// Returns the logarithm of the base used in the decomposition.
+    pub fn base_log(&self) -> DecompositionBaseLog {
+        self.base_log
+    }
+
+    /// Returns the number of levels used in the decomposition.
+    pub fn level_count(&self) -> DecompositionLevelCount {
+        self.level_count
+    }
+}
+
+/// The parameters of a key switching key, from an LWE instance to another.
+///
+/// # Note
+///
+/// Like the other bundles of this module, the fields are private and checked once by
+/// [`LweKeyswitchParameters::new`], so that a bundle can not be modified into an invalid state.
+/// The noise is stored as a [`LogStandardDev`] rather than as any [`DispersionParameter`], since
+/// this trait requires `Clone` and hence can not be used as a boxed trait object.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::crypto::parameters::LweKeyswitchParameters;
+/// use concrete_core::crypto::LweDimension;
+/// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+/// use concrete_core::math::dispersion::LogStandardDev;
+/// let params = LweKeyswitchParameters::new(
+///     LweDimension(1024),
+///     LweDimension(630),
+///     DecompositionBaseLog(2),
+///     DecompositionLevelCount(8),
+///     LogStandardDev(-15.),
+/// )
+/// .unwrap();
+/// assert_eq!(params.input_dimension(), LweDimension(1024));
+/// assert_eq!(params.output_dimension(), LweDimension(630));
+/// assert_eq!(params.base_log(), DecompositionBaseLog(2));
+/// assert_eq!(params.level_count(), DecompositionLevelCount(8));
+/// assert_eq!(params.noise(), LogStandardDev(-15.));
+///
+/// assert!(LweKeyswitchParameters::new(
+///     LweDimension(1024),
+///     LweDimension(630),
+///     DecompositionBaseLog(8),
+///     DecompositionLevelCount(9),
+///     LogStandardDev(-15.),
+/// )
+/// .is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LweKeyswitchParameters {
+    input_dimension: LweDimension,
+    output_dimension: LweDimension,
+    base_log: DecompositionBaseLog,
+    level_count: DecompositionLevelCount,
+    noise: LogStandardDev,
+}
+
+impl LweKeyswitchParameters {
+    /// Creates a new set of key switching parameters.
+    ///
+    /// # Errors
+    ///
+    /// Fails if one of the dimensions is zero, if the base log or the level count is zero, if the
+    /// decomposition covers more than 64 bits, or if the standard deviation of the noise is not
+    /// smaller than one.
+    ///
+    /// As for [`PbsParameters::new`], only the 64 bits of the widest integer type are checked.
+    /// [`LweKeyswitchKey::generate_with`](crate::crypto::lwe::LweKeyswitchKey::generate_with)
+    /// checks the decomposition against the bits of the integer type of the key as well.
+    pub fn new(
+        input_dimension: LweDimension,
+        output_dimension: LweDimension,
+        base_log: DecompositionBaseLog,
+        level_count: DecompositionLevelCount,
+        noise: impl DispersionParameter,
+    ) -> Result<Self, Error> {
+        if input_dimension.0 == 0 {
+            return Err(Error::InvalidParameter {
+                parameter: "keyswitch input dimension",
+                reason: "must be non-zero".into(),
+            });
+        }
+        if output_dimension.0 == 0 {
+            return Err(Error::InvalidParameter {
+                parameter: "keyswitch output dimension",
+                reason: "must be non-zero".into(),
+            });
+        }
+        check_decomposition(base_log, level_count)?;
+        let noise = LogStandardDev(noise.get_log_standard_dev());
+        check_noise(&noise)?;
+        Ok(LweKeyswitchParameters {
+            input_dimension,
+            output_dimension,
+            base_log,
+            level_count,
+            noise,
+        })
+    }
+
+    /// Returns the dimension of the LWE key the ciphertexts are switched from.
+    pub fn input_dimension(&self) -> LweDimension {
+        self.input_dimension
+    }
+
+    /// Returns the dimension of the LWE key the ciphertexts are switched to.
+    pub fn output_dimension(&self) -> LweDimension {
+        self.output_dimension
+    }
+
+    /// Returns the logarithm of the base used in the decomposition.
+    pub fn base_log(&self) -> DecompositionBaseLog {
+        self.base_log
+    }
+
+    /// Returns the number of levels used in the decomposition.
+    pub fn level_count(&self) -> DecompositionLevelCount {
+        self.level_count
+    }
+
+    /// Returns the noise of the encryptions of the key switching key.
+    pub fn noise(&self) -> LogStandardDev {
+        self.noise
+    }
+}
@@ -0,0 +1,204 @@
+use concrete_npe as npe;
+
+use crate::crypto::bootstrap::BootstrapKey;
+use crate::crypto::cross::bootstrap;
+use crate::crypto::encoding::Plaintext;
+use crate::crypto::glwe::GlweCiphertext;
+use crate::crypto::lwe::{LweCiphertext, LweKeyswitchKey};
+use crate::crypto::parameters::{
+    GlweParameters, LweKeyswitchParameters, LweParameters, PbsParameters,
+};
+use crate::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::crypto::{GlweDimension, LweDimension, UnsignedTorus};
+use crate::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+use crate::math::dispersion::{DispersionParameter, LogStandardDev, StandardDev, Variance};
+use crate::math::fft::Complex64;
+use crate::math::polynomial::PolynomialSize;
+use crate::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
+use crate::numeric::Numeric;
+use crate::test_tools::assert_delta_std_dev;
+use crate::Error;
+
+fn assert_invalid<T: std::fmt::Debug>(result: Result<T, Error>, expected: &str) {
+    match result {
+        Err(Error::InvalidParameter { parameter, .. }) => assert_eq!(parameter, expected),
+        other => panic!("expected an invalid {}, got {:?}", expected, other),
+    }
+}
+
+#[test]
+fn test_invalid_lwe_parameters() {
+    assert_invalid(
+        LweParameters::new(LweDimension(0), LogStandardDev(-15.)),
+        "lwe dimension",
+    );
+    assert_invalid(
+        LweParameters::new(LweDimension(630), LogStandardDev(0.)),
+        "noise",
+    );
+    assert_invalid(
+        LweParameters::new(LweDimension(630), StandardDev::from_standard_dev(2.)),
+        "noise",
+    );
+    assert_invalid(
+        LweParameters::new(LweDimension(630), LogStandardDev(f64::NAN)),
+        "noise",
+    );
+    assert!(
+        LweParameters::new(LweDimension(630), Variance::from_variance(2_f64.powi(-30))).is_ok()
+    );
+}
+
+#[test]
+fn test_invalid_glwe_parameters() {
+    assert_invalid(
+        GlweParameters::new(GlweDimension(0), PolynomialSize(1024), LogStandardDev(-29.)),
+        "glwe dimension",
+    );
+    assert_invalid(
+        GlweParameters::new(GlweDimension(1), PolynomialSize(1000), LogStandardDev(-29.)),
+        "polynomial size",
+    );
+    assert_invalid(
+        GlweParameters::new(GlweDimension(1), PolynomialSize(0), LogStandardDev(-29.)),
+        "polynomial size",
+    );
+    assert_invalid(
+        GlweParameters::new(GlweDimension(1), PolynomialSize(1024), LogStandardDev(1.)),
+        "noise",
+    );
+}
+
+#[test]
+fn test_invalid_pbs_parameters() {
+    assert_invalid(
+        PbsParameters::new(DecompositionBaseLog(0), DecompositionLevelCount(3)),
+        "decomposition base log",
+    );
+    assert_invalid(
+        PbsParameters::new(DecompositionBaseLog(7), DecompositionLevelCount(0)),
+        "decomposition level count",
+    );
+    assert_invalid(
+        PbsParameters::new(DecompositionBaseLog(7), DecompositionLevelCount(10)),
+        "decomposition",
+    );
+    assert!(PbsParameters::new(DecompositionBaseLog(8), DecompositionLevelCount(8)).is_ok());
+}
+
+#[test]
+fn test_invalid_keyswitch_parameters() {
+    let keyswitch = |input, output, base_log, level_count, noise| {
+        LweKeyswitchParameters::new(
+            LweDimension(input),
+            LweDimension(output),
+            DecompositionBaseLog(base_log),
+            DecompositionLevelCount(level_count),
+            LogStandardDev(noise),
+        )
+    };
+    assert_invalid(keyswitch(0, 630, 2, 8, -15.), "keyswitch input dimension");
+    assert_invalid(keyswitch(1024, 0, 2, 8, -15.), "keyswitch output dimension");
+    assert_invalid(keyswitch(1024, 630, 0, 8, -15.), "decomposition base log");
+    assert_invalid(
+        keyswitch(1024, 630, 2, 0, -15.),
+        "decomposition level count",
+    );
+    assert_invalid(keyswitch(1024, 630, 5, 13, -15.), "decomposition");
+    assert_invalid(keyswitch(1024, 630, 2, 8, 0.), "noise");
+    assert!(keyswitch(1024, 630, 4, 16, -15.).is_ok());
+}
+
+#[test]
+fn test_keyswitch_key_with_mismatched_keys() {
+    let params = LweKeyswitchParameters::new(
+        LweDimension(20),
+        LweDimension(10),
+        DecompositionBaseLog(3),
+        DecompositionLevelCount(5),
+        LogStandardDev(-15.),
+    )
+    .unwrap();
+    let input_key = LweSecretKey::generate(LweDimension(20));
+    let output_key = LweSecretKey::generate(LweDimension(11));
+    match LweKeyswitchKey::<Vec<u64>>::generate_with(&params, &input_key, &output_key) {
+        Err(Error::DimensionMismatch {
+            parameter,
+            expected,
+            found,
+        }) => {
+            assert_eq!(parameter, "keyswitch output dimension");
+            assert_eq!(expected, 10);
+            assert_eq!(found, 11);
+        }
+        other => panic!("expected a dimension mismatch, got {:?}", other),
+    }
+}
+
+fn test_bootstrap_with_parameters<T: UnsignedTorus + npe::Cross>() {
+    let lwe_params = LweParameters::new(LweDimension(630), LogStandardDev(-29.)).unwrap();
+    let glwe_params =
+        GlweParameters::new(GlweDimension(1), PolynomialSize(1024), LogStandardDev(-29.)).unwrap();
+    let pbs_params =
+        PbsParameters::new(DecompositionBaseLog(7), DecompositionLevelCount(3)).unwrap();
+
+    let lwe_sk = LweSecretKey::generate_with(&lwe_params);
+    let glwe_sk = GlweSecretKey::generate_with(&glwe_params);
+
+    let mut coef_bsk = BootstrapKey::allocate_with(T::ZERO, &lwe_params, &glwe_params, &pbs_params);
+    coef_bsk.fill_with_new_key(&lwe_sk, &glwe_sk, glwe_params.noise());
+    let mut fourier_bsk = BootstrapKey::allocate_complex_with(
+        Complex64::new(0., 0.),
+        &lwe_params,
+        &glwe_params,
+        &pbs_params,
+    );
+    fourier_bsk.fill_with_forward_fourier(&coef_bsk);
+
+    // Encrypts a message in the first half of the torus, far from the drift.
+    let val = T::cast_from(0.25 * (<T as Numeric>::MAX.cast_into() + 1_f64));
+    let mut lwe_in = LweCiphertext::allocate_with(T::ZERO, &lwe_params);
+    lwe_sk.encrypt_lwe(&mut lwe_in, &Plaintext(val), lwe_params.noise());
+
+    // Bootstraps with a constant accumulator.
+    let cst = T::ONE << (<T as Numeric>::BITS - 3);
+    let mut accumulator = GlweCiphertext::allocate_with(T::ZERO, &glwe_params);
+    accumulator
+        .get_mut_body()
+        .as_mut_tensor()
+        .fill_with_element(cst);
+    let mut lwe_out = LweCiphertext::allocate_with(T::ZERO, &glwe_params.to_lwe_parameters());
+    bootstrap(&mut lwe_out, &lwe_in, &fourier_bsk, &mut accumulator);
+
+    let mut decrypted = Plaintext(T::ZERO);
+    let flattened_key = LweSecretKey::from_container(glwe_sk.as_tensor().as_slice());
+    flattened_key.decrypt_lwe(&mut decrypted, &lwe_out);
+
+    let output_variance = <T as npe::Cross>::bootstrap(
+        lwe_params.dimension().0,
+        glwe_params.dimension().0,
+        pbs_params.level_count().0,
+        pbs_params.base_log().0,
+        glwe_params.polynomial_size().0,
+        glwe_params.noise().get_variance(),
+    );
+    let mut expected = Tensor::allocate(T::ZERO, 1);
+    expected.as_mut_slice()[0] = cst;
+    let mut obtained = Tensor::allocate(T::ZERO, 1);
+    obtained.as_mut_slice()[0] = decrypted.0;
+    assert_delta_std_dev(
+        &expected,
+        &obtained,
+        Variance::from_variance(output_variance),
+    );
+}
+
+#[test]
+fn test_bootstrap_with_parameters_u32() {
+    test_bootstrap_with_parameters::<u32>();
+}
+
+#[test]
+fn test_bootstrap_with_parameters_u64() {
+    test_bootstrap_with_parameters::<u64>();
+}
@@ -0,0 +1,155 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::crypto::{GlweDimension, LweDimension};
+use crate::math::polynomial::PolynomialSize;
+use crate::math::random::{random_uniform, with_seed, with_seeded_stream};
+
+// The version of the derivation scheme, absorbed before anything else. It must be incremented
+// whenever the derivation changes, so that a key can never be silently derived differently.
+const DERIVATION_VERSION: u64 = 1;
+
+// The tags separating the domains of the different types of keys.
+const LWE_KEY_TAG: u64 = 1;
+const GLWE_KEY_TAG: u64 = 2;
+
+/// The label identifying a key derived by a [`KeyDerivation`].
+///
+/// A label is made of a byte string, typically naming the purpose of the key, and of an integer,
+/// typically a session or an epoch number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct KeyLabel<'a>(pub &'a [u8], pub u64);
+
+/// A deterministic derivation of secret keys from a single master seed.
+///
+/// Every key is identified by a [`KeyLabel`], and by its type and dimensions. Deriving a key
+/// twice with the same master seed and the same label always gives the same key, while keys
+/// derived with different labels are independent: knowing some of the derived keys reveals
+/// nothing about the others, nor about the master seed.
+///
+/// The derivation only relies on the `aes128-ctr` generator of `concrete-csprng`. Starting from
+/// the master seed, every 64 bits word of the label (and of the key description) selects a
+/// stream of the generator seeded with the current seed, whose first 128 bits become the next
+/// seed. The final seed then initializes the generator used to sample the key. The words
+/// absorbed are, in order:
+///
+/// + a version number of the scheme,
+/// + a tag for the type of key,
+/// + the dimensions of the key,
+/// + the length of the byte string of the label, followed by its bytes packed in little endian
+///   words, the last one being padded with zeros,
+/// + the integer of the label.
+///
+/// This procedure is part of the stability guarantees of the library: a given version of the
+/// scheme will always derive the same keys.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::crypto::secret::{KeyDerivation, KeyLabel};
+/// use concrete_core::crypto::LweDimension;
+/// let derivation = KeyDerivation::new(0x0123_4567_89ab_cdef_0123_4567_89ab_cdef);
+/// let first = derivation.derive_lwe_key(KeyLabel(b"session", 1), LweDimension(630));
+/// let again = derivation.derive_lwe_key(KeyLabel(b"session", 1), LweDimension(630));
+/// let second = derivation.derive_lwe_key(KeyLabel(b"session", 2), LweDimension(630));
+/// assert_eq!(first, again);
+/// assert_ne!(first, second);
+/// ```
+#[derive(Clone)]
+pub struct KeyDerivation {
+    master_seed: u128,
+}
+
+// The master seed must not be displayed.
+impl Debug for KeyDerivation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "KeyDerivation")
+    }
+}
+
+impl KeyDerivation {
+    /// Creates a key derivation from a master seed.
+    ///
+    /// The master seed is the only secret of the derivation, and must be sampled uniformly, for
+    /// instance with [`random_uniform`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::secret::KeyDerivation;
+    /// use concrete_core::math::random::random_uniform;
+    /// let derivation = KeyDerivation::new(random_uniform());
+    /// ```
+    pub fn new(master_seed: u128) -> Self {
+        KeyDerivation { master_seed }
+    }
+
+    /// Derives an LWE secret key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::secret::{KeyDerivation, KeyLabel};
+    /// use concrete_core::crypto::LweDimension;
+    /// let derivation = KeyDerivation::new(42);
+    /// let key = derivation.derive_lwe_key(KeyLabel(b"keyswitch", 0), LweDimension(630));
+    /// assert_eq!(key.key_size(), LweDimension(630));
+    /// ```
+    pub fn derive_lwe_key(
+        &self,
+        label: KeyLabel,
+        dimension: LweDimension,
+    ) -> LweSecretKey<Vec<bool>> {
+        let seed = self.derive_seed(&[LWE_KEY_TAG, dimension.0 as u64], label);
+        with_seed(seed, || LweSecretKey::generate(dimension))
+    }
+
+    /// Derives a GLWE secret key.
+    ///
+    /// The key is independent from the LWE keys derived with the same label.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::secret::{KeyDerivation, KeyLabel};
+    /// use concrete_core::crypto::GlweDimension;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let derivation = KeyDerivation::new(42);
+    /// let key = derivation.derive_glwe_key(
+    ///     KeyLabel(b"bootstrap", 0),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// assert_eq!(key.key_size(), GlweDimension(1));
+    /// assert_eq!(key.polynomial_size(), PolynomialSize(1024));
+    /// ```
+    pub fn derive_glwe_key(
+        &self,
+        label: KeyLabel,
+        dimension: GlweDimension,
+        poly_size: PolynomialSize,
+    ) -> GlweSecretKey<Vec<bool>> {
+        let seed = self.derive_seed(
+            &[GLWE_KEY_TAG, dimension.0 as u64, poly_size.0 as u64],
+            label,
+        );
+        with_seed(seed, || GlweSecretKey::generate(dimension, poly_size))
+    }
+
+    // Absorbs the description of the key and its label, and returns the seed of the key.
+    fn derive_seed(&self, description: &[u64], KeyLabel(name, index): KeyLabel) -> u128 {
+        let name_words = name.chunks(8).map(|chunk| {
+            let mut bytes = [0u8; 8];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(bytes)
+        });
+        std::iter::once(DERIVATION_VERSION)
+            .chain(description.iter().copied())
+            .chain(std::iter::once(name.len() as u64))
+            .chain(name_words)
+            .chain(std::iter::once(index))
+            .fold(self.master_seed, |seed, word| {
+                with_seeded_stream(seed, word, random_uniform::<u128>)
+            })
+    }
+}
@@ -5,14 +5,16 @@ use serde::{Deserialize, Serialize};
 use crate::crypto::encoding::{Plaintext, PlaintextList};
 use crate::crypto::ggsw::GgswCiphertext;
 use crate::crypto::glwe::{GlweCiphertext, GlweList};
+use crate::crypto::parameters::GlweParameters;
 use crate::crypto::secret::LweSecretKey;
 use crate::crypto::{GlweDimension, PlaintextCount, UnsignedTorus};
 use crate::math::dispersion::DispersionParameter;
-use crate::math::polynomial::{PolynomialList, PolynomialSize};
+use crate::math::modular::ModQ;
+use crate::math::polynomial::{BinaryPolynomialList, PolynomialList, PolynomialSize};
 use crate::math::random;
 use crate::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
 use crate::numeric::Numeric;
-use crate::{ck_dim_div, ck_dim_eq, tensor_traits};
+use crate::{ck_dim_div, ck_dim_eq, tensor_memory_footprint, tensor_traits};
 
 /// A GLWE secret key
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
@@ -22,6 +24,7 @@ pub struct GlweSecretKey<Container> {
 }
 
 tensor_traits!(GlweSecretKey);
+tensor_memory_footprint!(GlweSecretKey);
 
 impl GlweSecretKey<Vec<bool>> {
     /// Allocates a container for a new key, and fill it with random values.
@@ -45,6 +48,25 @@ impl GlweSecretKey<Vec<bool>> {
         }
     }
 
+    /// Generates a new secret key for the given set of GLWE parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::parameters::GlweParameters;
+    /// use concrete_core::crypto::{*, secret::*};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let params =
+    ///     GlweParameters::new(GlweDimension(2), PolynomialSize(16), LogStandardDev(-25.)).unwrap();
+    /// let secret_key = GlweSecretKey::generate_with(&params);
+    /// assert_eq!(secret_key.key_size(), GlweDimension(2));
+    /// assert_eq!(secret_key.polynomial_size(), PolynomialSize(16));
+    /// ```
+    pub fn generate_with(params: &GlweParameters) -> Self {
+        GlweSecretKey::generate(params.dimension(), params.polynomial_size())
+    }
+
     /// Consumes the current GLWE secret key and turns it into an LWE secret key.
     ///
     /// # Examples
@@ -184,6 +206,31 @@ impl<Cont> GlweSecretKey<Cont> {
         PolynomialList::from_container(self.as_mut_tensor().as_mut_slice(), poly_size)
     }
 
+    /// Returns a copy of the key polynomials, with their coefficients packed in words.
+    ///
+    /// The packed copy is meant for [`PackedGlweSecretKey`], whose encryption does not run in
+    /// constant time. The methods of the current key do not use it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::{*, secret::*};
+    /// use concrete_core::math::polynomial::{PolynomialCount, PolynomialSize};
+    /// let secret_key = GlweSecretKey::generate(
+    ///     GlweDimension(256),
+    ///     PolynomialSize(10),
+    /// );
+    /// let packed = secret_key.to_binary_polynomial_list();
+    /// assert_eq!(packed.polynomial_count(), PolynomialCount(256));
+    /// assert_eq!(packed.polynomial_size(), PolynomialSize(10));
+    /// ```
+    pub fn to_binary_polynomial_list(&self) -> BinaryPolynomialList<Vec<u64>>
+    where
+        Self: AsRefTensor<Element = bool>,
+    {
+        BinaryPolynomialList::from_boolean_list(&self.as_polynomial_list())
+    }
+
     /// Encrypts a single GLWE ciphertext.
     ///
     /// # Example
@@ -207,12 +254,8 @@ impl<Cont> GlweSecretKey<Cont> {
     /// secret_key.encrypt_glwe(&mut ciphertext, &plaintexts, noise);
     /// let mut decrypted = PlaintextList::from_container(vec![0 as u32,0,0,0,0]);
     /// secret_key.decrypt_glwe(&mut decrypted, &ciphertext);
-    /// for (dec, plain) in decrypted.plaintext_iter().zip(plaintexts.plaintext_iter()){
-    ///     let d0 = dec.0.wrapping_sub(plain.0);
-    ///     let d1 = plain.0.wrapping_sub(dec.0);
-    ///     let dist = std::cmp::min(d0, d1);
-    ///     assert!(dist < 400, "dist: {:?}", dist);
-    /// }
+    /// let dist = decrypted.max_wrapping_distance(&plaintexts);
+    /// assert!(dist < 400, "dist: {:?}", dist);
     /// ```
     pub fn encrypt_glwe<OutputCont, EncCont, Scalar>(
         &self,
@@ -229,7 +272,7 @@ impl<Cont> GlweSecretKey<Cont> {
         random::fill_with_random_gaussian(&mut body, 0., noise_parameter.get_standard_dev());
         random::fill_with_random_uniform(&mut masks);
         body.as_mut_polynomial()
-            .update_with_wrapping_add_binary_multisum(
+            .ct_update_with_wrapping_add_binary_multisum(
                 &masks.as_mut_polynomial_list(),
                 &self.as_polynomial_list(),
             );
@@ -257,12 +300,9 @@ impl<Cont> GlweSecretKey<Cont> {
     /// secret_key.encrypt_zero_glwe(&mut ciphertext, noise);
     /// let mut decrypted = PlaintextList::from_container(vec![0 as u32,0,0,0,0]);
     /// secret_key.decrypt_glwe(&mut decrypted, &ciphertext);
-    /// for dec in decrypted.plaintext_iter(){
-    ///     let d0 = dec.0.wrapping_sub(0u32);
-    ///     let d1 = 0u32.wrapping_sub(dec.0);
-    ///     let dist = std::cmp::min(d0, d1);
-    ///     assert!(dist < 500, "dist: {:?}", dist);
-    /// }
+    /// let zeros = PlaintextList::allocate(0 as u32, PlaintextCount(5));
+    /// let dist = decrypted.max_wrapping_distance(&zeros);
+    /// assert!(dist < 500, "dist: {:?}", dist);
     /// ```
     pub fn encrypt_zero_glwe<Scalar, OutputCont>(
         &self,
@@ -277,7 +317,7 @@ impl<Cont> GlweSecretKey<Cont> {
         random::fill_with_random_gaussian(&mut body, 0., noise_parameters.get_standard_dev());
         random::fill_with_random_uniform(&mut masks);
         body.as_mut_polynomial()
-            .update_with_wrapping_add_binary_multisum(
+            .ct_update_with_wrapping_add_binary_multisum(
                 &masks.as_mut_polynomial_list(),
                 &self.as_polynomial_list(),
             );
@@ -309,12 +349,8 @@ impl<Cont> GlweSecretKey<Cont> {
     /// secret_key.encrypt_glwe_list(&mut ciphertexts, &plaintexts, noise);
     /// let mut decrypted = PlaintextList::from_container(vec![0 as u32,0,0,0]);
     /// secret_key.decrypt_glwe_list(&mut decrypted, &ciphertexts);
-    /// for (dec, plain) in decrypted.plaintext_iter().zip(plaintexts.plaintext_iter()){
-    ///     let d0 = dec.0.wrapping_sub(plain.0);
-    ///     let d1 = plain.0.wrapping_sub(dec.0);
-    ///     let dist = std::cmp::min(d0, d1);
-    ///     assert!(dist < 400, "dist: {:?}", dist);
-    /// }
+    /// let dist = decrypted.max_wrapping_distance(&plaintexts);
+    /// assert!(dist < 400, "dist: {:?}", dist);
     /// ```
     pub fn encrypt_glwe_list<CiphCont, EncCont, Scalar>(
         &self,
@@ -328,8 +364,8 @@ impl<Cont> GlweSecretKey<Cont> {
         Scalar: UnsignedTorus,
         for<'a> PlaintextList<&'a [Scalar]>: AsRefTensor<Element = Scalar>,
     {
-        ck_dim_eq!(encrypt.ciphertext_count().0 * encrypt.polynomial_size().0 => encoded.count().0);
-        ck_dim_eq!(encrypt.glwe_dimension().0 => self.key_size().0);
+        ck_dim_eq!(encoded.count() => PlaintextCount(encrypt.ciphertext_count().0 * encrypt.polynomial_size().0));
+        ck_dim_eq!(encrypt.glwe_dimension() => self.key_size());
 
         let count = PlaintextCount(encrypt.polynomial_size().0);
         for (mut ciphertext, encoded) in encrypt
@@ -365,12 +401,9 @@ impl<Cont> GlweSecretKey<Cont> {
     /// secret_key.encrypt_zero_glwe_list(&mut ciphertexts, noise);
     /// let mut decrypted = PlaintextList::from_container(vec![0 as u32,0,0,0]);
     /// secret_key.decrypt_glwe_list(&mut decrypted, &ciphertexts);
-    /// for dec in decrypted.plaintext_iter(){
-    ///     let d0 = dec.0.wrapping_sub(0u32);
-    ///     let d1 = 0u32.wrapping_sub(dec.0);
-    ///     let dist = std::cmp::min(d0, d1);
-    ///     assert!(dist < 400, "dist: {:?}", dist);
-    /// }
+    /// let zeros = PlaintextList::allocate(0 as u32, PlaintextCount(4));
+    /// let dist = decrypted.max_wrapping_distance(&zeros);
+    /// assert!(dist < 400, "dist: {:?}", dist);
     /// ```
     pub fn encrypt_zero_glwe_list<Scalar, OutputCont>(
         &self,
@@ -388,6 +421,9 @@ impl<Cont> GlweSecretKey<Cont> {
 
     /// Decrypts a single GLWE ciphertext.
     ///
+    /// The multisum between the masks and the key is computed in constant time, as in
+    /// [`GlweSecretKey::ct_decrypt_glwe`].
+    ///
     /// See ['GlweSecretKey::encrypt_glwe`] for an example.
     pub fn decrypt_glwe<CiphCont, EncCont, Scalar>(
         &self,
@@ -399,14 +435,159 @@ impl<Cont> GlweSecretKey<Cont> {
         GlweCiphertext<CiphCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus + Add,
     {
-        ck_dim_eq!(encoded.count().0 => encrypted.polynomial_size().0);
+        ck_dim_eq!(encoded.as_polynomial().polynomial_size() => encrypted.polynomial_size());
+        let (body, masks) = encrypted.get_body_and_mask();
+        encoded
+            .as_mut_tensor()
+            .fill_with_one(body.as_tensor(), |a| *a);
+        encoded
+            .as_mut_polynomial()
+            .ct_update_with_wrapping_sub_binary_multisum(
+                &masks.as_polynomial_list(),
+                &self.as_polynomial_list(),
+            );
+    }
+
+    /// Encrypts a single GLWE ciphertext whose coefficients are integers modulo an arbitrary
+    /// modulus $Q$.
+    ///
+    /// The standard deviation of the noise is given on the torus, i.e. as a fraction of $Q$.
+    ///
+    /// # Constant-time
+    ///
+    /// The coefficients of the masks are selected by the bits of the key with a mask, and the
+    /// modular arithmetic does not branch on the values, so that the timing does not depend on
+    /// the key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::encoding::PlaintextList;
+    /// use concrete_core::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::crypto::secret::GlweSecretKey;
+    /// use concrete_core::crypto::{GlweDimension, GlweSize};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::modular::ModQ;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// use concrete_core::math::tensor::AsRefTensor;
+    /// type Zq = ModQ<{ (1 << 62) - 57 }>;
+    /// let secret_key = GlweSecretKey::generate(GlweDimension(2), PolynomialSize(4));
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    /// let plaintexts = PlaintextList::from_container(vec![
+    ///     Zq::new(1 << 50),
+    ///     Zq::new(2 << 50),
+    ///     Zq::from_signed(-(3 << 50)),
+    ///     Zq::new(4 << 50),
+    /// ]);
+    /// let mut ciphertext = GlweCiphertext::allocate(Zq::ZERO, PolynomialSize(4), GlweSize(3));
+    /// secret_key.encrypt_modular_glwe(&mut ciphertext, &plaintexts, noise);
+    /// let mut decrypted = PlaintextList::from_container(vec![Zq::ZERO; 4]);
+    /// secret_key.decrypt_modular_glwe(&mut decrypted, &ciphertext);
+    /// for (dec, plain) in decrypted.as_tensor().iter().zip(plaintexts.as_tensor().iter()) {
+    ///     assert!((*dec - *plain).to_signed().abs() < 1 << 30);
+    /// }
+    /// ```
+    pub fn encrypt_modular_glwe<OutputCont, EncCont, const Q: u64>(
+        &self,
+        encrypted: &mut GlweCiphertext<OutputCont>,
+        encoded: &PlaintextList<EncCont>,
+        noise_parameter: impl DispersionParameter,
+    ) where
+        Self: AsRefTensor<Element = bool>,
+        GlweCiphertext<OutputCont>: AsMutTensor<Element = ModQ<Q>>,
+        PlaintextList<EncCont>: AsRefTensor<Element = ModQ<Q>>,
+    {
+        ck_dim_eq!(encoded.as_polynomial().polynomial_size() => encrypted.polynomial_size());
+        let (mut body, mut masks) = encrypted.get_mut_body_and_mask();
+        random::fill_with_random_gaussian(&mut body, 0., noise_parameter.get_standard_dev());
+        random::fill_with_random_uniform(&mut masks);
+        body.as_mut_polynomial().update_with_add_binary_multisum(
+            &masks.as_mut_polynomial_list(),
+            &self.as_polynomial_list(),
+        );
+        body.as_mut_tensor().update_with_add(encoded.as_tensor());
+    }
+
+    /// Decrypts a single GLWE ciphertext whose coefficients are integers modulo an arbitrary
+    /// modulus $Q$.
+    ///
+    /// As for the encryption, the timing does not depend on the key.
+    ///
+    /// See [`GlweSecretKey::encrypt_modular_glwe`] for an example.
+    pub fn decrypt_modular_glwe<CiphCont, EncCont, const Q: u64>(
+        &self,
+        encoded: &mut PlaintextList<EncCont>,
+        encrypted: &GlweCiphertext<CiphCont>,
+    ) where
+        Self: AsRefTensor<Element = bool>,
+        PlaintextList<EncCont>: AsMutTensor<Element = ModQ<Q>>,
+        GlweCiphertext<CiphCont>: AsRefTensor<Element = ModQ<Q>>,
+    {
+        ck_dim_eq!(encoded.as_polynomial().polynomial_size() => encrypted.polynomial_size());
+        let (body, masks) = encrypted.get_body_and_mask();
+        encoded
+            .as_mut_tensor()
+            .fill_with_one(body.as_tensor(), |a| *a);
+        encoded.as_mut_polynomial().update_with_sub_binary_multisum(
+            &masks.as_polynomial_list(),
+            &self.as_polynomial_list(),
+        );
+    }
+
+    /// Decrypts a single GLWE ciphertext in constant time.
+    ///
+    /// This computes the same result as [`GlweSecretKey::decrypt_glwe`].
+    ///
+    /// # Constant-time
+    ///
+    /// The binary multisum between the masks and the key is computed with
+    /// [`Polynomial::ct_update_with_wrapping_sub_binary_multisum`](crate::math::polynomial::Polynomial::ct_update_with_wrapping_sub_binary_multisum),
+    /// which never branches on the key bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::encoding::PlaintextList;
+    /// use concrete_core::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::crypto::secret::GlweSecretKey;
+    /// use concrete_core::crypto::{GlweDimension, GlweSize, PlaintextCount};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    ///
+    /// let secret_key = GlweSecretKey::generate(GlweDimension(2), PolynomialSize(10));
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let plaintexts = PlaintextList::allocate(1u32 << 30, PlaintextCount(10));
+    /// let mut ciphertext = GlweCiphertext::allocate(0u32, PolynomialSize(10), GlweSize(3));
+    /// secret_key.encrypt_glwe(&mut ciphertext, &plaintexts, noise);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0u32, PlaintextCount(10));
+    /// secret_key.decrypt_glwe(&mut decrypted, &ciphertext);
+    /// let mut ct_decrypted = PlaintextList::allocate(0u32, PlaintextCount(10));
+    /// secret_key.ct_decrypt_glwe(&mut ct_decrypted, &ciphertext);
+    ///
+    /// assert!(decrypted
+    ///     .plaintext_iter()
+    ///     .zip(ct_decrypted.plaintext_iter())
+    ///     .all(|(a, b)| a == b));
+    /// ```
+    pub fn ct_decrypt_glwe<CiphCont, EncCont, Scalar>(
+        &self,
+        encoded: &mut PlaintextList<EncCont>,
+        encrypted: &GlweCiphertext<CiphCont>,
+    ) where
+        Self: AsRefTensor<Element = bool>,
+        PlaintextList<EncCont>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<CiphCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Add,
+    {
+        ck_dim_eq!(encoded.as_polynomial().polynomial_size() => encrypted.polynomial_size());
         let (body, masks) = encrypted.get_body_and_mask();
         encoded
             .as_mut_tensor()
             .fill_with_one(body.as_tensor(), |a| *a);
         encoded
             .as_mut_polynomial()
-            .update_with_wrapping_sub_binary_multisum(
+            .ct_update_with_wrapping_sub_binary_multisum(
                 &masks.as_polynomial_list(),
                 &self.as_polynomial_list(),
             );
@@ -426,8 +607,8 @@ impl<Cont> GlweSecretKey<Cont> {
         Scalar: UnsignedTorus + Add,
         for<'a> PlaintextList<&'a mut [Scalar]>: AsMutTensor<Element = Scalar>,
     {
-        ck_dim_eq!(encrypted.ciphertext_count().0 * encrypted.polynomial_size().0 => encoded.count().0);
-        ck_dim_eq!(encrypted.glwe_dimension().0 => self.key_size().0);
+        ck_dim_eq!(encoded.count() => PlaintextCount(encrypted.ciphertext_count().0 * encrypted.polynomial_size().0));
+        ck_dim_eq!(encrypted.glwe_dimension() => self.key_size());
         for (ciphertext, mut encoded) in encrypted
             .ciphertext_iter()
             .zip(encoded.sublist_iter_mut(PlaintextCount(encrypted.polynomial_size().0)))
@@ -563,3 +744,125 @@ impl<Cont> GlweSecretKey<Cont> {
         }
     }
 }
+
+/// A GLWE secret key, along with a copy of its polynomials packed in words.
+///
+/// The key polynomials are packed once, when the key is created, and the packed copy is used by
+/// [`PackedGlweSecretKey::encrypt_glwe`] to compute the multisum between the masks and the key
+/// with [`Polynomial::update_with_wrapping_add_packed_binary_multisum`], which only visits the
+/// coefficients of the key equal to one. This makes the encryption of many ciphertexts faster.
+///
+/// # Constant-time
+///
+/// Unlike the methods of [`GlweSecretKey`], the encryption time of this key depends on the
+/// number and on the positions of the ones in the key. It must only be used when the timing of
+/// the encryptions can not be observed, and the other operations, such as the decryption, should
+/// use the key returned by [`PackedGlweSecretKey::as_glwe_secret_key`].
+///
+/// [`Polynomial::update_with_wrapping_add_packed_binary_multisum`]:
+///     crate::math::polynomial::Polynomial::update_with_wrapping_add_packed_binary_multisum
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackedGlweSecretKey<Cont> {
+    key: GlweSecretKey<Cont>,
+    packed: BinaryPolynomialList<Vec<u64>>,
+}
+
+impl<Cont> PackedGlweSecretKey<Cont> {
+    /// Packs the polynomials of a GLWE secret key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::{*, secret::*};
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let secret_key = GlweSecretKey::generate(GlweDimension(2), PolynomialSize(10));
+    /// let packed_key = PackedGlweSecretKey::new(secret_key.clone());
+    /// assert_eq!(packed_key.as_glwe_secret_key(), &secret_key);
+    /// ```
+    pub fn new(key: GlweSecretKey<Cont>) -> Self
+    where
+        GlweSecretKey<Cont>: AsRefTensor<Element = bool>,
+    {
+        let packed = key.to_binary_polynomial_list();
+        PackedGlweSecretKey { key, packed }
+    }
+
+    /// Returns the unpacked key.
+    pub fn as_glwe_secret_key(&self) -> &GlweSecretKey<Cont> {
+        &self.key
+    }
+
+    /// Consumes the current key and returns the unpacked key.
+    pub fn into_glwe_secret_key(self) -> GlweSecretKey<Cont> {
+        self.key
+    }
+
+    /// Returns a borrowed list of the packed key polynomials.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::{*, secret::*};
+    /// use concrete_core::math::polynomial::{PolynomialCount, PolynomialSize};
+    /// let secret_key = GlweSecretKey::generate(GlweDimension(256), PolynomialSize(10));
+    /// let packed_key = PackedGlweSecretKey::new(secret_key);
+    /// let packed = packed_key.as_binary_polynomial_list();
+    /// assert_eq!(packed.polynomial_count(), PolynomialCount(256));
+    /// assert_eq!(packed.polynomial_size(), PolynomialSize(10));
+    /// ```
+    pub fn as_binary_polynomial_list(&self) -> BinaryPolynomialList<&[u64]> {
+        BinaryPolynomialList::from_container(
+            self.packed.as_tensor().as_slice(),
+            self.packed.polynomial_size(),
+        )
+    }
+
+    /// Encrypts a single GLWE ciphertext, using the packed key polynomials.
+    ///
+    /// See the constant-time section of [`PackedGlweSecretKey`] before using this method.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::encoding::PlaintextList;
+    /// use concrete_core::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::crypto::{*, secret::*};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let secret_key = GlweSecretKey::generate(GlweDimension(256), PolynomialSize(5));
+    /// let packed_key = PackedGlweSecretKey::new(secret_key);
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let plaintexts =
+    ///     PlaintextList::from_container(vec![100000 as u32, 200000, 300000, 400000, 500000]);
+    /// let mut ciphertext = GlweCiphertext::allocate(0 as u32, PolynomialSize(5), GlweSize(257));
+    /// packed_key.encrypt_glwe(&mut ciphertext, &plaintexts, noise);
+    /// let mut decrypted = PlaintextList::from_container(vec![0 as u32, 0, 0, 0, 0]);
+    /// packed_key
+    ///     .as_glwe_secret_key()
+    ///     .decrypt_glwe(&mut decrypted, &ciphertext);
+    /// let dist = decrypted.max_wrapping_distance(&plaintexts);
+    /// assert!(dist < 400, "dist: {:?}", dist);
+    /// ```
+    pub fn encrypt_glwe<OutputCont, EncCont, Scalar>(
+        &self,
+        encrypted: &mut GlweCiphertext<OutputCont>,
+        encoded: &PlaintextList<EncCont>,
+        noise_parameter: impl DispersionParameter,
+    ) where
+        GlweCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
+        PlaintextList<EncCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.packed.polynomial_size() => encrypted.polynomial_size());
+        let (mut body, mut masks) = encrypted.get_mut_body_and_mask();
+        random::fill_with_random_gaussian(&mut body, 0., noise_parameter.get_standard_dev());
+        random::fill_with_random_uniform(&mut masks);
+        body.as_mut_polynomial()
+            .update_with_wrapping_add_packed_binary_multisum(
+                &masks.as_mut_polynomial_list(),
+                &self.packed,
+            );
+        body.as_mut_polynomial()
+            .update_with_wrapping_add(&encoded.as_polynomial());
+    }
+}
@@ -1,13 +1,20 @@
+use std::io::{Read, Write};
+
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 use crate::crypto::encoding::{Plaintext, PlaintextList};
 use crate::crypto::lwe::{LweCiphertext, LweList};
-use crate::crypto::{LweDimension, UnsignedTorus};
+use crate::crypto::parameters::LweParameters;
+use crate::crypto::{CiphertextCount, LweDimension, LweSize, PlaintextCount, UnsignedTorus};
 use crate::math::dispersion::DispersionParameter;
 use crate::math::random::{self, Gaussian, RandomGenerable};
-use crate::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
+use crate::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor, LoadError, SaveError, Tensor};
 use crate::numeric::Numeric;
-use crate::tensor_traits;
+use crate::{ck_dim_eq, tensor_memory_footprint, tensor_traits, Error};
+
+/// The number of ciphertexts encrypted at once by [`LweSecretKey::encrypt_stream`].
+pub const DEFAULT_STREAM_BATCH_SIZE: CiphertextCount = CiphertextCount(1024);
 
 /// A LWE secret key.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
@@ -16,6 +23,7 @@ pub struct LweSecretKey<Cont> {
 }
 
 tensor_traits!(LweSecretKey);
+tensor_memory_footprint!(LweSecretKey);
 
 impl LweSecretKey<Vec<bool>> {
     /// Generates a new secret key; e.g. allocates a storage and samples random values for the key.
@@ -32,6 +40,22 @@ impl LweSecretKey<Vec<bool>> {
             tensor: random::random_uniform_boolean_tensor(size.0),
         }
     }
+
+    /// Generates a new secret key for the given set of LWE parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::parameters::LweParameters;
+    /// use concrete_core::crypto::{*, secret::*};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// let params = LweParameters::new(LweDimension(256), LogStandardDev(-15.)).unwrap();
+    /// let secret_key = LweSecretKey::generate_with(&params);
+    /// assert_eq!(secret_key.key_size(), LweDimension(256));
+    /// ```
+    pub fn generate_with(params: &LweParameters) -> Self {
+        LweSecretKey::generate(params.dimension())
+    }
 }
 
 impl<Cont> LweSecretKey<Cont> {
@@ -109,6 +133,7 @@ impl<Cont> LweSecretKey<Cont> {
         LweCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
+        ck_dim_eq!(self.key_size() => output.get_mask().mask_size());
         let (output_body, mut output_masks) = output.get_mut_body_and_mask();
 
         // generate a uniformly random mask
@@ -127,7 +152,7 @@ impl<Cont> LweSecretKey<Cont> {
         // compute the multisum between the secret key and the mask
         output_body.0 = output_body
             .0
-            .wrapping_add(output_masks.compute_binary_multisum(&self));
+            .wrapping_add(output_masks.ct_compute_binary_multisum(self));
 
         // add the encoded message
         output_body.0 = output_body.0.wrapping_add(encoded.0);
@@ -177,10 +202,7 @@ impl<Cont> LweSecretKey<Cont> {
         PlaintextList<InputCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
-        debug_assert!(
-            output.count().0 == encoded.count().0,
-            "Lwe cipher list size and encoded list size are not compatible"
-        );
+        ck_dim_eq!(output.count() => CiphertextCount(encoded.count().0));
         for (mut cipher, message) in output.ciphertext_iter_mut().zip(encoded.plaintext_iter()) {
             self.encrypt_lwe(&mut cipher, message, noise_parameters.clone());
         }
@@ -222,6 +244,7 @@ impl<Cont> LweSecretKey<Cont> {
         LweCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
+        ck_dim_eq!(self.key_size() => output.get_mask().mask_size());
         let (output_body, mut output_masks) = output.get_mut_body_and_mask();
 
         // generate a uniformly random mask
@@ -239,7 +262,7 @@ impl<Cont> LweSecretKey<Cont> {
         // compute the multisum between the secret key and the mask
         output_body.0 = output_body
             .0
-            .wrapping_add(output_masks.compute_binary_multisum(&self));
+            .wrapping_add(output_masks.ct_compute_binary_multisum(self));
 
         // add the encoded message
         output_body.0 = output_body.0.wrapping_add(encoded.0);
@@ -295,10 +318,7 @@ impl<Cont> LweSecretKey<Cont> {
         PlaintextList<InputCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
-        debug_assert!(
-            output.count().0 == encoded.count().0,
-            "Lwe cipher list size and encoded list size are not compatible"
-        );
+        ck_dim_eq!(output.count() => CiphertextCount(encoded.count().0));
         for (mut cipher, message) in output.ciphertext_iter_mut().zip(encoded.plaintext_iter()) {
             self.trivial_encrypt_lwe(&mut cipher, message, noise_parameters.clone());
         }
@@ -306,6 +326,9 @@ impl<Cont> LweSecretKey<Cont> {
 
     /// Decrypts a single ciphertext.
     ///
+    /// The multisum between the mask and the key is computed in constant time, as in
+    /// [`LweSecretKey::ct_decrypt_lwe`].
+    ///
     /// See ['encrypt_lwe'] for an example.
     pub fn decrypt_lwe<Scalar, CipherCont>(
         &self,
@@ -316,11 +339,64 @@ impl<Cont> LweSecretKey<Cont> {
         LweCiphertext<CipherCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
+        ck_dim_eq!(self.key_size() => cipher.get_mask().mask_size());
+        let (body, masks) = cipher.get_body_and_mask();
+        // put body inside result
+        output.0 = output.0.wrapping_add(body.0);
+        // subtract the multisum between the key and the mask
+        output.0 = output
+            .0
+            .wrapping_sub(masks.ct_compute_binary_multisum(self));
+    }
+
+    /// Decrypts a single ciphertext in constant time.
+    ///
+    /// This computes the same result as [`LweSecretKey::decrypt_lwe`].
+    ///
+    /// # Constant-time
+    ///
+    /// The multisum between the mask and the key is computed with
+    /// [`LweMask::ct_compute_binary_multisum`](crate::crypto::lwe::LweMask::ct_compute_binary_multisum),
+    /// so that the execution time of the decryption does not depend on the bits of the key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::{*, secret::*, lwe::*};
+    /// use concrete_core::crypto::encoding::*;
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    ///
+    /// let secret_key = LweSecretKey::generate(LweDimension(256));
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let plain = Plaintext(1u32 << 30);
+    /// let mut encrypted = LweCiphertext::allocate(0u32, LweSize(257));
+    /// secret_key.encrypt_lwe(&mut encrypted, &plain, noise);
+    ///
+    /// let mut decrypted = Plaintext(0u32);
+    /// secret_key.decrypt_lwe(&mut decrypted, &encrypted);
+    /// let mut ct_decrypted = Plaintext(0u32);
+    /// secret_key.ct_decrypt_lwe(&mut ct_decrypted, &encrypted);
+    ///
+    /// assert_eq!(decrypted, ct_decrypted);
+    /// ```
+    pub fn ct_decrypt_lwe<Scalar, CipherCont>(
+        &self,
+        output: &mut Plaintext<Scalar>,
+        cipher: &LweCiphertext<CipherCont>,
+    ) where
+        Self: AsRefTensor<Element = bool>,
+        LweCiphertext<CipherCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.key_size() => cipher.get_mask().mask_size());
         let (body, masks) = cipher.get_body_and_mask();
         // put body inside result
         output.0 = output.0.wrapping_add(body.0);
         // subtract the multisum between the key and the mask
-        output.0 = output.0.wrapping_sub(masks.compute_binary_multisum(&self));
+        output.0 = output
+            .0
+            .wrapping_sub(masks.ct_compute_binary_multisum(self));
     }
 
     /// Decrypts a list of ciphertexts.
@@ -336,14 +412,242 @@ impl<Cont> LweSecretKey<Cont> {
         LweList<CipherCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
-        debug_assert!(
-            output.count().0 == cipher.count().0,
-            "Tried to decrypt a list into one with incompatible size.Expected {} found {}",
-            output.count().0,
-            cipher.count().0
-        );
+        ck_dim_eq!(output.count() => PlaintextCount(cipher.count().0));
         for (cipher, mut output) in cipher.ciphertext_iter().zip(output.plaintext_iter_mut()) {
             self.decrypt_lwe(&mut output, &cipher);
         }
     }
+
+    /// Encrypts a stream of plaintexts, and writes the ciphertexts to a writer as they are
+    /// produced.
+    ///
+    /// The plaintexts are encrypted in batches of [`DEFAULT_STREAM_BATCH_SIZE`] ciphertexts. See
+    /// [`LweSecretKey::encrypt_stream_with_batch_size`] for the details, and to use another batch
+    /// size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::secret::LweSecretKey;
+    /// use concrete_core::crypto::LweDimension;
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    ///
+    /// let secret_key = LweSecretKey::generate(LweDimension(256));
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    ///
+    /// let mut stream = Vec::new();
+    /// let plaintexts = (0..10_000u64).map(|i| i << 48);
+    /// let written = secret_key
+    ///     .encrypt_stream(plaintexts, noise, &mut stream)
+    ///     .unwrap();
+    /// assert_eq!(written, 10_000);
+    ///
+    /// let mut decrypted = Vec::new();
+    /// let read = secret_key
+    ///     .decrypt_stream(stream.as_slice(), |plaintext: u64| {
+    ///         decrypted.push(plaintext.wrapping_add(1 << 47) >> 48)
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(read, 10_000);
+    /// assert_eq!(decrypted, (0..10_000).collect::<Vec<_>>());
+    /// ```
+    pub fn encrypt_stream<Scalar>(
+        &self,
+        plaintexts: impl Iterator<Item = Scalar>,
+        noise_parameters: impl DispersionParameter,
+        writer: impl Write,
+    ) -> Result<u64, Error>
+    where
+        Self: AsRefTensor<Element = bool>,
+        Scalar: UnsignedTorus + Serialize,
+    {
+        self.encrypt_stream_with_batch_size(
+            plaintexts,
+            noise_parameters,
+            writer,
+            DEFAULT_STREAM_BATCH_SIZE,
+        )
+    }
+
+    /// Encrypts a stream of plaintexts in batches of a given size, and writes the ciphertexts to
+    /// a writer as they are produced.
+    ///
+    /// Only one batch of ciphertexts is kept in memory: the buffer holding it is allocated once,
+    /// and reused for every batch. The stream is made of the following values, serialized with
+    /// `bincode` as the rest of the library:
+    ///
+    /// + the [`LweSize`] of the ciphertexts,
+    /// + one slice of coefficients per batch, holding the ciphertexts of the batch one after the
+    ///   other,
+    /// + an empty slice, marking the end of the stream.
+    ///
+    /// Since the values are written one at a time, wrapping a file or a socket in a
+    /// [`BufWriter`](std::io::BufWriter) is recommended. The stream can be decrypted with
+    /// [`LweSecretKey::decrypt_stream`].
+    ///
+    /// Returns the number of ciphertexts written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Save`] if the writer fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the batch size is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::secret::LweSecretKey;
+    /// use concrete_core::crypto::{CiphertextCount, LweDimension};
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    ///
+    /// let secret_key = LweSecretKey::generate(LweDimension(256));
+    /// let noise = LogStandardDev::from_log_standard_dev(-20.);
+    ///
+    /// let mut stream = Vec::new();
+    /// let plaintexts = (0..100u32).map(|i| i << 24);
+    /// let written = secret_key
+    ///     .encrypt_stream_with_batch_size(plaintexts, noise, &mut stream, CiphertextCount(32))
+    ///     .unwrap();
+    /// assert_eq!(written, 100);
+    ///
+    /// let mut sum = 0;
+    /// secret_key
+    ///     .decrypt_stream(stream.as_slice(), |plaintext: u32| {
+    ///         sum += plaintext.wrapping_add(1 << 23) >> 24
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(sum, 4950);
+    /// ```
+    pub fn encrypt_stream_with_batch_size<Scalar>(
+        &self,
+        plaintexts: impl Iterator<Item = Scalar>,
+        noise_parameters: impl DispersionParameter,
+        writer: impl Write,
+        batch_size: CiphertextCount,
+    ) -> Result<u64, Error>
+    where
+        Self: AsRefTensor<Element = bool>,
+        Scalar: UnsignedTorus + Serialize,
+    {
+        assert!(batch_size.0 > 0, "The batch size must be positive.");
+        let to_error = |source: bincode::Error| Error::from(SaveError::WritingStream { source });
+        let (mut plaintexts, mut writer) = (plaintexts, writer);
+        let lwe_size = self.key_size().to_lwe_size();
+        bincode::serialize_into(&mut writer, &lwe_size).map_err(to_error)?;
+
+        let mut buffer = LweList::allocate(Scalar::ZERO, lwe_size, batch_size);
+        let mut count = 0;
+        loop {
+            let mut filled = 0;
+            for (mut ciphertext, plaintext) in buffer.ciphertext_iter_mut().zip(&mut plaintexts) {
+                self.encrypt_lwe(
+                    &mut ciphertext,
+                    &Plaintext(plaintext),
+                    noise_parameters.clone(),
+                );
+                filled += 1;
+            }
+            if filled == 0 {
+                break;
+            }
+            let batch = &buffer.as_tensor().as_slice()[..filled * lwe_size.0];
+            bincode::serialize_into(&mut writer, batch).map_err(to_error)?;
+            count += filled as u64;
+            if filled < batch_size.0 {
+                break;
+            }
+        }
+        bincode::serialize_into(&mut writer, &[] as &[Scalar]).map_err(to_error)?;
+        Ok(count)
+    }
+
+    /// Reads a stream of ciphertexts and decrypts them, passing the plaintexts to a sink as they
+    /// are decrypted.
+    ///
+    /// The stream must have been written by [`LweSecretKey::encrypt_stream`], or
+    /// [`LweSecretKey::encrypt_stream_with_batch_size`]. The ciphertexts are read one batch at a
+    /// time, in a buffer reused for all the batches. Returns the number of ciphertexts decrypted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::DimensionMismatch`] if the ciphertexts of the stream do not match the
+    /// size of the key, and an [`Error::Load`] if the reader fails or the stream is malformed.
+    /// The plaintexts of the batches read before the error was met have already been passed to
+    /// the sink.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::crypto::secret::LweSecretKey;
+    /// use concrete_core::crypto::LweDimension;
+    /// use concrete_core::math::dispersion::LogStandardDev;
+    /// use concrete_core::Error;
+    ///
+    /// let secret_key = LweSecretKey::generate(LweDimension(256));
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    /// let mut stream = Vec::new();
+    /// secret_key
+    ///     .encrypt_stream((0..10u64).map(|i| i << 60), noise, &mut stream)
+    ///     .unwrap();
+    ///
+    /// let other_key = LweSecretKey::generate(LweDimension(512));
+    /// let error = other_key
+    ///     .decrypt_stream(stream.as_slice(), |_: u64| {})
+    ///     .unwrap_err();
+    /// assert!(matches!(error, Error::DimensionMismatch { .. }));
+    ///
+    /// let truncated = &stream[..stream.len() - 1];
+    /// let error = secret_key
+    ///     .decrypt_stream(truncated, |_: u64| {})
+    ///     .unwrap_err();
+    /// assert!(matches!(error, Error::Load(_)));
+    /// ```
+    pub fn decrypt_stream<Scalar>(
+        &self,
+        reader: impl Read,
+        mut sink: impl FnMut(Scalar),
+    ) -> Result<u64, Error>
+    where
+        Self: AsRefTensor<Element = bool>,
+        Scalar: UnsignedTorus + DeserializeOwned,
+    {
+        let to_error = |source: bincode::Error| Error::from(LoadError::ReadingStream { source });
+        let mut reader = reader;
+        let lwe_size: LweSize = bincode::deserialize_from(&mut reader).map_err(to_error)?;
+        let expected_size = self.key_size().to_lwe_size();
+        if lwe_size != expected_size {
+            return Err(Error::DimensionMismatch {
+                parameter: "lwe size",
+                expected: expected_size.0,
+                found: lwe_size.0,
+            });
+        }
+
+        let mut buffer: Vec<Scalar> = Vec::new();
+        let mut count = 0;
+        loop {
+            let len: u64 = bincode::deserialize_from(&mut reader).map_err(to_error)?;
+            if len == 0 {
+                break;
+            }
+            if !len.is_multiple_of(lwe_size.0 as u64) {
+                return Err(to_error(Box::new(bincode::ErrorKind::Custom(
+                    "the length of a batch is not a multiple of the lwe size".into(),
+                ))));
+            }
+            buffer.clear();
+            for _ in 0..len {
+                buffer.push(bincode::deserialize_from(&mut reader).map_err(to_error)?);
+            }
+            for coefficients in buffer.chunks_exact(lwe_size.0) {
+                let mut plaintext = Plaintext(Scalar::ZERO);
+                self.decrypt_lwe(&mut plaintext, &LweCiphertext::from_container(coefficients));
+                sink(plaintext.0);
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
 }
@@ -1,4 +1,10 @@
 //! Secret keys for the concrete schemes.
+#[cfg(test)]
+mod tests;
+
+mod derivation;
+pub use derivation::*;
+
 mod glwe;
 pub use glwe::*;
 
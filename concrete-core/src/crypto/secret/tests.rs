@@ -0,0 +1,147 @@
+use crate::crypto::encoding::PlaintextList;
+use crate::crypto::glwe::GlweCiphertext;
+use crate::crypto::lwe::LweList;
+use crate::crypto::secret::{GlweSecretKey, KeyDerivation, KeyLabel, PackedGlweSecretKey};
+use crate::crypto::{GlweDimension, LweDimension, PlaintextCount};
+use crate::math::dispersion::LogStandardDev;
+use crate::math::polynomial::PolynomialSize;
+use crate::math::random;
+use crate::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::test_tools::{
+    assert_delta_std_dev, random_ciphertext_count, random_glwe_dimension, random_lwe_dimension,
+    random_polynomial_size,
+};
+
+// Writes the bits of a key as a string of zeros and ones.
+fn key_bits<K: AsRefTensor<Element = bool>>(key: &K) -> String {
+    key.as_tensor()
+        .iter()
+        .map(|bit| if *bit { '1' } else { '0' })
+        .collect()
+}
+
+#[test]
+fn test_derivation_fixtures() {
+    //! the derivation must not change across releases: changing those values is a breaking change
+    let derivation = KeyDerivation::new(0x0123_4567_89ab_cdef_fedc_ba98_7654_3210);
+    let lwe_key = derivation.derive_lwe_key(KeyLabel(b"session", 7), LweDimension(64));
+    assert_eq!(
+        key_bits(&lwe_key),
+        "1010011001111101001100011000111101100011111000101001100111111110"
+    );
+    let glwe_key = derivation.derive_glwe_key(
+        KeyLabel(b"bootstrapping key", 0),
+        GlweDimension(1),
+        PolynomialSize(32),
+    );
+    assert_eq!(key_bits(&glwe_key), "11010010000110101101011111010010");
+}
+
+#[test]
+fn test_derivation_repeatable() {
+    let master_seed = random::random_uniform();
+    let dimension = random_lwe_dimension(1000);
+    let index = random::random_uniform();
+    let first = KeyDerivation::new(master_seed).derive_lwe_key(KeyLabel(b"key", index), dimension);
+    let second = KeyDerivation::new(master_seed).derive_lwe_key(KeyLabel(b"key", index), dimension);
+    assert_eq!(first, second);
+
+    // a derivation does not depend on the keys derived before
+    let derivation = KeyDerivation::new(master_seed);
+    derivation.derive_lwe_key(KeyLabel(b"other", index), dimension);
+    assert_eq!(
+        derivation.derive_lwe_key(KeyLabel(b"key", index), dimension),
+        first
+    );
+}
+
+#[test]
+fn test_derivation_distinct_labels() {
+    let derivation = KeyDerivation::new(random::random_uniform());
+    let dimension = LweDimension(256);
+    let labels = [
+        KeyLabel(b"", 0),
+        KeyLabel(b"", 1),
+        KeyLabel(b"a", 0),
+        KeyLabel(b"a\0", 0),
+        KeyLabel(b"b", 0),
+        KeyLabel(b"session", 0),
+        KeyLabel(b"session", 1),
+        KeyLabel(b"session\0", 0),
+        KeyLabel(b"session key", 0),
+    ];
+    let keys: Vec<_> = labels
+        .iter()
+        .map(|label| derivation.derive_lwe_key(*label, dimension))
+        .collect();
+    for (i, first) in keys.iter().enumerate() {
+        for second in keys[i + 1..].iter() {
+            assert_ne!(first, second);
+        }
+    }
+
+    // the keys of different types or dimensions are independent as well
+    let label = KeyLabel(b"session", 0);
+    let small = derivation.derive_lwe_key(label, LweDimension(128));
+    assert_ne!(
+        small.as_tensor().as_slice(),
+        &keys[5].as_tensor().as_slice()[..128]
+    );
+    let glwe = derivation.derive_glwe_key(label, GlweDimension(1), PolynomialSize(256));
+    assert_ne!(glwe.as_tensor().as_slice(), keys[5].as_tensor().as_slice());
+}
+
+#[test]
+fn test_derived_keys_encrypt_decrypt() {
+    let derivation = KeyDerivation::new(random::random_uniform());
+    let noise = LogStandardDev::from_log_standard_dev(-25.);
+
+    // lwe
+    let nb_ct = random_ciphertext_count(100);
+    let dimension = random_lwe_dimension(1000);
+    let key = derivation.derive_lwe_key(KeyLabel(b"lwe", 0), dimension);
+    let plaintexts: PlaintextList<Vec<u64>> =
+        PlaintextList::from_tensor(random::random_uniform_tensor(nb_ct.0));
+    let mut ciphertexts = LweList::allocate(0u64, dimension.to_lwe_size(), nb_ct);
+    key.encrypt_lwe_list(&mut ciphertexts, &plaintexts, noise);
+    let mut decryptions = PlaintextList::allocate(0u64, PlaintextCount(nb_ct.0));
+    key.decrypt_lwe_list(&mut decryptions, &ciphertexts);
+    assert_delta_std_dev(&plaintexts, &decryptions, noise);
+
+    // glwe
+    let dimension = random_glwe_dimension(10);
+    let polynomial_size = random_polynomial_size(1024);
+    let key = derivation.derive_glwe_key(KeyLabel(b"glwe", 0), dimension, polynomial_size);
+    let plaintexts: PlaintextList<Vec<u64>> =
+        PlaintextList::from_tensor(random::random_uniform_tensor(polynomial_size.0));
+    let mut ciphertext = GlweCiphertext::allocate(0u64, polynomial_size, dimension.to_glwe_size());
+    key.encrypt_glwe(&mut ciphertext, &plaintexts, noise);
+    let mut decryption = PlaintextList::allocate(0u64, PlaintextCount(polynomial_size.0));
+    key.decrypt_glwe(&mut decryption, &ciphertext);
+    assert_delta_std_dev(&plaintexts, &decryption, noise);
+}
+
+#[test]
+fn test_packed_glwe_key_encrypt_decrypt() {
+    let noise = LogStandardDev::from_log_standard_dev(-25.);
+    let dimension = random_glwe_dimension(10);
+    let polynomial_size = random_polynomial_size(1024);
+    let key = GlweSecretKey::generate(dimension, polynomial_size);
+    let packed_key = PackedGlweSecretKey::new(key.clone());
+    assert_eq!(
+        packed_key
+            .as_binary_polynomial_list()
+            .as_tensor()
+            .as_slice(),
+        key.to_binary_polynomial_list().as_tensor().as_slice()
+    );
+
+    // The packed key encrypts for the unpacked one.
+    let plaintexts: PlaintextList<Vec<u64>> =
+        PlaintextList::from_tensor(random::random_uniform_tensor(polynomial_size.0));
+    let mut ciphertext = GlweCiphertext::allocate(0u64, polynomial_size, dimension.to_glwe_size());
+    packed_key.encrypt_glwe(&mut ciphertext, &plaintexts, noise);
+    let mut decryption = PlaintextList::allocate(0u64, PlaintextCount(polynomial_size.0));
+    key.decrypt_glwe(&mut decryption, &ciphertext);
+    assert_delta_std_dev(&plaintexts, &decryption, noise);
+}
@@ -0,0 +1,627 @@
+//! A high-level engine
+//!
+//! Encrypting and computing over a single integer with the rest of the library requires choosing
+//! a dozen parameters, generating several keys, and allocating the buffers used by the
+//! bootstrap. This module bundles all of them in an [`FheEngine`], which exposes a small set of
+//! operations over encrypted integers of a few bits:
+//!
+//! + [`FheEngine::encrypt`] and [`FheEngine::decrypt`],
+//! + [`FheEngine::add`] and [`FheEngine::mul_constant`], which are cheap,
+//! + [`FheEngine::apply_lut`], which evaluates any function of a single input with a
+//!   programmable bootstrap, and resets the noise of the ciphertext.
+//!
+//! The public part of the engine, the [`EvaluationEngine`], can be sent to a third party which
+//! performs the computations without being able to decrypt.
+//!
+//! This module is only available with the `engine` feature, and is entirely built on the public
+//! API of the other modules.
+//!
+//! # Example
+//!
+//! ```rust
+//! use concrete_core::engine::{EngineParameters, EvaluationEngine, FheEngine};
+//!
+//! let mut engine = FheEngine::new(EngineParameters::preset_2_bits());
+//! let a = engine.encrypt(1);
+//! let b = engine.encrypt(2);
+//!
+//! // The operations which do not need the secret key can be performed by another party.
+//! let mut public_material = Vec::new();
+//! engine.save_public_material(&mut public_material).unwrap();
+//! let mut evaluator = EvaluationEngine::load_public_material(public_material.as_slice()).unwrap();
+//! let sum = evaluator.add(&a, &b);
+//! let result = evaluator.apply_lut(&sum, |x| x * x);
+//!
+//! assert_eq!(engine.decrypt(&result), 1);
+//! ```
+use std::fmt::{Debug, Formatter};
+use std::io::{Read, Write};
+
+use fftw::array::AlignedVec;
+use serde::{Deserialize, Serialize};
+
+use crate::crypto::boolean::GateBootstrappingKey;
+use crate::crypto::bootstrap::BootstrapKey;
+use crate::crypto::cross::{blind_rotate, constant_sample_extract};
+use crate::crypto::encoding::{Cleartext, Plaintext};
+use crate::crypto::glwe::GlweCiphertext;
+use crate::crypto::lwe::{LweCiphertext, LweKeyswitchKey};
+use crate::crypto::parameters::{GlweParameters, LweParameters, PbsParameters};
+use crate::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::crypto::{GlweDimension, LweDimension};
+use crate::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+use crate::math::dispersion::LogStandardDev;
+use crate::math::fft::{Complex64, Fft, FourierPolynomial};
+use crate::math::polynomial::PolynomialSize;
+use crate::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::math::tensor::{LoadError, SaveError};
+use crate::Error;
+
+#[cfg(test)]
+mod tests;
+
+/// The largest number of bits of the messages handled by an engine.
+const MAX_MESSAGE_BITS: usize = 8;
+
+/// The parameters of an [`FheEngine`].
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::crypto::parameters::{GlweParameters, LweParameters, PbsParameters};
+/// use concrete_core::crypto::{GlweDimension, LweDimension};
+/// use concrete_core::engine::EngineParameters;
+/// use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+/// use concrete_core::math::dispersion::LogStandardDev;
+/// use concrete_core::math::polynomial::PolynomialSize;
+/// let lwe = LweParameters::new(LweDimension(630), LogStandardDev(-15.)).unwrap();
+/// let glwe =
+///     GlweParameters::new(GlweDimension(1), PolynomialSize(1024), LogStandardDev(-25.)).unwrap();
+/// let pbs = PbsParameters::new(DecompositionBaseLog(7), DecompositionLevelCount(3)).unwrap();
+/// let params = EngineParameters::new(
+///     2,
+///     lwe,
+///     glwe,
+///     pbs,
+///     DecompositionBaseLog(2),
+///     DecompositionLevelCount(8),
+/// )
+/// .unwrap();
+/// assert_eq!(params, EngineParameters::preset_2_bits());
+///
+/// // The messages have at most 8 bits.
+/// assert!(EngineParameters::new(
+///     9,
+///     lwe,
+///     glwe,
+///     pbs,
+///     DecompositionBaseLog(2),
+///     DecompositionLevelCount(8),
+/// )
+/// .is_err());
+///
+/// // The polynomials must be supported by the fourier transforms.
+/// let small_glwe =
+///     GlweParameters::new(GlweDimension(1), PolynomialSize(64), LogStandardDev(-25.)).unwrap();
+/// assert!(EngineParameters::new(
+///     2,
+///     lwe,
+///     small_glwe,
+///     pbs,
+///     DecompositionBaseLog(2),
+///     DecompositionLevelCount(8),
+/// )
+/// .is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EngineParameters {
+    message_bits: usize,
+    lwe: LweParameters,
+    glwe: GlweParameters,
+    pbs: PbsParameters,
+    keyswitch_base_log: DecompositionBaseLog,
+    keyswitch_level_count: DecompositionLevelCount,
+}
+
+impl EngineParameters {
+    /// Creates a new set of engine parameters.
+    ///
+    /// The messages have `message_bits` bits. They are encrypted under the `lwe` instance, and
+    /// bootstrapped with a key encrypted under the `glwe` instance using the `pbs`
+    /// decomposition. The result of the bootstrap is switched back to the `lwe` instance with a
+    /// keyswitching key using the given decomposition.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the number of bits of the messages is zero or larger than 8, if the polynomials
+    /// of the `glwe` instance can not hold two coefficients per message or are not supported by
+    /// the fourier transforms (see [`Fft::try_new`]), or if the keyswitch decomposition is empty
+    /// or covers more than 64 bits.
+    pub fn new(
+        message_bits: usize,
+        lwe: LweParameters,
+        glwe: GlweParameters,
+        pbs: PbsParameters,
+        keyswitch_base_log: DecompositionBaseLog,
+        keyswitch_level_count: DecompositionLevelCount,
+    ) -> Result<Self, Error> {
+        if message_bits == 0 || message_bits > MAX_MESSAGE_BITS {
+            return Err(Error::InvalidParameter {
+                parameter: "message bits",
+                reason: format!(
+                    "must be between 1 and {}, got {}",
+                    MAX_MESSAGE_BITS, message_bits
+                ),
+            });
+        }
+        if glwe.polynomial_size().0 < 2 << message_bits {
+            return Err(Error::InvalidParameter {
+                parameter: "polynomial size",
+                reason: format!(
+                    "must be at least {} to hold {} bits messages, got {}",
+                    2 << message_bits,
+                    message_bits,
+                    glwe.polynomial_size().0
+                ),
+            });
+        }
+        // The bootstrap needs a transformer for the polynomials. Its plans are cached, so the one
+        // created by the engine later on is cheap.
+        Fft::try_new(glwe.polynomial_size())?;
+        // The keyswitch decomposition obeys the same rules as the bootstrap one.
+        PbsParameters::new(keyswitch_base_log, keyswitch_level_count)?;
+        Ok(EngineParameters {
+            message_bits,
+            lwe,
+            glwe,
+            pbs,
+            keyswitch_base_log,
+            keyswitch_level_count,
+        })
+    }
+
+    /// Returns a set of parameters for messages of 2 bits.
+    ///
+    /// Those are the parameters of the gate bootstrapping of TFHE, for which the noise after a
+    /// bootstrap and a keyswitch stays far below the distance between two messages.
+    pub fn preset_2_bits() -> Self {
+        EngineParameters {
+            message_bits: 2,
+            lwe: LweParameters::new(LweDimension(630), LogStandardDev(-15.)).unwrap(),
+            glwe: GlweParameters::new(GlweDimension(1), PolynomialSize(1024), LogStandardDev(-25.))
+                .unwrap(),
+            pbs: PbsParameters::new(DecompositionBaseLog(7), DecompositionLevelCount(3)).unwrap(),
+            keyswitch_base_log: DecompositionBaseLog(2),
+            keyswitch_level_count: DecompositionLevelCount(8),
+        }
+    }
+
+    /// Returns the number of bits of the messages.
+    pub fn message_bits(&self) -> usize {
+        self.message_bits
+    }
+
+    /// Returns the parameters of the LWE instance the messages are encrypted under.
+    pub fn lwe(&self) -> LweParameters {
+        self.lwe
+    }
+
+    /// Returns the parameters of the GLWE instance the bootstrapping key is encrypted under.
+    pub fn glwe(&self) -> GlweParameters {
+        self.glwe
+    }
+
+    /// Returns the decomposition of the bootstrapping key.
+    pub fn pbs(&self) -> PbsParameters {
+        self.pbs
+    }
+
+    /// Returns the logarithm of the base of the keyswitch decomposition.
+    pub fn keyswitch_base_log(&self) -> DecompositionBaseLog {
+        self.keyswitch_base_log
+    }
+
+    /// Returns the number of levels of the keyswitch decomposition.
+    pub fn keyswitch_level_count(&self) -> DecompositionLevelCount {
+        self.keyswitch_level_count
+    }
+
+    // The shift bringing a message to the most significant bits, below the padding bit.
+    fn shift(&self) -> usize {
+        64 - 1 - self.message_bits
+    }
+
+    fn encode(&self, message: u64) -> u64 {
+        (message & self.message_mask()) << self.shift()
+    }
+
+    fn decode(&self, plaintext: u64) -> u64 {
+        let shift = self.shift();
+        (plaintext.wrapping_add(1 << (shift - 1)) >> shift) & self.message_mask()
+    }
+
+    fn message_mask(&self) -> u64 {
+        (1 << self.message_bits) - 1
+    }
+}
+
+/// An integer encrypted by an [`FheEngine`].
+///
+/// The message is encoded in the most significant bits of an LWE ciphertext, right below a
+/// padding bit which is kept to zero for the bootstrap to be correct.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ciphertext {
+    lwe: LweCiphertext<Vec<u64>>,
+}
+
+impl Ciphertext {
+    /// Returns the underlying LWE ciphertext.
+    pub fn as_lwe_ciphertext(&self) -> &LweCiphertext<Vec<u64>> {
+        &self.lwe
+    }
+}
+
+// The layout of the serialized public material.
+#[derive(Serialize, Deserialize)]
+struct PublicMaterial<BskCont> {
+    message_bits: usize,
+    lwe_dimension: LweDimension,
+    lwe_log_std_dev: f64,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    glwe_log_std_dev: f64,
+    pbs_base_log: DecompositionBaseLog,
+    pbs_level_count: DecompositionLevelCount,
+    keyswitch_base_log: DecompositionBaseLog,
+    keyswitch_level_count: DecompositionLevelCount,
+    bootstrap_key: BootstrapKey<BskCont>,
+    keyswitch_key: LweKeyswitchKey<Vec<u64>>,
+}
+
+/// The public part of an [`FheEngine`], which performs the homomorphic operations.
+///
+/// It owns the bootstrapping and keyswitching keys, along with the FFT plan and the buffers used
+/// by the bootstrap, which are allocated once for all the operations.
+pub struct EvaluationEngine {
+    parameters: EngineParameters,
+    key: GateBootstrappingKey<u64>,
+    fft: Fft,
+    dec_i_fft: FourierPolynomial<AlignedVec<Complex64>>,
+    tmp_dec_i_fft: FourierPolynomial<AlignedVec<Complex64>>,
+    res_fft: Vec<FourierPolynomial<AlignedVec<Complex64>>>,
+    accumulator: GlweCiphertext<Vec<u64>>,
+    extracted: LweCiphertext<Vec<u64>>,
+}
+
+impl Debug for EvaluationEngine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EvaluationEngine")
+            .field("parameters", &self.parameters)
+            .finish()
+    }
+}
+
+impl EvaluationEngine {
+    fn from_key(parameters: EngineParameters, key: GateBootstrappingKey<u64>) -> Self {
+        let poly_size = parameters.glwe.polynomial_size();
+        let glwe_size = parameters.glwe.size();
+        EvaluationEngine {
+            parameters,
+            key,
+            fft: Fft::new(poly_size),
+            dec_i_fft: FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size),
+            tmp_dec_i_fft: FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size),
+            res_fft: vec![
+                FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
+                glwe_size.0
+            ],
+            accumulator: GlweCiphertext::allocate(0, poly_size, glwe_size),
+            extracted: LweCiphertext::allocate(
+                0,
+                parameters
+                    .glwe
+                    .to_lwe_parameters()
+                    .dimension()
+                    .to_lwe_size(),
+            ),
+        }
+    }
+
+    /// Returns the parameters of the engine.
+    pub fn parameters(&self) -> &EngineParameters {
+        &self.parameters
+    }
+
+    /// Writes the parameters and the public keys of the engine.
+    ///
+    /// The output is the `bincode` serialization of the parameters, of the bootstrapping key in
+    /// the fourier domain, and of the keyswitching key.
+    pub fn save_public_material(&self, writer: impl Write) -> Result<(), Error> {
+        let params = &self.parameters;
+        let bootstrap_key = self.key.bootstrap_key();
+        let material = PublicMaterial {
+            message_bits: params.message_bits,
+            lwe_dimension: params.lwe.dimension(),
+            lwe_log_std_dev: params.lwe.noise().0,
+            glwe_dimension: params.glwe.dimension(),
+            polynomial_size: params.glwe.polynomial_size(),
+            glwe_log_std_dev: params.glwe.noise().0,
+            pbs_base_log: params.pbs.base_log(),
+            pbs_level_count: params.pbs.level_count(),
+            keyswitch_base_log: params.keyswitch_base_log,
+            keyswitch_level_count: params.keyswitch_level_count,
+            bootstrap_key: BootstrapKey::from_container(
+                bootstrap_key.as_tensor().as_slice(),
+                bootstrap_key.glwe_size(),
+                bootstrap_key.polynomial_size(),
+                bootstrap_key.level_count(),
+                bootstrap_key.base_log(),
+            ),
+            keyswitch_key: self.key.keyswitch_key().clone(),
+        };
+        bincode::serialize_into(writer, &material)
+            .map_err(|source| SaveError::WritingStream { source }.into())
+    }
+
+    /// Reads the public material written by [`EvaluationEngine::save_public_material`], and
+    /// creates an engine using it.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the material can not be read, if the parameters are invalid, or if the keys do
+    /// not match the parameters.
+    pub fn load_public_material(reader: impl Read) -> Result<Self, Error> {
+        let material: PublicMaterial<Vec<Complex64>> = bincode::deserialize_from(reader)
+            .map_err(|source| LoadError::ReadingStream { source })?;
+        let parameters = EngineParameters::new(
+            material.message_bits,
+            LweParameters::new(
+                material.lwe_dimension,
+                LogStandardDev(material.lwe_log_std_dev),
+            )?,
+            GlweParameters::new(
+                material.glwe_dimension,
+                material.polynomial_size,
+                LogStandardDev(material.glwe_log_std_dev),
+            )?,
+            PbsParameters::new(material.pbs_base_log, material.pbs_level_count)?,
+            material.keyswitch_base_log,
+            material.keyswitch_level_count,
+        )?;
+
+        let loaded = material.bootstrap_key;
+        let keyswitch_key = material.keyswitch_key;
+        // The stored dimensions are checked first, and the lengths of the tensors after them, so
+        // that the sizes the keys compute from their lengths are only queried on valid keys.
+        let lwe_dimension = parameters.lwe.dimension();
+        let flattened_dimension = parameters.glwe.to_lwe_parameters().dimension();
+        let glwe_size = parameters.glwe.size();
+        let polynomial_size = parameters.glwe.polynomial_size();
+        let pbs_level_count = parameters.pbs.level_count();
+        // The lengths saturate rather than overflow on absurd parameters, which no tensor matches.
+        let bootstrap_key_len = lwe_dimension
+            .0
+            .saturating_mul(glwe_size.0)
+            .saturating_mul(glwe_size.0)
+            .saturating_mul(polynomial_size.0)
+            .saturating_mul(pbs_level_count.0);
+        let keyswitch_key_len = flattened_dimension
+            .0
+            .saturating_mul(lwe_dimension.to_lwe_size().0)
+            .saturating_mul(parameters.keyswitch_level_count.0);
+        for (parameter, expected, found) in [
+            ("bootstrap key glwe size", glwe_size.0, loaded.glwe_size().0),
+            (
+                "bootstrap key polynomial size",
+                polynomial_size.0,
+                loaded.polynomial_size().0,
+            ),
+            (
+                "bootstrap key base log",
+                parameters.pbs.base_log().0,
+                loaded.base_log().0,
+            ),
+            (
+                "bootstrap key level count",
+                pbs_level_count.0,
+                loaded.level_count().0,
+            ),
+            (
+                "keyswitch key lwe size",
+                lwe_dimension.to_lwe_size().0,
+                keyswitch_key.lwe_size().0,
+            ),
+            (
+                "keyswitch key base log",
+                parameters.keyswitch_base_log.0,
+                keyswitch_key.decomposition_base_log().0,
+            ),
+            (
+                "keyswitch key level count",
+                parameters.keyswitch_level_count.0,
+                keyswitch_key.decomposition_levels_count().0,
+            ),
+            (
+                "bootstrap key length",
+                bootstrap_key_len,
+                loaded.as_tensor().len(),
+            ),
+            (
+                "keyswitch key length",
+                keyswitch_key_len,
+                keyswitch_key.as_tensor().len(),
+            ),
+        ] {
+            if expected != found {
+                return Err(Error::DimensionMismatch {
+                    parameter,
+                    expected,
+                    found,
+                });
+            }
+        }
+
+        // The fourier transforms need aligned memory, in which the deserialized coefficients are
+        // moved once.
+        let mut coefficients = AlignedVec::new(loaded.as_tensor().len());
+        coefficients
+            .as_slice_mut()
+            .copy_from_slice(loaded.as_tensor().as_slice());
+        let bootstrap_key = BootstrapKey::from_container(
+            coefficients,
+            loaded.glwe_size(),
+            loaded.polynomial_size(),
+            loaded.level_count(),
+            loaded.base_log(),
+        );
+        let key = GateBootstrappingKey::from_keys(bootstrap_key, keyswitch_key);
+        Ok(EvaluationEngine::from_key(parameters, key))
+    }
+
+    /// Returns the encryption of the sum of two messages.
+    ///
+    /// The result decrypts to the sum modulo $2^{bits}$. When the sum overflows, the carry is
+    /// stored in the padding bit, which makes the result unfit for [`EvaluationEngine::apply_lut`].
+    pub fn add(&self, a: &Ciphertext, b: &Ciphertext) -> Ciphertext {
+        let mut lwe = a.lwe.clone();
+        lwe.update_with_add(&b.lwe);
+        Ciphertext { lwe }
+    }
+
+    /// Returns the encryption of the product of a message by a constant.
+    ///
+    /// The result decrypts to the product modulo $2^{bits}$. As for [`EvaluationEngine::add`],
+    /// the result can only be bootstrapped if the product does not overflow. The noise is
+    /// multiplied by the constant.
+    pub fn mul_constant(&self, a: &Ciphertext, constant: u64) -> Ciphertext {
+        let mut lwe = a.lwe.clone();
+        lwe.update_with_scalar_mul(Cleartext(constant));
+        Ciphertext { lwe }
+    }
+
+    /// Returns the encryption of `f` applied to a message.
+    ///
+    /// The function is evaluated by a programmable bootstrap followed by a keyswitch, hence the
+    /// noise of the result does not depend on the noise of the input. The outputs of `f` are
+    /// taken modulo $2^{bits}$.
+    pub fn apply_lut(&mut self, a: &Ciphertext, f: impl Fn(u64) -> u64) -> Ciphertext {
+        let params = self.parameters;
+        let poly_size = params.glwe.polynomial_size().0;
+        let box_size = poly_size >> params.message_bits;
+
+        // The coefficients of the accumulator are split in one box per message, centered around
+        // the position the message is rotated to. The first half of the box of zero lies at the
+        // end of the polynomial, where the rotation brings the negated coefficients.
+        self.accumulator.as_mut_tensor().fill_with_element(0);
+        let mut body = self.accumulator.get_mut_body();
+        for (index, coefficient) in body.as_mut_tensor().iter_mut().enumerate() {
+            let message = (index + box_size / 2) / box_size;
+            *coefficient = if message < 1 << params.message_bits {
+                params.encode(f(message as u64))
+            } else {
+                params.encode(f(0)).wrapping_neg()
+            };
+        }
+
+        blind_rotate(
+            &mut self.fft,
+            &mut self.dec_i_fft,
+            &mut self.tmp_dec_i_fft,
+            &mut self.res_fft,
+            &mut self.accumulator,
+            &a.lwe,
+            self.key.bootstrap_key(),
+        );
+        constant_sample_extract(&mut self.extracted, &self.accumulator);
+        let mut lwe = LweCiphertext::allocate(0, self.key.keyswitch_key().lwe_size());
+        self.key
+            .keyswitch_key()
+            .keyswitch_ciphertext(&mut lwe, &self.extracted);
+        Ciphertext { lwe }
+    }
+}
+
+/// An engine owning the secret key, and able to encrypt, decrypt and compute over small
+/// integers.
+///
+/// See the documentation of the [`engine`](crate::engine) module for an example.
+pub struct FheEngine {
+    secret_key: LweSecretKey<Vec<bool>>,
+    evaluator: EvaluationEngine,
+}
+
+// The secret key must not be displayed.
+impl Debug for FheEngine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FheEngine")
+            .field("parameters", &self.evaluator.parameters)
+            .finish()
+    }
+}
+
+impl FheEngine {
+    /// Creates a new engine, generating fresh secret and public keys.
+    pub fn new(parameters: EngineParameters) -> Self {
+        let secret_key = LweSecretKey::generate_with(&parameters.lwe);
+        let glwe_secret_key = GlweSecretKey::generate_with(&parameters.glwe);
+        let key = GateBootstrappingKey::generate(
+            &secret_key,
+            &glwe_secret_key,
+            &parameters.pbs,
+            parameters.glwe.noise(),
+            parameters.keyswitch_base_log,
+            parameters.keyswitch_level_count,
+            parameters.lwe.noise(),
+        );
+        FheEngine {
+            secret_key,
+            evaluator: EvaluationEngine::from_key(parameters, key),
+        }
+    }
+
+    /// Returns the parameters of the engine.
+    pub fn parameters(&self) -> &EngineParameters {
+        &self.evaluator.parameters
+    }
+
+    /// Encrypts a message, whose bits above the message precision are discarded.
+    pub fn encrypt(&self, message: u64) -> Ciphertext {
+        let params = &self.evaluator.parameters;
+        let mut lwe = LweCiphertext::allocate(0, params.lwe.size());
+        self.secret_key.encrypt_lwe(
+            &mut lwe,
+            &Plaintext(params.encode(message)),
+            params.lwe.noise(),
+        );
+        Ciphertext { lwe }
+    }
+
+    /// Decrypts a message.
+    pub fn decrypt(&self, ciphertext: &Ciphertext) -> u64 {
+        let mut plaintext = Plaintext(0);
+        self.secret_key.decrypt_lwe(&mut plaintext, &ciphertext.lwe);
+        self.evaluator.parameters.decode(plaintext.0)
+    }
+
+    /// Returns the encryption of the sum of two messages. See [`EvaluationEngine::add`].
+    pub fn add(&self, a: &Ciphertext, b: &Ciphertext) -> Ciphertext {
+        self.evaluator.add(a, b)
+    }
+
+    /// Returns the encryption of the product of a message by a constant. See
+    /// [`EvaluationEngine::mul_constant`].
+    pub fn mul_constant(&self, a: &Ciphertext, constant: u64) -> Ciphertext {
+        self.evaluator.mul_constant(a, constant)
+    }
+
+    /// Returns the encryption of `f` applied to a message. See [`EvaluationEngine::apply_lut`].
+    pub fn apply_lut(&mut self, a: &Ciphertext, f: impl Fn(u64) -> u64) -> Ciphertext {
+        self.evaluator.apply_lut(a, f)
+    }
+
+    /// Writes the parameters and the public keys of the engine. See
+    /// [`EvaluationEngine::save_public_material`].
+    pub fn save_public_material(&self, writer: impl Write) -> Result<(), Error> {
+        self.evaluator.save_public_material(writer)
+    }
+}
@@ -0,0 +1,214 @@
+use crate::crypto::parameters::{GlweParameters, LweParameters, PbsParameters};
+use crate::crypto::{GlweDimension, LweDimension};
+use crate::engine::{EngineParameters, EvaluationEngine, FheEngine, PublicMaterial};
+use crate::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+use crate::math::dispersion::LogStandardDev;
+use crate::math::fft::Complex64;
+use crate::math::polynomial::PolynomialSize;
+use crate::Error;
+
+use serde::{Deserialize, Serialize};
+
+// The serialized layouts of the keys, whose fields and lengths can be altered freely.
+#[derive(Serialize, Deserialize)]
+struct RawBootstrapKey {
+    tensor: Vec<Complex64>,
+    poly_size: usize,
+    glwe_size: usize,
+    level_count: usize,
+    base_log: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawKeyswitchKey {
+    tensor: Vec<u64>,
+    base_log: usize,
+    level_count: usize,
+    lwe_size: usize,
+}
+
+// Converts a value to another one with the same serialized layout.
+fn transcode<T: Serialize, U: for<'de> Deserialize<'de>>(value: &T) -> U {
+    bincode::deserialize(&bincode::serialize(value).unwrap()).unwrap()
+}
+
+// Small and insecure parameters, which keep the tests fast.
+fn small_parameters() -> EngineParameters {
+    EngineParameters::new(
+        2,
+        LweParameters::new(LweDimension(256), LogStandardDev(-30.)).unwrap(),
+        GlweParameters::new(GlweDimension(1), PolynomialSize(512), LogStandardDev(-40.)).unwrap(),
+        PbsParameters::new(DecompositionBaseLog(15), DecompositionLevelCount(2)).unwrap(),
+        DecompositionBaseLog(4),
+        DecompositionLevelCount(5),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_encrypt_decrypt() {
+    let engine = FheEngine::new(small_parameters());
+    for message in 0..4 {
+        assert_eq!(engine.decrypt(&engine.encrypt(message)), message);
+    }
+    // The bits above the precision are discarded.
+    assert_eq!(engine.decrypt(&engine.encrypt(6)), 2);
+}
+
+#[test]
+fn test_add_mul_constant() {
+    let engine = FheEngine::new(small_parameters());
+    for a in 0..4 {
+        let enc_a = engine.encrypt(a);
+        for b in 0..4 {
+            let enc_b = engine.encrypt(b);
+            assert_eq!(engine.decrypt(&engine.add(&enc_a, &enc_b)), (a + b) % 4);
+            assert_eq!(engine.decrypt(&engine.mul_constant(&enc_a, b)), (a * b) % 4);
+        }
+    }
+}
+
+#[test]
+fn test_apply_lut() {
+    let mut engine = FheEngine::new(small_parameters());
+    let functions: [fn(u64) -> u64; 4] = [|x| x, |x| 3 - x, |x| x * x, |_| 2];
+    for f in functions.iter() {
+        for message in 0..4 {
+            let encrypted = engine.encrypt(message);
+            let result = engine.apply_lut(&encrypted, f);
+            assert_eq!(engine.decrypt(&result), f(message) % 4);
+        }
+    }
+
+    // The bootstrapped ciphertexts can be combined and bootstrapped again, as long as the sums
+    // do not overflow.
+    let mut acc = engine.encrypt(1);
+    for _ in 0..5 {
+        let doubled = engine.add(&acc, &acc);
+        acc = engine.apply_lut(&doubled, |x| x / 2);
+    }
+    assert_eq!(engine.decrypt(&acc), 1);
+}
+
+#[test]
+fn test_public_material() {
+    let mut engine = FheEngine::new(small_parameters());
+    let mut material = Vec::new();
+    engine.save_public_material(&mut material).unwrap();
+    let mut evaluator = EvaluationEngine::load_public_material(material.as_slice()).unwrap();
+    assert_eq!(evaluator.parameters(), engine.parameters());
+
+    let a = engine.encrypt(2);
+    let b = engine.encrypt(1);
+    let sum = evaluator.add(&a, &b);
+    let tripled = evaluator.mul_constant(&b, 3);
+    assert_eq!(engine.decrypt(&sum), 3);
+    assert_eq!(engine.decrypt(&tripled), 3);
+    // The loaded keys compute the same bootstraps as the original ones.
+    for message in 0..4 {
+        let encrypted = engine.encrypt(message);
+        let loaded = evaluator.apply_lut(&encrypted, |x| x * 3);
+        let original = engine.apply_lut(&encrypted, |x| x * 3);
+        assert_eq!(loaded, original);
+        assert_eq!(engine.decrypt(&loaded), (message * 3) % 4);
+    }
+
+    // Truncated material can not be loaded.
+    let truncated = &material[..material.len() / 2];
+    assert!(matches!(
+        EvaluationEngine::load_public_material(truncated),
+        Err(Error::Load(_))
+    ));
+
+    // Keys whose decomposition parameters do not match the parameters can not be loaded.
+    let alterations: [fn(&mut PublicMaterial<Vec<Complex64>>); 3] = [
+        |material| material.pbs_base_log = DecompositionBaseLog(14),
+        |material| material.keyswitch_base_log = DecompositionBaseLog(3),
+        |material| material.keyswitch_level_count = DecompositionLevelCount(6),
+    ];
+    for alteration in alterations.iter() {
+        let mut altered: PublicMaterial<Vec<Complex64>> = bincode::deserialize(&material).unwrap();
+        alteration(&mut altered);
+        let altered = bincode::serialize(&altered).unwrap();
+        assert!(matches!(
+            EvaluationEngine::load_public_material(altered.as_slice()),
+            Err(Error::DimensionMismatch { .. })
+        ));
+    }
+}
+
+#[test]
+fn test_unsupported_polynomial_size() {
+    // A polynomial of 64 coefficients holds 2 bits messages, but is too small for the fourier
+    // transforms.
+    let result = EngineParameters::new(
+        2,
+        LweParameters::new(LweDimension(256), LogStandardDev(-30.)).unwrap(),
+        GlweParameters::new(GlweDimension(1), PolynomialSize(64), LogStandardDev(-40.)).unwrap(),
+        PbsParameters::new(DecompositionBaseLog(15), DecompositionLevelCount(2)).unwrap(),
+        DecompositionBaseLog(4),
+        DecompositionLevelCount(5),
+    );
+    assert!(matches!(result, Err(Error::InvalidSize(_))));
+
+    // Neither can the public material of such an engine be loaded.
+    let engine = FheEngine::new(small_parameters());
+    let mut material = Vec::new();
+    engine.save_public_material(&mut material).unwrap();
+    let mut altered: PublicMaterial<Vec<Complex64>> = bincode::deserialize(&material).unwrap();
+    altered.polynomial_size = PolynomialSize(64);
+    let altered = bincode::serialize(&altered).unwrap();
+    assert!(matches!(
+        EvaluationEngine::load_public_material(altered.as_slice()),
+        Err(Error::InvalidSize(_))
+    ));
+}
+
+#[test]
+fn test_malformed_public_material() {
+    let engine = FheEngine::new(small_parameters());
+    let mut material = Vec::new();
+    engine.save_public_material(&mut material).unwrap();
+
+    // Keys whose tensors are truncated, oversized or empty, or whose sizes are zero, are rejected
+    // without computing any size from their lengths.
+    let bootstrap_alterations: [fn(&mut RawBootstrapKey); 5] = [
+        |key| {
+            key.tensor.pop();
+        },
+        |key| key.tensor.push(Complex64::new(0., 0.)),
+        |key| key.tensor.clear(),
+        |key| key.poly_size = 0,
+        |key| key.level_count = 0,
+    ];
+    let keyswitch_alterations: [fn(&mut RawKeyswitchKey); 5] = [
+        |key| {
+            key.tensor.pop();
+        },
+        |key| key.tensor.push(0),
+        |key| key.tensor.clear(),
+        |key| key.lwe_size = 0,
+        |key| key.level_count = 0,
+    ];
+    let mut altered_materials = Vec::new();
+    for alteration in bootstrap_alterations.iter() {
+        let mut altered: PublicMaterial<Vec<Complex64>> = bincode::deserialize(&material).unwrap();
+        let mut key: RawBootstrapKey = transcode(&altered.bootstrap_key);
+        alteration(&mut key);
+        altered.bootstrap_key = transcode(&key);
+        altered_materials.push(bincode::serialize(&altered).unwrap());
+    }
+    for alteration in keyswitch_alterations.iter() {
+        let mut altered: PublicMaterial<Vec<Complex64>> = bincode::deserialize(&material).unwrap();
+        let mut key: RawKeyswitchKey = transcode(&altered.keyswitch_key);
+        alteration(&mut key);
+        altered.keyswitch_key = transcode(&key);
+        altered_materials.push(bincode::serialize(&altered).unwrap());
+    }
+    for altered in altered_materials.iter() {
+        assert!(matches!(
+            EvaluationEngine::load_public_material(altered.as_slice()),
+            Err(Error::DimensionMismatch { .. })
+        ));
+    }
+}
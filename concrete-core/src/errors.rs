@@ -0,0 +1,193 @@
+//! Errors returned by the fallible operations of the library.
+//!
+//! The different modules of the library define their own error types, which carry the details
+//! specific to the failing operation (see [`SaveError`] and [`LoadError`] for instance). Each of
+//! those types can be converted into the crate-level [`Error`] type, which allows to handle all
+//! the errors of the library in a single place:
+//!
+//! ```rust
+//! use concrete_core::math::tensor::Tensor;
+//! use concrete_core::Error;
+//!
+//! fn load_and_check(path: &str, size: usize) -> Result<Tensor<Vec<u8>>, Error> {
+//!     let tensor: Tensor<Vec<u8>> = Tensor::load_from_file(path)?;
+//!     if tensor.len() != size {
+//!         return Err(Error::DimensionMismatch {
+//!             parameter: "tensor length",
+//!             expected: size,
+//!             found: tensor.len(),
+//!         });
+//!     }
+//!     Ok(tensor)
+//! }
+//!
+//! // The error composes with any error handling relying on `std::error::Error`.
+//! fn run() -> Result<(), Box<dyn std::error::Error>> {
+//!     load_and_check("/this/file/does/not/exist", 10)?;
+//!     Ok(())
+//! }
+//! assert!(run().is_err());
+//! ```
+use std::fmt;
+
+use crate::math::polynomial::InvalidSizeError;
+#[cfg(feature = "npy")]
+use crate::math::tensor::NpyError;
+use crate::math::tensor::{BinaryError, CastError, LoadError, SaveError};
+use crate::math::DimensionError;
+
+/// The error type of the library.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::Error;
+/// let error = Error::DimensionMismatch {
+///     parameter: "lwe dimension",
+///     expected: 630,
+///     found: 512,
+/// };
+/// assert_eq!(
+///     error.to_string(),
+///     "Mismatch on lwe dimension: expected 630, found 512."
+/// );
+/// let error = Error::InvalidParameter {
+///     parameter: "decomposition base log",
+///     reason: "must be smaller than the integer precision".into(),
+/// };
+/// assert_eq!(
+///     error.to_string(),
+///     "Invalid value for decomposition base log: must be smaller than the integer precision."
+/// );
+/// let error = Error::MissingParameter {
+///     parameter: "noise",
+/// };
+/// assert_eq!(error.to_string(), "Missing value for noise.");
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// Two objects were used together, while having incompatible dimensions.
+    DimensionMismatch {
+        /// The name of the dimension.
+        parameter: &'static str,
+        /// The expected value of the dimension.
+        expected: usize,
+        /// The value of the dimension which was found.
+        found: usize,
+    },
+    /// A parameter was given an invalid value.
+    InvalidParameter {
+        /// The name of the parameter.
+        parameter: &'static str,
+        /// A description of the constraint that was not met.
+        reason: String,
+    },
+    /// A required parameter was not provided.
+    MissingParameter {
+        /// The name of the parameter.
+        parameter: &'static str,
+    },
+    /// An index was out of the range of a dimension.
+    OutOfRange(DimensionError),
+    /// A polynomial size was not valid for an operation.
+    InvalidSize(InvalidSizeError),
+    /// An error occurred when saving an object to a file.
+    Save(SaveError),
+    /// An error occurred when loading an object from a file.
+    Load(LoadError),
+    /// An error occurred when writing or reading an object in the binary format.
+    Binary(BinaryError),
+    /// Bytes could not be viewed as the elements of a tensor.
+    Cast(CastError),
+    /// An error occurred when writing or reading an object in the npy format.
+    #[cfg(feature = "npy")]
+    Npy(NpyError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DimensionMismatch {
+                parameter,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Mismatch on {}: expected {}, found {}.",
+                parameter, expected, found
+            ),
+            Self::InvalidParameter { parameter, reason } => {
+                write!(f, "Invalid value for {}: {}.", parameter, reason)
+            }
+            Self::MissingParameter { parameter } => write!(f, "Missing value for {}.", parameter),
+            Self::OutOfRange(error) => write!(f, "Out of range: {}.", error),
+            Self::InvalidSize(error) => write!(f, "Invalid size: {}.", error),
+            Self::Save(error) => write!(f, "Failed to save: {}", error),
+            Self::Load(error) => write!(f, "Failed to load: {}", error),
+            Self::Binary(error) => write!(f, "Failed to process binary tensor: {}", error),
+            Self::Cast(error) => write!(f, "Failed to cast bytes: {}.", error),
+            #[cfg(feature = "npy")]
+            Self::Npy(error) => write!(f, "Failed to process npy file: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::OutOfRange(error) => Some(error),
+            Self::InvalidSize(error) => Some(error),
+            Self::Save(error) => Some(error),
+            Self::Load(error) => Some(error),
+            Self::Binary(error) => Some(error),
+            Self::Cast(error) => Some(error),
+            #[cfg(feature = "npy")]
+            Self::Npy(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<DimensionError> for Error {
+    fn from(error: DimensionError) -> Self {
+        Self::OutOfRange(error)
+    }
+}
+
+impl From<InvalidSizeError> for Error {
+    fn from(error: InvalidSizeError) -> Self {
+        Self::InvalidSize(error)
+    }
+}
+
+impl From<SaveError> for Error {
+    fn from(error: SaveError) -> Self {
+        Self::Save(error)
+    }
+}
+
+impl From<LoadError> for Error {
+    fn from(error: LoadError) -> Self {
+        Self::Load(error)
+    }
+}
+
+impl From<BinaryError> for Error {
+    fn from(error: BinaryError) -> Self {
+        Self::Binary(error)
+    }
+}
+
+impl From<CastError> for Error {
+    fn from(error: CastError) -> Self {
+        Self::Cast(error)
+    }
+}
+
+#[cfg(feature = "npy")]
+impl From<NpyError> for Error {
+    fn from(error: NpyError) -> Self {
+        Self::Npy(error)
+    }
+}
@@ -102,10 +102,17 @@ macro_rules! modular_distance {
 }
 
 pub mod crypto;
+#[cfg(feature = "engine")]
+pub mod engine;
+pub mod errors;
 pub mod math;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod numeric;
 pub mod utils;
 
+pub use errors::Error;
+
 #[doc(hidden)]
 #[cfg(test)]
 pub mod test_tools {
@@ -116,9 +123,9 @@ pub mod test_tools {
     };
     use crate::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
     use crate::math::dispersion::DispersionParameter;
-    use crate::math::polynomial::PolynomialSize;
+    use crate::math::polynomial::{Polynomial, PolynomialCount, PolynomialList, PolynomialSize};
     use crate::math::random;
-    use crate::math::random::random_uniform;
+    use crate::math::random::{random_uniform, RandomGenerable, Uniform};
     use crate::math::tensor::{AsRefSlice, AsRefTensor};
     use crate::numeric::UnsignedInteger;
 
@@ -293,4 +300,33 @@ pub mod test_tools {
     pub fn any_utorus<T: UnsignedTorus>() -> T {
         random_uniform()
     }
+
+    /// Returns a polynomial of `size` uniformly random coefficients.
+    pub fn random_polynomial<T: RandomGenerable<Uniform>>(
+        size: PolynomialSize,
+    ) -> Polynomial<Vec<T>> {
+        Polynomial::from_container(random::random_uniform_tensor(size.0).into_container())
+    }
+
+    /// Returns a list of `count` polynomials of `size` uniformly random coefficients.
+    pub fn random_polynomial_list<T: RandomGenerable<Uniform>>(
+        count: PolynomialCount,
+        size: PolynomialSize,
+    ) -> PolynomialList<Vec<T>> {
+        PolynomialList::from_container(
+            random::random_uniform_tensor(count.0 * size.0).into_container(),
+            size,
+        )
+    }
+
+    /// Returns a list of `count` polynomials of `size` uniformly random binary coefficients.
+    pub fn random_binary_polynomial_list(
+        count: PolynomialCount,
+        size: PolynomialSize,
+    ) -> PolynomialList<Vec<bool>> {
+        PolynomialList::from_container(
+            random::random_uniform_boolean_tensor(count.0 * size.0).into_container(),
+            size,
+        )
+    }
 }
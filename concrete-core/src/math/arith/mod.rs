@@ -0,0 +1,107 @@
+//! Elementary integer arithmetic.
+//!
+//! This module contains number theoretic helpers working on plain integers, such as the
+//! extended euclidean algorithm and the computation of modular inverses. They are meant for the
+//! generation of parameters (e.g. to find the inverse of a polynomial size modulo a prime), and
+//! for the algebraic checks of the tests.
+//!
+//! # Example
+//!
+//! ```rust
+//! use concrete_core::math::arith::{extended_gcd, mod_inverse};
+//! let (gcd, x, y) = extended_gcd(240, 46);
+//! assert_eq!(gcd, 2);
+//! assert_eq!(240 * x + 46 * y, 2);
+//! assert_eq!(mod_inverse(3, 11), Some(4));
+//! assert_eq!(mod_inverse(4, 10), None);
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+/// Computes the greatest common divisor of two integers, along with Bézout coefficients.
+///
+/// Returns a triplet $(g, x, y)$ such that $a \cdot x + b \cdot y = g$, where $g$ is the
+/// greatest common divisor of $a$ and $b$. The divisor is always non-negative, and is zero only
+/// if both inputs are zero. The coefficients are the ones computed by the extended euclidean
+/// algorithm, which satisfy $|x| \leq |b / g|$ and $|y| \leq |a / g|$ when both inputs are
+/// non-zero.
+///
+/// # Panics
+///
+/// The inputs must be larger than `i64::MIN`, whose absolute value can not be represented. This
+/// is only checked in debug builds, as any other overflow.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::math::arith::extended_gcd;
+/// assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+/// assert_eq!(extended_gcd(-12, 18), (6, 1, 1));
+/// assert_eq!(extended_gcd(0, -5), (5, 0, -1));
+/// assert_eq!(extended_gcd(0, 0), (0, 1, 0));
+/// ```
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        let next_r = old_r - quotient * r;
+        old_r = r;
+        r = next_r;
+        let next_x = old_x - quotient * x;
+        old_x = x;
+        x = next_x;
+        let next_y = old_y - quotient * y;
+        old_y = y;
+        y = next_y;
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Computes the inverse of an integer modulo `m`.
+///
+/// Returns the unique value $x \in [0, m[$ such that $a \cdot x \equiv 1 \pmod m$, or `None`
+/// if $a$ is not invertible modulo $m$, i.e. if $a$ and $m$ are not coprime, or if $m$ is zero.
+/// The input does not need to be reduced modulo $m$, and any modulus up to `u64::MAX` is
+/// supported.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::math::arith::mod_inverse;
+/// assert_eq!(mod_inverse(3, 11), Some(4));
+/// assert_eq!(mod_inverse(25, 11), Some(4));
+/// assert_eq!(mod_inverse(6, 9), None);
+/// assert_eq!(mod_inverse(5, 1), Some(0));
+/// assert_eq!(mod_inverse(5, 0), None);
+/// assert_eq!(mod_inverse(u64::MAX - 1, u64::MAX), Some(u64::MAX - 1));
+/// ```
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+    // The euclidean algorithm is carried over 128 bits integers, since the remainders and the
+    // coefficients may not fit in an i64 for moduli larger than 2^63.
+    let (mut old_r, mut r) = (m as i128, (a % m) as i128);
+    let (mut old_x, mut x) = (0i128, 1i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        let next_r = old_r - quotient * r;
+        old_r = r;
+        r = next_r;
+        let next_x = old_x - quotient * x;
+        old_x = x;
+        x = next_x;
+    }
+    if old_r != 1 {
+        // The modulus 1 is the only one for which zero is invertible.
+        return if m == 1 { Some(0) } else { None };
+    }
+    Some(old_x.rem_euclid(m as i128) as u64)
+}
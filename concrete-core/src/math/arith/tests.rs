@@ -0,0 +1,62 @@
+use crate::math::arith::{extended_gcd, mod_inverse};
+use crate::math::random::random_uniform;
+
+// A prime modulus larger than 2^63, for which the euclidean algorithm does not fit in an i64.
+const PRIME_64: u64 = u64::MAX - 58;
+
+#[test]
+fn test_extended_gcd_bezout() {
+    for _ in 0..10_000 {
+        // the inputs are kept on 62 bits, so that the products of the check do not overflow
+        let a = (random_uniform::<u64>() >> 2) as i64 - (1 << 61);
+        let b = (random_uniform::<u64>() >> 2) as i64 - (1 << 61);
+        let (gcd, x, y) = extended_gcd(a, b);
+        assert!(gcd > 0);
+        assert_eq!(a % gcd, 0);
+        assert_eq!(b % gcd, 0);
+        assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, gcd as i128);
+        assert!(x.abs() <= (b / gcd).abs().max(1));
+        assert!(y.abs() <= (a / gcd).abs().max(1));
+    }
+}
+
+#[test]
+fn test_extended_gcd_common_factor() {
+    for _ in 0..10_000 {
+        let factor = (random_uniform::<u64>() % 1000 + 1) as i64;
+        let a = (random_uniform::<u64>() % (1 << 40)) as i64 * factor;
+        let b = (random_uniform::<u64>() % (1 << 40)) as i64 * factor;
+        let (gcd, _, _) = extended_gcd(a, b);
+        assert_eq!(gcd % factor, 0);
+    }
+}
+
+#[test]
+fn test_mod_inverse_prime() {
+    for &modulus in [17, (1 << 62) - 57, PRIME_64].iter() {
+        for _ in 0..10_000 {
+            let a = random_uniform::<u64>();
+            match mod_inverse(a, modulus) {
+                None => assert_eq!(a % modulus, 0),
+                Some(inverse) => {
+                    assert!(inverse < modulus);
+                    let product = a as u128 * inverse as u128 % modulus as u128;
+                    assert_eq!(product, 1);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_mod_inverse_composite() {
+    // an element is invertible modulo 2^k iff it is odd
+    let modulus = 1 << 40;
+    for _ in 0..10_000 {
+        let a = random_uniform::<u64>();
+        match mod_inverse(a, modulus) {
+            None => assert_eq!(a % 2, 0),
+            Some(inverse) => assert_eq!(a.wrapping_mul(inverse) % modulus, 1),
+        }
+    }
+}
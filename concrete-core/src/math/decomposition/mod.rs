@@ -3,6 +3,8 @@
 #[cfg(test)]
 mod tests;
 
+use crate::math::polynomial::Polynomial;
+use crate::math::tensor::{AsMutTensor, AsRefTensor};
 use crate::numeric::{Numeric, SignedInteger, UnsignedInteger};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
@@ -111,3 +113,62 @@ implement!(u16);
 implement!(u32);
 implement!(u64);
 implement!(u128);
+
+/// Computes the signed decomposition of every coefficient of a polynomial.
+///
+/// The coefficients are first rounded to the closest multiple representable with `level_count`
+/// levels of base $2^B$. The output contains `level_count` polynomials, the $k$-th of which holds
+/// the $k$-th piece of the decomposition of each coefficient, in $[-B/2, B/2[$ and represented as
+/// an unsigned integer. The level $0$ is the one with the most significant bits. This is the
+/// decomposition performed on the GLWE ciphertext during an external product.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::math::decomposition::{
+///     decompose_polynomial, DecompositionBaseLog, DecompositionLevelCount,
+/// };
+/// use concrete_core::math::polynomial::Polynomial;
+/// let poly = Polynomial::from_container(vec![0x1234_0000u32, 0xfedc_0000]);
+/// let decomp = decompose_polynomial(&poly, DecompositionBaseLog(8), DecompositionLevelCount(2));
+/// assert_eq!(decomp.len(), 2);
+/// assert_eq!(
+///     decomp[0],
+///     Polynomial::from_container(vec![0x12, 0xffff_ffff])
+/// );
+/// assert_eq!(
+///     decomp[1],
+///     Polynomial::from_container(vec![0x34, 0xffff_ffdc])
+/// );
+/// ```
+pub fn decompose_polynomial<Scalar>(
+    poly: &Polynomial<Vec<Scalar>>,
+    base_log: DecompositionBaseLog,
+    level_count: DecompositionLevelCount,
+) -> Vec<Polynomial<Vec<Scalar>>>
+where
+    Scalar: UnsignedInteger + SignedDecomposable,
+{
+    let rounded: Vec<Scalar> = poly
+        .as_tensor()
+        .iter()
+        .map(|coef| coef.round_to_closest_multiple(base_log, level_count))
+        .collect();
+    let mut carries = vec![Scalar::ZERO; rounded.len()];
+    let mut output =
+        vec![Polynomial::allocate(Scalar::ZERO, poly.polynomial_size()); level_count.0];
+    // The carries are propagated from the least significant level to the most significant one.
+    for (level, decomp) in output.iter_mut().enumerate().rev() {
+        for (digit, (carry, value)) in decomp
+            .as_mut_tensor()
+            .iter_mut()
+            .zip(carries.iter_mut().zip(rounded.iter()))
+        {
+            let (res, next_carry) =
+                value.signed_decompose_one_level(*carry, base_log, DecompositionLevel(level));
+            *digit = res;
+            *carry = next_carry;
+        }
+    }
+    output
+}
@@ -2,6 +2,7 @@ use std::convert::TryInto;
 use std::fmt::Binary;
 
 use crate::crypto::UnsignedTorus;
+use crate::math::polynomial::PolynomialSize;
 use crate::numeric::CastInto;
 use crate::test_tools::{any_usize, any_utorus};
 
@@ -151,3 +152,40 @@ fn test_signed_decompose_one_level_u32() {
 fn test_signed_decompose_one_level_u64() {
     test_signed_decompose_one_level::<u64>();
 }
+
+fn test_decompose_polynomial<T: UnsignedTorus>() {
+    // This test decomposes a random polynomial, recomposes each coefficient from its pieces, and
+    // makes sure that they are equal to the rounded coefficients.
+    let base_log = DecompositionBaseLog((any_usize() % ((T::BITS / 4) - 1)) + 1);
+    let level_count = DecompositionLevelCount((any_usize() % 4) + 1);
+    let mut poly = Polynomial::allocate(T::ZERO, PolynomialSize(128));
+    crate::math::random::fill_with_random_uniform(&mut poly);
+
+    let decomp = decompose_polynomial(&poly, base_log, level_count);
+    assert_eq!(decomp.len(), level_count.0);
+
+    for (i, coef) in poly.as_tensor().iter().enumerate() {
+        let rounded = coef.round_to_closest_multiple(base_log, level_count);
+        let mut recomposed = T::ZERO;
+        for (level, decomp_poly) in decomp.iter().enumerate() {
+            let digit = *decomp_poly.as_tensor().get_element(i);
+            let half_base = T::ONE << (base_log.0 - 1);
+            let signed_digit = digit.into_signed();
+            assert!(signed_digit >= -half_base.into_signed());
+            assert!(signed_digit < half_base.into_signed());
+            recomposed = recomposed
+                .wrapping_add(digit.set_val_at_level(base_log, DecompositionLevel(level)));
+        }
+        assert_eq!(recomposed, rounded);
+    }
+}
+
+#[test]
+fn test_decompose_polynomial_u32() {
+    test_decompose_polynomial::<u32>();
+}
+
+#[test]
+fn test_decompose_polynomial_u64() {
+    test_decompose_polynomial::<u64>();
+}
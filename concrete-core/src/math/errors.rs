@@ -0,0 +1,60 @@
+use std::fmt;
+
+/// A type representing an error when an index is out of the range of a dimension.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::polynomial::{MonomialDegree, Polynomial};
+/// use concrete_core::math::DimensionError;
+/// let poly = Polynomial::from_container(vec![1u8, 2, 3]);
+/// let error = poly.try_get_monomial(MonomialDegree(3)).err().unwrap();
+/// assert_eq!(
+///     error,
+///     DimensionError {
+///         parameter: "monomial degree",
+///         index: 3,
+///         size: 3
+///     }
+/// );
+/// assert_eq!(
+///     error.to_string(),
+///     "The monomial degree 3 is out of range, the size is 3"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DimensionError {
+    /// The name of the indexed dimension.
+    pub parameter: &'static str,
+    /// The requested index.
+    pub index: usize,
+    /// The actual size of the dimension, i.e. one more than the largest valid index.
+    pub size: usize,
+}
+
+impl DimensionError {
+    // Checks that `index` is in the range of a dimension of size `size`.
+    pub(crate) fn check(parameter: &'static str, index: usize, size: usize) -> Result<(), Self> {
+        if index < size {
+            Ok(())
+        } else {
+            Err(DimensionError {
+                parameter,
+                index,
+                size,
+            })
+        }
+    }
+}
+
+impl fmt::Display for DimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The {} {} is out of range, the size is {}",
+            self.parameter, self.index, self.size
+        )
+    }
+}
+
+impl std::error::Error for DimensionError {}
@@ -0,0 +1,77 @@
+use fftw::array::AlignedVec;
+
+use crate::math::polynomial::PolynomialSize;
+use crate::math::tensor::MemoryFootprint;
+
+use super::{Complex64, FourierPolynomial};
+
+/// The fourier polynomials used as temporaries to multiply polynomials of a given size.
+///
+/// A set of buffers is allocated once, and borrowed by
+/// [`Fft::polynomial_mul_assign`](super::Fft::polynomial_mul_assign) or by
+/// [`Polynomial::fill_with_fast_mul_buffered`] for every product of polynomials of this size, so
+/// that no fourier polynomial is allocated inside the loops computing many products.
+///
+/// [`Polynomial::fill_with_fast_mul_buffered`]:
+/// crate::math::polynomial::Polynomial::fill_with_fast_mul_buffered
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::fft::{Fft, FftBuffers};
+/// use concrete_core::math::polynomial::{MonomialDegree, Polynomial, PolynomialSize};
+/// let mut fft = Fft::new(PolynomialSize(256));
+/// let mut buffers = FftBuffers::for_size(PolynomialSize(256));
+/// let mut poly = Polynomial::allocate(0u64, PolynomialSize(256));
+/// *poly.get_mut_monomial(MonomialDegree(0)).get_mut_coefficient() = 1;
+/// let mut monomial = Polynomial::allocate(0u64, PolynomialSize(256));
+/// *monomial.get_mut_monomial(MonomialDegree(1)).get_mut_coefficient() = 1;
+/// for _ in 0..10 {
+///     fft.polynomial_mul_assign(&mut poly, &monomial, &mut buffers);
+/// }
+/// assert_eq!(*poly.get_monomial(MonomialDegree(10)).get_coefficient(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FftBuffers {
+    pub(super) fourier_lhs: FourierPolynomial<AlignedVec<Complex64>>,
+    pub(super) fourier_rhs: FourierPolynomial<AlignedVec<Complex64>>,
+    pub(super) fourier_product: FourierPolynomial<AlignedVec<Complex64>>,
+}
+
+// The aligned vectors of the buffers are uniquely owned allocations, so the buffers can be moved
+// to the worker threads along with their transformers.
+unsafe impl Send for FftBuffers {}
+unsafe impl Sync for FftBuffers {}
+
+impl FftBuffers {
+    /// Allocates the buffers needed to multiply polynomials of a given size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::FftBuffers;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let buffers = FftBuffers::for_size(PolynomialSize(1024));
+    /// assert_eq!(buffers.polynomial_size(), PolynomialSize(1024));
+    /// ```
+    pub fn for_size(size: PolynomialSize) -> FftBuffers {
+        FftBuffers {
+            fourier_lhs: FourierPolynomial::allocate(Complex64::new(0., 0.), size),
+            fourier_rhs: FourierPolynomial::allocate(Complex64::new(0., 0.), size),
+            fourier_product: FourierPolynomial::allocate(Complex64::new(0., 0.), size),
+        }
+    }
+
+    /// Returns the size of the polynomials which can be multiplied with the buffers.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.fourier_product.polynomial_size()
+    }
+}
+
+impl MemoryFootprint for FftBuffers {
+    fn heap_size(&self) -> usize {
+        self.fourier_lhs.heap_size()
+            + self.fourier_rhs.heap_size()
+            + self.fourier_product.heap_size()
+    }
+}
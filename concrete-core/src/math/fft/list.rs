@@ -0,0 +1,126 @@
+use fftw::array::AlignedVec;
+
+use crate::math::polynomial::{PolynomialCount, PolynomialSize};
+use crate::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
+use crate::{ck_dim_div, tensor_memory_footprint, tensor_traits};
+
+use super::{Complex64, FourierPolynomial};
+
+/// A list of polynomials in the fourier domain.
+///
+/// This is the fourier counterpart of a
+/// [`PolynomialList`](crate::math::polynomial::PolynomialList), and is filled at once with
+/// [`Fft::forward_as_torus_list`](super::Fft::forward_as_torus_list).
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::fft::{Complex64, FourierPolynomialList};
+/// use concrete_core::math::polynomial::{PolynomialCount, PolynomialSize};
+/// let list = FourierPolynomialList::allocate(
+///     Complex64::new(0., 0.),
+///     PolynomialCount(2),
+///     PolynomialSize(256),
+/// );
+/// assert_eq!(list.polynomial_count(), PolynomialCount(2));
+/// assert_eq!(list.polynomial_size(), PolynomialSize(256));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FourierPolynomialList<Cont> {
+    tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+}
+
+tensor_traits!(FourierPolynomialList);
+tensor_memory_footprint!(FourierPolynomialList);
+
+impl FourierPolynomialList<AlignedVec<Complex64>> {
+    /// Allocates a new list of fourier polynomials, whose coefficients are all equal to `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::{Complex64, FourierPolynomialList};
+    /// use concrete_core::math::polynomial::{PolynomialCount, PolynomialSize};
+    /// let list = FourierPolynomialList::allocate(
+    ///     Complex64::new(1., 0.),
+    ///     PolynomialCount(3),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert!(list
+    ///     .polynomial_iter()
+    ///     .all(|poly| poly.coefficient_iter().all(|c| *c == Complex64::new(1., 0.))));
+    /// ```
+    pub fn allocate(value: Complex64, count: PolynomialCount, size: PolynomialSize) -> Self {
+        let mut tensor = Tensor::from_container(AlignedVec::new(count.0 * size.0));
+        tensor.fill_with_element(value);
+        FourierPolynomialList {
+            tensor,
+            poly_size: size,
+        }
+    }
+}
+
+impl<Cont> FourierPolynomialList<Cont> {
+    /// Creates a list of fourier polynomials from a container of values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::{Complex64, FourierPolynomialList};
+    /// use concrete_core::math::polynomial::{PolynomialCount, PolynomialSize};
+    /// let list = FourierPolynomialList::from_container(
+    ///     vec![Complex64::new(0., 0.); 512],
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(list.polynomial_count(), PolynomialCount(2));
+    /// ```
+    pub fn from_container(cont: Cont, poly_size: PolynomialSize) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        ck_dim_div!(cont.as_slice().len() => poly_size.0);
+        FourierPolynomialList {
+            tensor: Tensor::from_container(cont),
+            poly_size,
+        }
+    }
+
+    /// Returns the number of polynomials in the list.
+    pub fn polynomial_count(&self) -> PolynomialCount
+    where
+        Self: AsRefTensor,
+    {
+        PolynomialCount(self.as_tensor().len() / self.poly_size.0)
+    }
+
+    /// Returns the size of the polynomials in the list.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns an iterator over borrowed fourier polynomials contained in the list.
+    pub fn polynomial_iter(
+        &self,
+    ) -> impl Iterator<Item = FourierPolynomial<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        self.as_tensor()
+            .subtensor_iter(self.poly_size.0)
+            .map(|sub| FourierPolynomial::from_container(sub.into_container()))
+    }
+
+    /// Returns an iterator over mutably borrowed fourier polynomials contained in the list.
+    pub fn polynomial_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = FourierPolynomial<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+    {
+        let chunks_size = self.poly_size.0;
+        self.as_mut_tensor()
+            .subtensor_iter_mut(chunks_size)
+            .map(|sub| FourierPolynomial::from_container(sub.into_container()))
+    }
+}
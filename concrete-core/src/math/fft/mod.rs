@@ -1,19 +1,49 @@
 //! Fourier transform for polynomials.
 //!
 //! This module provides the tools to perform a fast product of two polynomials, reduced modulo
-//! $X^N+1$, using the fast fourier transform.
+//! $X^N+1$, using the fast fourier transform. The products can also be reduced modulo $X^N-1$, by
+//! creating the transformer with [`ReductionMode::Cyclic`](crate::math::polynomial::ReductionMode).
+//!
+//! The transformers can be sent to other threads, see the concurrency section of [`Fft`].
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(test)]
+mod twiddle_tables;
 mod twiddles;
+pub use twiddles::TwiddleFactors;
 use twiddles::*;
 
+mod plans;
+pub use plans::clear_fft_cache;
+use plans::FftPlans;
+
 mod polynomial;
 pub use polynomial::*;
 
+mod list;
+pub use list::*;
+
 mod transform;
 pub use transform::*;
 
+mod buffers;
+pub use buffers::*;
+
+mod view;
+pub use view::*;
+
+mod single;
+pub use single::*;
+
+mod serialization;
+
+mod noise;
+pub use noise::*;
+
 /// A complex number encoded over two `f64`.
 pub type Complex64 = fftw::types::c64;
+
+/// A complex number encoded over two `f32`.
+pub type Complex32 = fftw::types::c32;
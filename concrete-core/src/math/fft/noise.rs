@@ -0,0 +1,137 @@
+use crate::crypto::UnsignedTorus;
+use crate::math::decomposition::DecompositionBaseLog;
+use crate::math::dispersion::Variance;
+use crate::math::ntt::Ntt;
+use crate::math::polynomial::{Polynomial, PolynomialSize};
+use crate::math::random::fill_with_random_uniform;
+use crate::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::numeric::{CastFrom, CastInto};
+
+use super::{Complex64, Fft, FourierPolynomial};
+
+/// The number of coefficients of the products on which the error is measured.
+const MEASURED_COEFFICIENTS: usize = 1 << 16;
+
+/// The error of the products of polynomials computed in the fourier domain, as returned by
+/// [`estimate_roundtrip_noise`].
+///
+/// The errors are given on the torus, that is, as fractions of the modulus of the coefficients.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FftNoise {
+    /// The mean square of the error of a coefficient, measured on random products.
+    pub variance: Variance,
+    /// The largest error of a coefficient, measured on random products.
+    pub max_error: f64,
+    /// An upper bound of the error of every coefficient of a product.
+    pub bound: f64,
+}
+
+/// Estimates the error of the products of a polynomial of torus elements with a polynomial of
+/// small integers, computed with the transformer returned by [`Fft::new`].
+///
+/// These are the products computed by an external product, between the polynomials of a GGSW
+/// ciphertext and the polynomials of a GLWE ciphertext decomposed in base $2^B$, whose
+/// coefficients are integers between $-2^{B-1}$ and $2^{B-1}$, with $B$ the `base_log`.
+///
+/// The products are computed with a forward transform of both polynomials, an element-wise
+/// product, and a backward transform. The variance and the largest error are measured on the
+/// products of random polynomials, against the exact products computed with a number theoretic
+/// transform. The bound is computed for every input:
+/// $$
+/// \left(16\left(\log_2(N)+1\right)+1\right) N 2^{B-1} 2^{-53} + 2^{-(w+1)}
+/// $$
+/// The first term bounds the rounding errors of the `f64` computations: the error of a product
+/// of polynomials computed with the fourier transform is bounded by the product of the norms of
+/// the polynomials, times a factor which grows linearly with the number of stages of the
+/// transform, here taken with a margin. The second term is the rounding to the nearest torus
+/// element of $w$ bits.
+///
+/// # Noise budgets
+///
+/// The variance is a variance on the torus, as the ones of the
+/// [`DispersionParameter`](crate::math::dispersion::DispersionParameter) types used to encrypt,
+/// and can be converted with `get_modular_variance::<T>()`. Every output polynomial of an
+/// external product with $l$ levels and a GLWE dimension $k$ sums $(k+1)l$ products in the
+/// fourier domain. Assuming independent errors, the transforms add to the variance of every
+/// output coefficient about $(k+1)l$ times the estimated variance, which can be added to the
+/// variance of the external product computed exactly. The bound gives the worst case, and
+/// should stay well below the precision of the messages for the decryption to be correct.
+///
+/// # Panics
+///
+/// Panics if the size is not supported by [`Fft`], or if `base_log` is not between 1 and the
+/// number of bits of `T`.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::decomposition::DecompositionBaseLog;
+/// use concrete_core::math::dispersion::DispersionParameter;
+/// use concrete_core::math::fft::estimate_roundtrip_noise;
+/// use concrete_core::math::polynomial::PolynomialSize;
+/// let noise = estimate_roundtrip_noise::<u64>(PolynomialSize(1024), DecompositionBaseLog(8));
+/// assert!(noise.max_error <= noise.bound);
+/// assert!(noise.variance.get_log_standard_dev() < -35.);
+/// ```
+pub fn estimate_roundtrip_noise<T>(size: PolynomialSize, base_log: DecompositionBaseLog) -> FftNoise
+where
+    T: UnsignedTorus + CastInto<u64> + CastFrom<u64>,
+{
+    assert!(
+        base_log.0 >= 1 && base_log.0 <= T::BITS,
+        "The base log {} is not between 1 and {}.",
+        base_log.0,
+        T::BITS
+    );
+    let mut fft = Fft::new(size);
+    let ntt = Ntt::new(size);
+    let mut torus_poly = Polynomial::allocate(T::ZERO, size);
+    let mut integer_poly = Polynomial::allocate(T::ZERO, size);
+    let mut product = Polynomial::allocate(T::ZERO, size);
+    let mut exact_product = Polynomial::allocate(T::ZERO, size);
+    let mut fourier_torus = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+    let mut fourier_integer = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+    let mut fourier_product = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+
+    let products = (MEASURED_COEFFICIENTS / size.0).max(1);
+    let mut sum_of_squares = 0.;
+    let mut max_error = 0f64;
+    for _ in 0..products {
+        fill_with_random_uniform(&mut torus_poly);
+        fill_with_random_uniform(&mut integer_poly);
+        integer_poly.as_mut_tensor().update_with(|x| {
+            *x = (*x >> (T::BITS - base_log.0)).wrapping_sub(T::ONE << (base_log.0 - 1))
+        });
+
+        fft.forward_as_torus(&mut fourier_torus, &torus_poly);
+        fft.forward_as_integer(&mut fourier_integer, &integer_poly);
+        fourier_product
+            .as_mut_tensor()
+            .fill_with_element(Complex64::new(0., 0.));
+        fourier_product.update_with_multiply_accumulate(&fourier_torus, &fourier_integer);
+        product.as_mut_tensor().fill_with_element(T::ZERO);
+        fft.add_backward_as_torus(&mut product, &mut fourier_product);
+        exact_product.fill_with_ntt_mul(&torus_poly, &integer_poly, &ntt);
+
+        for (coef, exact) in product
+            .as_tensor()
+            .iter()
+            .zip(exact_product.as_tensor().iter())
+        {
+            let error: f64 = coef.wrapping_sub(*exact).into_signed().cast_into();
+            let error = error * 2f64.powi(-(T::BITS as i32));
+            sum_of_squares += error * error;
+            max_error = max_error.max(error.abs());
+        }
+    }
+
+    let log_size = (size.0 as f64).log2();
+    let norms = size.0 as f64 * 2f64.powi(base_log.0 as i32 - 1);
+    let float_rounding = (16. * (log_size + 1.) + 1.) * norms * 2f64.powi(-53);
+    let torus_rounding = 2f64.powi(-(T::BITS as i32 + 1));
+    FftNoise {
+        variance: Variance::from_variance(sum_of_squares / (products * size.0) as f64),
+        max_error,
+        bound: float_rounding + torus_rounding,
+    }
+}
@@ -0,0 +1,102 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use fftw::plan::*;
+use fftw::types::{Flag, Sign};
+
+use crate::math::polynomial::PolynomialSize;
+
+thread_local! {
+    static CACHE: RefCell<HashMap<usize, Arc<FftPlans>>> = RefCell::new(HashMap::new());
+    static SINGLE_CACHE: RefCell<HashMap<usize, Arc<FftPlans<C2CPlan32>>>> =
+        RefCell::new(HashMap::new());
+    static CREATED_PLANS_COUNT: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The fftw plans of the forward and backward transforms of a given size.
+///
+/// The plans are shared by all the transformers of the same size created on a thread, hence the
+/// mutexes, as fftw needs an exclusive access to a plan to execute it. The transformers can be
+/// moved to other threads along with their plans. The `f64` plans of [`Fft`](super::Fft) and the
+/// `f32` plans of [`Fft32`](super::Fft32) are cached separately.
+pub(super) struct FftPlans<Plan = C2CPlan64> {
+    pub(super) forward: Mutex<Plan>,
+    pub(super) backward: Mutex<Plan>,
+}
+
+// The fftw crate creates and destroys the plans while holding its global lock, as the fftw
+// planner is not thread-safe. The plans are then executed with the new-array interface of fftw,
+// which is thread-safe, and the mutexes ensure that a plan is executed by a single thread at a
+// time. Hence the plans can be shared by transformers living on different threads. This only
+// holds for the fftw plans, so the implementations are restricted to the cached plan types.
+unsafe impl<Plan: CachedPlan> Send for FftPlans<Plan> {}
+unsafe impl<Plan: CachedPlan> Sync for FftPlans<Plan> {}
+
+/// The plans types which have a cache on every thread.
+pub(super) trait CachedPlan: C2CPlan + Sized + 'static {
+    /// Calls `f` with the cache of the plans of this type of the current thread.
+    fn with_cache<R>(f: impl FnOnce(&mut HashMap<usize, Arc<FftPlans<Self>>>) -> R) -> R;
+}
+
+impl CachedPlan for C2CPlan64 {
+    fn with_cache<R>(f: impl FnOnce(&mut HashMap<usize, Arc<FftPlans<Self>>>) -> R) -> R {
+        CACHE.with(|cache| f(&mut cache.borrow_mut()))
+    }
+}
+
+impl CachedPlan for C2CPlan32 {
+    fn with_cache<R>(f: impl FnOnce(&mut HashMap<usize, Arc<FftPlans<Self>>>) -> R) -> R {
+        SINGLE_CACHE.with(|cache| f(&mut cache.borrow_mut()))
+    }
+}
+
+impl<Plan: CachedPlan> FftPlans<Plan> {
+    /// Returns the plans of the given size, which are only created if they are not in the cache
+    /// of the current thread.
+    pub(super) fn get(size: PolynomialSize) -> Arc<FftPlans<Plan>> {
+        Plan::with_cache(|cache| {
+            cache
+                .entry(size.0)
+                .or_insert_with(|| Arc::new(FftPlans::new(size)))
+                .clone()
+        })
+    }
+
+    fn new(size: PolynomialSize) -> FftPlans<Plan> {
+        CREATED_PLANS_COUNT.with(|count| count.set(count.get() + 1));
+        FftPlans {
+            forward: Mutex::new(Plan::aligned(&[size.0], Sign::Forward, Flag::Measure).unwrap()),
+            backward: Mutex::new(Plan::aligned(&[size.0], Sign::Backward, Flag::Measure).unwrap()),
+        }
+    }
+}
+
+/// Empties the caches of fftw plans and twiddle factors of the current thread.
+///
+/// Creating the plans of a given size is expensive, so the first [`Fft`](super::Fft) or
+/// [`Fft32`](super::Fft32) of every size created on a thread stores its plans and twiddle factors
+/// in a cache, and the next ones reuse them. This function releases the plans and factors which
+/// are not used by a transformer anymore, the next transformers will compute them again.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::fft::{clear_fft_cache, Fft};
+/// use concrete_core::math::polynomial::PolynomialSize;
+/// let fft = Fft::new(PolynomialSize(256));
+/// clear_fft_cache();
+/// // The existing transformers keep their plans.
+/// assert_eq!(fft.polynomial_size(), PolynomialSize(256));
+/// ```
+pub fn clear_fft_cache() {
+    CACHE.with(|cache| cache.borrow_mut().clear());
+    SINGLE_CACHE.with(|cache| cache.borrow_mut().clear());
+    super::twiddles::clear_twisting_powers();
+}
+
+/// Returns the number of plans created on the current thread.
+#[cfg(test)]
+pub(super) fn created_plans_count() -> usize {
+    CREATED_PLANS_COUNT.with(|count| count.get())
+}
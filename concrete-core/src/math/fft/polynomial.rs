@@ -1,10 +1,10 @@
-use fftw::array::AlignedVec;
+use fftw::array::{AlignedAllocable, AlignedVec};
 
 use crate::math::polynomial::PolynomialSize;
 use crate::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
-use crate::{ck_dim_eq, tensor_traits, zip, zip_args};
+use crate::{ck_dim_eq, tensor_memory_footprint, tensor_traits, zip, zip_args};
 
-use super::Complex64;
+use super::{Complex32, Complex64};
 
 /// A polynomial in the fourier domain.
 ///
@@ -15,10 +15,17 @@ pub struct FourierPolynomial<Cont> {
 }
 
 tensor_traits!(FourierPolynomial);
+tensor_memory_footprint!(FourierPolynomial);
 
-impl FourierPolynomial<AlignedVec<Complex64>> {
+impl<Coef> FourierPolynomial<AlignedVec<Coef>>
+where
+    Coef: AlignedAllocable,
+{
     /// Allocates a new empty fourier polynomial.
     ///
+    /// The coefficients are either [`Complex64`] values, or [`Complex32`] values for the
+    /// polynomials transformed with [`Fft32`](super::Fft32).
+    ///
     /// # Example
     ///
     /// ```
@@ -30,7 +37,7 @@ impl FourierPolynomial<AlignedVec<Complex64>> {
     /// );
     /// assert_eq!(fourier_poly.polynomial_size(), PolynomialSize(128));
     /// ```
-    pub fn allocate(value: Complex64, coef_count: PolynomialSize) -> Self {
+    pub fn allocate(value: Coef, coef_count: PolynomialSize) -> Self {
         let mut tensor = Tensor::from_container(AlignedVec::new(coef_count.0));
         tensor.fill_with_element(value);
         FourierPolynomial { tensor }
@@ -138,6 +145,13 @@ impl<Cont> FourierPolynomial<Cont> {
     /// self\[i\] = self\[i\] + poly_1\[i\] * poly_2\[i\]
     /// $$
     ///
+    /// # Note
+    ///
+    /// When the processor supports avx2, which is detected at runtime, the products are computed
+    /// two coefficients at a time with simd instructions. The instructions do not fuse the
+    /// multiplications and additions, and the results agree with the ones of the scalar code
+    /// within 1 ulp.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -171,11 +185,59 @@ impl<Cont> FourierPolynomial<Cont> {
         FourierPolynomial<PolyCont2>: AsRefTensor<Element = Complex64>,
     {
         ck_dim_eq!(self.polynomial_size().0 => poly_1.polynomial_size().0, poly_2.polynomial_size().0);
-        #[cfg(not(target_feature = "avx2"))]
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("avx2") {
+                // Safe because the processor supports avx2.
+                unsafe { avx2_uhwap(self, poly_1, poly_2) };
+                return;
+            }
+        }
         regular_uhwap(self, poly_1, poly_2);
-        #[cfg(target_feature = "avx2")]
-        avx2_uhwap(self, poly_1, poly_2);
     }
+
+    /// Adds the result of the element-wise product of two polynomials of `f32` coefficients to
+    /// the $(self.len()/2)$ first elements of the current polynomial, which are the ones used by
+    /// [`Fft32`](super::Fft32):
+    /// $$
+    /// self\[i\] = self\[i\] + poly_1\[i\] * poly_2\[i\]
+    /// $$
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::math::fft::{Complex32, FourierPolynomial};
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// use concrete_core::math::tensor::{AsRefSlice, AsRefTensor};
+    /// let mut fpoly1 = FourierPolynomial::allocate(Complex32::new(1., 2.), PolynomialSize(128));
+    /// let fpoly2 = FourierPolynomial::allocate(Complex32::new(3., 4.), PolynomialSize(128));
+    /// let fpoly3 = FourierPolynomial::allocate(Complex32::new(5., 6.), PolynomialSize(128));
+    /// fpoly1.update_with_f32_multiply_accumulate(&fpoly2, &fpoly3);
+    /// let half = fpoly1.polynomial_size().0 / 2;
+    /// let coefs = fpoly1.as_tensor().as_slice();
+    /// assert!(coefs[..half].iter().all(|a| *a == Complex32::new(-8., 40.)));
+    /// assert!(coefs[half..].iter().all(|a| *a == Complex32::new(1., 2.)));
+    /// ```
+    pub fn update_with_f32_multiply_accumulate<PolyCont1, PolyCont2>(
+        &mut self,
+        poly_1: &FourierPolynomial<PolyCont1>,
+        poly_2: &FourierPolynomial<PolyCont2>,
+    ) where
+        Self: AsMutTensor<Element = Complex32>,
+        FourierPolynomial<PolyCont1>: AsRefTensor<Element = Complex32>,
+        FourierPolynomial<PolyCont2>: AsRefTensor<Element = Complex32>,
+    {
+        ck_dim_eq!(self.polynomial_size().0 => poly_1.polynomial_size().0, poly_2.polynomial_size().0);
+        let half = self.polynomial_size().0 / 2;
+        for zip_args!(res, coef_1, coef_2) in zip!(
+            self.as_mut_tensor().iter_mut().take(half),
+            poly_1.as_tensor().iter().take(half),
+            poly_2.as_tensor().iter().take(half)
+        ) {
+            *res += coef_1 * coef_2;
+        }
+    }
+
     /// Adds the result of the element-wise product of `poly_1` with `poly_2`, and the result
     /// of the element-wise product of `poly_3` with `poly_4`, to $(self.len()/2)+2$ elements of
     /// the current polynomial:
@@ -233,10 +295,15 @@ impl<Cont> FourierPolynomial<Cont> {
             poly_3.polynomial_size().0,
             poly_4.polynomial_size().0
         );
-        #[cfg(not(target_feature = "avx2"))]
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("avx2") {
+                // Safe because the processor supports avx2.
+                unsafe { avx2_uhwatp(self, poly_1, poly_2, poly_3, poly_4) };
+                return;
+            }
+        }
         regular_uhwatp(self, poly_1, poly_2, poly_3, poly_4);
-        #[cfg(target_feature = "avx2")]
-        avx2_uhwatp(self, poly_1, poly_2, poly_3, poly_4);
     }
 
     /// Updates two polynomials with the following operation:
@@ -314,18 +381,24 @@ impl<Cont> FourierPolynomial<Cont> {
             poly_a_2.polynomial_size().0,
             poly_c_2.polynomial_size().0
         );
-        #[cfg(not(target_feature = "avx2"))]
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            if is_x86_feature_detected!("avx2") {
+                // Safe because the processor supports avx2.
+                unsafe {
+                    avx2_uthwatp(
+                        result_1, result_2, poly_a_1, poly_a_2, poly_b, poly_c_1, poly_c_2, poly_d,
+                    )
+                };
+                return;
+            }
+        }
         regular_uthwatp(
             result_1, result_2, poly_a_1, poly_a_2, poly_b, poly_c_1, poly_c_2, poly_d,
         );
-        #[cfg(target_feature = "avx2")]
-        avx2_uthwatp(
-            result_1, result_2, poly_a_1, poly_a_2, poly_b, poly_c_1, poly_c_2, poly_d,
-        );
     }
 }
 
-#[allow(unused)]
 fn regular_uhwap<C1, C2, C3>(
     res: &mut FourierPolynomial<C1>,
     poly_1: &FourierPolynomial<C2>,
@@ -345,8 +418,9 @@ fn regular_uhwap<C1, C2, C3>(
     }
 }
 
-#[allow(unused)]
-fn avx2_uhwap<C1, C2, C3>(
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_uhwap<C1, C2, C3>(
     res: &mut FourierPolynomial<C1>,
     poly_1: &FourierPolynomial<C2>,
     poly_2: &FourierPolynomial<C3>,
@@ -387,13 +461,13 @@ fn avx2_uhwap<C1, C2, C3>(
             vec_b = _mm256_permute_pd(vec_b, 0x5);
             vec_b = _mm256_mul_pd(vec_b, neg);
             let vec_ab_bis = _mm256_mul_pd(vec_a, vec_b);
-            let resref: *mut __m256d = coeff_tmp_ref.as_mut_ptr() as *mut __m256d;
-            *resref = _mm256_add_pd(_mm256_hsub_pd(vec_ab, vec_ab_bis), *resref);
+            let resref = coeff_tmp_ref.as_mut_ptr() as *mut f64;
+            let sum = _mm256_add_pd(_mm256_hsub_pd(vec_ab, vec_ab_bis), _mm256_loadu_pd(resref));
+            _mm256_storeu_pd(resref, sum);
         }
     }
 }
 
-#[allow(unused)]
 fn regular_uhwatp<C1, C2, C3, C4, C5>(
     res: &mut FourierPolynomial<C1>,
     poly_1: &FourierPolynomial<C2>,
@@ -419,8 +493,9 @@ fn regular_uhwatp<C1, C2, C3, C4, C5>(
     }
 }
 
-#[allow(unused)]
-fn avx2_uhwatp<C1, C2, C3, C4, C5>(
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_uhwatp<C1, C2, C3, C4, C5>(
     res: &mut FourierPolynomial<C1>,
     poly_1: &FourierPolynomial<C2>,
     poly_2: &FourierPolynomial<C3>,
@@ -467,19 +542,20 @@ fn avx2_uhwatp<C1, C2, C3, C4, C5>(
             let vec_ab_bis = _mm256_mul_pd(vec_a, vec_b);
             let vec_cd_bis = _mm256_mul_pd(vec_c, vec_d);
 
-            let res_ref_avx: *mut __m256d = res_ref.as_mut_ptr() as *mut __m256d;
-            *res_ref_avx = _mm256_add_pd(
+            let res_ref_avx = res_ref.as_mut_ptr() as *mut f64;
+            let sum = _mm256_add_pd(
                 _mm256_add_pd(
                     _mm256_hsub_pd(vec_ab, vec_ab_bis),
                     _mm256_hsub_pd(vec_cd, vec_cd_bis),
                 ),
-                *res_ref_avx,
+                _mm256_loadu_pd(res_ref_avx),
             );
+            _mm256_storeu_pd(res_ref_avx, sum);
         }
     }
 }
 
-#[allow(unused, clippy::too_many_arguments)]
+#[allow(clippy::too_many_arguments)]
 fn regular_uthwatp<C1, C2, C3, C4, C5, C6, C7, C8>(
     result_1: &mut FourierPolynomial<C1>,
     result_2: &mut FourierPolynomial<C2>,
@@ -516,8 +592,10 @@ fn regular_uthwatp<C1, C2, C3, C4, C5, C6, C7, C8>(
     }
 }
 
-#[allow(unused, clippy::too_many_arguments)]
-fn avx2_uthwatp<C1, C2, C3, C4, C5, C6, C7, C8>(
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2")]
+#[allow(clippy::too_many_arguments)]
+unsafe fn avx2_uthwatp<C1, C2, C3, C4, C5, C6, C7, C8>(
     result_1: &mut FourierPolynomial<C1>,
     result_2: &mut FourierPolynomial<C2>,
     poly_a_1: &FourierPolynomial<C3>,
@@ -615,23 +693,24 @@ fn avx2_uthwatp<C1, C2, C3, C4, C5, C6, C7, C8>(
             let vec_c1d_bis: __m256d = _mm256_mul_pd(vec_c1, vec_d);
             let vec_c2d_bis: __m256d = _mm256_mul_pd(vec_c2, vec_d);
 
-            let res_1_ref = res_1_i.as_mut_ptr() as *mut __m256d;
-            *res_1_ref = _mm256_add_pd(
+            let res_1_ref = res_1_i.as_mut_ptr() as *mut f64;
+            let sum_1 = _mm256_add_pd(
                 _mm256_add_pd(
                     _mm256_hsub_pd(vec_a1b, vec_a1b_bis),
                     _mm256_hsub_pd(vec_c1d, vec_c1d_bis),
                 ),
-                *res_1_ref,
+                _mm256_loadu_pd(res_1_ref),
             );
-            let res_2_ref = res_2_i.as_mut_ptr() as *mut __m256d;
-
-            *res_2_ref = _mm256_add_pd(
+            _mm256_storeu_pd(res_1_ref, sum_1);
+            let res_2_ref = res_2_i.as_mut_ptr() as *mut f64;
+            let sum_2 = _mm256_add_pd(
                 _mm256_add_pd(
                     _mm256_hsub_pd(vec_a2b, vec_a2b_bis),
                     _mm256_hsub_pd(vec_c2d, vec_c2d_bis),
                 ),
-                *res_2_ref,
+                _mm256_loadu_pd(res_2_ref),
             );
+            _mm256_storeu_pd(res_2_ref, sum_2);
         }
     }
 }
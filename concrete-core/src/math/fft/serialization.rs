@@ -0,0 +1,101 @@
+use fftw::array::{AlignedAllocable, AlignedVec};
+use serde::de::Error as DeserializationError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::math::polynomial::PolynomialSize;
+use crate::math::tensor::{AsRefSlice, AsRefTensor};
+
+use super::{FourierPolynomial, FourierPolynomialList};
+
+// The fourier polynomials are serialized as their polynomial size, followed by the sequence of
+// their coefficients. Each coefficient is written as the pair of its real and imaginary parts, so
+// that the floats are restored bit for bit, in the byte order chosen by the serde format.
+
+#[derive(Serialize)]
+struct SerializedFourierPolynomials<'a, Coef> {
+    poly_size: PolynomialSize,
+    coefficients: &'a [Coef],
+}
+
+#[derive(Deserialize)]
+struct DeserializedFourierPolynomials<Coef> {
+    poly_size: PolynomialSize,
+    coefficients: Vec<Coef>,
+}
+
+impl<Coef> DeserializedFourierPolynomials<Coef>
+where
+    Coef: AlignedAllocable,
+{
+    fn into_aligned_vec(self) -> AlignedVec<Coef> {
+        let mut aligned = AlignedVec::new(self.coefficients.len());
+        aligned.as_slice_mut().copy_from_slice(&self.coefficients);
+        aligned
+    }
+}
+
+impl<Cont> Serialize for FourierPolynomial<Cont>
+where
+    FourierPolynomial<Cont>: AsRefTensor,
+    <FourierPolynomial<Cont> as AsRefTensor>::Element: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedFourierPolynomials {
+            poly_size: self.polynomial_size(),
+            coefficients: self.as_tensor().as_slice(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, Coef> Deserialize<'de> for FourierPolynomial<AlignedVec<Coef>>
+where
+    Coef: AlignedAllocable + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let deserialized = DeserializedFourierPolynomials::<Coef>::deserialize(deserializer)?;
+        if deserialized.coefficients.len() != deserialized.poly_size.0 {
+            return Err(D::Error::invalid_length(
+                deserialized.coefficients.len(),
+                &"as many coefficients as the polynomial size",
+            ));
+        }
+        Ok(FourierPolynomial::from_container(
+            deserialized.into_aligned_vec(),
+        ))
+    }
+}
+
+impl<Cont> Serialize for FourierPolynomialList<Cont>
+where
+    FourierPolynomialList<Cont>: AsRefTensor,
+    <FourierPolynomialList<Cont> as AsRefTensor>::Element: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedFourierPolynomials {
+            poly_size: self.polynomial_size(),
+            coefficients: self.as_tensor().as_slice(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, Coef> Deserialize<'de> for FourierPolynomialList<AlignedVec<Coef>>
+where
+    Coef: AlignedAllocable + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let deserialized = DeserializedFourierPolynomials::<Coef>::deserialize(deserializer)?;
+        let poly_size = deserialized.poly_size;
+        if poly_size.0 == 0 || deserialized.coefficients.len() % poly_size.0 != 0 {
+            return Err(D::Error::invalid_length(
+                deserialized.coefficients.len(),
+                &"a multiple of the polynomial size",
+            ));
+        }
+        Ok(FourierPolynomialList::from_container(
+            deserialized.into_aligned_vec(),
+            poly_size,
+        ))
+    }
+}
@@ -0,0 +1,304 @@
+use std::sync::Arc;
+
+use fftw::array::AlignedVec;
+use fftw::plan::*;
+
+use crate::math::polynomial::{InvalidSizeError, Polynomial, PolynomialSize};
+use crate::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, MemoryFootprint};
+use crate::math::torus::{FromTorus, IntoTorus};
+use crate::numeric::{CastInto, SignedInteger, UnsignedInteger};
+use crate::{ck_dim_eq, zip, zip_args};
+
+use super::transform::validated_size;
+use super::{Complex32, FftPlans, FourierPolynomial, SingleCorrectors};
+
+/// The smallest polynomial size supported by the single precision transformer.
+const MIN_SIZE: usize = 256;
+
+/// The largest polynomial size supported by the single precision transformer.
+///
+/// Unlike the one of [`Fft`](super::Fft), this bound comes from the precision: the rounding error
+/// of a product grows linearly with the size (see the precision section of [`Fft32`]). At 16384,
+/// the product of a torus polynomial by a binary polynomial already carries an error of about
+/// $2^{-11}$ on the torus, which leaves no room for the messages and the noise beyond this size.
+const MAX_SIZE: usize = 16384;
+
+/// A fast fourier transformer computing with `f32`, for the polynomials of `u32` coefficients.
+///
+/// This transformer works as the negacyclic transformers returned by [`Fft::new`](super::Fft),
+/// by folding the polynomials of size $N$ in complex polynomials of size $N/2$, but its fourier
+/// polynomials hold [`Complex32`] values. Their products are computed with
+/// [`FourierPolynomial::update_with_f32_multiply_accumulate`]. As the transforms are mostly
+/// bound by the memory bandwidth, halving the size of the fourier polynomials makes them faster,
+/// at the price of a much larger rounding error.
+///
+/// # Precision
+///
+/// The mantissa of a `f32` holds 24 bits, which is less than the 32 bits of a torus coefficient.
+/// When a polynomial of torus coefficients is multiplied by a polynomial of integer coefficients
+/// bounded by $2^{\beta-1}$, as in the external product of a decomposition with a base of
+/// $2^\beta$, the rounding error of every coefficient of the product stays below about
+/// $2^{\beta-1} \cdot N \cdot 2^{-25}$ on the torus. For instance, with $N=1024$ and $\beta=7$,
+/// the error is around $2^{-9}$, compared to $2^{-38}$ with [`Fft`](super::Fft).
+///
+/// An external product sums $(k+1) \cdot \ell$ such products, whose errors add up, and a
+/// bootstrap performs as many external products as the dimension of the input LWE ciphertext.
+/// The precision of this transformer is hence only safe for small polynomial sizes, decomposition
+/// bases and level counts, for which the accumulated error stays well below the noise tolerated
+/// by the encoding of the messages. When in doubt, use [`Fft`](super::Fft).
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::fft::{Complex32, Fft32, FourierPolynomial};
+/// use concrete_core::math::polynomial::{MonomialDegree, Polynomial, PolynomialSize};
+/// let size = PolynomialSize(256);
+/// let mut fft = Fft32::new(size);
+/// let mut torus = Polynomial::allocate(0u32, size);
+/// *torus.get_mut_monomial(MonomialDegree(255)).get_mut_coefficient() = 1 << 30;
+/// let mut integer = Polynomial::allocate(0u32, size);
+/// *integer.get_mut_monomial(MonomialDegree(1)).get_mut_coefficient() = 3;
+/// let mut fourier_torus = FourierPolynomial::allocate(Complex32::new(0., 0.), size);
+/// let mut fourier_integer = FourierPolynomial::allocate(Complex32::new(0., 0.), size);
+/// fft.forward_as_torus(&mut fourier_torus, &torus);
+/// fft.forward_as_integer(&mut fourier_integer, &integer);
+/// let mut fourier_product = FourierPolynomial::allocate(Complex32::new(0., 0.), size);
+/// fourier_product.update_with_f32_multiply_accumulate(&fourier_torus, &fourier_integer);
+/// let mut product = Polynomial::allocate(0u32, size);
+/// fft.add_backward_as_torus(&mut product, &mut fourier_product);
+///
+/// // 1/4 X^255 * 3X = -3/4 = 1/4 modulo X^256 + 1, up to the rounding error.
+/// let constant = *product.get_monomial(MonomialDegree(0)).get_coefficient();
+/// assert!((constant.wrapping_sub(1 << 30) as i32).abs() < 1 << 12);
+/// ```
+pub struct Fft32 {
+    plans: Arc<FftPlans<C2CPlan32>>,
+    correctors: SingleCorrectors,
+    poly_size: PolynomialSize,
+    temporary: FourierPolynomial<AlignedVec<Complex32>>,
+}
+
+// As for `Fft`, the temporary buffer is only accessed through a mutable borrow of the
+// transformer, and the plans are `Send` and `Sync`.
+unsafe impl Send for Fft32 {}
+unsafe impl Sync for Fft32 {}
+
+impl Fft32 {
+    /// Generates a new transformer for polynomials a given size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size is not supported, see [`Fft32::try_new`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::Fft32;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let fft = Fft32::new(PolynomialSize(256));
+    /// assert_eq!(fft.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn new(size: PolynomialSize) -> Fft32 {
+        Fft32::try_new(size).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Generates a new transformer for polynomials a given size, or returns an error if the size
+    /// is not supported.
+    ///
+    /// The size must be a power of two between 256 and 16384.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::Fft32;
+    /// use concrete_core::math::polynomial::{InvalidSizeError, PolynomialSize};
+    /// assert!(Fft32::try_new(PolynomialSize(2048)).is_ok());
+    /// assert!(matches!(
+    ///     Fft32::try_new(PolynomialSize(128)),
+    ///     Err(InvalidSizeError::TooSmall { size: 128, min: 256 })
+    /// ));
+    /// ```
+    pub fn try_new(size: PolynomialSize) -> Result<Fft32, InvalidSizeError> {
+        let size = validated_size(size, MIN_SIZE, MAX_SIZE)?;
+        let transform_size = PolynomialSize(size.0 / 2);
+        Ok(Fft32 {
+            plans: FftPlans::get(transform_size),
+            correctors: SingleCorrectors::new_folded(size.0),
+            poly_size: size,
+            temporary: FourierPolynomial::allocate(Complex32::new(0., 0.), transform_size),
+        })
+    }
+
+    /// Returns the size of the polynomials accepted by the transformer.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Performs the forward fourier transform of the `poly` polynomial, viewed as a polynomial of
+    /// torus coefficients, and stores the result in `fourier_poly`.
+    ///
+    /// See the [type-level](`Fft32`) documentation for an example.
+    pub fn forward_as_torus<OutCont, InCont>(
+        &mut self,
+        fourier_poly: &mut FourierPolynomial<OutCont>,
+        poly: &Polynomial<InCont>,
+    ) where
+        FourierPolynomial<OutCont>: AsMutTensor<Element = Complex32>,
+        Polynomial<InCont>: AsRefTensor<Element = u32>,
+    {
+        self.forward(fourier_poly, poly, |coef| {
+            let torus: f64 = coef.into_torus();
+            torus as f32
+        });
+    }
+
+    /// Performs the forward fourier transform of the `poly` polynomial, viewed as a polynomial of
+    /// signed integer coefficients, and stores the result in `fourier_poly`.
+    ///
+    /// See the [type-level](`Fft32`) documentation for an example.
+    pub fn forward_as_integer<OutCont, InCont>(
+        &mut self,
+        fourier_poly: &mut FourierPolynomial<OutCont>,
+        poly: &Polynomial<InCont>,
+    ) where
+        FourierPolynomial<OutCont>: AsMutTensor<Element = Complex32>,
+        Polynomial<InCont>: AsRefTensor<Element = u32>,
+    {
+        self.forward(fourier_poly, poly, |coef| {
+            let integer: f64 = coef.into_signed().cast_into();
+            integer as f32
+        });
+    }
+
+    /// Performs the backward fourier transform of the `fourier_poly` polynomial, viewed as a
+    /// polynomial of torus coefficients, and adds the result to `poly`.
+    ///
+    /// The content of `fourier_poly` is modified by the transform. See the
+    /// [type-level](`Fft32`) documentation for an example.
+    pub fn add_backward_as_torus<OutCont, InCont>(
+        &mut self,
+        poly: &mut Polynomial<OutCont>,
+        fourier_poly: &mut FourierPolynomial<InCont>,
+    ) where
+        Polynomial<OutCont>: AsMutTensor<Element = u32>,
+        FourierPolynomial<InCont>: AsMutTensor<Element = Complex32>,
+    {
+        self.add_backward(poly, fourier_poly, |value| u32::from_torus(value as f64));
+    }
+
+    /// Performs the backward fourier transform of the `fourier_poly` polynomial, viewed as a
+    /// polynomial of signed integer coefficients, and adds the result to `poly`.
+    ///
+    /// The content of `fourier_poly` is modified by the transform.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::{Complex32, Fft32, FourierPolynomial};
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialSize};
+    /// let size = PolynomialSize(256);
+    /// let mut fft = Fft32::new(size);
+    /// let coefs = (0..256).map(|i| (i as u32).wrapping_sub(128)).collect();
+    /// let poly = Polynomial::from_container(coefs);
+    /// let mut fourier_poly = FourierPolynomial::allocate(Complex32::new(0., 0.), size);
+    /// fft.forward_as_integer(&mut fourier_poly, &poly);
+    /// let mut output = Polynomial::allocate(0u32, size);
+    /// fft.add_backward_as_integer(&mut output, &mut fourier_poly);
+    /// assert_eq!(output, poly);
+    /// ```
+    pub fn add_backward_as_integer<OutCont, InCont>(
+        &mut self,
+        poly: &mut Polynomial<OutCont>,
+        fourier_poly: &mut FourierPolynomial<InCont>,
+    ) where
+        Polynomial<OutCont>: AsMutTensor<Element = u32>,
+        FourierPolynomial<InCont>: AsMutTensor<Element = Complex32>,
+    {
+        self.add_backward(poly, fourier_poly, |value| {
+            let signed: i32 = (value as f64).round().cast_into();
+            signed.into_unsigned()
+        });
+    }
+
+    fn forward<OutCont, InCont>(
+        &mut self,
+        fourier_poly: &mut FourierPolynomial<OutCont>,
+        poly: &Polynomial<InCont>,
+        convert: impl Fn(u32) -> f32,
+    ) where
+        FourierPolynomial<OutCont>: AsMutTensor<Element = Complex32>,
+        Polynomial<InCont>: AsRefTensor<Element = u32>,
+    {
+        #[cfg(feature = "metrics")]
+        let _timer = crate::metrics::FFT.start();
+        ck_dim_eq!(self.polynomial_size().0 => fourier_poly.polynomial_size().0, poly.polynomial_size().0);
+        let half = self.temporary.as_tensor().len();
+
+        let (first, second) = poly.as_tensor().as_slice().split_at(half);
+        for zip_args!(output, re, im, twist) in zip!(
+            self.temporary.as_mut_tensor().iter_mut(),
+            first.iter(),
+            second.iter(),
+            self.correctors.forward.as_tensor().iter()
+        ) {
+            *output = Complex32::new(convert(*re), convert(*im)) * twist;
+        }
+
+        self.plans
+            .forward
+            .lock()
+            .unwrap()
+            .c2c(
+                self.temporary.as_mut_tensor().as_mut_slice(),
+                &mut fourier_poly.as_mut_tensor().as_mut_slice()[..half],
+            )
+            .expect("forward: fft.c2c threw an error...");
+    }
+
+    fn add_backward<OutCont, InCont>(
+        &mut self,
+        poly: &mut Polynomial<OutCont>,
+        fourier_poly: &mut FourierPolynomial<InCont>,
+        convert: impl Fn(f32) -> u32,
+    ) where
+        Polynomial<OutCont>: AsMutTensor<Element = u32>,
+        FourierPolynomial<InCont>: AsMutTensor<Element = Complex32>,
+    {
+        #[cfg(feature = "metrics")]
+        let _timer = crate::metrics::FFT.start();
+        ck_dim_eq!(self.polynomial_size().0 => fourier_poly.polynomial_size().0, poly.polynomial_size().0);
+        let half = self.temporary.as_tensor().len();
+
+        self.plans
+            .backward
+            .lock()
+            .unwrap()
+            .c2c(
+                &mut fourier_poly.as_mut_tensor().as_mut_slice()[..half],
+                self.temporary.as_mut_tensor().as_mut_slice(),
+            )
+            .expect("add_backward: fft.c2c threw an error...");
+
+        let (first, second) = poly.as_mut_tensor().as_mut_slice().split_at_mut(half);
+        for zip_args!(input, untwist, re, im) in zip!(
+            self.temporary.as_tensor().iter(),
+            self.correctors.backward.as_tensor().iter(),
+            first.iter_mut(),
+            second.iter_mut()
+        ) {
+            let interm = input * untwist;
+            *re = re.wrapping_add(convert(interm.re));
+            *im = im.wrapping_add(convert(interm.im));
+        }
+    }
+}
+
+impl MemoryFootprint for Fft32 {
+    /// Returns the size of the temporary fourier polynomial and of the correcting factors owned by
+    /// the transformer.
+    fn heap_size(&self) -> usize {
+        self.temporary.heap_size()
+            + self.correctors.forward.as_tensor().heap_size()
+            + self.correctors.backward.as_tensor().heap_size()
+    }
+}
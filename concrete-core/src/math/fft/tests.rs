@@ -1,17 +1,49 @@
+use crate::crypto::UnsignedTorus;
 use crate::math::fft::twiddles::{BackwardCorrector, ForwardCorrector};
-use crate::math::fft::{Complex64, Fft, FourierPolynomial};
-use crate::math::polynomial::{Polynomial, PolynomialSize};
-use crate::math::random::fill_with_random_gaussian;
-use crate::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::math::fft::{Complex64, Fft, FftView, FourierPolynomial};
+use crate::math::polynomial::{InvalidSizeError, Polynomial, PolynomialSize, ReductionMode};
+use crate::math::random::{fill_with_random_gaussian, RandomGenerable, Uniform};
+use crate::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor};
 use crate::numeric::*;
 use fftw::array::AlignedVec;
+use std::fmt::Debug;
+
+// The number of representable floats between two floats of the same sign.
+fn ulps(a: f64, b: f64) -> u64 {
+    if a == b {
+        0
+    } else {
+        (a.to_bits() as i64 - b.to_bits() as i64).unsigned_abs()
+    }
+}
+
+// Asserts that the torus polynomials sent back from the fourier domain are the original ones.
+// The coefficients go through `f64`, which keeps their 53 most significant bits, and the rounding
+// errors of the transforms add up over a few more bits: the `u32` torus elements come back
+// exactly, while the 16 lowest bits of the `u64` ones may be off.
+fn assert_torus_round_trip<T, Output, Expected>(output: &Output, expected: &Expected)
+where
+    T: UnsignedTorus,
+    Output: AsRefTensor<Element = T>,
+    Expected: AsRefTensor<Element = T>,
+{
+    let tolerance = if T::BITS > 48 {
+        T::ONE << (T::BITS - 48)
+    } else {
+        T::ZERO
+    };
+    let distance = output
+        .as_tensor()
+        .max_wrapping_distance(expected.as_tensor());
+    assert!(distance <= tolerance, "distance: {:?}", distance);
+}
 
 #[test]
 fn test_single_forward_backward() {
     fn fw_conv(
         out: &mut FourierPolynomial<AlignedVec<Complex64>>,
         inp: &Polynomial<Vec<f64>>,
-        corr: &ForwardCorrector<&'static [Complex64]>,
+        corr: &ForwardCorrector<Vec<Complex64>>,
     ) {
         for (input, (corrector, output)) in inp
             .as_tensor()
@@ -24,7 +56,7 @@ fn test_single_forward_backward() {
     fn bw_conv(
         out: &mut Polynomial<Vec<f64>>,
         inp: &FourierPolynomial<AlignedVec<Complex64>>,
-        corr: &BackwardCorrector<&'static [Complex64]>,
+        corr: &BackwardCorrector<Vec<Complex64>>,
     ) {
         for (input, (corrector, output)) in inp
             .as_tensor()
@@ -36,7 +68,7 @@ fn test_single_forward_backward() {
     }
     for _ in 0..100 {
         for size in &[256, 512, 1024, 2048] {
-            let mut fft = Fft::new(PolynomialSize(*size));
+            let mut fft = Fft::new_unfolded(PolynomialSize(*size));
             let mut poly = Polynomial::allocate(f64::ZERO, PolynomialSize(*size));
             fill_with_random_gaussian(&mut poly, 0., 1.);
             let mut fourier_poly =
@@ -58,7 +90,7 @@ fn test_two_forward_backward() {
         out: &mut FourierPolynomial<AlignedVec<Complex64>>,
         inp1: &Polynomial<Vec<f64>>,
         inp2: &Polynomial<Vec<f64>>,
-        corr: &ForwardCorrector<&'static [Complex64]>,
+        corr: &ForwardCorrector<Vec<Complex64>>,
     ) {
         for (input_1, (input_2, (corrector, output))) in inp1.as_tensor().iter().zip(
             inp2.as_tensor()
@@ -72,7 +104,7 @@ fn test_two_forward_backward() {
         out1: &mut Polynomial<Vec<f64>>,
         out2: &mut Polynomial<Vec<f64>>,
         inp: &FourierPolynomial<AlignedVec<Complex64>>,
-        corr: &BackwardCorrector<&'static [Complex64]>,
+        corr: &BackwardCorrector<Vec<Complex64>>,
     ) {
         for (input, (corrector, (output1, output2))) in inp.as_tensor().iter().zip(
             corr.as_tensor().iter().zip(
@@ -88,7 +120,7 @@ fn test_two_forward_backward() {
     }
     for _ in 0..100 {
         for size in &[256, 512, 1024, 2048] {
-            let mut fft = Fft::new(PolynomialSize(*size));
+            let mut fft = Fft::new_unfolded(PolynomialSize(*size));
             let mut poly1 = Polynomial::allocate(f64::ZERO, PolynomialSize(*size));
             fill_with_random_gaussian(&mut poly1, 0., 1.);
             let mut poly2 = Polynomial::allocate(f64::ZERO, PolynomialSize(*size));
@@ -126,3 +158,1052 @@ fn test_two_forward_backward() {
         }
     }
 }
+
+// Computes the product of two polynomials modulo $(X^N+1)$, with the schoolbook algorithm.
+fn schoolbook_negacyclic_product(lhs: &[i64], rhs: &[i64]) -> Vec<i64> {
+    let size = lhs.len();
+    let mut result = vec![0i64; size];
+    for (i, lhs_i) in lhs.iter().enumerate() {
+        for (j, rhs_j) in rhs.iter().enumerate() {
+            if i + j < size {
+                result[i + j] += lhs_i * rhs_j;
+            } else {
+                result[i + j - size] -= lhs_i * rhs_j;
+            }
+        }
+    }
+    result
+}
+
+#[test]
+fn test_product_against_schoolbook() {
+    use crate::math::random::random_uniform_tensor;
+
+    fn fw_conv(
+        out: &mut FourierPolynomial<AlignedVec<Complex64>>,
+        inp: &Polynomial<Vec<f64>>,
+        corr: &ForwardCorrector<Vec<Complex64>>,
+    ) {
+        for (input, (corrector, output)) in inp
+            .as_tensor()
+            .iter()
+            .zip(corr.as_tensor().iter().zip(out.as_mut_tensor().iter_mut()))
+        {
+            *output = Complex64::new(*input, 0.) * corrector;
+        }
+    }
+    fn bw_conv(
+        out: &mut Polynomial<Vec<f64>>,
+        inp: &FourierPolynomial<AlignedVec<Complex64>>,
+        corr: &BackwardCorrector<Vec<Complex64>>,
+    ) {
+        for (input, (corrector, output)) in inp
+            .as_tensor()
+            .iter()
+            .zip(corr.as_tensor().iter().zip(out.as_mut_tensor().iter_mut()))
+        {
+            *output = (input * corrector).re;
+        }
+    }
+    // Returns random coefficients in [-2^(bits-1), 2^(bits-1)[.
+    fn random_coefficients(size: usize, bits: u32) -> Vec<i64> {
+        random_uniform_tensor::<u64>(size)
+            .iter()
+            .map(|x| (x >> (64 - bits)) as i64 - (1 << (bits - 1)))
+            .collect()
+    }
+
+    for _ in 0..10 {
+        for size in &[256, 512, 1024, 2048] {
+            let mut fft = Fft::new_unfolded(PolynomialSize(*size));
+            // The bounds mimic the product of a decomposed polynomial with a key polynomial.
+            let lhs = random_coefficients(*size, 8);
+            let rhs = random_coefficients(*size, 12);
+            let expected = schoolbook_negacyclic_product(&lhs, &rhs);
+
+            let lhs_poly = Polynomial::from_container(lhs.iter().map(|x| *x as f64).collect());
+            let rhs_poly = Polynomial::from_container(rhs.iter().map(|x| *x as f64).collect());
+            let mut fourier_lhs =
+                FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(*size));
+            let mut fourier_rhs =
+                FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(*size));
+            fft.forward(&mut fourier_lhs, &lhs_poly, fw_conv);
+            fft.forward(&mut fourier_rhs, &rhs_poly, fw_conv);
+            let mut fourier_product =
+                FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(*size));
+            fourier_product.update_with_multiply_accumulate(&fourier_lhs, &fourier_rhs);
+            let mut product = Polynomial::allocate(f64::ZERO, PolynomialSize(*size));
+            fft.backward(&mut product, &mut fourier_product, bw_conv);
+
+            for (exp, out) in expected.iter().zip(product.as_tensor().iter()) {
+                assert!(
+                    (*exp as f64 - out).abs() < 0.5,
+                    "fft product coefficient {} is too far from {}",
+                    out,
+                    exp
+                );
+                assert_eq!(out.round() as i64, *exp);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_integer_product_against_schoolbook() {
+    use crate::math::random::fill_with_random_uniform;
+
+    for _ in 0..10 {
+        for size in &[256, 512, 1024, 2048] {
+            let mut fft = Fft::new(PolynomialSize(*size));
+            let mut lhs = Polynomial::allocate(0u64, PolynomialSize(*size));
+            fill_with_random_uniform(&mut lhs);
+            // We keep small signed coefficients so that the product fits in the mantissa.
+            lhs.as_mut_tensor()
+                .update_with(|x| *x = (*x >> 56).wrapping_sub(1 << 7));
+            let mut rhs = Polynomial::allocate(0u64, PolynomialSize(*size));
+            fill_with_random_uniform(&mut rhs);
+            rhs.as_mut_tensor()
+                .update_with(|x| *x = (*x >> 52).wrapping_sub(1 << 11));
+            let mut expected = Polynomial::allocate(0u64, PolynomialSize(*size));
+            expected.fill_with_wrapping_mul(&lhs, &rhs);
+
+            let mut fourier_lhs =
+                FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(*size));
+            let mut fourier_rhs =
+                FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(*size));
+            fft.forward_two_as_integer(&mut fourier_lhs, &mut fourier_rhs, &lhs, &rhs);
+            let mut fourier_product =
+                FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(*size));
+            fourier_product.update_with_multiply_accumulate(&fourier_lhs, &fourier_rhs);
+            let mut product = Polynomial::allocate(0u64, PolynomialSize(*size));
+            fft.add_backward_as_integer(&mut product, &mut fourier_product);
+
+            assert_eq!(product, expected);
+        }
+    }
+}
+
+#[test]
+fn test_cyclic_product_against_schoolbook() {
+    use crate::math::random::fill_with_random_uniform;
+
+    for _ in 0..10 {
+        for size in &[256, 512, 1024, 2048] {
+            let mut fft = Fft::new_with_reduction(PolynomialSize(*size), ReductionMode::Cyclic);
+            let mut lhs = Polynomial::allocate(0u64, PolynomialSize(*size));
+            fill_with_random_uniform(&mut lhs);
+            lhs.as_mut_tensor()
+                .update_with(|x| *x = (*x >> 56).wrapping_sub(1 << 7));
+            let mut rhs = Polynomial::allocate(0u64, PolynomialSize(*size));
+            fill_with_random_uniform(&mut rhs);
+            rhs.as_mut_tensor()
+                .update_with(|x| *x = (*x >> 52).wrapping_sub(1 << 11));
+            let mut expected = Polynomial::allocate(0u64, PolynomialSize(*size));
+            expected.fill_with_wrapping_mul_with_reduction(&lhs, &rhs, ReductionMode::Cyclic);
+
+            // The polynomials are sent to the fourier domain one by one, and two by two.
+            let mut fourier_lhs =
+                FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(*size));
+            let mut fourier_rhs =
+                FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(*size));
+            fft.forward_as_integer(&mut fourier_lhs, &lhs);
+            fft.forward_as_integer(&mut fourier_rhs, &rhs);
+            let mut fourier_product_1 =
+                FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(*size));
+            fourier_product_1.update_with_multiply_accumulate(&fourier_lhs, &fourier_rhs);
+            let mut fourier_product_3 =
+                FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(*size));
+            fourier_product_3.update_with_multiply_accumulate(&fourier_lhs, &fourier_rhs);
+            fft.forward_two_as_integer(&mut fourier_lhs, &mut fourier_rhs, &lhs, &rhs);
+            let mut fourier_product_2 =
+                FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(*size));
+            fourier_product_2.update_with_multiply_accumulate(&fourier_lhs, &fourier_rhs);
+
+            // The products are sent back one by one, and two by two.
+            let mut product = Polynomial::allocate(0u64, PolynomialSize(*size));
+            fft.add_backward_as_integer(&mut product, &mut fourier_product_3);
+            assert_eq!(product, expected);
+            let mut product_1 = Polynomial::allocate(0u64, PolynomialSize(*size));
+            let mut product_2 = Polynomial::allocate(0u64, PolynomialSize(*size));
+            fft.add_backward_two_as_integer(
+                &mut product_1,
+                &mut product_2,
+                &mut fourier_product_1,
+                &mut fourier_product_2,
+            );
+            assert_eq!(product_1, expected);
+            assert_eq!(product_2, expected);
+        }
+    }
+}
+
+#[test]
+fn test_view_product_against_schoolbook() {
+    use crate::math::random::fill_with_random_uniform;
+
+    for size in &[256, 512, 1024, 2048] {
+        let mut fft = Fft::new(PolynomialSize(*size));
+        let mut view = FftView::new(&mut fft);
+        // The same view is used for all the products.
+        for _ in 0..10 {
+            let mut lhs = Polynomial::allocate(0u64, PolynomialSize(*size));
+            fill_with_random_uniform(&mut lhs);
+            lhs.as_mut_tensor()
+                .update_with(|x| *x = (*x >> 56).wrapping_sub(1 << 7));
+            let mut rhs = Polynomial::allocate(0u64, PolynomialSize(*size));
+            fill_with_random_uniform(&mut rhs);
+            rhs.as_mut_tensor()
+                .update_with(|x| *x = (*x >> 52).wrapping_sub(1 << 11));
+            let mut expected = Polynomial::allocate(0u64, PolynomialSize(*size));
+            expected.fill_with_wrapping_mul(&lhs, &rhs);
+
+            view.polynomial_mul_assign(&mut lhs, &rhs);
+            assert_eq!(lhs, expected);
+
+            // The forward and backward transforms round trip.
+            let mut fourier =
+                FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(*size));
+            let mut output = Polynomial::allocate(1u64, PolynomialSize(*size));
+            view.forward(&mut fourier, &rhs);
+            view.backward(&mut output, &mut fourier);
+            assert_eq!(output, rhs);
+        }
+    }
+}
+
+#[test]
+fn test_external_twiddles_against_schoolbook() {
+    use crate::math::fft::TwiddleFactors;
+    use crate::math::random::fill_with_random_uniform;
+
+    for size in &[256, 512, 1024, 2048, 4096] {
+        let twiddles = TwiddleFactors::precompute(PolynomialSize(*size));
+        // The precomputed factors are the powers of the 2N-th root of unity.
+        for (k, twiddle) in twiddles.as_slice().iter().enumerate() {
+            let angle = std::f64::consts::PI * k as f64 / *size as f64;
+            assert!((twiddle.re - angle.cos()).abs() < 1e-12);
+            assert!((twiddle.im - angle.sin()).abs() < 1e-12);
+        }
+
+        let mut fft = Fft::new_with_twiddles(PolynomialSize(*size), &twiddles);
+        let mut view = FftView::new(&mut fft);
+        let mut lhs = Polynomial::allocate(0u64, PolynomialSize(*size));
+        fill_with_random_uniform(&mut lhs);
+        lhs.as_mut_tensor()
+            .update_with(|x| *x = (*x >> 56).wrapping_sub(1 << 7));
+        let mut rhs = Polynomial::allocate(0u64, PolynomialSize(*size));
+        fill_with_random_uniform(&mut rhs);
+        rhs.as_mut_tensor()
+            .update_with(|x| *x = (*x >> 52).wrapping_sub(1 << 11));
+        let mut expected = Polynomial::allocate(0u64, PolynomialSize(*size));
+        expected.fill_with_wrapping_mul(&lhs, &rhs);
+
+        view.polynomial_mul_assign(&mut lhs, &rhs);
+        assert_eq!(lhs, expected);
+    }
+}
+
+#[test]
+fn test_computed_twiddles_against_tables() {
+    use crate::math::fft::twiddle_tables::*;
+    use crate::math::fft::Correctors;
+
+    let tables: [(&[Complex64], &[Complex64]); 5] = [
+        (&TWIDDLES_256, &INVERSE_TWIDDLES_256),
+        (&TWIDDLES_512, &INVERSE_TWIDDLES_512),
+        (&TWIDDLES_1024, &INVERSE_TWIDDLES_1024),
+        (&TWIDDLES_2048, &INVERSE_TWIDDLES_2048),
+        (&TWIDDLES_4096, &INVERSE_TWIDDLES_4096),
+    ];
+    for (forward, backward) in tables.iter() {
+        let correctors = Correctors::new(forward.len());
+        let computed = correctors
+            .forward
+            .as_tensor()
+            .iter()
+            .zip(correctors.backward.as_tensor().iter());
+        for ((twiddle, inverse), (expected_twiddle, expected_inverse)) in
+            computed.zip(forward.iter().zip(backward.iter()))
+        {
+            assert!(ulps(twiddle.re, expected_twiddle.re) <= 1);
+            assert!(ulps(twiddle.im, expected_twiddle.im) <= 1);
+            assert!(ulps(inverse.re, expected_inverse.re) <= 1);
+            assert!(ulps(inverse.im, expected_inverse.im) <= 1);
+        }
+    }
+}
+
+#[test]
+fn test_try_new_validates_size() {
+    for size in 0..=32768 {
+        let result = Fft::try_new_with_reduction(PolynomialSize(size), ReductionMode::Cyclic);
+        match result {
+            Ok(fft) => {
+                assert!(size.is_power_of_two() && size >= 256);
+                assert_eq!(fft.polynomial_size(), PolynomialSize(size));
+            }
+            Err(InvalidSizeError::NotPowerOfTwo { size: found }) => {
+                assert_eq!(found, size);
+                assert!(!size.is_power_of_two());
+            }
+            Err(InvalidSizeError::TooSmall { size: found, min }) => {
+                assert_eq!(found, size);
+                assert!(size.is_power_of_two());
+                assert!(size < min);
+            }
+            Err(InvalidSizeError::TooLarge { size: found, max }) => {
+                assert_eq!(found, size);
+                assert!(size.is_power_of_two());
+                assert!(size > max);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_constructors_reject_invalid_sizes() {
+    use crate::math::fft::{Fft32, TwiddleFactors};
+    use std::panic::catch_unwind;
+
+    // The errors of the double and of the single precision transformers.
+    let cases = [
+        (
+            0,
+            InvalidSizeError::NotPowerOfTwo { size: 0 },
+            InvalidSizeError::NotPowerOfTwo { size: 0 },
+        ),
+        (
+            3,
+            InvalidSizeError::NotPowerOfTwo { size: 3 },
+            InvalidSizeError::NotPowerOfTwo { size: 3 },
+        ),
+        (
+            128,
+            InvalidSizeError::TooSmall {
+                size: 128,
+                min: 256,
+            },
+            InvalidSizeError::TooSmall {
+                size: 128,
+                min: 256,
+            },
+        ),
+        (
+            1 << 31,
+            InvalidSizeError::TooLarge {
+                size: 1 << 31,
+                max: 1 << 30,
+            },
+            InvalidSizeError::TooLarge {
+                size: 1 << 31,
+                max: 16384,
+            },
+        ),
+    ];
+    for (size, error, single_error) in cases.iter() {
+        let size = PolynomialSize(*size);
+        for reduction in &[ReductionMode::Negacyclic, ReductionMode::Cyclic] {
+            assert_eq!(
+                Fft::try_new_with_reduction(size, *reduction).err(),
+                Some(error.clone())
+            );
+        }
+        assert_eq!(Fft32::try_new(size).err(), Some(single_error.clone()));
+
+        // The constructors which do not return a result panic with the error, before reaching
+        // fftw or the twiddle factors.
+        assert!(catch_unwind(|| Fft::new(size)).is_err());
+        assert!(catch_unwind(|| Fft::new_unfolded(size)).is_err());
+        assert!(catch_unwind(|| Fft32::new(size)).is_err());
+        let twiddles = TwiddleFactors::from_container(vec![Complex64::new(1., 0.); 256]);
+        assert!(catch_unwind(|| Fft::new_with_twiddles(size, &twiddles)).is_err());
+    }
+
+    // The single precision transformer stops at a smaller size.
+    let size = PolynomialSize(32768);
+    assert!(Fft::try_new(size).is_ok());
+    assert_eq!(
+        Fft32::try_new(size).err(),
+        Some(InvalidSizeError::TooLarge {
+            size: 32768,
+            max: 16384,
+        })
+    );
+}
+
+#[test]
+fn test_plan_cache() {
+    use crate::math::fft::clear_fft_cache;
+    use crate::math::fft::plans::created_plans_count;
+    use crate::math::random::fill_with_random_uniform;
+
+    let size = PolynomialSize(512);
+    clear_fft_cache();
+    let created = created_plans_count();
+    let mut fft_1 = Fft::new(size);
+    assert_eq!(created_plans_count(), created + 1);
+    // The second transformer reuses the plans of the first one.
+    let mut fft_2 = Fft::new(size);
+    assert_eq!(created_plans_count(), created + 1);
+
+    let mut poly = Polynomial::allocate(0u32, size);
+    fill_with_random_uniform(&mut poly);
+    let mut fourier_1 = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+    let mut fourier_2 = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+    fft_1.forward_as_torus(&mut fourier_1, &poly);
+    fft_2.forward_as_torus(&mut fourier_2, &poly);
+    assert_eq!(
+        fourier_1.as_tensor().as_slice(),
+        fourier_2.as_tensor().as_slice()
+    );
+    let mut output_1 = Polynomial::allocate(0u32, size);
+    let mut output_2 = Polynomial::allocate(0u32, size);
+    fft_1.add_backward_as_torus(&mut output_1, &mut fourier_1);
+    fft_2.add_backward_as_torus(&mut output_2, &mut fourier_2);
+    assert_eq!(output_1, poly);
+    assert_eq!(output_2, poly);
+
+    // Once the cache is cleared, new plans are created.
+    clear_fft_cache();
+    let mut fft_3 = Fft::new(size);
+    assert_eq!(created_plans_count(), created + 2);
+    let mut fourier_3 = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+    let mut output_3 = Polynomial::allocate(0u32, size);
+    fft_3.forward_as_torus(&mut fourier_3, &poly);
+    fft_3.add_backward_as_torus(&mut output_3, &mut fourier_3);
+    assert_eq!(output_3, poly);
+}
+
+#[test]
+fn test_twiddles_cache() {
+    use crate::math::fft::clear_fft_cache;
+    use crate::math::fft::twiddles::cached_twisting_powers_count;
+
+    clear_fft_cache();
+    assert_eq!(cached_twisting_powers_count(), 0);
+    let fft = Fft::new(PolynomialSize(512));
+    assert_eq!(cached_twisting_powers_count(), 1);
+    // The existing transformers keep their factors, the cache is emptied.
+    clear_fft_cache();
+    assert_eq!(cached_twisting_powers_count(), 0);
+    assert_eq!(fft.polynomial_size(), PolynomialSize(512));
+}
+
+fn test_list_transforms<T: UnsignedTorus>() {
+    use crate::math::fft::FourierPolynomialList;
+    use crate::math::polynomial::{PolynomialCount, PolynomialList};
+    use crate::math::random::fill_with_random_uniform;
+
+    for size in &[256, 1024] {
+        let size = PolynomialSize(*size);
+        let mut fft = Fft::new(size);
+        for count in 1..=4 {
+            let count = PolynomialCount(count);
+            let mut list = PolynomialList::allocate(T::ZERO, count, size);
+            fill_with_random_uniform(&mut list);
+            let mut fourier_list =
+                FourierPolynomialList::allocate(Complex64::new(0., 0.), count, size);
+            fft.forward_as_torus_list(&mut fourier_list, &list);
+
+            // Every fourier polynomial goes back to its polynomial with the single transform.
+            for (mut fourier_poly, poly) in fourier_list
+                .clone()
+                .polynomial_iter_mut()
+                .zip(list.polynomial_iter())
+            {
+                let mut output = Polynomial::allocate(T::ZERO, size);
+                fft.add_backward_as_torus(&mut output, &mut fourier_poly);
+                assert_torus_round_trip(&output, &poly);
+            }
+
+            // The polynomials transformed one by one go back with the list transform.
+            let mut single_fourier_list =
+                FourierPolynomialList::allocate(Complex64::new(0., 0.), count, size);
+            for (mut fourier_poly, poly) in single_fourier_list
+                .polynomial_iter_mut()
+                .zip(list.polynomial_iter())
+            {
+                fft.forward_as_torus(&mut fourier_poly, &poly);
+            }
+            let mut output = PolynomialList::allocate(T::ZERO, count, size);
+            fft.add_backward_as_torus_list(&mut output, &mut single_fourier_list);
+            assert_torus_round_trip(&output, &list);
+
+            // And the list transforms round trip.
+            let mut output = PolynomialList::allocate(T::ZERO, count, size);
+            fft.add_backward_as_torus_list(&mut output, &mut fourier_list);
+            assert_torus_round_trip(&output, &list);
+        }
+    }
+}
+
+#[test]
+fn test_list_transforms_u32() {
+    test_list_transforms::<u32>()
+}
+
+#[test]
+fn test_list_transforms_u64() {
+    test_list_transforms::<u64>()
+}
+
+fn test_two_against_single_torus<T: UnsignedTorus>() {
+    use crate::math::random::fill_with_random_uniform;
+
+    for size in &[256, 512, 1024, 2048, 4096] {
+        let size = PolynomialSize(*size);
+        let mut fft = Fft::new(size);
+        for _ in 0..10 {
+            let mut poly_1 = Polynomial::allocate(T::ZERO, size);
+            fill_with_random_uniform(&mut poly_1);
+            let mut poly_2 = Polynomial::allocate(T::ZERO, size);
+            fill_with_random_uniform(&mut poly_2);
+            let mut fourier_1 = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            let mut fourier_2 = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            fft.forward_two_as_torus(&mut fourier_1, &mut fourier_2, &poly_1, &poly_2);
+            let mut single_fourier_1 = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            let mut single_fourier_2 = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            fft.forward_as_torus(&mut single_fourier_1, &poly_1);
+            fft.forward_as_torus(&mut single_fourier_2, &poly_2);
+
+            // The backward transforms only read the first N/2 + 1 coefficients, the others are
+            // deduced by conjugation.
+            let error_budget = 1e-12 * size.0 as f64;
+            for (fourier, single_fourier) in [
+                (&fourier_1, &single_fourier_1),
+                (&fourier_2, &single_fourier_2),
+            ]
+            .iter()
+            {
+                for (two, single) in fourier
+                    .coefficient_iter()
+                    .zip(single_fourier.coefficient_iter())
+                    .take(size.0 / 2 + 1)
+                {
+                    assert!((two - single).norm() < error_budget);
+                }
+            }
+
+            // The results of both paths can be sent back with the other one.
+            let mut out_1 = Polynomial::allocate(T::ZERO, size);
+            let mut out_2 = Polynomial::allocate(T::ZERO, size);
+            fft.add_backward_as_torus(&mut out_1, &mut fourier_1);
+            fft.add_backward_as_torus(&mut out_2, &mut fourier_2);
+            assert_torus_round_trip(&out_1, &poly_1);
+            assert_torus_round_trip(&out_2, &poly_2);
+            let mut out_1 = Polynomial::allocate(T::ZERO, size);
+            let mut out_2 = Polynomial::allocate(T::ZERO, size);
+            fft.add_backward_two_as_torus(
+                &mut out_1,
+                &mut out_2,
+                &mut single_fourier_1,
+                &mut single_fourier_2,
+            );
+            assert_torus_round_trip(&out_1, &poly_1);
+            assert_torus_round_trip(&out_2, &poly_2);
+        }
+    }
+}
+
+#[test]
+fn test_two_against_single_torus_u32() {
+    test_two_against_single_torus::<u32>()
+}
+
+#[test]
+fn test_two_against_single_torus_u64() {
+    test_two_against_single_torus::<u64>()
+}
+
+fn test_folded_against_exact_product<T>()
+where
+    T: UnsignedInteger + CastInto<u64> + CastFrom<u64> + RandomGenerable<Uniform> + Debug,
+{
+    use crate::math::ntt::Ntt;
+    use crate::math::random::fill_with_random_uniform;
+
+    for size in &[256, 512, 1024, 2048, 4096, 8192, 16384] {
+        let size = PolynomialSize(*size);
+        let ntt = Ntt::new(size);
+        let mut folded_fft = Fft::new(size);
+        assert!(folded_fft.is_folded());
+        let mut unfolded_fft = Fft::new_unfolded(size);
+        for _ in 0..3 {
+            // The coefficients are small enough for the products to be exact in the fourier
+            // domain.
+            let mut lhs = Polynomial::allocate(T::ZERO, size);
+            fill_with_random_uniform(&mut lhs);
+            lhs.as_mut_tensor()
+                .update_with(|x| *x = (*x >> (T::BITS - 8)).wrapping_sub(T::ONE << 7));
+            let mut rhs = Polynomial::allocate(T::ZERO, size);
+            fill_with_random_uniform(&mut rhs);
+            rhs.as_mut_tensor()
+                .update_with(|x| *x = (*x >> (T::BITS - 8)).wrapping_sub(T::ONE << 7));
+            let mut expected = Polynomial::allocate(T::ZERO, size);
+            expected.fill_with_ntt_mul(&lhs, &rhs, &ntt);
+
+            let mut folded_product = lhs.clone();
+            FftView::new(&mut folded_fft).polynomial_mul_assign(&mut folded_product, &rhs);
+            assert_eq!(folded_product, expected);
+
+            let mut unfolded_product = lhs.clone();
+            FftView::new(&mut unfolded_fft).polynomial_mul_assign(&mut unfolded_product, &rhs);
+            assert_eq!(unfolded_product, expected);
+        }
+    }
+}
+
+#[test]
+fn test_folded_against_exact_product_u32() {
+    test_folded_against_exact_product::<u32>()
+}
+
+#[test]
+fn test_folded_against_exact_product_u64() {
+    test_folded_against_exact_product::<u64>()
+}
+
+fn test_folded_round_trip<T: UnsignedTorus>() {
+    use crate::math::random::fill_with_random_uniform;
+
+    for size in &[8192, 16384] {
+        let size = PolynomialSize(*size);
+        let mut fft = Fft::new(size);
+        for _ in 0..10 {
+            let mut poly_1 = Polynomial::allocate(T::ZERO, size);
+            fill_with_random_uniform(&mut poly_1);
+            let mut poly_2 = Polynomial::allocate(T::ZERO, size);
+            fill_with_random_uniform(&mut poly_2);
+            let mut fourier_1 = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            let mut fourier_2 = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            fft.forward_two_as_torus(&mut fourier_1, &mut fourier_2, &poly_1, &poly_2);
+            let mut out_1 = Polynomial::allocate(T::ZERO, size);
+            let mut out_2 = Polynomial::allocate(T::ZERO, size);
+            fft.add_backward_two_as_torus(&mut out_1, &mut out_2, &mut fourier_1, &mut fourier_2);
+            assert_torus_round_trip(&out_1, &poly_1);
+            assert_torus_round_trip(&out_2, &poly_2);
+        }
+    }
+}
+
+#[test]
+fn test_folded_round_trip_u32() {
+    test_folded_round_trip::<u32>()
+}
+
+#[test]
+fn test_folded_round_trip_u64() {
+    test_folded_round_trip::<u64>()
+}
+
+fn test_buffered_against_allocating_product<T>()
+where
+    T: UnsignedInteger + RandomGenerable<Uniform> + Debug,
+{
+    use crate::math::fft::FftBuffers;
+    use crate::math::random::fill_with_random_uniform;
+
+    let size = PolynomialSize(256);
+    let mut fft = Fft::new(size);
+    let mut buffers = FftBuffers::for_size(size);
+    let mut buffered = Polynomial::allocate(T::ZERO, size);
+    let mut allocating = Polynomial::allocate(T::ZERO, size);
+    for _ in 0..1000 {
+        let mut lhs = Polynomial::allocate(T::ZERO, size);
+        fill_with_random_uniform(&mut lhs);
+        lhs.as_mut_tensor()
+            .update_with(|x| *x = (*x >> (T::BITS - 8)).wrapping_sub(T::ONE << 7));
+        let mut rhs = Polynomial::allocate(T::ZERO, size);
+        fill_with_random_uniform(&mut rhs);
+        rhs.as_mut_tensor()
+            .update_with(|x| *x = (*x >> (T::BITS - 12)).wrapping_sub(T::ONE << 11));
+        buffered.fill_with_fast_mul_buffered(&lhs, &rhs, &mut fft, &mut buffers);
+        allocating.fill_with_fast_mul(&lhs, &rhs, None);
+        assert_eq!(buffered, allocating);
+    }
+}
+
+#[test]
+fn test_buffered_against_allocating_product_u32() {
+    test_buffered_against_allocating_product::<u32>()
+}
+
+#[test]
+fn test_buffered_against_allocating_product_u64() {
+    test_buffered_against_allocating_product::<u64>()
+}
+
+#[test]
+fn test_concurrent_products() {
+    use crate::math::fft::FftBuffers;
+    use crate::math::random::fill_with_random_uniform;
+    use std::thread;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Fft>();
+    assert_send_sync::<FftBuffers>();
+
+    let size = PolynomialSize(1024);
+    let mut inputs = Vec::new();
+    for _ in 0..8 {
+        let mut lhs = Polynomial::allocate(0u64, size);
+        fill_with_random_uniform(&mut lhs);
+        lhs.as_mut_tensor()
+            .update_with(|x| *x = (*x >> 56).wrapping_sub(1 << 7));
+        let mut rhs = Polynomial::allocate(0u64, size);
+        fill_with_random_uniform(&mut rhs);
+        rhs.as_mut_tensor()
+            .update_with(|x| *x = (*x >> 52).wrapping_sub(1 << 11));
+        inputs.push((lhs, rhs));
+    }
+
+    // The reference products are computed on the current thread.
+    let mut fft = Fft::new(size);
+    let mut buffers = FftBuffers::for_size(size);
+    let expected: Vec<_> = inputs
+        .iter()
+        .map(|(lhs, rhs)| {
+            let mut product = lhs.clone();
+            fft.polynomial_mul_assign(&mut product, rhs, &mut buffers);
+            product
+        })
+        .collect();
+
+    // Half of the workers get a transformer created on the current thread, which shares its plans
+    // with the reference one, and the other half create their own.
+    let workers: Vec<_> = inputs
+        .into_iter()
+        .enumerate()
+        .map(|(index, (lhs, rhs))| {
+            let moved_fft = if index % 2 == 0 {
+                Some(Fft::new(size))
+            } else {
+                None
+            };
+            thread::spawn(move || {
+                let mut fft = moved_fft.unwrap_or_else(|| Fft::new(size));
+                let mut buffers = FftBuffers::for_size(size);
+                let mut products = Vec::new();
+                for _ in 0..100 {
+                    let mut product = lhs.clone();
+                    fft.polynomial_mul_assign(&mut product, &rhs, &mut buffers);
+                    products.push(product);
+                }
+                products
+            })
+        })
+        .collect();
+    for (worker, expected) in workers.into_iter().zip(expected.iter()) {
+        for product in worker.join().unwrap() {
+            assert_eq!(&product, expected);
+        }
+    }
+}
+
+#[test]
+fn test_single_precision_against_double_precision() {
+    use crate::math::fft::{Complex32, Fft32};
+    use crate::math::random::fill_with_random_uniform;
+
+    for base_log in &[4usize, 6, 8] {
+        for size in &[256, 512, 1024, 2048] {
+            let size = PolynomialSize(*size);
+            let mut fft = Fft::new(size);
+            let mut fft_32 = Fft32::new(size);
+            let mut max_error = 0u32;
+            for _ in 0..10 {
+                // The product of a key polynomial with a decomposed polynomial.
+                let mut key = Polynomial::allocate(0u32, size);
+                fill_with_random_uniform(&mut key);
+                let mut decomposed = Polynomial::allocate(0u32, size);
+                fill_with_random_uniform(&mut decomposed);
+                decomposed.as_mut_tensor().update_with(|x| {
+                    *x = (*x >> (32 - base_log)).wrapping_sub(1 << (base_log - 1))
+                });
+
+                let mut fourier_key = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+                let mut fourier_dec = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+                fft.forward_as_torus(&mut fourier_key, &key);
+                fft.forward_as_integer(&mut fourier_dec, &decomposed);
+                let mut fourier_product = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+                fourier_product.update_with_multiply_accumulate(&fourier_key, &fourier_dec);
+                let mut expected = Polynomial::allocate(0u32, size);
+                fft.add_backward_as_torus(&mut expected, &mut fourier_product);
+
+                let mut fourier_key = FourierPolynomial::allocate(Complex32::new(0., 0.), size);
+                let mut fourier_dec = FourierPolynomial::allocate(Complex32::new(0., 0.), size);
+                fft_32.forward_as_torus(&mut fourier_key, &key);
+                fft_32.forward_as_integer(&mut fourier_dec, &decomposed);
+                let mut fourier_product = FourierPolynomial::allocate(Complex32::new(0., 0.), size);
+                fourier_product.update_with_f32_multiply_accumulate(&fourier_key, &fourier_dec);
+                let mut product = Polynomial::allocate(0u32, size);
+                fft_32.add_backward_as_torus(&mut product, &mut fourier_product);
+
+                for (coef, expected) in product.coefficient_iter().zip(expected.coefficient_iter())
+                {
+                    let error = (coef.wrapping_sub(*expected) as i32).wrapping_abs() as u32;
+                    max_error = max_error.max(error);
+                }
+            }
+
+            // The documented bound is 2^(base_log - 1) * N * 2^-25 on the torus, which is checked
+            // with a margin.
+            let log_error = (max_error as f64).log2() - 32.;
+            let log_bound = (*base_log as f64 - 1.) + (size.0 as f64).log2() - 23.;
+            assert!(
+                log_error < log_bound,
+                "base_log {}, size {}",
+                base_log,
+                size.0
+            );
+        }
+    }
+}
+
+fn test_serialization_round_trip<T: UnsignedTorus>() {
+    use crate::math::fft::FourierPolynomialList;
+    use crate::math::polynomial::{PolynomialCount, PolynomialList};
+    use crate::math::random::fill_with_random_uniform;
+
+    for size in &[256, 1024, 4096] {
+        let size = PolynomialSize(*size);
+        let mut fft = Fft::new(size);
+        let count = PolynomialCount(3);
+        let mut list = PolynomialList::allocate(T::ZERO, count, size);
+        fill_with_random_uniform(&mut list);
+        let mut fourier_list = FourierPolynomialList::allocate(Complex64::new(0., 0.), count, size);
+        fft.forward_as_torus_list(&mut fourier_list, &list);
+
+        // The list is restored bit for bit, along with its polynomial size.
+        let bytes = bincode::serialize(&fourier_list).unwrap();
+        let mut recovered: FourierPolynomialList<AlignedVec<Complex64>> =
+            bincode::deserialize(&bytes).unwrap();
+        assert_eq!(recovered.polynomial_size(), size);
+        assert_eq!(recovered.polynomial_count(), count);
+        for (coef, expected) in recovered
+            .as_tensor()
+            .iter()
+            .zip(fourier_list.as_tensor().iter())
+        {
+            assert_eq!(coef.re.to_bits(), expected.re.to_bits());
+            assert_eq!(coef.im.to_bits(), expected.im.to_bits());
+        }
+
+        // So is every polynomial of the list, borrowed or not.
+        for fourier_poly in fourier_list.polynomial_iter() {
+            let bytes = bincode::serialize(&fourier_poly).unwrap();
+            let recovered: FourierPolynomial<AlignedVec<Complex64>> =
+                bincode::deserialize(&bytes).unwrap();
+            assert_eq!(recovered.polynomial_size(), size);
+            for (coef, expected) in recovered
+                .coefficient_iter()
+                .zip(fourier_poly.coefficient_iter())
+            {
+                assert_eq!(coef.re.to_bits(), expected.re.to_bits());
+                assert_eq!(coef.im.to_bits(), expected.im.to_bits());
+            }
+        }
+
+        // And the restored list still goes back to the original polynomials.
+        let mut output = PolynomialList::allocate(T::ZERO, count, size);
+        fft.add_backward_as_torus_list(&mut output, &mut recovered);
+        assert_torus_round_trip(&output, &list);
+    }
+}
+
+#[test]
+fn test_serialization_round_trip_u32() {
+    test_serialization_round_trip::<u32>()
+}
+
+#[test]
+fn test_serialization_round_trip_u64() {
+    test_serialization_round_trip::<u64>()
+}
+
+#[test]
+fn test_deserialization_checks_polynomial_size() {
+    use crate::math::fft::FourierPolynomialList;
+
+    // A list of 300 coefficients can not hold polynomials of size 256.
+    let list = FourierPolynomialList::from_container(
+        vec![Complex64::new(1., 0.); 300],
+        PolynomialSize(100),
+    );
+    let mut bytes = bincode::serialize(&list).unwrap();
+    bytes[..8].copy_from_slice(&256u64.to_le_bytes());
+    assert!(bincode::deserialize::<FourierPolynomialList<AlignedVec<Complex64>>>(&bytes).is_err());
+}
+
+#[test]
+fn test_multiply_accumulate_against_naive() {
+    use crate::math::random::random_gaussian;
+
+    fn random_poly(size: usize) -> FourierPolynomial<Vec<Complex64>> {
+        let coefs = (0..size)
+            .map(|_| {
+                let (re, im) = random_gaussian(0., 1e6);
+                Complex64::new(re, im)
+            })
+            .collect();
+        FourierPolynomial::from_container(coefs)
+    }
+    let assert_close = |result: &FourierPolynomial<Vec<Complex64>>, expected: &[Complex64]| {
+        for (coef, expected) in result.coefficient_iter().zip(expected.iter()) {
+            assert!(ulps(coef.re, expected.re) <= 1);
+            assert!(ulps(coef.im, expected.im) <= 1);
+        }
+    };
+
+    for size in &[256, 1024, 4096] {
+        // The vectors of the polynomials are not necessarily aligned for the simd instructions.
+        let (res_1, res_2) = (random_poly(*size), random_poly(*size));
+        let (a_1, a_2, b) = (random_poly(*size), random_poly(*size), random_poly(*size));
+        let (c_1, c_2, d) = (random_poly(*size), random_poly(*size), random_poly(*size));
+        let coefs = |poly: &FourierPolynomial<Vec<Complex64>>| poly.as_tensor().as_slice().to_vec();
+        let half = *size / 2 + 2;
+
+        let mut result = res_1.clone();
+        result.update_with_multiply_accumulate(&a_1, &b);
+        let mut expected = coefs(&res_1);
+        for (i, coef) in expected.iter_mut().enumerate().take(half) {
+            *coef += a_1.as_tensor().get_element(i) * b.as_tensor().get_element(i);
+        }
+        assert_close(&result, &expected);
+
+        let mut result = res_1.clone();
+        result.update_with_two_multiply_accumulate(&a_1, &b, &c_1, &d);
+        let mut expected = coefs(&res_1);
+        for (i, coef) in expected.iter_mut().enumerate().take(half) {
+            *coef += a_1.as_tensor().get_element(i) * b.as_tensor().get_element(i)
+                + c_1.as_tensor().get_element(i) * d.as_tensor().get_element(i);
+        }
+        assert_close(&result, &expected);
+
+        let (mut result_1, mut result_2) = (res_1.clone(), res_2.clone());
+        FourierPolynomial::update_two_with_two_multiply_accumulate(
+            &mut result_1,
+            &mut result_2,
+            &a_1,
+            &a_2,
+            &b,
+            &c_1,
+            &c_2,
+            &d,
+        );
+        let (mut expected_1, mut expected_2) = (coefs(&res_1), coefs(&res_2));
+        for i in 0..half {
+            let coef = |poly: &FourierPolynomial<Vec<Complex64>>| *poly.as_tensor().get_element(i);
+            expected_1[i] += coef(&a_1) * coef(&b) + coef(&c_1) * coef(&d);
+            expected_2[i] += coef(&a_2) * coef(&b) + coef(&c_2) * coef(&d);
+        }
+        assert_close(&result_1, &expected_1);
+        assert_close(&result_2, &expected_2);
+    }
+}
+
+fn test_add_backward_against_backward_then_add<T: UnsignedTorus>() {
+    use crate::math::random::fill_with_random_uniform;
+
+    for size in &[256, 1024, 4096] {
+        let size = PolynomialSize(*size);
+        for fft in &mut [Fft::new(size), Fft::new_unfolded(size)] {
+            let mut poly = Polynomial::allocate(T::ZERO, size);
+            fill_with_random_uniform(&mut poly);
+            let mut body = Polynomial::allocate(T::ZERO, size);
+            fill_with_random_uniform(&mut body);
+            let mut fourier_poly = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            fft.forward_as_torus(&mut fourier_poly, &poly);
+            // As after the pointwise products of an external product, the transform of a torus
+            // polynomial is multiplied by the one of a polynomial of small integers.
+            let mut decomposed = Polynomial::allocate(T::ZERO, size);
+            fill_with_random_uniform(&mut decomposed);
+            decomposed
+                .as_mut_tensor()
+                .update_with(|x| *x = (*x >> (T::BITS - 4)).wrapping_sub(T::ONE << 3));
+            let mut fourier_decomposed = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            fft.forward_as_integer(&mut fourier_decomposed, &decomposed);
+            let mut fourier_product = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+            fourier_product.update_with_multiply_accumulate(&fourier_poly, &fourier_decomposed);
+
+            // The backward transform in a standalone polynomial, added to the body afterwards.
+            let mut standalone = Polynomial::allocate(T::ZERO, size);
+            fft.add_backward_as_torus(&mut standalone, &mut fourier_product.clone());
+            let mut expected = body.clone();
+            expected.update_with_wrapping_add(&standalone);
+
+            // The backward transform accumulated directly in the body.
+            fft.add_backward_as_torus(&mut body, &mut fourier_product);
+            assert_eq!(body, expected);
+        }
+    }
+}
+
+#[test]
+fn test_add_backward_against_backward_then_add_u32() {
+    test_add_backward_against_backward_then_add::<u32>()
+}
+
+#[test]
+fn test_add_backward_against_backward_then_add_u64() {
+    test_add_backward_against_backward_then_add::<u64>()
+}
+
+fn test_roundtrip_noise_bounds_observed_error<T>()
+where
+    T: UnsignedTorus + CastInto<u64> + CastFrom<u64>,
+{
+    use crate::math::decomposition::DecompositionBaseLog;
+    use crate::math::dispersion::DispersionParameter;
+    use crate::math::fft::estimate_roundtrip_noise;
+    use crate::math::ntt::Ntt;
+    use crate::math::random::fill_with_random_uniform;
+
+    for size in &[256, 1024, 2048] {
+        let size = PolynomialSize(*size);
+        let mut fft = Fft::new(size);
+        let ntt = Ntt::new(size);
+        for base_log in &[4, 8, 12] {
+            let noise = estimate_roundtrip_noise::<T>(size, DecompositionBaseLog(*base_log));
+            assert!(noise.max_error <= noise.bound);
+            assert!(noise.variance.get_standard_dev() <= noise.bound);
+
+            // The bound holds on other random products.
+            for _ in 0..10 {
+                let mut torus_poly = Polynomial::allocate(T::ZERO, size);
+                fill_with_random_uniform(&mut torus_poly);
+                let mut integer_poly = Polynomial::allocate(T::ZERO, size);
+                fill_with_random_uniform(&mut integer_poly);
+                integer_poly.as_mut_tensor().update_with(|x| {
+                    *x = (*x >> (T::BITS - base_log)).wrapping_sub(T::ONE << (base_log - 1))
+                });
+                let mut fourier_torus = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+                fft.forward_as_torus(&mut fourier_torus, &torus_poly);
+                let mut fourier_integer = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+                fft.forward_as_integer(&mut fourier_integer, &integer_poly);
+                let mut fourier_product = FourierPolynomial::allocate(Complex64::new(0., 0.), size);
+                fourier_product.update_with_multiply_accumulate(&fourier_torus, &fourier_integer);
+                let mut product = Polynomial::allocate(T::ZERO, size);
+                fft.add_backward_as_torus(&mut product, &mut fourier_product);
+                let mut expected = Polynomial::allocate(T::ZERO, size);
+                expected.fill_with_ntt_mul(&torus_poly, &integer_poly, &ntt);
+
+                for (coef, expected) in product.coefficient_iter().zip(expected.coefficient_iter())
+                {
+                    let error: f64 = coef.wrapping_sub(*expected).into_signed().cast_into();
+                    assert!(error.abs() * 2f64.powi(-(T::BITS as i32)) <= noise.bound);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_roundtrip_noise_bounds_observed_error_u32() {
+    test_roundtrip_noise_bounds_observed_error::<u32>()
+}
+
+#[test]
+fn test_roundtrip_noise_bounds_observed_error_u64() {
+    test_roundtrip_noise_bounds_observed_error::<u64>()
+}
@@ -1,32 +1,112 @@
 use std::slice;
+use std::sync::Arc;
 
 use fftw::array::AlignedVec;
 use fftw::plan::*;
-use fftw::types::{c64, Flag, Sign};
+use fftw::types::c64;
 
 use crate::crypto::UnsignedTorus;
 use crate::math::fft::twiddles::{BackwardCorrector, ForwardCorrector};
-use crate::math::polynomial::{Polynomial, PolynomialSize};
-use crate::math::tensor::{AsMutSlice, AsMutTensor, AsRefTensor};
+use crate::math::polynomial::{
+    InvalidSizeError, Polynomial, PolynomialList, PolynomialSize, ReductionMode,
+};
+use crate::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, MemoryFootprint};
 use crate::numeric::{CastInto, SignedInteger, UnsignedInteger};
-use crate::{ck_dim_eq, zip};
+use crate::{ck_dim_eq, zip, zip_args};
 
-use super::{Complex64, Correctors, FourierPolynomial};
+use super::{
+    Complex64, Correctors, FftBuffers, FftPlans, FourierPolynomial, FourierPolynomialList,
+    TwiddleFactors,
+};
+
+/// The smallest polynomial size supported by the transformers.
+const MIN_SIZE: usize = 256;
+
+/// The largest polynomial size supported by the transformers.
+///
+/// The twiddle factors are computed for any size, and this bound comes from fftw, which describes
+/// the sizes of the transforms with C `int`s: the unfolded transforms use $N$ points. It is not a
+/// precision bound: the rounding error of a product computed with `f64` grows linearly with the
+/// size and with the magnitude of the coefficients of both operands (see the bound of
+/// [`estimate_roundtrip_noise`](super::estimate_roundtrip_noise)). For a product of integer
+/// polynomials to be exact, $N \cdot \max |a\_i| \cdot \max |b\_i|$ must stay well below
+/// $2^{53}$, which the large sizes only allow for small coefficients.
+const MAX_SIZE: usize = 1 << 30;
 
 /// A fast fourier transformer.
 ///
 /// This transformer type allows to send polynomials of a fixed size, back and forth in the fourier
-/// domain.
+/// domain. The element-wise product of two fourier polynomials corresponds to the product of the
+/// polynomials, reduced modulo $(X^N+1)$, or $(X^N-1)$ if the transformer was created with
+/// [`Fft::new_with_reduction`] and [`ReductionMode::Cyclic`].
+///
+/// The negacyclic transformers returned by [`Fft::new`] fold the polynomials of size $N$ in
+/// complex polynomials of size $N/2$, whose coefficients are twisted by the powers of a $2N$-th
+/// root of unity, so that a single transform of $N/2$ points evaluates them on half of the
+/// $2N$-th roots of unity. The other evaluations are the conjugates of those, and are not needed
+/// to compute the products. The transformers returned by [`Fft::new_unfolded`] and
+/// [`Fft::new_with_twiddles`] use a transform of $N$ points instead. The fourier polynomials
+/// computed with a folded transformer can only be used with other folded transformers.
+///
+/// The fftw plans are shared by all the transformers of the same size created on a thread, see
+/// [`clear_fft_cache`](super::clear_fft_cache).
+///
+/// # Concurrency
+///
+/// A transformer is `Send` and `Sync`: it can be created on a thread and moved to a worker, for
+/// instance to run several bootstraps in parallel. Every transform borrows the transformer
+/// mutably, so a transformer is used by a single thread at a time, and each worker should own
+/// its transformer. Creating one transformer per worker is cheap once the plans of its size are
+/// in the cache of the worker thread. When a transformer is moved to another thread, it keeps
+/// the plans of the thread which created it, and the transforms executed with the same plans on
+/// both threads are serialized.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::fft::{Fft, FftBuffers};
+/// use concrete_core::math::polynomial::{MonomialDegree, Polynomial, PolynomialSize};
+/// let size = PolynomialSize(256);
+/// let workers: Vec<_> = (0..4)
+///     .map(|degree| {
+///         let mut fft = Fft::new(size);
+///         std::thread::spawn(move || {
+///             let mut buffers = FftBuffers::for_size(size);
+///             let mut poly = Polynomial::allocate(0u64, size);
+///             *poly.get_mut_monomial(MonomialDegree(0)).get_mut_coefficient() = 1;
+///             let mut monomial = Polynomial::allocate(0u64, size);
+///             *monomial.get_mut_monomial(MonomialDegree(degree)).get_mut_coefficient() = 1;
+///             fft.polynomial_mul_assign(&mut poly, &monomial, &mut buffers);
+///             poly
+///         })
+///     })
+///     .collect();
+/// for (degree, worker) in workers.into_iter().enumerate() {
+///     let poly = worker.join().unwrap();
+///     assert_eq!(*poly.get_monomial(MonomialDegree(degree)).get_coefficient(), 1);
+/// }
+/// ```
 pub struct Fft {
-    forward_plan: C2CPlan64,
-    backward_plan: C2CPlan64,
+    plans: Arc<FftPlans>,
     correctors: Correctors,
+    reduction: ReductionMode,
+    folded: bool,
+    poly_size: PolynomialSize,
     temporary: FourierPolynomial<AlignedVec<Complex64>>,
 }
 
+// The temporary buffer is owned by the transformer, and is only accessed through a mutable borrow
+// of it. The plans are `Send` and `Sync`, see `FftPlans`.
+unsafe impl Send for Fft {}
+unsafe impl Sync for Fft {}
+
 impl Fft {
     /// Generates a new transformer for polynomials a given size.
     ///
+    /// # Panics
+    ///
+    /// Panics if the size is not supported, see [`Fft::try_new`].
+    ///
     /// # Example
     ///
     /// ```
@@ -36,23 +116,190 @@ impl Fft {
     /// assert_eq!(fft.polynomial_size(), PolynomialSize(256));
     /// ```
     pub fn new(size: PolynomialSize) -> Fft {
-        debug_assert!(
-            [256, 512, 1024, 2048, 4096].contains(&size.0),
-            "The size chosen is not valid ({}). Should be 256, 512, 1024, 2048 or 4096",
-            size.0
-        );
-        let forward_plan = C2CPlan64::aligned(&[size.0], Sign::Forward, Flag::Measure).unwrap();
-        let backward_plan = C2CPlan64::aligned(&[size.0], Sign::Backward, Flag::Measure).unwrap();
-        let temporary = FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(size.0));
+        Fft::new_with_reduction(size, ReductionMode::Negacyclic)
+    }
+
+    /// Generates a new transformer for polynomials a given size, or returns an error if the size
+    /// is not supported.
+    ///
+    /// The size must be a power of two between 256 and $2^{30}$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::Fft;
+    /// use concrete_core::math::polynomial::{InvalidSizeError, PolynomialSize};
+    /// let fft = Fft::try_new(PolynomialSize(1024)).unwrap();
+    /// assert_eq!(fft.polynomial_size(), PolynomialSize(1024));
+    /// assert!(matches!(
+    ///     Fft::try_new(PolynomialSize(1000)),
+    ///     Err(InvalidSizeError::NotPowerOfTwo { size: 1000 })
+    /// ));
+    /// assert!(matches!(
+    ///     Fft::try_new(PolynomialSize(128)),
+    ///     Err(InvalidSizeError::TooSmall { size: 128, min: 256 })
+    /// ));
+    /// assert!(matches!(
+    ///     Fft::try_new(PolynomialSize(1 << 31)),
+    ///     Err(InvalidSizeError::TooLarge { size: 2147483648, max: 1073741824 })
+    /// ));
+    /// ```
+    pub fn try_new(size: PolynomialSize) -> Result<Fft, InvalidSizeError> {
+        Fft::try_new_with_reduction(size, ReductionMode::Negacyclic)
+    }
+
+    /// Generates a new transformer for polynomials a given size, whose products are reduced
+    /// according to the `reduction` mode, or returns an error if the size is not supported.
+    ///
+    /// The supported sizes are the same for both reductions, see [`Fft::try_new`].
+    pub fn try_new_with_reduction(
+        size: PolynomialSize,
+        reduction: ReductionMode,
+    ) -> Result<Fft, InvalidSizeError> {
+        let size = validated_size(size, MIN_SIZE, MAX_SIZE)?;
+        match reduction {
+            ReductionMode::Negacyclic => {
+                let correctors = Correctors::new_folded(size.0);
+                Ok(Fft::with_correctors(size, reduction, correctors, true))
+            }
+            ReductionMode::Cyclic => {
+                let correctors = Correctors::new_cyclic(size.0);
+                Ok(Fft::with_correctors(size, reduction, correctors, false))
+            }
+        }
+    }
+
+    /// Generates a new negacyclic transformer for polynomials of a given size, which uses a
+    /// transform of $N$ points instead of folding the polynomials.
+    ///
+    /// The products computed with this transformer are the same as the ones computed with the
+    /// transformer returned by [`Fft::new`], up to rounding errors, but the fourier polynomials
+    /// are not.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size is not a power of two between 256 and $2^{30}$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::Fft;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let fft = Fft::new_unfolded(PolynomialSize(256));
+    /// assert!(!fft.is_folded());
+    /// assert!(Fft::new(PolynomialSize(256)).is_folded());
+    /// ```
+    pub fn new_unfolded(size: PolynomialSize) -> Fft {
+        let size =
+            validated_size(size, MIN_SIZE, MAX_SIZE).unwrap_or_else(|error| panic!("{}", error));
         let correctors = Correctors::new(size.0);
+        Fft::with_correctors(size, ReductionMode::Negacyclic, correctors, false)
+    }
+
+    /// Generates a new transformer for polynomials a given size, whose products are reduced
+    /// according to the `reduction` mode.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size is not supported, see [`Fft::try_new`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::{Complex64, Fft, FourierPolynomial};
+    /// use concrete_core::math::polynomial::{
+    ///     MonomialDegree, Polynomial, PolynomialSize, ReductionMode,
+    /// };
+    /// let mut fft = Fft::new_with_reduction(PolynomialSize(256), ReductionMode::Cyclic);
+    /// assert_eq!(fft.reduction_mode(), ReductionMode::Cyclic);
+    /// // We compute the product of X^255 by 2X, which is equal to 2 modulo X^256 - 1.
+    /// let mut lhs = Polynomial::allocate(0u32, PolynomialSize(256));
+    /// *lhs.get_mut_monomial(MonomialDegree(255)).get_mut_coefficient() = 1;
+    /// let mut rhs = Polynomial::allocate(0u32, PolynomialSize(256));
+    /// *rhs.get_mut_monomial(MonomialDegree(1)).get_mut_coefficient() = 2;
+    /// let mut fourier_lhs = FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(256));
+    /// let mut fourier_rhs = FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(256));
+    /// fft.forward_two_as_integer(&mut fourier_lhs, &mut fourier_rhs, &lhs, &rhs);
+    /// let mut fourier_res = FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(256));
+    /// fourier_res.update_with_multiply_accumulate(&fourier_lhs, &fourier_rhs);
+    /// let mut res = Polynomial::allocate(0u32, PolynomialSize(256));
+    /// fft.add_backward_as_integer(&mut res, &mut fourier_res);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 2);
+    /// assert!(res.coefficient_iter().skip(1).all(|c| *c == 0));
+    /// ```
+    pub fn new_with_reduction(size: PolynomialSize, reduction: ReductionMode) -> Fft {
+        Fft::try_new_with_reduction(size, reduction).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn with_correctors(
+        size: PolynomialSize,
+        reduction: ReductionMode,
+        correctors: Correctors,
+        folded: bool,
+    ) -> Fft {
+        let transform_size = if folded {
+            PolynomialSize(size.0 / 2)
+        } else {
+            size
+        };
+        let plans = FftPlans::get(transform_size);
+        let temporary = FourierPolynomial::allocate(Complex64::new(0., 0.), transform_size);
         Fft {
-            forward_plan,
-            backward_plan,
+            plans,
             correctors,
+            reduction,
+            folded,
+            poly_size: size,
             temporary,
         }
     }
 
+    /// Generates a new transformer for polynomials of a given size, whose products are reduced
+    /// modulo $(X^N+1)$, using externally provided twiddle factors.
+    ///
+    /// The factors of the backward transform are deduced from `twiddles`. With the factors
+    /// returned by [`TwiddleFactors::precompute`], the transformer is equivalent to the one
+    /// returned by [`Fft::new_unfolded`], up to rounding errors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size is not supported, see [`Fft::try_new`], or if the number of twiddle
+    /// factors differs from `size`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::{Fft, TwiddleFactors};
+    /// use concrete_core::math::polynomial::{PolynomialSize, ReductionMode};
+    /// let twiddles = TwiddleFactors::precompute(PolynomialSize(256));
+    /// let fft = Fft::new_with_twiddles(PolynomialSize(256), &twiddles);
+    /// assert_eq!(fft.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(fft.reduction_mode(), ReductionMode::Negacyclic);
+    /// ```
+    pub fn new_with_twiddles<Cont>(size: PolynomialSize, twiddles: &TwiddleFactors<Cont>) -> Fft
+    where
+        TwiddleFactors<Cont>: AsRefTensor<Element = Complex64>,
+    {
+        let size =
+            validated_size(size, MIN_SIZE, MAX_SIZE).unwrap_or_else(|error| panic!("{}", error));
+        let correctors = Correctors::from_twiddles(size.0, twiddles);
+        Fft::with_correctors(size, ReductionMode::Negacyclic, correctors, false)
+    }
+
+    /// Returns the reduction applied to the products computed with this transformer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::Fft;
+    /// use concrete_core::math::polynomial::{PolynomialSize, ReductionMode};
+    /// let fft = Fft::new(PolynomialSize(256));
+    /// assert_eq!(fft.reduction_mode(), ReductionMode::Negacyclic);
+    /// ```
+    pub fn reduction_mode(&self) -> ReductionMode {
+        self.reduction
+    }
+
     /// Returns the polynomial size accepted by this transformer.
     ///
     /// # Example
@@ -64,7 +311,21 @@ impl Fft {
     /// assert_eq!(fft.polynomial_size(), PolynomialSize(256));
     /// ```
     pub fn polynomial_size(&self) -> PolynomialSize {
-        self.temporary.polynomial_size()
+        self.poly_size
+    }
+
+    /// Returns whether the polynomials are folded before being transformed, see [`Fft`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::Fft;
+    /// use concrete_core::math::polynomial::{PolynomialSize, ReductionMode};
+    /// assert!(Fft::new(PolynomialSize(8192)).is_folded());
+    /// assert!(!Fft::new_with_reduction(PolynomialSize(256), ReductionMode::Cyclic).is_folded());
+    /// ```
+    pub fn is_folded(&self) -> bool {
+        self.folded
     }
 
     /// Performs the forward fourier transform of the `poly` polynomial, viewed as a polynomial of
@@ -72,9 +333,10 @@ impl Fft {
     ///
     /// # Note
     ///
-    /// It should be noted that this method is subotpimal, as it only uses half of the computational
-    /// power of the transformer. For a faster approach, you should consider processing the
-    /// polynomials two by two with the [`Fft::forward_two_as_torus`] method.
+    /// For the transformers which do not fold the polynomials, it should be noted that this
+    /// method is subotpimal, as it only uses half of the computational power of the transformer.
+    /// For a faster approach, you should consider processing the polynomials two by two with the
+    /// [`Fft::forward_two_as_torus`] method.
     ///
     /// # Example
     ///
@@ -108,7 +370,11 @@ impl Fft {
         Polynomial<InCont>: AsRefTensor<Element = Coef>,
         Coef: UnsignedTorus,
     {
-        self.forward(fourier_poly, poly, regular_convert_forward_single_torus);
+        if self.folded {
+            self.forward_folded(fourier_poly, poly, torus_to_f64);
+        } else {
+            self.forward(fourier_poly, poly, regular_convert_forward_single_torus);
+        }
     }
 
     /// Performs the forward fourier transform of the `poly_1` and `poly_2` polynomials, viewed
@@ -174,13 +440,18 @@ impl Fft {
             fourier_poly_2.polynomial_size().0,
             poly_2.polynomial_size().0
         );
-        self.forward_two(
-            fourier_poly_1,
-            fourier_poly_2,
-            poly_1,
-            poly_2,
-            regular_convert_forward_two_torus,
-        );
+        if self.folded {
+            self.forward_folded(fourier_poly_1, poly_1, torus_to_f64);
+            self.forward_folded(fourier_poly_2, poly_2, torus_to_f64);
+        } else {
+            self.forward_two(
+                fourier_poly_1,
+                fourier_poly_2,
+                poly_1,
+                poly_2,
+                regular_convert_forward_two_torus,
+            );
+        }
     }
 
     /// Performs the forward fourier transform of the `poly` polynomial, viewed as a polynomial of
@@ -188,9 +459,10 @@ impl Fft {
     ///
     /// # Note
     ///
-    /// It should be noted that this method is subotpimal, as it only uses half of the computational
-    /// power of the transformer. For a faster approach, you should consider processing the
-    /// polynomials two by two with the [`Fft::forward_two_as_integer`] method.
+    /// For the transformers which do not fold the polynomials, it should be noted that this
+    /// method is subotpimal, as it only uses half of the computational power of the transformer.
+    /// For a faster approach, you should consider processing the polynomials two by two with the
+    /// [`Fft::forward_two_as_integer`] method.
     ///
     /// # Example
     ///
@@ -224,7 +496,11 @@ impl Fft {
         Polynomial<InCont>: AsRefTensor<Element = Coef>,
         Coef: UnsignedInteger,
     {
-        self.forward(fourier_poly, poly, regular_convert_forward_single_integer);
+        if self.folded {
+            self.forward_folded(fourier_poly, poly, integer_to_f64);
+        } else {
+            self.forward(fourier_poly, poly, regular_convert_forward_single_integer);
+        }
     }
 
     /// Performs the forward fourier transform of the `poly_1` and `poly_2` polynomials, viewed
@@ -289,25 +565,35 @@ impl Fft {
             fourier_poly_2.polynomial_size().0,
             poly_2.polynomial_size().0
         );
-        self.forward_two(
-            fourier_poly_1,
-            fourier_poly_2,
-            poly_1,
-            poly_2,
-            regular_convert_forward_two_integer,
-        );
+        if self.folded {
+            self.forward_folded(fourier_poly_1, poly_1, integer_to_f64);
+            self.forward_folded(fourier_poly_2, poly_2, integer_to_f64);
+        } else {
+            self.forward_two(
+                fourier_poly_1,
+                fourier_poly_2,
+                poly_1,
+                poly_2,
+                regular_convert_forward_two_integer,
+            );
+        }
     }
 
     /// Performs the backward fourier transform of the `fourier_poly` polynomial, viewed as a
     /// polynomial of torus coefficients, and adds the result to `poly`.
     ///
+    /// The rounded coefficients are added to the ones of `poly` with wrapping additions, as they
+    /// are computed. The result of an external product can thus be accumulated directly in the
+    /// body of a GLWE ciphertext, without going through a temporary polynomial.
+    ///
     /// See [`Fft::forward_as_torus`] for an example.
     ///
     /// # Note
     ///
-    /// It should be noted that this method is subotpimal, as it only uses half of the computational
-    /// power of the transformer. For a faster approach, you should consider processing the
-    /// polynomials two by two with the [`Fft::add_backward_two_as_torus`] method.
+    /// For the transformers which do not fold the polynomials, it should be noted that this
+    /// method is subotpimal, as it only uses half of the computational power of the transformer.
+    /// For a faster approach, you should consider processing the polynomials two by two with the
+    /// [`Fft::add_backward_two_as_torus`] method.
     pub fn add_backward_as_torus<OutCont, InCont, Coef>(
         &mut self,
         poly: &mut Polynomial<OutCont>,
@@ -318,11 +604,13 @@ impl Fft {
         Coef: UnsignedTorus,
     {
         ck_dim_eq!(self.polynomial_size().0 => fourier_poly.polynomial_size().0, poly.polynomial_size().0);
-        self.backward(
-            poly,
-            fourier_poly,
-            regular_convert_add_backward_single_torus,
-        );
+        if self.folded {
+            self.backward_folded(poly, fourier_poly, f64_to_torus);
+        } else {
+            self.backward(poly, fourier_poly, |poly, fourier_poly, corrector| {
+                regular_convert_add_backward_single(poly, fourier_poly, corrector, f64_to_torus)
+            });
+        }
     }
 
     /// Performs the backward fourier transform of the `fourier_poly` polynomial, viewed as a
@@ -332,9 +620,10 @@ impl Fft {
     ///
     /// # Note
     ///
-    /// It should be noted that this method is subotpimal, as it only uses half of the computational
-    /// power of the transformer. For a faster approach, you should consider processing the
-    /// polynomials two by two with the [`Fft::add_backward_two_as_integer`] method.
+    /// For the transformers which do not fold the polynomials, it should be noted that this
+    /// method is subotpimal, as it only uses half of the computational power of the transformer.
+    /// For a faster approach, you should consider processing the polynomials two by two with the
+    /// [`Fft::add_backward_two_as_integer`] method.
     pub fn add_backward_as_integer<OutCont, InCont, Coef>(
         &mut self,
         poly: &mut Polynomial<OutCont>,
@@ -345,11 +634,13 @@ impl Fft {
         Coef: UnsignedInteger,
     {
         ck_dim_eq!(self.polynomial_size().0 => fourier_poly.polynomial_size().0, poly.polynomial_size().0);
-        self.backward(
-            poly,
-            fourier_poly,
-            regular_convert_add_backward_single_integer,
-        );
+        if self.folded {
+            self.backward_folded(poly, fourier_poly, f64_to_integer);
+        } else {
+            self.backward(poly, fourier_poly, |poly, fourier_poly, corrector| {
+                regular_convert_add_backward_single(poly, fourier_poly, corrector, f64_to_integer)
+            });
+        }
     }
 
     /// Performs the backward fourier transform of the `fourier_poly_1` and `fourier_poly_2`
@@ -376,13 +667,26 @@ impl Fft {
             fourier_poly_2.polynomial_size().0,
             poly_2.polynomial_size().0
         );
-        self.backward_two(
-            poly_1,
-            poly_2,
-            fourier_poly_1,
-            fourier_poly_2,
-            regular_convert_add_backward_two_torus,
-        );
+        if self.folded {
+            self.backward_folded(poly_1, fourier_poly_1, f64_to_torus);
+            self.backward_folded(poly_2, fourier_poly_2, f64_to_torus);
+        } else {
+            self.backward_two(
+                poly_1,
+                poly_2,
+                fourier_poly_1,
+                fourier_poly_2,
+                |poly_1, poly_2, fourier_poly, corrector| {
+                    regular_convert_add_backward_two(
+                        poly_1,
+                        poly_2,
+                        fourier_poly,
+                        corrector,
+                        f64_to_torus,
+                    )
+                },
+            );
+        }
     }
 
     /// Performs the backward fourier transform of the `fourier_poly_1` and `fourier_poly_2`
@@ -409,13 +713,173 @@ impl Fft {
             fourier_poly_2.polynomial_size().0,
             poly_2.polynomial_size().0
         );
-        self.backward_two(
-            poly_1,
-            poly_2,
-            fourier_poly_1,
-            fourier_poly_2,
-            regular_convert_add_backward_two_integer,
+        if self.folded {
+            self.backward_folded(poly_1, fourier_poly_1, f64_to_integer);
+            self.backward_folded(poly_2, fourier_poly_2, f64_to_integer);
+        } else {
+            self.backward_two(
+                poly_1,
+                poly_2,
+                fourier_poly_1,
+                fourier_poly_2,
+                |poly_1, poly_2, fourier_poly, corrector| {
+                    regular_convert_add_backward_two(
+                        poly_1,
+                        poly_2,
+                        fourier_poly,
+                        corrector,
+                        f64_to_integer,
+                    )
+                },
+            );
+        }
+    }
+
+    /// Performs the forward fourier transform of every polynomial of `list`, viewed as
+    /// polynomials of torus coefficients, and stores the results in `fourier_list`.
+    ///
+    /// The polynomials are transformed two by two, as with [`Fft::forward_two_as_torus`], and
+    /// the last one alone if their number is odd. Every fourier polynomial of the output is the
+    /// transform of the polynomial of the same index, and can be sent back on its own with
+    /// [`Fft::add_backward_as_torus`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::{Complex64, Fft, FourierPolynomialList};
+    /// use concrete_core::math::polynomial::{PolynomialCount, PolynomialList, PolynomialSize};
+    /// use concrete_core::math::random::fill_with_random_uniform;
+    /// let mut fft = Fft::new(PolynomialSize(256));
+    /// let mut list = PolynomialList::allocate(0u32, PolynomialCount(3), PolynomialSize(256));
+    /// fill_with_random_uniform(&mut list);
+    /// let mut fourier_list = FourierPolynomialList::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     PolynomialCount(3),
+    ///     PolynomialSize(256),
+    /// );
+    /// fft.forward_as_torus_list(&mut fourier_list, &list);
+    /// let mut out = PolynomialList::allocate(0u32, PolynomialCount(3), PolynomialSize(256));
+    /// fft.add_backward_as_torus_list(&mut out, &mut fourier_list);
+    /// assert!(out == list);
+    /// ```
+    pub fn forward_as_torus_list<OutCont, InCont, Coef>(
+        &mut self,
+        fourier_list: &mut FourierPolynomialList<OutCont>,
+        list: &PolynomialList<InCont>,
+    ) where
+        FourierPolynomialList<OutCont>: AsMutTensor<Element = Complex64>,
+        PolynomialList<InCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedTorus,
+    {
+        ck_dim_eq!(self.polynomial_size().0 =>
+            fourier_list.polynomial_size().0,
+            list.polynomial_size().0
         );
+        ck_dim_eq!(fourier_list.polynomial_count().0 => list.polynomial_count().0);
+        let mut polys = fourier_list
+            .polynomial_iter_mut()
+            .zip(list.polynomial_iter());
+        while let Some((mut fourier_poly_1, poly_1)) = polys.next() {
+            match polys.next() {
+                Some((mut fourier_poly_2, poly_2)) => self.forward_two_as_torus(
+                    &mut fourier_poly_1,
+                    &mut fourier_poly_2,
+                    &poly_1,
+                    &poly_2,
+                ),
+                None => self.forward_as_torus(&mut fourier_poly_1, &poly_1),
+            }
+        }
+    }
+
+    /// Performs the backward fourier transform of every polynomial of `fourier_list`, viewed as
+    /// polynomials of torus coefficients, and adds the results to the polynomials of `list`.
+    ///
+    /// The polynomials are transformed two by two, as with [`Fft::add_backward_two_as_torus`].
+    /// See [`Fft::forward_as_torus_list`] for an example.
+    pub fn add_backward_as_torus_list<OutCont, InCont, Coef>(
+        &mut self,
+        list: &mut PolynomialList<OutCont>,
+        fourier_list: &mut FourierPolynomialList<InCont>,
+    ) where
+        PolynomialList<OutCont>: AsMutTensor<Element = Coef>,
+        FourierPolynomialList<InCont>: AsMutTensor<Element = Complex64>,
+        Coef: UnsignedTorus,
+    {
+        ck_dim_eq!(self.polynomial_size().0 =>
+            fourier_list.polynomial_size().0,
+            list.polynomial_size().0
+        );
+        ck_dim_eq!(fourier_list.polynomial_count().0 => list.polynomial_count().0);
+        let mut polys = list
+            .polynomial_iter_mut()
+            .zip(fourier_list.polynomial_iter_mut());
+        while let Some((mut poly_1, mut fourier_poly_1)) = polys.next() {
+            match polys.next() {
+                Some((mut poly_2, mut fourier_poly_2)) => self.add_backward_two_as_torus(
+                    &mut poly_1,
+                    &mut poly_2,
+                    &mut fourier_poly_1,
+                    &mut fourier_poly_2,
+                ),
+                None => self.add_backward_as_torus(&mut poly_1, &mut fourier_poly_1),
+            }
+        }
+    }
+
+    /// Multiplies the `lhs` polynomial by the `rhs` polynomial, viewed as polynomials of integer
+    /// coefficients and reduced according to the mode of the transformer, and stores the result
+    /// in `lhs`.
+    ///
+    /// The fourier polynomials needed by the product are borrowed from `buffers` instead of being
+    /// allocated, see [`FftBuffers`]. The buffers are expected to have the size of the
+    /// transformer, which is only checked in debug builds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::{Fft, FftBuffers};
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial, PolynomialSize};
+    /// let mut fft = Fft::new(PolynomialSize(256));
+    /// let mut buffers = FftBuffers::for_size(PolynomialSize(256));
+    /// let mut lhs = Polynomial::allocate(0u32, PolynomialSize(256));
+    /// *lhs.get_mut_monomial(MonomialDegree(255)).get_mut_coefficient() = 3;
+    /// let mut rhs = Polynomial::allocate(0u32, PolynomialSize(256));
+    /// *rhs.get_mut_monomial(MonomialDegree(2)).get_mut_coefficient() = 5;
+    ///
+    /// // 3X^255 * 5X^2 = -15X modulo X^256 + 1
+    /// fft.polynomial_mul_assign(&mut lhs, &rhs, &mut buffers);
+    /// assert_eq!(
+    ///     *lhs.get_monomial(MonomialDegree(1)).get_coefficient(),
+    ///     0u32.wrapping_sub(15)
+    /// );
+    /// ```
+    pub fn polynomial_mul_assign<LhsCont, RhsCont, Coef>(
+        &mut self,
+        lhs: &mut Polynomial<LhsCont>,
+        rhs: &Polynomial<RhsCont>,
+        buffers: &mut FftBuffers,
+    ) where
+        Polynomial<LhsCont>: AsMutTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.polynomial_size().0 => lhs.polynomial_size().0, rhs.polynomial_size().0);
+        debug_assert_eq!(
+            buffers.polynomial_size(),
+            self.polynomial_size(),
+            "The buffers do not have the size of the transformer."
+        );
+        self.forward_two_as_integer(&mut buffers.fourier_lhs, &mut buffers.fourier_rhs, lhs, rhs);
+        buffers
+            .fourier_product
+            .as_mut_tensor()
+            .fill_with_element(Complex64::new(0., 0.));
+        buffers
+            .fourier_product
+            .update_with_multiply_accumulate(&buffers.fourier_lhs, &buffers.fourier_rhs);
+        lhs.as_mut_tensor().fill_with_element(Coef::ZERO);
+        self.add_backward_as_integer(lhs, &mut buffers.fourier_product);
     }
 
     pub(super) fn forward<OutCont, InCont, Coef>(
@@ -425,24 +889,33 @@ impl Fft {
         convert_function: impl Fn(
             &mut FourierPolynomial<AlignedVec<Complex64>>,
             &Polynomial<InCont>,
-            &ForwardCorrector<&'static [Complex64]>,
+            &ForwardCorrector<Vec<Complex64>>,
         ),
     ) where
         Polynomial<InCont>: AsRefTensor<Element = Coef>,
         FourierPolynomial<OutCont>: AsMutTensor<Element = Complex64>,
     {
+        #[cfg(feature = "metrics")]
+        let _timer = crate::metrics::FFT.start();
         ck_dim_eq!(self.polynomial_size().0 => fourier_poly.polynomial_size().0, poly.polynomial_size().0);
 
         // We convert the data to real and fill the temporary buffer
         convert_function(&mut self.temporary, &poly, &self.correctors.forward);
 
         // We perform the forward fft
-        self.forward_plan
+        self.plans
+            .forward
+            .lock()
+            .unwrap()
             .c2c(
                 &mut self.temporary.as_mut_tensor().as_mut_slice(),
                 &mut fourier_poly.as_mut_tensor().as_mut_slice(),
             )
             .expect("forward: fft.c2c threw an error...");
+
+        if self.reduction == ReductionMode::Cyclic {
+            to_half_spectrum_order(fourier_poly.as_mut_tensor().as_mut_slice());
+        }
     }
 
     pub(super) fn forward_two<InCont1, InCont2, OutCont1, OutCont2, Coef>(
@@ -455,7 +928,7 @@ impl Fft {
             &mut FourierPolynomial<AlignedVec<Complex64>>,
             &Polynomial<InCont1>,
             &Polynomial<InCont2>,
-            &ForwardCorrector<&'static [Complex64]>,
+            &ForwardCorrector<Vec<Complex64>>,
         ),
     ) where
         Polynomial<InCont1>: AsRefTensor<Element = Coef>,
@@ -463,6 +936,8 @@ impl Fft {
         FourierPolynomial<OutCont1>: AsMutTensor<Element = Complex64>,
         FourierPolynomial<OutCont2>: AsMutTensor<Element = Complex64>,
     {
+        #[cfg(feature = "metrics")]
+        let _timer = crate::metrics::FFT.start();
         ck_dim_eq!(self.polynomial_size().0 =>
             fourier_poly_1.polynomial_size().0,
             poly_1.polynomial_size().0,
@@ -478,19 +953,39 @@ impl Fft {
         );
 
         // We perform the forward on the first fourier polynomial.
-        self.forward_plan
+        self.plans
+            .forward
+            .lock()
+            .unwrap()
             .c2c(
                 &mut self.temporary.as_mut_tensor().as_mut_slice(),
                 &mut fourier_poly_1.as_mut_tensor().as_mut_slice(),
             )
             .expect("forward_two: fft.c2c threw an error...");
 
+        if self.reduction == ReductionMode::Cyclic {
+            to_half_spectrum_order(fourier_poly_1.as_mut_tensor().as_mut_slice());
+        }
+        let fp1 = fourier_poly_1.as_tensor().as_slice();
+        let (first_root, second_root) = (fp1[0], fp1[1]);
+
         // We replicate the coefficients on the second fourier polynomial.
         replicate_coefficients(
             &mut fourier_poly_1.as_mut_tensor().as_mut_slice(),
             &mut fourier_poly_2.as_mut_tensor().as_mut_slice(),
             self.polynomial_size().0,
         );
+
+        // In the cyclic case, the two first coefficients are evaluations on the real roots 1 and
+        // -1, which are their own conjugates.
+        if self.reduction == ReductionMode::Cyclic {
+            let fp1 = fourier_poly_1.as_mut_tensor().as_mut_slice();
+            fp1[0] = Complex64::new(first_root.re, 0.);
+            fp1[1] = Complex64::new(second_root.re, 0.);
+            let fp2 = fourier_poly_2.as_mut_tensor().as_mut_slice();
+            fp2[0] = Complex64::new(first_root.im, 0.);
+            fp2[1] = Complex64::new(second_root.im, 0.);
+        }
     }
 
     pub(super) fn backward<OutCont, InCont, Coef>(
@@ -500,21 +995,29 @@ impl Fft {
         convert_function: impl Fn(
             &mut Polynomial<OutCont>,
             &FourierPolynomial<AlignedVec<Complex64>>,
-            &BackwardCorrector<&'static [Complex64]>,
+            &BackwardCorrector<Vec<Complex64>>,
         ),
     ) where
         Polynomial<OutCont>: AsMutTensor<Element = Coef>,
         FourierPolynomial<InCont>: AsMutTensor<Element = Complex64>,
     {
+        #[cfg(feature = "metrics")]
+        let _timer = crate::metrics::FFT.start();
         // We propagate the values to their conjugates that were not computed.
         let mut first_view = fourier_poly.as_mut_tensor().as_mut_slice();
         let (b_first, b_second) = split_in_imut_mut(&mut first_view, self.polynomial_size().0);
         for (fft_bj, rot_fft_bj) in zip!(b_first.iter(), b_second.iter_mut().rev()) {
             *rot_fft_bj = fft_bj.conj();
         }
+        if self.reduction == ReductionMode::Cyclic {
+            from_half_spectrum_order(fourier_poly.as_mut_tensor().as_mut_slice());
+        }
 
         // We perform the backward fft
-        self.backward_plan
+        self.plans
+            .backward
+            .lock()
+            .unwrap()
             .c2c(
                 &mut fourier_poly.as_mut_tensor().as_mut_slice(),
                 &mut self.temporary.as_mut_tensor().as_mut_slice(),
@@ -535,7 +1038,7 @@ impl Fft {
             &mut Polynomial<OutCont1>,
             &mut Polynomial<OutCont2>,
             &FourierPolynomial<AlignedVec<Complex64>>,
-            &BackwardCorrector<&'static [Complex64]>,
+            &BackwardCorrector<Vec<Complex64>>,
         ),
     ) where
         Polynomial<OutCont1>: AsMutTensor<Element = Coef>,
@@ -543,6 +1046,8 @@ impl Fft {
         FourierPolynomial<InCont1>: AsMutTensor<Element = Complex64>,
         FourierPolynomial<InCont2>: AsMutTensor<Element = Complex64>,
     {
+        #[cfg(feature = "metrics")]
+        let _timer = crate::metrics::FFT.start();
         ck_dim_eq!(self.polynomial_size().0 =>
             fourier_poly_1.polynomial_size().0,
             poly_1.polynomial_size().0,
@@ -569,9 +1074,15 @@ impl Fft {
             *fft_aj = Complex64::new(fft_aj.re - fft_bj.im, fft_aj.im + fft_bj.re);
             *rot_fft_aj = Complex64::new(re + fft_bj.im, -im + fft_bj.re);
         }
+        if self.reduction == ReductionMode::Cyclic {
+            from_half_spectrum_order(fourier_poly_1.as_mut_tensor().as_mut_slice());
+        }
 
         // We perform the backward fft
-        self.backward_plan
+        self.plans
+            .backward
+            .lock()
+            .unwrap()
             .c2c(
                 &mut fourier_poly_1.as_mut_tensor().as_mut_slice(),
                 &mut self.temporary.as_mut_tensor().as_mut_slice(),
@@ -580,6 +1091,95 @@ impl Fft {
 
         convert_function(poly_1, poly_2, &self.temporary, &self.correctors.backward)
     }
+
+    fn forward_folded<OutCont, InCont, Coef>(
+        &mut self,
+        fourier_poly: &mut FourierPolynomial<OutCont>,
+        poly: &Polynomial<InCont>,
+        convert: impl Fn(Coef) -> f64,
+    ) where
+        Polynomial<InCont>: AsRefTensor<Element = Coef>,
+        FourierPolynomial<OutCont>: AsMutTensor<Element = Complex64>,
+        Coef: Copy,
+    {
+        #[cfg(feature = "metrics")]
+        let _timer = crate::metrics::FFT.start();
+        ck_dim_eq!(self.polynomial_size().0 => fourier_poly.polynomial_size().0, poly.polynomial_size().0);
+        let half = self.temporary.as_tensor().len();
+
+        // We fold the coefficients of degree j and j + N/2 in the real and imaginary parts of the
+        // j-th coefficient, and twist it.
+        let (first, second) = poly.as_tensor().as_slice().split_at(half);
+        for zip_args!(output, re, im, twist) in zip!(
+            self.temporary.as_mut_tensor().iter_mut(),
+            first.iter(),
+            second.iter(),
+            self.correctors.forward.as_tensor().iter()
+        ) {
+            *output = Complex64::new(convert(*re), convert(*im)) * twist;
+        }
+
+        self.plans
+            .forward
+            .lock()
+            .unwrap()
+            .c2c(
+                self.temporary.as_mut_tensor().as_mut_slice(),
+                &mut fourier_poly.as_mut_tensor().as_mut_slice()[..half],
+            )
+            .expect("forward_folded: fft.c2c threw an error...");
+    }
+
+    fn backward_folded<OutCont, InCont, Coef>(
+        &mut self,
+        poly: &mut Polynomial<OutCont>,
+        fourier_poly: &mut FourierPolynomial<InCont>,
+        convert: impl Fn(f64) -> Coef,
+    ) where
+        Polynomial<OutCont>: AsMutTensor<Element = Coef>,
+        FourierPolynomial<InCont>: AsMutTensor<Element = Complex64>,
+        Coef: UnsignedInteger,
+    {
+        #[cfg(feature = "metrics")]
+        let _timer = crate::metrics::FFT.start();
+        ck_dim_eq!(self.polynomial_size().0 => fourier_poly.polynomial_size().0, poly.polynomial_size().0);
+        let half = self.temporary.as_tensor().len();
+
+        self.plans
+            .backward
+            .lock()
+            .unwrap()
+            .c2c(
+                &mut fourier_poly.as_mut_tensor().as_mut_slice()[..half],
+                self.temporary.as_mut_tensor().as_mut_slice(),
+            )
+            .expect("backward_folded: fft.c2c threw an error...");
+
+        // We untwist the coefficients, and unfold them in the two halves of the polynomial.
+        let (first, second) = poly.as_mut_tensor().as_mut_slice().split_at_mut(half);
+        for zip_args!(input, untwist, re, im) in zip!(
+            self.temporary.as_tensor().iter(),
+            self.correctors.backward.as_tensor().iter(),
+            first.iter_mut(),
+            second.iter_mut()
+        ) {
+            let interm = input * untwist;
+            *re = re.wrapping_add(convert(interm.re));
+            *im = im.wrapping_add(convert(interm.im));
+        }
+    }
+}
+
+impl MemoryFootprint for Fft {
+    /// Returns the size of the temporary fourier polynomial and of the correcting factors owned by
+    /// the transformer.
+    ///
+    /// The fftw plans are allocated by fftw, hence they are not accounted for.
+    fn heap_size(&self) -> usize {
+        self.temporary.heap_size()
+            + self.correctors.forward.as_tensor().heap_size()
+            + self.correctors.backward.as_tensor().heap_size()
+    }
 }
 
 fn split_in_mut_imut(sli: &mut [Complex64], big_n: usize) -> (&mut [Complex64], &[Complex64]) {
@@ -623,6 +1223,22 @@ fn split_in_mut_mut(s: &mut [Complex64], big_n: usize) -> (&mut [Complex64], &mu
     }
 }
 
+// The negacyclic transform evaluates the polynomials on the primitive $2N$-th roots of unity,
+// and the fourier polynomials only store the evaluations on half of those roots, the others being
+// their conjugates. In the cyclic case, the polynomials are evaluated on the $N$-th roots of unity
+// instead, and we reorder the evaluations so that a root and its conjugate are placed at the same
+// positions as in the negacyclic case. The evaluations on the real roots 1 and -1 are placed at
+// the positions 0 and 1, which allows to use the same element-wise operations for both modes.
+fn to_half_spectrum_order(fft: &mut [Complex64]) {
+    let big_n = fft.len();
+    fft[1..=big_n / 2].rotate_right(1);
+}
+
+fn from_half_spectrum_order(fft: &mut [Complex64]) {
+    let big_n = fft.len();
+    fft[1..=big_n / 2].rotate_left(1);
+}
+
 fn replicate_coefficients(fft_a: &mut [Complex64], fft_b: &mut [Complex64], big_n: usize) {
     // in fft_a there is FFT(coeff_a + i coeff_b) we now extract
     // the fourier transfform of coeff_a and of coeff_b using the fact
@@ -657,7 +1273,7 @@ fn replicate_coefficients(fft_a: &mut [Complex64], fft_b: &mut [Complex64], big_
 fn regular_convert_forward_single_torus<InCont, Coef>(
     out: &mut FourierPolynomial<AlignedVec<Complex64>>,
     inp: &Polynomial<InCont>,
-    corr: &ForwardCorrector<&'static [Complex64]>,
+    corr: &ForwardCorrector<Vec<Complex64>>,
 ) where
     Polynomial<InCont>: AsRefTensor<Element = Coef>,
     Coef: UnsignedTorus,
@@ -676,7 +1292,7 @@ fn regular_convert_forward_two_torus<InCont1, InCont2, Coef>(
     out: &mut FourierPolynomial<AlignedVec<Complex64>>,
     inp1: &Polynomial<InCont1>,
     inp2: &Polynomial<InCont2>,
-    corr: &ForwardCorrector<&'static [Complex64]>,
+    corr: &ForwardCorrector<Vec<Complex64>>,
 ) where
     Polynomial<InCont1>: AsRefTensor<Element = Coef>,
     Polynomial<InCont2>: AsRefTensor<Element = Coef>,
@@ -700,7 +1316,7 @@ fn regular_convert_forward_two_torus<InCont1, InCont2, Coef>(
 fn regular_convert_forward_single_integer<InCont, Coef>(
     out: &mut FourierPolynomial<AlignedVec<Complex64>>,
     inp: &Polynomial<InCont>,
-    corr: &ForwardCorrector<&'static [Complex64]>,
+    corr: &ForwardCorrector<Vec<Complex64>>,
 ) where
     Polynomial<InCont>: AsRefTensor<Element = Coef>,
     Coef: UnsignedInteger,
@@ -720,7 +1336,7 @@ fn regular_convert_forward_two_integer<InCont1, InCont2, Coef>(
     out: &mut FourierPolynomial<AlignedVec<Complex64>>,
     inp1: &Polynomial<InCont1>,
     inp2: &Polynomial<InCont2>,
-    corr: &ForwardCorrector<&'static [Complex64]>,
+    corr: &ForwardCorrector<Vec<Complex64>>,
 ) where
     Polynomial<InCont1>: AsRefTensor<Element = Coef>,
     Polynomial<InCont2>: AsRefTensor<Element = Coef>,
@@ -743,29 +1359,11 @@ fn regular_convert_forward_two_integer<InCont1, InCont2, Coef>(
     }
 }
 
-fn regular_convert_add_backward_single_torus<OutCont, Coef>(
+fn regular_convert_add_backward_single<OutCont, Coef>(
     out: &mut Polynomial<OutCont>,
     inp: &FourierPolynomial<AlignedVec<Complex64>>,
-    corr: &BackwardCorrector<&'static [Complex64]>,
-) where
-    Polynomial<OutCont>: AsMutTensor<Element = Coef>,
-    Coef: UnsignedTorus,
-{
-    ck_dim_eq!(inp.as_tensor().len() => corr.as_tensor().len(), out.as_tensor().len());
-    for (input, (corrector, output)) in inp
-        .as_tensor()
-        .iter()
-        .zip(corr.as_tensor().iter().zip(out.as_mut_tensor().iter_mut()))
-    {
-        let interm = (input * corrector).re;
-        *output = output.wrapping_add(Coef::from_torus(interm));
-    }
-}
-
-fn regular_convert_add_backward_single_integer<OutCont, Coef>(
-    out: &mut Polynomial<OutCont>,
-    inp: &FourierPolynomial<AlignedVec<Complex64>>,
-    corr: &BackwardCorrector<&'static [Complex64]>,
+    corr: &BackwardCorrector<Vec<Complex64>>,
+    convert: impl Fn(f64) -> Coef,
 ) where
     Polynomial<OutCont>: AsMutTensor<Element = Coef>,
     Coef: UnsignedInteger,
@@ -777,20 +1375,20 @@ fn regular_convert_add_backward_single_integer<OutCont, Coef>(
         .zip(corr.as_tensor().iter().zip(out.as_mut_tensor().iter_mut()))
     {
         let interm = (input * corrector).re;
-        let out: Coef::Signed = interm.round().cast_into();
-        *output = output.wrapping_add(out.into_unsigned());
+        *output = output.wrapping_add(convert(interm));
     }
 }
 
-fn regular_convert_add_backward_two_torus<OutCont1, OutCont2, Coef>(
+fn regular_convert_add_backward_two<OutCont1, OutCont2, Coef>(
     out1: &mut Polynomial<OutCont1>,
     out2: &mut Polynomial<OutCont2>,
     inp: &FourierPolynomial<AlignedVec<Complex64>>,
-    corr: &BackwardCorrector<&'static [Complex64]>,
+    corr: &BackwardCorrector<Vec<Complex64>>,
+    convert: impl Fn(f64) -> Coef,
 ) where
     Polynomial<OutCont1>: AsMutTensor<Element = Coef>,
     Polynomial<OutCont2>: AsMutTensor<Element = Coef>,
-    Coef: UnsignedTorus,
+    Coef: UnsignedInteger,
 {
     ck_dim_eq!(
         out1.as_tensor().len() =>
@@ -804,38 +1402,40 @@ fn regular_convert_add_backward_two_torus<OutCont1, OutCont2, Coef>(
             .zip(corr.as_tensor().iter().zip(inp.as_tensor().iter())),
     ) {
         let interm = input * corrector;
-        let re_interm = interm.re;
-        let im_interm = interm.im;
-        *output_1 = output_1.wrapping_add(Coef::from_torus(re_interm));
-        *output_2 = output_2.wrapping_add(Coef::from_torus(im_interm));
+        *output_1 = output_1.wrapping_add(convert(interm.re));
+        *output_2 = output_2.wrapping_add(convert(interm.im));
     }
 }
 
-fn regular_convert_add_backward_two_integer<OutCont1, OutCont2, Coef>(
-    out1: &mut Polynomial<OutCont1>,
-    out2: &mut Polynomial<OutCont2>,
-    inp: &FourierPolynomial<AlignedVec<Complex64>>,
-    corr: &BackwardCorrector<&'static [Complex64]>,
-) where
-    Polynomial<OutCont1>: AsMutTensor<Element = Coef>,
-    Polynomial<OutCont2>: AsMutTensor<Element = Coef>,
-    Coef: UnsignedInteger,
-{
-    ck_dim_eq!(
-        out1.as_tensor().len() =>
-        corr.as_tensor().len(),
-        inp.as_tensor().len(),
-        out2.as_tensor().len()
-    );
-    for (output_1, (output_2, (corrector, input))) in out1.as_mut_tensor().iter_mut().zip(
-        out2.as_mut_tensor()
-            .iter_mut()
-            .zip(corr.as_tensor().iter().zip(inp.as_tensor().iter())),
-    ) {
-        let interm = input * corrector;
-        let out_1: Coef::Signed = interm.re.round().cast_into();
-        let out_2: Coef::Signed = interm.im.round().cast_into();
-        *output_1 = output_1.wrapping_add(out_1.into_unsigned());
-        *output_2 = output_2.wrapping_add(out_2.into_unsigned());
+fn torus_to_f64<Coef: UnsignedTorus>(coef: Coef) -> f64 {
+    coef.into_torus()
+}
+
+fn integer_to_f64<Coef: UnsignedInteger>(coef: Coef) -> f64 {
+    coef.into_signed().cast_into()
+}
+
+fn f64_to_torus<Coef: UnsignedTorus>(value: f64) -> Coef {
+    Coef::from_torus(value)
+}
+
+fn f64_to_integer<Coef: UnsignedInteger>(value: f64) -> Coef {
+    let signed: Coef::Signed = value.round().cast_into();
+    signed.into_unsigned()
+}
+
+/// Checks that a polynomial size is a power of two between `min` and `max`.
+pub(super) fn validated_size(
+    size: PolynomialSize,
+    min: usize,
+    max: usize,
+) -> Result<PolynomialSize, InvalidSizeError> {
+    let size = PolynomialSize::validated_power_of_two(size.0)?;
+    if size.0 < min {
+        Err(InvalidSizeError::TooSmall { size: size.0, min })
+    } else if size.0 > max {
+        Err(InvalidSizeError::TooLarge { size: size.0, max })
+    } else {
+        Ok(size)
     }
 }
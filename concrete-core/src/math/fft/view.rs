@@ -0,0 +1,127 @@
+use crate::math::polynomial::{Polynomial, PolynomialSize, ReductionMode};
+use crate::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::numeric::UnsignedInteger;
+
+use super::{Complex64, Fft, FftBuffers, FourierPolynomial};
+
+/// A transformer borrowed from an [`Fft`], along with the buffers needed to multiply
+/// polynomials.
+///
+/// Creating the plans of an [`Fft`] is expensive, and so is allocating the fourier polynomials
+/// used to compute a product. A view borrows the plans of an existing transformer instead of
+/// owning them, and keeps its buffers for the whole duration of the borrow, so that a single
+/// transformer can be used for many products in a loop, and given back afterwards.
+///
+/// The polynomials are viewed as polynomials of integer coefficients. As the computations are
+/// performed with `f64`, the products are exact only as long as their coefficients fit in the
+/// mantissa.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::fft::{Fft, FftView};
+/// use concrete_core::math::polynomial::{MonomialDegree, Polynomial, PolynomialSize};
+/// let mut fft = Fft::new(PolynomialSize(256));
+/// let mut poly = Polynomial::allocate(0u64, PolynomialSize(256));
+/// *poly.get_mut_monomial(MonomialDegree(0)).get_mut_coefficient() = 1;
+/// let mut monomial = Polynomial::allocate(0u64, PolynomialSize(256));
+/// *monomial.get_mut_monomial(MonomialDegree(1)).get_mut_coefficient() = 1;
+/// {
+///     let mut view = FftView::new(&mut fft);
+///     // We compute X^300 = -X^44 modulo X^256 + 1.
+///     for _ in 0..300 {
+///         view.polynomial_mul_assign(&mut poly, &monomial);
+///     }
+/// }
+/// assert_eq!(
+///     *poly.get_monomial(MonomialDegree(44)).get_coefficient(),
+///     0u64.wrapping_sub(1)
+/// );
+/// // The transformer can still be used once the view is dropped.
+/// assert_eq!(fft.polynomial_size(), PolynomialSize(256));
+/// ```
+pub struct FftView<'fft> {
+    fft: &'fft mut Fft,
+    buffers: FftBuffers,
+}
+
+impl<'fft> FftView<'fft> {
+    /// Creates a view borrowing the plans of `fft`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::{Fft, FftView};
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let mut fft = Fft::new(PolynomialSize(256));
+    /// let view = FftView::new(&mut fft);
+    /// assert_eq!(view.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn new(fft: &'fft mut Fft) -> Self {
+        let size = fft.polynomial_size();
+        FftView {
+            fft,
+            buffers: FftBuffers::for_size(size),
+        }
+    }
+
+    /// Returns the polynomial size accepted by the borrowed transformer.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.fft.polynomial_size()
+    }
+
+    /// Returns the reduction applied to the products computed with the borrowed transformer.
+    pub fn reduction_mode(&self) -> ReductionMode {
+        self.fft.reduction_mode()
+    }
+
+    /// Performs the forward fourier transform of the `poly` polynomial, viewed as a polynomial of
+    /// integer coefficients, and stores the result in `fourier_poly`.
+    ///
+    /// See [`Fft::forward_as_integer`].
+    pub fn forward<OutCont, InCont, Coef>(
+        &mut self,
+        fourier_poly: &mut FourierPolynomial<OutCont>,
+        poly: &Polynomial<InCont>,
+    ) where
+        FourierPolynomial<OutCont>: AsMutTensor<Element = Complex64>,
+        Polynomial<InCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        self.fft.forward_as_integer(fourier_poly, poly);
+    }
+
+    /// Performs the backward fourier transform of the `fourier_poly` polynomial, viewed as a
+    /// polynomial of integer coefficients, and stores the result in `poly`.
+    ///
+    /// Unlike [`Fft::add_backward_as_integer`], the previous values of `poly` are
+    /// discarded. The content of `fourier_poly` is modified by the transform.
+    pub fn backward<OutCont, InCont, Coef>(
+        &mut self,
+        poly: &mut Polynomial<OutCont>,
+        fourier_poly: &mut FourierPolynomial<InCont>,
+    ) where
+        Polynomial<OutCont>: AsMutTensor<Element = Coef>,
+        FourierPolynomial<InCont>: AsMutTensor<Element = Complex64>,
+        Coef: UnsignedInteger,
+    {
+        poly.as_mut_tensor().fill_with_element(Coef::ZERO);
+        self.fft.add_backward_as_integer(poly, fourier_poly);
+    }
+
+    /// Multiplies the `lhs` polynomial by the `rhs` polynomial, reduced according to the mode of
+    /// the borrowed transformer, and stores the result in `lhs`.
+    ///
+    /// See the [type-level](`FftView`) documentation for an example.
+    pub fn polynomial_mul_assign<LhsCont, RhsCont, Coef>(
+        &mut self,
+        lhs: &mut Polynomial<LhsCont>,
+        rhs: &Polynomial<RhsCont>,
+    ) where
+        Polynomial<LhsCont>: AsMutTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        self.fft.polynomial_mul_assign(lhs, rhs, &mut self.buffers);
+    }
+}
@@ -1,8 +1,14 @@
 //! A module containing general mathematical tools.
 
+pub use errors::DimensionError;
+
+pub mod arith;
 pub mod decomposition;
 pub mod dispersion;
+mod errors;
 pub mod fft;
+pub mod modular;
+pub mod ntt;
 pub mod polynomial;
 pub mod random;
 pub mod tensor;
@@ -0,0 +1,280 @@
+//! Modular arithmetic with an arbitrary modulus.
+//!
+//! The rest of the library represents torus elements with unsigned integers, which implicitly
+//! performs the arithmetic modulo $2^q$ where $q$ is the number of bits of the integer type. Some
+//! applications need a modulus which is not a power of two, for instance a prime modulus allowing
+//! to use the number theoretic transform. This module contains the [`ModQ`] type, representing
+//! an element of $\mathbb{Z}/Q\mathbb{Z}$ for a modulus $Q$ fixed at compile time.
+//!
+//! Since [`ModQ`] implements the standard arithmetic operators, tensors, polynomials and
+//! plaintext lists of [`ModQ`] values can be operated on with their non-wrapping methods (e.g.
+//! [`Tensor::update_with_add`](crate::math::tensor::Tensor::update_with_add)). GLWE ciphertexts
+//! can be encrypted and decrypted with
+//! [`GlweSecretKey::encrypt_modular_glwe`](crate::crypto::secret::GlweSecretKey::encrypt_modular_glwe)
+//! and
+//! [`GlweSecretKey::decrypt_modular_glwe`](crate::crypto::secret::GlweSecretKey::decrypt_modular_glwe).
+//! The fourier transforms are not supported for such values.
+//!
+//! # Example
+//!
+//! ```rust
+//! use concrete_core::math::modular::ModQ;
+//! type Z17 = ModQ<17>;
+//! let a = Z17::new(12);
+//! let b = Z17::new(9);
+//! assert_eq!((a + b).value(), 4);
+//! assert_eq!((a - b).value(), 3);
+//! assert_eq!((b - a).value(), 14);
+//! assert_eq!((a * b).value(), 6);
+//! assert_eq!((-a).value(), 5);
+//! ```
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::math::random::{random_uniform, Gaussian, RandomGenerable, Uniform};
+use crate::numeric::{CastFrom, ConstantTimeSelect, UnsignedInteger};
+
+#[cfg(test)]
+mod tests;
+
+/// An element of $\mathbb{Z}/Q\mathbb{Z}$.
+///
+/// The value is always kept in its canonical representation in $[0, Q[$. The modulus must be at
+/// least 2, and smaller than $2^{63}$, which allows to perform the additions without overflow and
+/// the multiplications with a Barrett reduction over 128 bits integers. Using another modulus
+/// fails the compilation.
+///
+/// The arithmetic operations do not branch on the values of the elements, so that they can be
+/// used on secret values.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct ModQ<const Q: u64>(u64);
+
+impl<const Q: u64> ModQ<Q> {
+    /// The modulus.
+    pub const MODULUS: u64 = Q;
+
+    /// The zero element.
+    pub const ZERO: Self = Self::from_canonical(0);
+
+    /// The one element.
+    pub const ONE: Self = Self::from_canonical(1);
+
+    // Evaluating this constant fails the compilation if the modulus is not in $[2, 2^{63}[$.
+    const VALID_MODULUS: () = assert!(Q >= 2 && Q < 1 << 63, "The modulus must be in [2, 2^63[.");
+
+    // The number of bits of the modulus.
+    const BITS: u32 = 64 - Q.leading_zeros();
+
+    // The Barrett constant $\lfloor 4^k / Q \rfloor$ where $k$ is the number of bits of Q.
+    const BARRETT: u128 = (1u128 << (2 * Self::BITS)) / Q as u128;
+
+    /// Creates a new element by reducing an integer modulo $Q$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::math::modular::ModQ;
+    /// assert_eq!(ModQ::<17>::new(20).value(), 3);
+    /// ```
+    pub fn new(value: u64) -> Self {
+        Self::from_canonical(value % Q)
+    }
+
+    // Creates an element from a value already in $[0, Q[$. All the elements are created through
+    // this function, which checks the modulus at compile time.
+    #[allow(clippy::let_unit_value)]
+    const fn from_canonical(value: u64) -> Self {
+        let () = Self::VALID_MODULUS;
+        ModQ(value)
+    }
+
+    /// Creates a new element from a signed integer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::math::modular::ModQ;
+    /// assert_eq!(ModQ::<17>::from_signed(-3).value(), 14);
+    /// ```
+    pub fn from_signed(value: i64) -> Self {
+        Self::new(value.rem_euclid(Q as i64) as u64)
+    }
+
+    /// Returns the canonical representative of the element, in $[0, Q[$.
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the centered representative of the element, in $]-Q/2, Q/2]$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::math::modular::ModQ;
+    /// assert_eq!(ModQ::<17>::new(14).to_signed(), -3);
+    /// assert_eq!(ModQ::<17>::new(8).to_signed(), 8);
+    /// ```
+    pub fn to_signed(self) -> i64 {
+        if self.0 > Q / 2 {
+            self.0 as i64 - Q as i64
+        } else {
+            self.0 as i64
+        }
+    }
+
+    /// Returns the closest element to a torus value given in floating point representation,
+    /// i.e. $\lfloor x \cdot Q \rceil \bmod Q$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::math::modular::ModQ;
+    /// assert_eq!(ModQ::<17>::from_torus(0.5).value(), 9);
+    /// assert_eq!(ModQ::<17>::from_torus(-0.1).value(), 15);
+    /// ```
+    pub fn from_torus(input: f64) -> Self {
+        let fract = input - input.floor();
+        Self::new((fract * Q as f64).round() as u64)
+    }
+
+    /// Raises the element to an integer power.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::math::modular::ModQ;
+    /// assert_eq!(ModQ::<17>::new(3).pow(16), ModQ::ONE);
+    /// ```
+    pub fn pow(self, mut exponent: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::ONE;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    // Reduces a value smaller than Q^2 with the Barrett algorithm. The estimated quotient is
+    // lower than the actual one by at most 2, so the remainder is brought back in $[0, Q[$ with
+    // two conditional subtractions, which are always performed.
+    fn reduce(value: u128) -> Self {
+        let estimate = ((value >> (Self::BITS - 1)) * Self::BARRETT) >> (Self::BITS + 1);
+        let remainder = value - estimate * Q as u128;
+        let remainder =
+            Self::subtract_modulus_if_larger(Self::subtract_modulus_if_larger(remainder));
+        Self::from_canonical(remainder as u64)
+    }
+
+    // Returns `value - Q` if `value` is at least `Q`, and `value` otherwise, without branching.
+    fn subtract_modulus_if_larger<T>(value: T) -> T
+    where
+        T: UnsignedInteger + CastFrom<u64>,
+    {
+        let modulus = T::cast_from(Q);
+        T::ct_select(value.ct_lt(modulus), value, value.wrapping_sub(modulus))
+    }
+}
+
+impl<const Q: u64> Default for ModQ<Q> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const Q: u64> Display for ModQ<Q> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} mod {}", self.0, Q)
+    }
+}
+
+impl<const Q: u64> Add for ModQ<Q> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::from_canonical(Self::subtract_modulus_if_larger(self.0 + rhs.0))
+    }
+}
+
+impl<const Q: u64> Sub for ModQ<Q> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        let (difference, borrow) = self.0.overflowing_sub(rhs.0);
+        Self::from_canonical(difference.wrapping_add(u64::ct_select(borrow, Q, 0)))
+    }
+}
+
+impl<const Q: u64> Neg for ModQ<Q> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+impl<const Q: u64> Mul for ModQ<Q> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::reduce(self.0 as u128 * rhs.0 as u128)
+    }
+}
+
+impl<const Q: u64> AddAssign for ModQ<Q> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const Q: u64> SubAssign for ModQ<Q> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const Q: u64> MulAssign for ModQ<Q> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const Q: u64> CastFrom<bool> for ModQ<Q> {
+    fn cast_from(input: bool) -> Self {
+        Self::from_canonical(input as u64)
+    }
+}
+
+impl<const Q: u64> ConstantTimeSelect for ModQ<Q> {
+    fn ct_select(choice: bool, first: Self, second: Self) -> Self {
+        Self::from_canonical(u64::ct_select(choice, first.0, second.0))
+    }
+}
+
+/// The element is uniformly sampled in $[0, Q[$, by rejecting the values above the modulus.
+impl<const Q: u64> RandomGenerable<Uniform> for ModQ<Q> {
+    fn sample(_distribution: Uniform) -> Self {
+        let mask = u64::MAX >> (64 - Self::BITS);
+        loop {
+            let value = random_uniform::<u64>() & mask;
+            if value < Q {
+                break Self::from_canonical(value);
+            }
+        }
+    }
+}
+
+/// The standard deviation and the mean are given on the torus, i.e. as fractions of the modulus.
+impl<const Q: u64> RandomGenerable<Gaussian<f64>> for (ModQ<Q>, ModQ<Q>) {
+    fn sample(distribution: Gaussian<f64>) -> Self {
+        let (s1, s2) = <(f64, f64)>::sample(distribution);
+        (ModQ::from_torus(s1), ModQ::from_torus(s2))
+    }
+}
+
+impl<const Q: u64> RandomGenerable<Gaussian<f64>> for ModQ<Q> {
+    fn sample(distribution: Gaussian<f64>) -> Self {
+        let (s1, _) = <(f64, f64)>::sample(distribution);
+        ModQ::from_torus(s1)
+    }
+}
@@ -0,0 +1,101 @@
+use crate::math::modular::ModQ;
+use crate::math::random::random_uniform;
+
+// The largest prime smaller than 2^62.
+const PRIME_62: u64 = (1 << 62) - 57;
+
+fn test_ring_axioms<const Q: u64>() {
+    for _ in 0..10_000 {
+        let a: ModQ<Q> = random_uniform();
+        let b: ModQ<Q> = random_uniform();
+        let c: ModQ<Q> = random_uniform();
+        assert!(a.value() < Q);
+
+        // additive group
+        assert_eq!(a + b, b + a);
+        assert_eq!((a + b) + c, a + (b + c));
+        assert_eq!(a + ModQ::ZERO, a);
+        assert_eq!(a + (-a), ModQ::ZERO);
+        assert_eq!(a - b, a + (-b));
+
+        // multiplicative monoid
+        assert_eq!(a * b, b * a);
+        assert_eq!((a * b) * c, a * (b * c));
+        assert_eq!(a * ModQ::ONE, a);
+        assert_eq!(a * ModQ::ZERO, ModQ::ZERO);
+
+        // distributivity
+        assert_eq!(a * (b + c), a * b + a * c);
+
+        // the barrett reduction matches the naive one
+        let expected = (a.value() as u128 * b.value() as u128) % Q as u128;
+        assert_eq!((a * b).value() as u128, expected);
+    }
+}
+
+#[test]
+fn test_ring_axioms_small() {
+    test_ring_axioms::<17>();
+}
+
+#[test]
+fn test_ring_axioms_prime_62() {
+    test_ring_axioms::<PRIME_62>();
+}
+
+#[test]
+fn test_ring_axioms_large() {
+    test_ring_axioms::<{ (1 << 63) - 25 }>();
+}
+
+// The additions, subtractions and reductions of the products close to the modulus are the ones
+// needing the conditional subtractions.
+fn test_edge_cases<const Q: u64>() {
+    let values = [0, 1, 2, Q / 2, Q / 2 + 1, Q - 2, Q - 1];
+    for a in values.iter().map(|value| *value % Q) {
+        for b in values.iter().map(|value| *value % Q) {
+            let (a_q, b_q) = (ModQ::<Q>::new(a), ModQ::<Q>::new(b));
+            assert_eq!(
+                (a_q + b_q).value() as u128,
+                (a as u128 + b as u128) % Q as u128
+            );
+            assert_eq!(
+                (a_q - b_q).value() as u128,
+                (a as u128 + (Q - b) as u128) % Q as u128
+            );
+            assert_eq!(
+                (a_q * b_q).value() as u128,
+                (a as u128 * b as u128) % Q as u128
+            );
+        }
+    }
+}
+
+#[test]
+fn test_edge_cases_all_moduli() {
+    test_edge_cases::<2>();
+    test_edge_cases::<17>();
+    test_edge_cases::<{ 1 << 32 }>();
+    test_edge_cases::<PRIME_62>();
+    test_edge_cases::<{ 1 << 62 }>();
+    test_edge_cases::<{ (1 << 63) - 25 }>();
+}
+
+#[test]
+fn test_fermat_inverse() {
+    for _ in 0..1000 {
+        let a: ModQ<PRIME_62> = random_uniform();
+        if a != ModQ::ZERO {
+            assert_eq!(a * a.pow(PRIME_62 - 2), ModQ::ONE);
+        }
+    }
+}
+
+#[test]
+fn test_signed_conversions() {
+    for _ in 0..1000 {
+        let a: ModQ<PRIME_62> = random_uniform();
+        assert_eq!(ModQ::from_signed(a.to_signed()), a);
+        assert!(a.to_signed().unsigned_abs() <= PRIME_62 / 2);
+    }
+}
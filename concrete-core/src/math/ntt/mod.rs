@@ -0,0 +1,37 @@
+//! Number theoretic transform for polynomials.
+//!
+//! This module provides the tools to perform an exact product of two polynomials, reduced modulo
+//! $X^N+1$, in $O(N \log N)$ operations. Contrary to the [fourier transform](crate::math::fft),
+//! whose products are computed with `f64` and are subject to rounding errors, the number
+//! theoretic transform computes over prime fields, and its results are bit-exact.
+//!
+//! The coefficients of the polynomials are lifted to integers of $[0, 2^{64}[$, so the exact
+//! product of two polynomials has coefficients smaller than $N \cdot 2^{128}$ in absolute value.
+//! The transform is performed modulo three primes of 62 bits, and the coefficients of the product
+//! are reconstructed with the chinese remainder theorem, before being reduced modulo $2^q$.
+//!
+//! # Example
+//!
+//! ```
+//! use concrete_core::math::ntt::Ntt;
+//! use concrete_core::math::polynomial::{MonomialDegree, Polynomial, PolynomialSize};
+//! let lhs = Polynomial::from_container(vec![u64::MAX, 0, 0, 1]);
+//! let rhs = Polynomial::from_container(vec![0u64, 2, 0, 0]);
+//! let mut res = Polynomial::allocate(0u64, PolynomialSize(4));
+//! let ntt = Ntt::new(PolynomialSize(4));
+//! // (-1 + X^3) * 2X = -2X + 2X^4 = -2 - 2X modulo X^4 + 1
+//! res.fill_with_ntt_mul(&lhs, &rhs, &ntt);
+//! assert_eq!(res, Polynomial::from_container(vec![u64::MAX - 1, u64::MAX - 1, 0, 0]));
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+mod polynomial;
+pub use polynomial::*;
+
+mod tables;
+use tables::*;
+
+mod transform;
+pub use transform::*;
@@ -0,0 +1,84 @@
+use crate::ck_dim_eq;
+use crate::math::modular::ModQ;
+use crate::math::polynomial::PolynomialSize;
+
+use super::{PRIME_0, PRIME_1, PRIME_2};
+
+/// A polynomial in the number theoretic domain.
+///
+/// The polynomial is stored as its three transforms modulo the primes of the residue number
+/// system. The element-wise product of two such polynomials corresponds to the product of the
+/// polynomials, reduced modulo $(X^N+1)$.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NttPolynomial {
+    pub(super) residues_0: Vec<ModQ<PRIME_0>>,
+    pub(super) residues_1: Vec<ModQ<PRIME_1>>,
+    pub(super) residues_2: Vec<ModQ<PRIME_2>>,
+}
+
+impl NttPolynomial {
+    /// Allocates a new polynomial in the number theoretic domain, whose coefficients are all
+    /// zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::ntt::NttPolynomial;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let ntt_poly = NttPolynomial::allocate(PolynomialSize(128));
+    /// assert_eq!(ntt_poly.polynomial_size(), PolynomialSize(128));
+    /// ```
+    pub fn allocate(coef_count: PolynomialSize) -> Self {
+        NttPolynomial {
+            residues_0: vec![ModQ::ZERO; coef_count.0],
+            residues_1: vec![ModQ::ZERO; coef_count.0],
+            residues_2: vec![ModQ::ZERO; coef_count.0],
+        }
+    }
+
+    /// Returns the number of coefficients of the polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::ntt::NttPolynomial;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let ntt_poly = NttPolynomial::allocate(PolynomialSize(128));
+    /// assert_eq!(ntt_poly.polynomial_size(), PolynomialSize(128));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.residues_0.len())
+    }
+
+    /// Adds the element-wise product of two polynomials to the current polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::ntt::{Ntt, NttPolynomial};
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialSize};
+    /// let ntt = Ntt::new(PolynomialSize(2));
+    /// let mut ntt_lhs = NttPolynomial::allocate(PolynomialSize(2));
+    /// let mut ntt_rhs = NttPolynomial::allocate(PolynomialSize(2));
+    /// ntt.forward_as_integer(&mut ntt_lhs, &Polynomial::from_container(vec![1u32, 2]));
+    /// ntt.forward_as_integer(&mut ntt_rhs, &Polynomial::from_container(vec![3u32, 4]));
+    /// let mut ntt_res = NttPolynomial::allocate(PolynomialSize(2));
+    /// ntt_res.update_with_multiply_accumulate(&ntt_lhs, &ntt_rhs);
+    /// let mut res = Polynomial::allocate(0u32, PolynomialSize(2));
+    /// ntt.add_backward_as_integer(&mut res, &mut ntt_res);
+    /// // (1 + 2X) * (3 + 4X) = 3 + 10X + 8X^2 = -5 + 10X modulo X^2 + 1
+    /// assert_eq!(res, Polynomial::from_container(vec![0u32.wrapping_sub(5), 10]));
+    /// ```
+    pub fn update_with_multiply_accumulate(&mut self, lhs: &NttPolynomial, rhs: &NttPolynomial) {
+        ck_dim_eq!(self.polynomial_size() => lhs.polynomial_size(), rhs.polynomial_size());
+        multiply_accumulate(&mut self.residues_0, &lhs.residues_0, &rhs.residues_0);
+        multiply_accumulate(&mut self.residues_1, &lhs.residues_1, &rhs.residues_1);
+        multiply_accumulate(&mut self.residues_2, &lhs.residues_2, &rhs.residues_2);
+    }
+}
+
+fn multiply_accumulate<const Q: u64>(output: &mut [ModQ<Q>], lhs: &[ModQ<Q>], rhs: &[ModQ<Q>]) {
+    for (out, (lhs, rhs)) in output.iter_mut().zip(lhs.iter().zip(rhs.iter())) {
+        *out += *lhs * *rhs;
+    }
+}
@@ -0,0 +1,181 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::math::modular::ModQ;
+use crate::math::polynomial::PolynomialSize;
+
+// The three primes of the residue number system. They are all of the form $c \cdot 2^{32} + 1$,
+// so they have $2N$-th roots of unity for all the power of two sizes up to $2^{31}$, and their
+// product is larger than $2^{185}$.
+pub(super) const PRIME_0: u64 = 0x3fff_ffee_0000_0001;
+pub(super) const PRIME_1: u64 = 0x3fff_ffb4_0000_0001;
+pub(super) const PRIME_2: u64 = 0x3fff_ffa0_0000_0001;
+
+thread_local! {
+    static CACHE: RefCell<HashMap<usize, Rc<NttTables>>> = RefCell::new(HashMap::new());
+}
+
+// Returns the `bits` lowest bits of `index` in reverse order.
+fn bit_reverse(index: usize, bits: u32) -> usize {
+    if bits == 0 {
+        0
+    } else {
+        ((index as u64).reverse_bits() >> (64 - bits)) as usize
+    }
+}
+
+fn inverse<const Q: u64>(value: ModQ<Q>) -> ModQ<Q> {
+    value.pow(Q - 2)
+}
+
+// Returns a primitive root of unity of a power of two order dividing Q - 1.
+fn primitive_root<const Q: u64>(order: usize) -> ModQ<Q> {
+    // A quadratic non-residue generates the whole 2-sylow subgroup of the multiplicative group.
+    let minus_one = -ModQ::<Q>::ONE;
+    let non_residue = (2..)
+        .map(ModQ::new)
+        .find(|candidate: &ModQ<Q>| candidate.pow((Q - 1) / 2) == minus_one)
+        .unwrap();
+    non_residue.pow((Q - 1) / order as u64)
+}
+
+/// The roots of unity used to transform polynomials of a given size modulo a prime `Q`.
+pub(super) struct PrimeTables<const Q: u64> {
+    // The powers of a primitive 2N-th root of unity, in bit-reversed order.
+    roots: Vec<ModQ<Q>>,
+    // The powers of the inverse of the same root, in bit-reversed order.
+    inverse_roots: Vec<ModQ<Q>>,
+    size_inverse: ModQ<Q>,
+}
+
+impl<const Q: u64> PrimeTables<Q> {
+    fn new(size: usize) -> Self {
+        let root = primitive_root::<Q>(2 * size);
+        let inverse_root = inverse(root);
+        let bits = size.trailing_zeros();
+        let mut roots = vec![ModQ::ZERO; size];
+        let mut inverse_roots = vec![ModQ::ZERO; size];
+        let mut power = ModQ::ONE;
+        let mut inverse_power = ModQ::ONE;
+        for i in 0..size {
+            roots[bit_reverse(i, bits)] = power;
+            inverse_roots[bit_reverse(i, bits)] = inverse_power;
+            power *= root;
+            inverse_power *= inverse_root;
+        }
+        PrimeTables {
+            roots,
+            inverse_roots,
+            size_inverse: inverse(ModQ::new(size as u64)),
+        }
+    }
+
+    /// Performs the negacyclic forward transform in place, with Cooley-Tukey butterflies.
+    ///
+    /// The values are given in natural order, and the output is in bit-reversed order.
+    pub(super) fn forward(&self, values: &mut [ModQ<Q>]) {
+        let size = values.len();
+        let mut half = size;
+        let mut groups = 1;
+        while groups < size {
+            half /= 2;
+            for (group, chunk) in values.chunks_exact_mut(2 * half).enumerate() {
+                let root = self.roots[groups + group];
+                let (low, high) = chunk.split_at_mut(half);
+                for (u, v) in low.iter_mut().zip(high.iter_mut()) {
+                    let product = *v * root;
+                    *v = *u - product;
+                    *u += product;
+                }
+            }
+            groups *= 2;
+        }
+    }
+
+    /// Performs the negacyclic backward transform in place, with Gentleman-Sande butterflies.
+    ///
+    /// The values are given in bit-reversed order, and the output is in natural order.
+    pub(super) fn backward(&self, values: &mut [ModQ<Q>]) {
+        let size = values.len();
+        let mut half = 1;
+        let mut groups = size / 2;
+        while half < size {
+            for (group, chunk) in values.chunks_exact_mut(2 * half).enumerate() {
+                let root = self.inverse_roots[groups + group];
+                let (low, high) = chunk.split_at_mut(half);
+                for (u, v) in low.iter_mut().zip(high.iter_mut()) {
+                    let difference = *u - *v;
+                    *u += *v;
+                    *v = difference * root;
+                }
+            }
+            half *= 2;
+            groups /= 2;
+        }
+        for value in values.iter_mut() {
+            *value *= self.size_inverse;
+        }
+    }
+}
+
+/// The precomputed tables of a given polynomial size, for the three primes.
+pub(super) struct NttTables {
+    pub(super) tables_0: PrimeTables<PRIME_0>,
+    pub(super) tables_1: PrimeTables<PRIME_1>,
+    pub(super) tables_2: PrimeTables<PRIME_2>,
+    prime_0_inverse_mod_1: ModQ<PRIME_1>,
+    prime_0_inverse_mod_2: ModQ<PRIME_2>,
+    prime_1_inverse_mod_2: ModQ<PRIME_2>,
+}
+
+impl NttTables {
+    fn new(size: usize) -> Self {
+        NttTables {
+            tables_0: PrimeTables::new(size),
+            tables_1: PrimeTables::new(size),
+            tables_2: PrimeTables::new(size),
+            prime_0_inverse_mod_1: inverse(ModQ::new(PRIME_0)),
+            prime_0_inverse_mod_2: inverse(ModQ::new(PRIME_0)),
+            prime_1_inverse_mod_2: inverse(ModQ::new(PRIME_1)),
+        }
+    }
+
+    /// Returns the tables of a given size, which are computed on the first call for this size
+    /// in the current thread, and shared afterwards.
+    pub(super) fn get(size: PolynomialSize) -> Rc<NttTables> {
+        CACHE.with(|cache| {
+            cache
+                .borrow_mut()
+                .entry(size.0)
+                .or_insert_with(|| Rc::new(NttTables::new(size.0)))
+                .clone()
+        })
+    }
+
+    /// Reconstructs, modulo $2^{64}$, the integer of smallest absolute value with the given
+    /// residues.
+    ///
+    /// The integer is first written in the mixed radix basis $(1, p_0, p_0 p_1)$ with Garner's
+    /// algorithm, whose last digit tells whether the integer is negative.
+    pub(super) fn reconstruct(
+        &self,
+        residue_0: ModQ<PRIME_0>,
+        residue_1: ModQ<PRIME_1>,
+        residue_2: ModQ<PRIME_2>,
+    ) -> u64 {
+        let digit_0 = residue_0.value();
+        let digit_1 = ((residue_1 - ModQ::new(digit_0)) * self.prime_0_inverse_mod_1).value();
+        let digit_2 = (((residue_2 - ModQ::new(digit_0)) * self.prime_0_inverse_mod_2
+            - ModQ::new(digit_1))
+            * self.prime_1_inverse_mod_2)
+            .value();
+        let low = digit_0.wrapping_add(digit_1.wrapping_mul(PRIME_0));
+        let radix = PRIME_0.wrapping_mul(PRIME_1);
+        if digit_2 > PRIME_2 / 2 {
+            low.wrapping_sub((PRIME_2 - digit_2).wrapping_mul(radix))
+        } else {
+            low.wrapping_add(digit_2.wrapping_mul(radix))
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::crypto::UnsignedTorus;
+use crate::math::ntt::{Ntt, NttPolynomial};
+use crate::math::polynomial::{Polynomial, PolynomialSize};
+use crate::math::random;
+use crate::numeric::{CastFrom, CastInto};
+
+fn test_ntt_mul<T: UnsignedTorus + CastInto<u64> + CastFrom<u64>>() {
+    //! tests that the products computed with the ntt are the same as the schoolbook ones
+    for log_size in 0..=11 {
+        let size = PolynomialSize(1 << log_size);
+        let ntt = Ntt::new(size);
+        for _ in 0..2 {
+            let lhs = Polynomial::from_container(
+                random::random_uniform_tensor::<T>(size.0).into_container(),
+            );
+            let rhs = Polynomial::from_container(
+                random::random_uniform_tensor::<T>(size.0).into_container(),
+            );
+            let mut expected = Polynomial::allocate(T::ZERO, size);
+            expected.fill_with_wrapping_mul(&lhs, &rhs);
+            let mut res = Polynomial::allocate(T::ZERO, size);
+            res.fill_with_ntt_mul(&lhs, &rhs, &ntt);
+            assert_eq!(res, expected);
+        }
+    }
+}
+
+#[test]
+pub fn test_ntt_mul_u32() {
+    test_ntt_mul::<u32>()
+}
+
+#[test]
+pub fn test_ntt_mul_u64() {
+    test_ntt_mul::<u64>()
+}
+
+#[test]
+pub fn test_ntt_mul_max_coefficients_u64() {
+    //! tests the products of maximal coefficients, whose exact values are the largest
+    let size = PolynomialSize(2048);
+    let poly = Polynomial::allocate(u64::MAX, size);
+    let mut expected = Polynomial::allocate(0u64, size);
+    expected.fill_with_wrapping_mul(&poly, &poly);
+    let mut res = Polynomial::allocate(0u64, size);
+    res.fill_with_ntt_mul(&poly, &poly, &Ntt::new(size));
+    assert_eq!(res, expected);
+}
+
+#[test]
+pub fn test_ntt_forward_backward_u64() {
+    //! tests that the backward transform adds the transformed polynomial
+    let size = PolynomialSize(1024);
+    let ntt = Ntt::new(size);
+    let poly =
+        Polynomial::from_container(random::random_uniform_tensor::<u64>(size.0).into_container());
+    let acc =
+        Polynomial::from_container(random::random_uniform_tensor::<u64>(size.0).into_container());
+    let mut ntt_poly = NttPolynomial::allocate(size);
+    ntt.forward_as_integer(&mut ntt_poly, &poly);
+    let mut res = acc.clone();
+    ntt.add_backward_as_integer(&mut res, &mut ntt_poly);
+    let mut expected = acc;
+    expected.update_with_wrapping_add(&poly);
+    assert_eq!(res, expected);
+}
@@ -0,0 +1,153 @@
+use std::rc::Rc;
+
+use crate::ck_dim_eq;
+use crate::math::modular::ModQ;
+use crate::math::polynomial::{InvalidSizeError, Polynomial, PolynomialSize};
+use crate::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::numeric::{CastFrom, CastInto, UnsignedInteger};
+
+use super::{NttPolynomial, NttTables};
+
+/// A number theoretic transformer.
+///
+/// This transformer type allows to send polynomials of a fixed size, back and forth in the
+/// number theoretic domain. The element-wise product of two transformed polynomials corresponds
+/// to the exact product of the polynomials, reduced modulo $(X^N+1)$.
+///
+/// The roots of unity are precomputed once per polynomial size and thread, so creating several
+/// transformers of the same size is cheap.
+pub struct Ntt {
+    tables: Rc<NttTables>,
+    size: PolynomialSize,
+}
+
+impl Ntt {
+    /// Generates a new transformer for polynomials a given size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size is not a power of two, see [`Ntt::try_new`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::ntt::Ntt;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let ntt = Ntt::new(PolynomialSize(4096));
+    /// assert_eq!(ntt.polynomial_size(), PolynomialSize(4096));
+    /// ```
+    pub fn new(size: PolynomialSize) -> Ntt {
+        Ntt::try_new(size).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Generates a new transformer for polynomials a given size, or returns an error if the size
+    /// is not a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::ntt::Ntt;
+    /// use concrete_core::math::polynomial::{InvalidSizeError, PolynomialSize};
+    /// let ntt = Ntt::try_new(PolynomialSize(32)).unwrap();
+    /// assert_eq!(ntt.polynomial_size(), PolynomialSize(32));
+    /// assert!(matches!(
+    ///     Ntt::try_new(PolynomialSize(1000)),
+    ///     Err(InvalidSizeError::NotPowerOfTwo { size: 1000 })
+    /// ));
+    /// ```
+    pub fn try_new(size: PolynomialSize) -> Result<Ntt, InvalidSizeError> {
+        let size = PolynomialSize::validated_power_of_two(size.0)?;
+        Ok(Ntt {
+            tables: NttTables::get(size),
+            size,
+        })
+    }
+
+    /// Returns the polynomial size accepted by this transformer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::ntt::Ntt;
+    /// use concrete_core::math::polynomial::PolynomialSize;
+    /// let ntt = Ntt::new(PolynomialSize(256));
+    /// assert_eq!(ntt.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.size
+    }
+
+    /// Performs the forward transform of the `poly` polynomial, viewed as a polynomial of
+    /// integer coefficients, and stores the result in `ntt_poly`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::ntt::{Ntt, NttPolynomial};
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialSize};
+    /// use concrete_core::math::random::fill_with_random_uniform;
+    /// let ntt = Ntt::new(PolynomialSize(256));
+    /// let mut ntt_poly = NttPolynomial::allocate(PolynomialSize(256));
+    /// let mut poly = Polynomial::allocate(0u64, PolynomialSize(256));
+    /// fill_with_random_uniform(&mut poly);
+    /// ntt.forward_as_integer(&mut ntt_poly, &poly);
+    /// let mut out = Polynomial::allocate(0u64, PolynomialSize(256));
+    /// ntt.add_backward_as_integer(&mut out, &mut ntt_poly);
+    /// assert_eq!(out, poly);
+    /// ```
+    pub fn forward_as_integer<InCont, Coef>(
+        &self,
+        ntt_poly: &mut NttPolynomial,
+        poly: &Polynomial<InCont>,
+    ) where
+        Polynomial<InCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger + CastInto<u64>,
+    {
+        ck_dim_eq!(self.size => ntt_poly.polynomial_size(), poly.polynomial_size());
+        for (coef, ((residue_0, residue_1), residue_2)) in poly.coefficient_iter().zip(
+            ntt_poly
+                .residues_0
+                .iter_mut()
+                .zip(ntt_poly.residues_1.iter_mut())
+                .zip(ntt_poly.residues_2.iter_mut()),
+        ) {
+            let value: u64 = (*coef).cast_into();
+            *residue_0 = ModQ::new(value);
+            *residue_1 = ModQ::new(value);
+            *residue_2 = ModQ::new(value);
+        }
+        self.tables.tables_0.forward(&mut ntt_poly.residues_0);
+        self.tables.tables_1.forward(&mut ntt_poly.residues_1);
+        self.tables.tables_2.forward(&mut ntt_poly.residues_2);
+    }
+
+    /// Performs the backward transform of the `ntt_poly` polynomial, viewed as a polynomial of
+    /// integer coefficients, and adds the result to `poly`, with wrapping.
+    ///
+    /// The transform is computed in place, so `ntt_poly` is left in an unspecified state.
+    ///
+    /// See [`Ntt::forward_as_integer`] for an example.
+    pub fn add_backward_as_integer<OutCont, Coef>(
+        &self,
+        poly: &mut Polynomial<OutCont>,
+        ntt_poly: &mut NttPolynomial,
+    ) where
+        Polynomial<OutCont>: AsMutTensor<Element = Coef>,
+        Coef: UnsignedInteger + CastFrom<u64>,
+    {
+        ck_dim_eq!(self.size => ntt_poly.polynomial_size(), poly.polynomial_size());
+        self.tables.tables_0.backward(&mut ntt_poly.residues_0);
+        self.tables.tables_1.backward(&mut ntt_poly.residues_1);
+        self.tables.tables_2.backward(&mut ntt_poly.residues_2);
+        for (coef, ((residue_0, residue_1), residue_2)) in poly.as_mut_tensor().iter_mut().zip(
+            ntt_poly
+                .residues_0
+                .iter()
+                .zip(ntt_poly.residues_1.iter())
+                .zip(ntt_poly.residues_2.iter()),
+        ) {
+            let value = self.tables.reconstruct(*residue_0, *residue_1, *residue_2);
+            *coef = coef.wrapping_add(Coef::cast_from(value));
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use crate::math::tensor::{AsRefSlice, AsRefTensor, Tensor};
+use crate::{ck_dim_div, tensor_memory_footprint, tensor_traits};
+
+use super::*;
+
+/// The number of coefficients packed in a word.
+const WORD_BITS: usize = 64;
+
+// Returns the number of words needed to pack the coefficients of a polynomial.
+fn word_count(poly_size: PolynomialSize) -> usize {
+    poly_size.0.div_ceil(WORD_BITS)
+}
+
+/// A binary polynomial, whose coefficients are packed in 64-bit words.
+///
+/// The coefficient of degree $i$ is the bit $i \bmod 64$ of the word $\lfloor i / 64 \rfloor$.
+/// Compared to a `Polynomial<Vec<bool>>`, this representation uses one bit of memory per
+/// coefficient instead of a byte, and allows to iterate over the non-zero coefficients only, by
+/// skipping the runs of zeros.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::polynomial::{BinaryPolynomial, MonomialDegree, Polynomial};
+/// let poly = Polynomial::from_container(vec![false, true, false, true]);
+/// let packed = BinaryPolynomial::from_boolean_polynomial(&poly);
+/// let degrees: Vec<MonomialDegree> = packed.set_degree_iter().collect();
+/// assert_eq!(degrees, vec![MonomialDegree(1), MonomialDegree(3)]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BinaryPolynomial<Cont> {
+    tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+}
+
+tensor_traits!(BinaryPolynomial);
+tensor_memory_footprint!(BinaryPolynomial);
+
+impl BinaryPolynomial<Vec<u64>> {
+    /// Packs the coefficients of a boolean polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{BinaryPolynomial, Polynomial, PolynomialSize};
+    /// let poly = Polynomial::from_container(vec![true; 100]);
+    /// let packed = BinaryPolynomial::from_boolean_polynomial(&poly);
+    /// assert_eq!(packed.polynomial_size(), PolynomialSize(100));
+    /// assert_eq!(packed.count_ones(), 100);
+    /// ```
+    pub fn from_boolean_polynomial<Cont>(poly: &Polynomial<Cont>) -> Self
+    where
+        Polynomial<Cont>: AsRefTensor<Element = bool>,
+    {
+        let poly_size = poly.polynomial_size();
+        let mut words = vec![0u64; word_count(poly_size)];
+        for (degree, coef) in poly.coefficient_iter().enumerate() {
+            words[degree / WORD_BITS] |= (*coef as u64) << (degree % WORD_BITS);
+        }
+        BinaryPolynomial::from_container(words, poly_size)
+    }
+}
+
+impl<Cont> BinaryPolynomial<Cont> {
+    /// Creates a binary polynomial of `poly_size` coefficients from a container of words.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the container does not hold exactly $\lceil N / 64 \rceil$ words.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{BinaryPolynomial, MonomialDegree, PolynomialSize};
+    /// let packed = BinaryPolynomial::from_container(vec![0b101u64], PolynomialSize(3));
+    /// assert!(packed.get_coefficient(MonomialDegree(0)));
+    /// assert!(!packed.get_coefficient(MonomialDegree(1)));
+    /// assert!(packed.get_coefficient(MonomialDegree(2)));
+    /// ```
+    pub fn from_container(cont: Cont, poly_size: PolynomialSize) -> Self
+    where
+        Cont: AsRefSlice<Element = u64>,
+    {
+        assert_eq!(
+            cont.as_slice().len(),
+            word_count(poly_size),
+            "The container does not hold the words of {} coefficients.",
+            poly_size.0
+        );
+        BinaryPolynomial {
+            tensor: Tensor::from_container(cont),
+            poly_size,
+        }
+    }
+
+    /// Returns the number of coefficients of the polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{BinaryPolynomial, PolynomialSize};
+    /// let packed = BinaryPolynomial::from_container(vec![0u64; 2], PolynomialSize(100));
+    /// assert_eq!(packed.polynomial_size(), PolynomialSize(100));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the coefficient of a given degree.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{BinaryPolynomial, MonomialDegree, PolynomialSize};
+    /// let packed = BinaryPolynomial::from_container(vec![0u64, 1], PolynomialSize(100));
+    /// assert!(packed.get_coefficient(MonomialDegree(64)));
+    /// assert!(!packed.get_coefficient(MonomialDegree(0)));
+    /// ```
+    pub fn get_coefficient(&self, degree: MonomialDegree) -> bool
+    where
+        Self: AsRefTensor<Element = u64>,
+    {
+        assert!(degree.0 < self.poly_size.0);
+        let word = *self.as_tensor().get_element(degree.0 / WORD_BITS);
+        (word >> (degree.0 % WORD_BITS)) & 1 == 1
+    }
+
+    /// Returns the number of coefficients equal to one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{BinaryPolynomial, PolynomialSize};
+    /// let packed = BinaryPolynomial::from_container(vec![0b1011u64], PolynomialSize(4));
+    /// assert_eq!(packed.count_ones(), 3);
+    /// ```
+    pub fn count_ones(&self) -> usize
+    where
+        Self: AsRefTensor<Element = u64>,
+    {
+        self.as_tensor()
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns an iterator over the degrees of the coefficients equal to one, in increasing
+    /// order.
+    ///
+    /// The zero coefficients are skipped a word at a time, so the cost of the iteration depends
+    /// on the number of coefficients equal to one, which is hence not constant-time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{BinaryPolynomial, MonomialDegree, PolynomialSize};
+    /// let packed = BinaryPolynomial::from_container(vec![0b1001u64, 0b10], PolynomialSize(70));
+    /// let degrees: Vec<MonomialDegree> = packed.set_degree_iter().collect();
+    /// assert_eq!(
+    ///     degrees,
+    ///     vec![MonomialDegree(0), MonomialDegree(3), MonomialDegree(65)]
+    /// );
+    /// ```
+    pub fn set_degree_iter(&self) -> impl Iterator<Item = MonomialDegree> + '_
+    where
+        Self: AsRefTensor<Element = u64>,
+    {
+        self.as_tensor()
+            .iter()
+            .enumerate()
+            .flat_map(|(index, word)| {
+                let mut word = *word;
+                std::iter::from_fn(move || {
+                    if word == 0 {
+                        return None;
+                    }
+                    let bit = word.trailing_zeros() as usize;
+                    // Clears the lowest bit set.
+                    word &= word - 1;
+                    Some(MonomialDegree(index * WORD_BITS + bit))
+                })
+            })
+    }
+}
+
+/// A list of binary polynomials, whose coefficients are packed in 64-bit words.
+///
+/// Every polynomial of the list is packed as a [`BinaryPolynomial`], and starts on a new word.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::polynomial::{
+///     BinaryPolynomialList, PolynomialCount, PolynomialList, PolynomialSize,
+/// };
+/// let list = PolynomialList::from_container(vec![true, false, false, true], PolynomialSize(2));
+/// let packed = BinaryPolynomialList::from_boolean_list(&list);
+/// assert_eq!(packed.polynomial_count(), PolynomialCount(2));
+/// assert_eq!(packed.polynomial_size(), PolynomialSize(2));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BinaryPolynomialList<Cont> {
+    tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+}
+
+tensor_traits!(BinaryPolynomialList);
+tensor_memory_footprint!(BinaryPolynomialList);
+
+impl BinaryPolynomialList<Vec<u64>> {
+    /// Packs the coefficients of a list of boolean polynomials.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{
+    ///     BinaryPolynomialList, MonomialDegree, PolynomialList, PolynomialSize,
+    /// };
+    /// let list =
+    ///     PolynomialList::from_container(vec![true, false, false, true], PolynomialSize(2));
+    /// let packed = BinaryPolynomialList::from_boolean_list(&list);
+    /// let second = packed.polynomial_iter().nth(1).unwrap();
+    /// assert!(!second.get_coefficient(MonomialDegree(0)));
+    /// assert!(second.get_coefficient(MonomialDegree(1)));
+    /// ```
+    pub fn from_boolean_list<Cont>(list: &PolynomialList<Cont>) -> Self
+    where
+        PolynomialList<Cont>: AsRefTensor<Element = bool>,
+    {
+        let poly_size = list.polynomial_size();
+        let words = list
+            .polynomial_iter()
+            .flat_map(|poly| {
+                BinaryPolynomial::from_boolean_polynomial(&poly)
+                    .tensor
+                    .into_container()
+            })
+            .collect();
+        BinaryPolynomialList::from_container(words, poly_size)
+    }
+}
+
+impl<Cont> BinaryPolynomialList<Cont> {
+    /// Creates a list of binary polynomials of `poly_size` coefficients from a container of
+    /// words.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{
+    ///     BinaryPolynomialList, PolynomialCount, PolynomialSize,
+    /// };
+    /// let packed = BinaryPolynomialList::from_container(vec![0u64; 6], PolynomialSize(100));
+    /// assert_eq!(packed.polynomial_count(), PolynomialCount(3));
+    /// ```
+    pub fn from_container(cont: Cont, poly_size: PolynomialSize) -> Self
+    where
+        Cont: AsRefSlice<Element = u64>,
+    {
+        ck_dim_div!(cont.as_slice().len() => word_count(poly_size));
+        BinaryPolynomialList {
+            tensor: Tensor::from_container(cont),
+            poly_size,
+        }
+    }
+
+    /// Returns the number of polynomials in the list.
+    pub fn polynomial_count(&self) -> PolynomialCount
+    where
+        Self: AsRefTensor,
+    {
+        PolynomialCount(self.as_tensor().len() / word_count(self.poly_size))
+    }
+
+    /// Returns the number of coefficients of the polynomials in the list.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns an iterator over the binary polynomials of the list.
+    pub fn polynomial_iter(&self) -> impl Iterator<Item = BinaryPolynomial<&[u64]>>
+    where
+        Self: AsRefTensor<Element = u64>,
+    {
+        let poly_size = self.poly_size;
+        self.as_tensor()
+            .subtensor_iter(word_count(poly_size))
+            .map(move |sub| BinaryPolynomial::from_container(sub.into_container(), poly_size))
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt;
+
+/// A type representing an error when a polynomial size is not valid for an operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidSizeError {
+    /// The size is not a power of two.
+    NotPowerOfTwo { size: usize },
+    /// The size is a power of two, smaller than the smallest size `min` supported by the
+    /// operation.
+    TooSmall { size: usize, min: usize },
+    /// The size is a power of two, larger than the largest size `max` supported by the operation.
+    TooLarge { size: usize, max: usize },
+}
+
+impl fmt::Display for InvalidSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotPowerOfTwo { size } => {
+                write!(f, "The polynomial size {} is not a power of two", size)
+            }
+            Self::TooSmall { size, min } => write!(
+                f,
+                "The polynomial size {} is too small, the smallest supported size is {}",
+                size, min
+            ),
+            Self::TooLarge { size, max } => write!(
+                f,
+                "The polynomial size {} is too large, the largest supported size is {}",
+                size, max
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InvalidSizeError {}
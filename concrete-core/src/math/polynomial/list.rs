@@ -1,9 +1,15 @@
-use std::iter::Iterator;
+use std::fmt;
+use std::iter::{FromIterator, Iterator};
+
+#[cfg(feature = "multithread")]
+use rayon::iter::IndexedParallelIterator;
 
 use crate::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
+use crate::math::DimensionError;
 use crate::numeric::UnsignedInteger;
-use crate::{ck_dim_div, tensor_traits};
+use crate::{ck_dim_div, ck_dim_eq, tensor_memory_footprint, tensor_traits};
 
+use super::polynomial::fmt_coefficients;
 use super::*;
 
 /// A generic polynomial list type.
@@ -25,6 +31,7 @@ pub struct PolynomialList<Cont> {
 }
 
 tensor_traits!(PolynomialList);
+tensor_memory_footprint!(PolynomialList);
 
 impl<Coef> PolynomialList<Vec<Coef>>
 where
@@ -46,6 +53,172 @@ where
             poly_size: size,
         }
     }
+
+    /// Appends a polynomial at the end of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the polynomial does not match the size of the polynomials of the
+    /// list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{
+    ///     Polynomial, PolynomialCount, PolynomialList, PolynomialSize,
+    /// };
+    /// let mut list = PolynomialList::allocate(0u8, PolynomialCount(0), PolynomialSize(2));
+    /// list.push_polynomial(&Polynomial::from_container(vec![1u8, 2]));
+    /// list.push_polynomial(&Polynomial::from_container(vec![3u8, 4]));
+    /// assert_eq!(list.polynomial_count(), PolynomialCount(2));
+    /// assert_eq!(list.get_polynomial(1), Polynomial::from_container(&[3u8, 4][..]));
+    /// ```
+    pub fn push_polynomial<InCont>(&mut self, poly: &Polynomial<InCont>)
+    where
+        Polynomial<InCont>: AsRefTensor<Element = Coef>,
+    {
+        assert_eq!(
+            poly.polynomial_size(),
+            self.poly_size,
+            "Tried to push a polynomial of size {} in a list of polynomials of size {}.",
+            poly.polynomial_size().0,
+            self.poly_size.0
+        );
+        self.tensor
+            .as_mut_container()
+            .extend_from_slice(poly.as_tensor().as_slice());
+    }
+
+    /// Appends the polynomials of another list at the end of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two lists do not contain polynomials of the same size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{PolynomialCount, PolynomialList, PolynomialSize};
+    /// let mut list = PolynomialList::from_container(vec![1u8, 2], PolynomialSize(2));
+    /// let other = PolynomialList::from_container(vec![3u8, 4, 5, 6], PolynomialSize(2));
+    /// list.extend_from_list(&other);
+    /// assert_eq!(list.polynomial_count(), PolynomialCount(3));
+    /// ```
+    pub fn extend_from_list<InCont>(&mut self, other: &PolynomialList<InCont>)
+    where
+        PolynomialList<InCont>: AsRefTensor<Element = Coef>,
+    {
+        assert_eq!(
+            other.polynomial_size(),
+            self.poly_size,
+            "Tried to extend a list of polynomials of size {} with polynomials of size {}.",
+            self.poly_size.0,
+            other.polynomial_size().0
+        );
+        self.tensor
+            .as_mut_container()
+            .extend_from_slice(other.as_tensor().as_slice());
+    }
+
+    /// Grows every polynomial of the list to `new_size` coefficients, setting the new
+    /// high-degree coefficients to `fill`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_size` is smaller than the current size of the polynomials. Use
+    /// [`PolynomialList::truncate`] to drop high-degree coefficients.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialList, PolynomialSize};
+    /// let mut list = PolynomialList::from_container(vec![1u8, 2, 3, 4], PolynomialSize(2));
+    /// list.resize(PolynomialSize(3), 0);
+    /// assert_eq!(list.polynomial_size(), PolynomialSize(3));
+    /// assert_eq!(list.get_polynomial(1), Polynomial::from_container(&[3u8, 4, 0][..]));
+    /// ```
+    pub fn resize(&mut self, new_size: PolynomialSize, fill: Coef) {
+        assert!(
+            new_size.0 >= self.poly_size.0,
+            "Tried to resize a list of polynomials of size {} to the smaller size {}, use \
+            `truncate`.",
+            self.poly_size.0,
+            new_size.0
+        );
+        let mut coefficients = Vec::with_capacity(self.polynomial_count().0 * new_size.0);
+        for poly in self.tensor.as_slice().chunks_exact(self.poly_size.0) {
+            coefficients.extend_from_slice(poly);
+            coefficients.resize(coefficients.len() + new_size.0 - poly.len(), fill);
+        }
+        *self = PolynomialList::from_container(coefficients, new_size);
+    }
+
+    /// Shrinks every polynomial of the list to `new_size` coefficients, dropping the
+    /// high-degree ones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_size` is larger than the current size of the polynomials.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialList, PolynomialSize};
+    /// let mut list = PolynomialList::from_container(vec![1u8, 2, 3, 4], PolynomialSize(2));
+    /// list.truncate(PolynomialSize(1));
+    /// assert_eq!(list.polynomial_size(), PolynomialSize(1));
+    /// assert_eq!(list.get_polynomial(1), Polynomial::from_container(&[3u8][..]));
+    /// ```
+    pub fn truncate(&mut self, new_size: PolynomialSize) {
+        assert!(
+            new_size.0 <= self.poly_size.0,
+            "Tried to truncate a list of polynomials of size {} to the larger size {}.",
+            self.poly_size.0,
+            new_size.0
+        );
+        let coefficients = self
+            .tensor
+            .as_slice()
+            .chunks_exact(self.poly_size.0)
+            .flat_map(|poly| poly[..new_size.0].iter().copied())
+            .collect();
+        *self = PolynomialList::from_container(coefficients, new_size);
+    }
+}
+
+/// Collects polynomials into a list, in order.
+///
+/// # Panics
+///
+/// Panics if the iterator is empty, as the size of the polynomials can not be inferred, or if the
+/// polynomials do not all have the same size.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::polynomial::{Polynomial, PolynomialCount, PolynomialList};
+/// let list: PolynomialList<Vec<u8>> = (0..3u8)
+///     .map(|i| Polynomial::from_container(vec![i, i + 1]))
+///     .collect();
+/// assert_eq!(list.polynomial_count(), PolynomialCount(3));
+/// assert_eq!(list.get_polynomial(2), Polynomial::from_container(&[2u8, 3][..]));
+/// ```
+impl<Coef> FromIterator<Polynomial<Vec<Coef>>> for PolynomialList<Vec<Coef>>
+where
+    Coef: Copy,
+{
+    fn from_iter<I: IntoIterator<Item = Polynomial<Vec<Coef>>>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let first = iter
+            .next()
+            .expect("Tried to collect an empty iterator into a polynomial list.");
+        let first_size = first.polynomial_size();
+        let mut list = PolynomialList::from_container(first.tensor.into_container(), first_size);
+        for poly in iter {
+            list.push_polynomial(&poly);
+        }
+        list
+    }
 }
 
 impl<Cont> PolynomialList<Cont> {
@@ -110,15 +283,43 @@ impl<Cont> PolynomialList<Cont> {
     /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 5u8);
     /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 6u8);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not smaller than the number of polynomials in the list. See
+    /// [`PolynomialList::try_get_polynomial`] for a fallible version.
     pub fn get_polynomial(&self, n: usize) -> Polynomial<&[<Self as AsRefTensor>::Element]>
     where
         Self: AsRefTensor,
     {
-        Polynomial {
+        self.try_get_polynomial(n)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns a reference to the n-th polynomial of the list, or an error if `n` is not smaller
+    /// than the number of polynomials in the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{PolynomialList, PolynomialSize};
+    /// let list = PolynomialList::from_container(vec![1u8, 2, 3, 4, 5, 6], PolynomialSize(2));
+    /// assert_eq!(list.try_get_polynomial(2).unwrap().sum_of_coefficients(), 11);
+    /// assert!(list.try_get_polynomial(3).is_err());
+    /// ```
+    pub fn try_get_polynomial(
+        &self,
+        n: usize,
+    ) -> Result<Polynomial<&[<Self as AsRefTensor>::Element]>, DimensionError>
+    where
+        Self: AsRefTensor,
+    {
+        DimensionError::check("polynomial index", n, self.polynomial_count().0)?;
+        Ok(Polynomial {
             tensor: self
                 .as_tensor()
                 .get_sub((n * self.poly_size.0)..(n + 1) * self.poly_size.0),
-        }
+        })
     }
 
     /// Returns a mutable reference to the n-th polynomial of the list.
@@ -135,6 +336,11 @@ impl<Cont> PolynomialList<Cont> {
     /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 10u8);
     /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 11u8);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is not smaller than the number of polynomials in the list. See
+    /// [`PolynomialList::try_get_mut_polynomial`] for a fallible version.
     pub fn get_mut_polynomial(
         &mut self,
         n: usize,
@@ -142,10 +348,37 @@ impl<Cont> PolynomialList<Cont> {
     where
         Self: AsMutTensor,
     {
+        self.try_get_mut_polynomial(n)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns a mutable reference to the n-th polynomial of the list, or an error if `n` is not
+    /// smaller than the number of polynomials in the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, PolynomialList, PolynomialSize};
+    /// let mut list = PolynomialList::from_container(vec![1u8, 2, 3, 4], PolynomialSize(2));
+    /// list.try_get_mut_polynomial(1)
+    ///     .unwrap()
+    ///     .get_mut_monomial(MonomialDegree(0))
+    ///     .set_coefficient(10);
+    /// assert_eq!(*list.get_polynomial(1).get_monomial(MonomialDegree(0)).get_coefficient(), 10);
+    /// assert!(list.try_get_mut_polynomial(2).is_err());
+    /// ```
+    pub fn try_get_mut_polynomial(
+        &mut self,
+        n: usize,
+    ) -> Result<Polynomial<&mut [<Self as AsRefTensor>::Element]>, DimensionError>
+    where
+        Self: AsMutTensor,
+    {
+        DimensionError::check("polynomial index", n, self.polynomial_count().0)?;
         let index = (n * self.poly_size.0)..((n + 1) * self.poly_size.0);
-        Polynomial {
+        Ok(Polynomial {
             tensor: self.as_mut_tensor().get_sub_mut(index),
-        }
+        })
     }
 
     /// Returns an iterator over references to the polynomials contained in the list.
@@ -199,6 +432,182 @@ impl<Cont> PolynomialList<Cont> {
             .map(|sub| Polynomial::from_container(sub.into_container()))
     }
 
+    /// Returns a parallel iterator over borrowed polynomials contained in the list.
+    ///
+    /// The polynomials are exact chunks of `polynomial_size` coefficients of the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{PolynomialList, PolynomialSize};
+    /// use rayon::iter::ParallelIterator;
+    /// let list =
+    ///     PolynomialList::from_container(vec![1u8, 2, 3, 4, 5, 6, 7, 8], PolynomialSize(2));
+    /// let sums: Vec<u8> = list
+    ///     .par_polynomial_iter()
+    ///     .map(|poly| poly.sum_of_coefficients())
+    ///     .collect();
+    /// assert_eq!(sums, vec![3, 7, 11, 15]);
+    /// ```
+    #[cfg(feature = "multithread")]
+    pub fn par_polynomial_iter(
+        &self,
+    ) -> impl IndexedParallelIterator<Item = Polynomial<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+        <Self as AsRefTensor>::Element: Sync,
+    {
+        use rayon::prelude::*;
+        self.as_tensor()
+            .as_slice()
+            .par_chunks_exact(self.poly_size.0)
+            .map(Polynomial::from_container)
+    }
+
+    /// Returns a parallel iterator over mutably borrowed polynomials contained in the list.
+    ///
+    /// The polynomials are exact chunks of `polynomial_size` coefficients of the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, PolynomialList, PolynomialSize};
+    /// use concrete_core::math::tensor::{AsRefSlice, AsRefTensor};
+    /// use rayon::iter::ParallelIterator;
+    /// let mut list = PolynomialList::from_container(vec![1u8, 2, 3, 4, 5, 6], PolynomialSize(2));
+    /// list.par_polynomial_iter_mut()
+    ///     .for_each(|mut poly| poly.get_mut_monomial(MonomialDegree(0)).set_coefficient(10));
+    /// assert_eq!(list.as_tensor().as_slice(), &[10, 2, 10, 4, 10, 6]);
+    /// ```
+    #[cfg(feature = "multithread")]
+    pub fn par_polynomial_iter_mut(
+        &mut self,
+    ) -> impl IndexedParallelIterator<Item = Polynomial<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+        <Self as AsMutTensor>::Element: Send,
+    {
+        use crate::math::tensor::AsMutSlice;
+        use rayon::prelude::*;
+        let chunks_size = self.poly_size.0;
+        self.as_mut_tensor()
+            .as_mut_slice()
+            .par_chunks_exact_mut(chunks_size)
+            .map(Polynomial::from_container)
+    }
+
+    /// Reduces the polynomials of the list into a single polynomial.
+    ///
+    /// Starting from `init`, the function `f` is called on the accumulator and on every
+    /// polynomial of the list, in order. Sums, weighted sums or inner products of the
+    /// polynomials can all be expressed this way.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialList, PolynomialSize};
+    /// let list = PolynomialList::from_container(vec![1u8, 2, 3, 4, 5, 6], PolynomialSize(2));
+    ///
+    /// // The sum of the polynomials.
+    /// let sum = list.fold_to_polynomial(Polynomial::allocate(0u8, PolynomialSize(2)), |acc, poly| {
+    ///     acc.update_with_wrapping_add(&poly)
+    /// });
+    /// assert_eq!(sum, Polynomial::from_container(vec![9, 12]));
+    ///
+    /// // A weighted sum of the polynomials.
+    /// let mut weights = vec![1u8, 2, 3].into_iter();
+    /// let weighted = list.fold_to_polynomial(
+    ///     Polynomial::allocate(0u8, PolynomialSize(2)),
+    ///     |acc, poly| {
+    ///         let weight = weights.next().unwrap();
+    ///         for (a, p) in acc.coefficient_iter_mut().zip(poly.coefficient_iter()) {
+    ///             *a = a.wrapping_add(p.wrapping_mul(weight));
+    ///         }
+    ///     },
+    /// );
+    /// assert_eq!(weighted, Polynomial::from_container(vec![22, 28]));
+    /// ```
+    pub fn fold_to_polynomial<Coef, F>(
+        &self,
+        init: Polynomial<Vec<Coef>>,
+        mut f: F,
+    ) -> Polynomial<Vec<Coef>>
+    where
+        Self: AsRefTensor<Element = Coef>,
+        F: FnMut(&mut Polynomial<Vec<Coef>>, Polynomial<&[Coef]>),
+    {
+        ck_dim_eq!(init.polynomial_size().0 => self.poly_size.0);
+        let mut accumulator = init;
+        for poly in self.polynomial_iter() {
+            f(&mut accumulator, poly);
+        }
+        accumulator
+    }
+
+    /// Returns the largest distance to zero of the coefficients of the polynomials of the list,
+    /// when interpreted on the torus.
+    ///
+    /// See [`Polynomial::max_torus_distance_from_zero`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{PolynomialList, PolynomialSize};
+    /// let list = PolynomialList::from_container(vec![3u8, 250, 0, 5], PolynomialSize(2));
+    /// assert_eq!(list.max_torus_distance_from_zero(), 6);
+    /// ```
+    pub fn max_torus_distance_from_zero<Coef>(&self) -> Coef
+    where
+        Self: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        self.polynomial_iter()
+            .map(|poly| poly.max_torus_distance_from_zero())
+            .fold(Coef::ZERO, std::cmp::max)
+    }
+
+    /// Returns the wrapping sum of the coefficients of all the polynomials of the list.
+    ///
+    /// See [`Polynomial::sum_of_coefficients`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{PolynomialList, PolynomialSize};
+    /// let list = PolynomialList::from_container(vec![3u8, 250, 0, 5], PolynomialSize(2));
+    /// assert_eq!(list.sum_of_coefficients(), 2);
+    /// ```
+    pub fn sum_of_coefficients<Coef>(&self) -> Coef
+    where
+        Self: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        self.polynomial_iter().fold(Coef::ZERO, |acc, poly| {
+            acc.wrapping_add(poly.sum_of_coefficients())
+        })
+    }
+
+    /// Returns the number of non-zero coefficients of all the polynomials of the list.
+    ///
+    /// See [`Polynomial::count_nonzero`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{PolynomialList, PolynomialSize};
+    /// let list = PolynomialList::from_container(vec![3u8, 250, 0, 5], PolynomialSize(2));
+    /// assert_eq!(list.count_nonzero(), 3);
+    /// ```
+    pub fn count_nonzero<Coef>(&self) -> usize
+    where
+        Self: AsRefTensor<Element = Coef>,
+        Coef: PartialEq + Default,
+    {
+        self.polynomial_iter()
+            .map(|poly| poly.count_nonzero())
+            .sum()
+    }
+
     /// Multiplies (mod $(X^N+1)$), all the polynomials of the list with a unit monomial of a
     /// given degree.
     ///
@@ -223,6 +632,35 @@ impl<Cont> PolynomialList<Cont> {
         }
     }
 
+    /// Multiplies (mod $(X^N+1)$), all the polynomials of the list with a unit monomial of a
+    /// given degree, on the rayon thread pool.
+    ///
+    /// See [`PolynomialList::update_with_wrapping_monic_monomial_mul`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, PolynomialList, PolynomialSize};
+    /// let mut list = PolynomialList::from_container(vec![1u8,2,3,4,5,6], PolynomialSize(3));
+    /// list.par_update_with_wrapping_monic_monomial_mul(MonomialDegree(2));
+    /// let poly = list.get_polynomial(0);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 254);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 253);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 1);
+    /// ```
+    #[cfg(feature = "multithread")]
+    pub fn par_update_with_wrapping_monic_monomial_mul<Coef>(
+        &mut self,
+        monomial_degree: MonomialDegree,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Coef: UnsignedInteger + Send,
+    {
+        use rayon::iter::ParallelIterator;
+        self.par_polynomial_iter_mut()
+            .for_each(|mut poly| poly.update_with_wrapping_monic_monomial_mul(monomial_degree));
+    }
+
     /// Divides (mod $(X^N+1)$), all the polynomials of the list with a unit monomial of a
     /// given degree.
     ///
@@ -246,4 +684,125 @@ impl<Cont> PolynomialList<Cont> {
             poly.update_with_wrapping_unit_monomial_div(monomial_degree);
         }
     }
+
+    /// Multiplies all the coefficients of the polynomials of the list by a scalar, with
+    /// wrapping.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, PolynomialList, PolynomialSize};
+    /// let mut list =
+    ///     PolynomialList::from_container(vec![1u8, 2, 3, 4, 5, 100], PolynomialSize(3));
+    /// list.update_with_wrapping_scalar_mul(3);
+    /// let poly = list.get_polynomial(1);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 12);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 15);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 44);
+    /// ```
+    pub fn update_with_wrapping_scalar_mul<Coef>(&mut self, scalar: Coef)
+    where
+        Self: AsMutTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        self.as_mut_tensor()
+            .update_with_wrapping_scalar_mul(&scalar);
+    }
+
+    /// Fills the current list with the polynomials of another list multiplied by a scalar, with
+    /// wrapping.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{
+    ///     MonomialDegree, PolynomialCount, PolynomialList, PolynomialSize,
+    /// };
+    /// let list = PolynomialList::from_container(vec![1u8, 2, 3, 4, 5, 100], PolynomialSize(3));
+    /// let mut res = PolynomialList::allocate(0u8, PolynomialCount(2), PolynomialSize(3));
+    /// res.fill_with_wrapping_scalar_mul(&list, 255);
+    /// let poly = res.get_polynomial(1);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 252);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 251);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 156);
+    /// ```
+    pub fn fill_with_wrapping_scalar_mul<Coef, InCont>(
+        &mut self,
+        list: &PolynomialList<InCont>,
+        scalar: Coef,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        PolynomialList<InCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.polynomial_size() => list.polynomial_size());
+        ck_dim_eq!(self.polynomial_count() => list.polynomial_count());
+        self.as_mut_tensor()
+            .fill_with_wrapping_element_mul(list.as_tensor(), scalar);
+    }
+
+    /// Fills every polynomial of the current list with the coefficients of the polynomial of a
+    /// source list with the same index, and zeroes the coefficients of higher degrees.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polynomials of the source list are larger than the ones of the current
+    /// list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{
+    ///     Polynomial, PolynomialCount, PolynomialList, PolynomialSize,
+    /// };
+    /// let source = PolynomialList::from_container(vec![1u8, 2, 3, 4], PolynomialSize(2));
+    /// let mut list = PolynomialList::allocate(7u8, PolynomialCount(2), PolynomialSize(4));
+    /// list.fill_with_resized_copy(&source);
+    /// assert_eq!(list.get_polynomial(1), Polynomial::from_container(&[3u8, 4, 0, 0][..]));
+    /// ```
+    pub fn fill_with_resized_copy<Coef, InCont>(&mut self, source: &PolynomialList<InCont>)
+    where
+        Self: AsMutTensor<Element = Coef>,
+        PolynomialList<InCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.polynomial_count() => source.polynomial_count());
+        for (mut poly, source_poly) in self.polynomial_iter_mut().zip(source.polynomial_iter()) {
+            poly.fill_with_resized_copy(&source_poly);
+        }
+    }
+}
+
+/// Displays a list of polynomials, one polynomial per line.
+///
+/// The polynomials are formatted as in the [`Display`](fmt::Display) implementation of
+/// [`Polynomial`], with the same precision and alternate flag.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::polynomial::{PolynomialList, PolynomialSize};
+/// let list = PolynomialList::from_container(vec![1u8, 2, 3, 4], PolynomialSize(2));
+/// assert_eq!(format!("{}", list), "P_0(X) = 1 + 2·X\nP_1(X) = 3 + 4·X");
+/// ```
+impl<Cont, Coef> fmt::Display for PolynomialList<Cont>
+where
+    PolynomialList<Cont>: AsRefTensor<Element = Coef>,
+    Coef: UnsignedInteger + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, poly) in self
+            .as_tensor()
+            .as_slice()
+            .chunks(self.poly_size.0)
+            .enumerate()
+        {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "P_{}(X) = ", index)?;
+            fmt_coefficients(f, poly)?;
+        }
+        Ok(())
+    }
 }
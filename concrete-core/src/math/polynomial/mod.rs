@@ -7,21 +7,31 @@
 //! + [`Polynomial`], which represents a dense polynomial of a given degree.
 //! + [`PolynomialList`], which represent a set of polynomials with the same degree, on which
 //! operations can be performed.
+//! + [`BinaryPolynomial`] and [`BinaryPolynomialList`], which store binary polynomials with their
+//!   coefficients packed in words.
+//! + [`SparsePolynomial`], which stores the non-zero monomials of a polynomial.
 //!
 
 use serde::{Deserialize, Serialize};
 
+pub use binary::*;
+pub use errors::*;
 pub use list::*;
 pub use monomial::*;
 pub use polynomial::*;
+pub use sparse::*;
 
 #[cfg(test)]
 mod tests;
 
+mod binary;
+mod errors;
 mod list;
 mod monomial;
 #[allow(clippy::module_inception)]
 mod polynomial;
+mod sparse;
+mod toom;
 
 /// The degree of a monomial.
 ///
@@ -35,8 +45,51 @@ pub struct MonomialDegree(pub usize);
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PolynomialSize(pub usize);
 
+impl PolynomialSize {
+    /// Returns the size of polynomials with `n` coefficients, if `n` is a power of two.
+    ///
+    /// The fourier and number theoretic transforms only accept sizes which are powers of two,
+    /// which is not enforced when a `PolynomialSize` is built directly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{InvalidSizeError, PolynomialSize};
+    /// assert_eq!(
+    ///     PolynomialSize::validated_power_of_two(1024),
+    ///     Ok(PolynomialSize(1024))
+    /// );
+    /// assert_eq!(
+    ///     PolynomialSize::validated_power_of_two(1000),
+    ///     Err(InvalidSizeError::NotPowerOfTwo { size: 1000 })
+    /// );
+    /// ```
+    pub fn validated_power_of_two(n: usize) -> Result<PolynomialSize, InvalidSizeError> {
+        if n.is_power_of_two() {
+            Ok(PolynomialSize(n))
+        } else {
+            Err(InvalidSizeError::NotPowerOfTwo { size: n })
+        }
+    }
+}
+
 /// The number of polynomials in a polynomial list.
 ///
 /// Assuming a polynomial list, this return the number of polynomials.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PolynomialCount(pub usize);
+
+/// The modulus polynomial used to reduce the polynomial products.
+///
+/// The polynomials manipulated by the library are elements of $\mathbb{Z}_{2^q}\[X\] / <X^N + 1>$,
+/// and the products are reduced modulo $X^N + 1$ by default. The cyclic reduction modulo
+/// $X^N - 1$ can be used instead, in which case the monomials exceeding the degree wrap around
+/// without flipping their sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReductionMode {
+    /// The reduction modulo $X^N + 1$.
+    #[default]
+    Negacyclic,
+    /// The reduction modulo $X^N - 1$.
+    Cyclic,
+}
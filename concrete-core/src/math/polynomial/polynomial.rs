@@ -1,12 +1,33 @@
+use std::fmt;
 use std::fmt::Debug;
 use std::iter::Iterator;
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+#[cfg(feature = "npy")]
+use std::path::Path;
 
-use crate::math::tensor::{AsMutSlice, AsMutTensor, AsRefTensor, Tensor};
-use crate::numeric::{CastFrom, UnsignedInteger};
-use crate::{ck_dim_eq, tensor_traits};
+use crate::math::fft::{Fft, FftBuffers, FftView};
+use crate::math::ntt::{Ntt, NttPolynomial};
+use crate::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
+#[cfg(feature = "npy")]
+use crate::math::tensor::{NpyElement, NpyError};
+use crate::math::DimensionError;
+use crate::numeric::{
+    CastFrom, CastInto, ConstantTimeSelect, Numeric, SignedInteger, UnsignedInteger,
+};
+use crate::{ck_dim_eq, tensor_memory_footprint, tensor_traits};
 
 use super::*;
 
+/// The largest size for which [`Polynomial::fill_with_fast_mul`] computes the products in the
+/// fourier domain without being given a transformer.
+///
+/// The rounding error of the `f64` products grows linearly with the size. With the bound of
+/// [`estimate_roundtrip_noise`](crate::math::fft::estimate_roundtrip_noise), a product of size
+/// 16384 is only exact when $\max |a\_i| \cdot \max |b\_i|$ stays below about $2^{30}$, and
+/// the larger sizes are no longer exact for coefficients of 15 bits. They use the schoolbook
+/// product instead.
+const FAST_MUL_MAX_SIZE: usize = 16384;
+
 /// A dense polynomial.
 ///
 /// This type represent a dense polynomial in $\mathbb{Z}_{2^q}\[X\] / <X^N + 1>$, composed of $N$
@@ -25,6 +46,7 @@ pub struct Polynomial<Cont> {
 }
 
 tensor_traits!(Polynomial);
+tensor_memory_footprint!(Polynomial);
 
 impl<Scalar> Polynomial<Vec<Scalar>>
 where
@@ -42,6 +64,60 @@ where
     pub fn allocate(value: Scalar, coef_count: PolynomialSize) -> Polynomial<Vec<Scalar>> {
         Polynomial::from_container(vec![value; coef_count.0])
     }
+
+    /// Grows the polynomial to `new_size` coefficients, setting the new high-degree coefficients
+    /// to `fill`.
+    ///
+    /// The existing coefficients keep their degrees.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_size` is smaller than the current size. Use [`Polynomial::truncate`] to
+    /// drop high-degree coefficients.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialSize};
+    /// let mut poly = Polynomial::from_container(vec![1u8, 2]);
+    /// poly.resize(PolynomialSize(4), 0);
+    /// assert_eq!(poly.polynomial_size(), PolynomialSize(4));
+    /// assert_eq!(poly, Polynomial::from_container(vec![1u8, 2, 0, 0]));
+    /// ```
+    pub fn resize(&mut self, new_size: PolynomialSize, fill: Scalar) {
+        assert!(
+            new_size.0 >= self.tensor.len(),
+            "Tried to resize a polynomial of size {} to the smaller size {}, use `truncate`.",
+            self.tensor.len(),
+            new_size.0
+        );
+        self.tensor.as_mut_container().resize(new_size.0, fill);
+    }
+
+    /// Shrinks the polynomial to `new_size` coefficients, dropping the high-degree ones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_size` is larger than the current size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialSize};
+    /// let mut poly = Polynomial::from_container(vec![1u8, 2, 3, 4]);
+    /// poly.truncate(PolynomialSize(2));
+    /// assert_eq!(poly.polynomial_size(), PolynomialSize(2));
+    /// assert_eq!(poly, Polynomial::from_container(vec![1u8, 2]));
+    /// ```
+    pub fn truncate(&mut self, new_size: PolynomialSize) {
+        assert!(
+            new_size.0 <= self.tensor.len(),
+            "Tried to truncate a polynomial of size {} to the larger size {}.",
+            self.tensor.len(),
+            new_size.0
+        );
+        self.tensor.as_mut_container().truncate(new_size.0);
+    }
 }
 
 impl<Cont> Polynomial<Cont> {
@@ -132,6 +208,11 @@ impl<Cont> Polynomial<Cont> {
     /// let mono = poly.get_monomial(MonomialDegree(2));
     /// assert_eq!(*mono.get_coefficient(), 19_u32);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the degree is not smaller than the size of the polynomial. See
+    /// [`Polynomial::try_get_monomial`] for a fallible version.
     pub fn get_monomial(
         &self,
         degree: MonomialDegree,
@@ -139,12 +220,319 @@ impl<Cont> Polynomial<Cont> {
     where
         Self: AsRefTensor,
     {
-        Monomial::from_container(
+        self.try_get_monomial(degree)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns the monomial of a given degree, or an error if the degree is not smaller than the
+    /// size of the polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial};
+    /// let poly = Polynomial::from_container(vec![16_u32, 8, 19]);
+    /// let mono = poly.try_get_monomial(MonomialDegree(2)).unwrap();
+    /// assert_eq!(*mono.get_coefficient(), 19_u32);
+    /// assert!(poly.try_get_monomial(MonomialDegree(3)).is_err());
+    /// ```
+    pub fn try_get_monomial(
+        &self,
+        degree: MonomialDegree,
+    ) -> Result<Monomial<&[<Self as AsRefTensor>::Element]>, DimensionError>
+    where
+        Self: AsRefTensor,
+    {
+        DimensionError::check("monomial degree", degree.0, self.as_tensor().len())?;
+        Ok(Monomial::from_container(
             self.as_tensor()
                 .get_sub(degree.0..=degree.0)
                 .into_container(),
             degree,
-        )
+        ))
+    }
+
+    /// Computes the $L\_1$ norm of the polynomial in centered representation.
+    ///
+    /// Each coefficient $a\_i$ is interpreted as an element of $[-2^{q-1}, 2^{q-1})$, values in
+    /// $[2^{q-1}, 2^q)$ being considered negative, and the absolute values
+    /// $\min(a\_i, 2^q - a\_i)$ are summed.
+    ///
+    /// # Note
+    ///
+    /// The absolute values, and their sum, saturate at `u64::MAX`, which only matters for
+    /// coefficients wider than 64 bits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::Polynomial;
+    /// let poly = Polynomial::from_container(vec![1u8, 255, 128, 3, 250]);
+    /// assert_eq!(poly.centered_l1_norm(), 1 + 1 + 128 + 3 + 6);
+    /// ```
+    pub fn centered_l1_norm<Coef>(&self) -> u64
+    where
+        Self: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger + CastInto<u64> + CastFrom<u64>,
+    {
+        self.coefficient_iter().fold(0u64, |acc, coef| {
+            let abs = (*coef).min(coef.wrapping_neg());
+            let abs: u64 = if Coef::BITS > 64 && abs > Coef::cast_from(u64::MAX) {
+                u64::MAX
+            } else {
+                abs.cast_into()
+            };
+            acc.saturating_add(abs)
+        })
+    }
+
+    /// Builds an iterator over the coefficients of the polynomial in centered representation.
+    ///
+    /// Each coefficient $a\_i$ is reinterpreted in two's complement as an element of
+    /// $[-2^{q-1}, 2^{q-1})$, values in $[2^{q-1}, 2^q)$ being mapped to $a\_i - 2^q$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::Polynomial;
+    /// let poly = Polynomial::from_container(vec![1u8, 255, 128, 127, 250]);
+    /// let centered: Vec<i8> = poly.centered_coefficients_iter().collect();
+    /// assert_eq!(centered, vec![1, -1, -128, 127, -6]);
+    /// ```
+    pub fn centered_coefficients_iter<Coef, SignedScalar>(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = SignedScalar> + '_
+    where
+        Self: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger<Signed = SignedScalar>,
+        SignedScalar: SignedInteger<Unsigned = Coef>,
+    {
+        self.coefficient_iter().map(|coef| coef.into_signed())
+    }
+
+    /// Computes the auto-correlation of the polynomial for a given cyclic shift.
+    ///
+    /// The coefficients are taken in centered representation (see
+    /// [`Polynomial::centered_coefficients_iter`]), and the auto-correlation is
+    /// $\frac{1}{N}\sum\_{i=0}^{N-1} a\_i a\_{(i + shift) \bmod N}$.
+    ///
+    /// # Note
+    ///
+    /// A single shift is computed directly in $O(N)$, which is cheaper than going through the
+    /// fourier domain. The terms are accumulated in `f64`, so the result is only approximate for
+    /// coefficients larger than $2^{26}$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::Polynomial;
+    /// let poly = Polynomial::from_container(vec![1u32, 0u32.wrapping_sub(1), 2, 0]);
+    /// assert_eq!(poly.auto_correlation(0), (1. + 1. + 4.) / 4.);
+    /// assert_eq!(poly.auto_correlation(1), (-1. - 2. + 0. + 0.) / 4.);
+    /// assert_eq!(poly.auto_correlation(5), poly.auto_correlation(1));
+    /// ```
+    pub fn auto_correlation<Coef>(&self, shift: usize) -> f64
+    where
+        Self: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        let size = self.polynomial_size().0;
+        if size == 0 {
+            return 0.;
+        }
+        let coefficients = self.as_tensor().as_slice();
+        let (head, tail) = coefficients.split_at(shift % size);
+        let sum: f64 = coefficients
+            .iter()
+            .zip(tail.iter().chain(head.iter()))
+            .map(|(a, b)| {
+                let a: f64 = a.into_signed().cast_into();
+                let b: f64 = b.into_signed().cast_into();
+                a * b
+            })
+            .sum();
+        sum / size as f64
+    }
+
+    /// Returns the number of non-zero coefficients of the polynomial.
+    ///
+    /// A coefficient is zero if it is equal to the default value of its type, i.e. `0` for the
+    /// numeric types, and `false` for the booleans. For a binary polynomial, this is its Hamming
+    /// weight.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::Polynomial;
+    /// let poly = Polynomial::from_container(vec![1u8, 0, 128, 0, 250]);
+    /// assert_eq!(poly.count_nonzero(), 3);
+    /// let binary_poly = Polynomial::from_container(vec![true, false, false, true]);
+    /// assert_eq!(binary_poly.count_nonzero(), 2);
+    /// ```
+    pub fn count_nonzero<Coef>(&self) -> usize
+    where
+        Self: AsRefTensor<Element = Coef>,
+        Coef: PartialEq + Default,
+    {
+        let zero = Coef::default();
+        self.coefficient_iter()
+            .filter(|coef| **coef != zero)
+            .count()
+    }
+
+    /// Returns the proportion of non-zero coefficients of the polynomial, in $[0, 1]$.
+    ///
+    /// See [`Polynomial::count_nonzero`] for the definition of a non-zero coefficient.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::Polynomial;
+    /// let poly = Polynomial::from_container(vec![1u8, 0, 128, 0]);
+    /// assert_eq!(poly.sparsity(), 0.5);
+    /// ```
+    pub fn sparsity<Coef>(&self) -> f64
+    where
+        Self: AsRefTensor<Element = Coef>,
+        Coef: PartialEq + Default,
+    {
+        self.count_nonzero() as f64 / self.polynomial_size().0 as f64
+    }
+
+    /// Returns the wrapping sum of the coefficients of the polynomial.
+    ///
+    /// This is the evaluation $P(1)$ of the polynomial at $X = 1$, which does not depend on the
+    /// reduction modulo $X^N + 1$ or $X^N - 1$. When the slots of a plaintext are packed in the
+    /// coefficients of a polynomial, this extracts the sum of the slots with a single pass over
+    /// the coefficients, without evaluating any power of $X$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::Polynomial;
+    /// let poly = Polynomial::from_container(vec![1u8, 2, 3, 4]);
+    /// assert_eq!(poly.sum_of_coefficients(), 10);
+    /// let poly = Polynomial::from_container(vec![200u8, 100, 0, 1]);
+    /// assert_eq!(poly.sum_of_coefficients(), 45);
+    /// ```
+    pub fn sum_of_coefficients<Coef>(&self) -> Coef
+    where
+        Self: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        self.coefficient_iter()
+            .fold(Coef::ZERO, |acc, coef| acc.wrapping_add(*coef))
+    }
+
+    /// Returns the largest distance to zero of the coefficients of the polynomial, when
+    /// interpreted on the torus.
+    ///
+    /// A coefficient $a$ encoded on $q$ bits is at distance $\min(a, 2^q - a)$ from zero, i.e. the
+    /// coefficients larger than $2^{q-1}$ are seen as negative values. This is the infinity norm
+    /// of the centered coefficients (see [`Polynomial::centered_coefficients_iter`]), which is
+    /// handy to monitor the noise contained in a polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::Polynomial;
+    /// let poly = Polynomial::from_container(vec![3u8, 250, 0, 5]);
+    /// assert_eq!(poly.max_torus_distance_from_zero(), 6);
+    /// let poly = Polynomial::from_container(vec![1u8, 128]);
+    /// assert_eq!(poly.max_torus_distance_from_zero(), 128);
+    /// ```
+    pub fn max_torus_distance_from_zero<Coef>(&self) -> Coef
+    where
+        Self: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        self.coefficient_iter()
+            .map(|coef| std::cmp::min(*coef, coef.wrapping_neg()))
+            .fold(Coef::ZERO, std::cmp::max)
+    }
+
+    /// Computes the negacyclic number theoretic transform of the polynomial modulo `prime`.
+    ///
+    /// The coefficients are reduced modulo `prime`, and the $i$-th value of the output is the
+    /// evaluation $\sum\_{j=0}^{N-1} a\_j \psi^{(2i+1)j}$ of the polynomial at the $(2i+1)$-th
+    /// power of a primitive $2N$-th root of unity $\psi$. The root is $\psi = g^{(p-1)/2N}$, with
+    /// $g$ the smallest integer greater than one such that $\psi^N = -1 \bmod p$.
+    ///
+    /// # Note
+    ///
+    /// This transform is computed with the schoolbook $O(N^2)$ algorithm, and is only meant to be
+    /// used as a reference when debugging an optimized implementation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the polynomial is not a power of two, or if `prime` is not congruent
+    /// to one modulo $2N$. The primality of `prime` is not checked, but the transform panics if
+    /// no root of unity of the right order is found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::Polynomial;
+    /// // Modulo 17, the primitive 8-th root of unity is 9.
+    /// let poly = Polynomial::from_container(vec![0u32, 1, 0, 0]);
+    /// assert_eq!(poly.to_negacyclic_ntt(17), vec![9, 15, 8, 2]);
+    /// ```
+    pub fn to_negacyclic_ntt<Coef>(&self, prime: u64) -> Vec<u64>
+    where
+        Self: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger + CastInto<u64>,
+    {
+        fn add_mod(a: u64, b: u64, modulus: u64) -> u64 {
+            ((a as u128 + b as u128) % modulus as u128) as u64
+        }
+        fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+            ((a as u128 * b as u128) % modulus as u128) as u64
+        }
+        fn pow_mod(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+            let mut result = 1 % modulus;
+            while exponent > 0 {
+                if exponent & 1 == 1 {
+                    result = mul_mod(result, base, modulus);
+                }
+                base = mul_mod(base, base, modulus);
+                exponent >>= 1;
+            }
+            result
+        }
+
+        let size = self.polynomial_size().0;
+        assert!(
+            size.is_power_of_two(),
+            "The polynomial size must be a power of two, got {}.",
+            size
+        );
+        let order = 2 * size as u64;
+        assert!(
+            prime > 2 && prime % order == 1,
+            "The modulus {} is not congruent to one modulo {}.",
+            prime,
+            order
+        );
+        let psi = (2..prime)
+            .map(|g| pow_mod(g, (prime - 1) / order, prime))
+            .find(|psi| pow_mod(*psi, size as u64, prime) == prime - 1)
+            .expect("No primitive root of unity found, the modulus is not a prime.");
+
+        let coefficients: Vec<u64> = self
+            .coefficient_iter()
+            .map(|coef| {
+                let coef: u64 = (*coef).cast_into();
+                coef % prime
+            })
+            .collect();
+        (0..size as u64)
+            .map(|i| {
+                let root = pow_mod(psi, 2 * i + 1, prime);
+                coefficients.iter().rev().fold(0, |acc, coef| {
+                    add_mod(mul_mod(acc, root, prime), *coef, prime)
+                })
+            })
+            .collect()
     }
 
     /// Builds an iterator over `Monomial<&mut Coef>` elements.
@@ -210,6 +598,11 @@ impl<Cont> Polynomial<Cont> {
     /// let mono = poly.get_monomial(MonomialDegree(0));
     /// assert_eq!(*mono.get_coefficient(), 18);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the degree is not smaller than the size of the polynomial. See
+    /// [`Polynomial::try_get_mut_monomial`] for a fallible version.
     pub fn get_mut_monomial(
         &mut self,
         degree: MonomialDegree,
@@ -217,12 +610,38 @@ impl<Cont> Polynomial<Cont> {
     where
         Self: AsMutTensor,
     {
-        Monomial::from_container(
+        self.try_get_mut_monomial(degree)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns the mutable monomial of a given degree, or an error if the degree is not smaller
+    /// than the size of the polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial};
+    /// let mut poly = Polynomial::from_container(vec![16_u32, 8, 19]);
+    /// poly.try_get_mut_monomial(MonomialDegree(2))
+    ///     .unwrap()
+    ///     .set_coefficient(18);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 18);
+    /// assert!(poly.try_get_mut_monomial(MonomialDegree(3)).is_err());
+    /// ```
+    pub fn try_get_mut_monomial(
+        &mut self,
+        degree: MonomialDegree,
+    ) -> Result<Monomial<&mut [<Self as AsMutTensor>::Element]>, DimensionError>
+    where
+        Self: AsMutTensor,
+    {
+        DimensionError::check("monomial degree", degree.0, self.as_tensor().len())?;
+        Ok(Monomial::from_container(
             self.as_mut_tensor()
                 .get_sub_mut(degree.0..=degree.0)
                 .into_container(),
             degree,
-        )
+        ))
     }
 
     /// Fills the current polynomial, with the result of the (slow) product of two polynomials,
@@ -250,110 +669,599 @@ impl<Cont> Polynomial<Cont> {
         Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
         Coef: UnsignedInteger,
     {
-        ck_dim_eq!(self.polynomial_size() => lhs.polynomial_size(), rhs.polynomial_size());
-        self.coefficient_iter_mut().for_each(|a| *a = Coef::ZERO);
-        let degree = lhs.polynomial_size().0 - 1;
-        for lhsi in lhs.monomial_iter() {
-            for rhsi in rhs.monomial_iter() {
-                let target_degree = lhsi.degree().0 + rhsi.degree().0;
-                if target_degree <= degree {
-                    let element = self.as_mut_tensor().get_element_mut(target_degree);
-                    let new = lhsi.get_coefficient().wrapping_mul(*rhsi.get_coefficient());
-                    *element = element.wrapping_add(new);
-                } else {
-                    let element = self
-                        .as_mut_tensor()
-                        .get_element_mut(target_degree % (degree + 1));
-                    let new = lhsi.get_coefficient().wrapping_mul(*rhsi.get_coefficient());
-                    *element = element.wrapping_sub(new);
-                }
-            }
-        }
+        self.fill_with_wrapping_mul_with_reduction(lhs, rhs, ReductionMode::Negacyclic);
     }
 
-    /// Fills the current polynomial with the result of the product between an integer polynomial
-    /// and binary one, reduced modulo $(X^N + 1)$.
+    /// Fills the current polynomial, with the result of the (slow) product of two polynomials,
+    /// reduced modulo $(X^N - 1)$.
     ///
-    /// # Example:
+    /// This is the cyclic convolution of the coefficients: contrary to
+    /// [`Polynomial::fill_with_wrapping_mul`], where the monomials of degree $N + i$ are
+    /// subtracted from the coefficient of degree $i$ since $X^N = -1$, they are added to it since
+    /// $X^N = 1$.
+    ///
+    /// # Example
     ///
     /// ```
-    /// use concrete_core::math::polynomial::{Polynomial, PolynomialSize, MonomialDegree};
-    /// let poly = Polynomial::from_container(vec![1_u8, 2, 3]);
-    /// let bin_poly = Polynomial::from_container(vec![false, true, true]);
-    /// let mut res = Polynomial::allocate(133 as u8, PolynomialSize(3));
-    /// res.fill_with_wrapping_binary_mul(&poly, &bin_poly);
-    /// dbg!(&res);
-    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 251 as u8);
-    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 254 as u8);
-    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 3 as u8);
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialSize};
+    /// let lhs = Polynomial::from_container(vec![4_u8, 5, 1]);
+    /// let rhs = Polynomial::from_container(vec![7_u8, 9, 0]);
+    /// let mut res = Polynomial::allocate(0 as u8, PolynomialSize(3));
+    /// res.fill_with_wrapping_cyclic_mul(&lhs, &rhs);
+    /// // 9X^3 = 9 modulo X^3 - 1
+    /// assert_eq!(res, Polynomial::from_container(vec![37_u8, 71, 52]));
     /// ```
-    pub fn fill_with_wrapping_binary_mul<Coef, PolyCont, BinCont>(
+    pub fn fill_with_wrapping_cyclic_mul<Coef, LhsCont, RhsCont>(
         &mut self,
-        poly: &Polynomial<PolyCont>,
-        bin_poly: &Polynomial<BinCont>,
+        lhs: &Polynomial<LhsCont>,
+        rhs: &Polynomial<RhsCont>,
     ) where
         Self: AsMutTensor<Element = Coef>,
-        Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
-        Polynomial<BinCont>: AsRefTensor<Element = bool>,
+        Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
         Coef: UnsignedInteger,
     {
-        ck_dim_eq!(
-            self.polynomial_size() =>
-            poly.polynomial_size(),
-            bin_poly.polynomial_size()
-        );
-        self.coefficient_iter_mut().for_each(|a| *a = Coef::ZERO);
-        self.update_with_wrapping_add_binary_mul(poly, bin_poly)
+        self.fill_with_wrapping_mul_with_reduction(lhs, rhs, ReductionMode::Cyclic);
     }
 
-    /// Adds the sum of the element-wise product between a list of integer polynomial, and a
-    /// list of binary polynomial, to the current polynomial.
-    ///
-    /// I.e., if the current polynomial is $C(X)$, for a collection of polynomials $(P_i(X)))_i$
-    /// and a collection of binary polynomials $(B_i(X))_i$ we perform the operation:
-    /// $$
-    /// C(X) := C(X) + \sum_i P_i(X) \times B_i(X) mod (X^N + 1)
-    /// $$
+    /// Fills the current polynomial, with the result of the (slow) product of two polynomials,
+    /// reduced modulo $(X^N + 1)$ or $(X^N - 1)$ depending on the `reduction` mode.
     ///
     /// # Example
     ///
     /// ```
-    /// use concrete_core::math::polynomial::{PolynomialList, PolynomialSize, Polynomial, MonomialDegree};
-    /// let poly_list = PolynomialList::from_container(
-    ///     vec![100 as u8,20,3,4,5,6],
-    ///     PolynomialSize(3)
-    /// );
-    /// let bin_poly_list = PolynomialList::from_container(
-    ///     vec![false, true, true, true, false, false],
-    ///     PolynomialSize(3)
-    /// );
-    /// let mut output = Polynomial::allocate(250 as u8, PolynomialSize(3));
-    /// output.update_with_wrapping_add_binary_multisum(&poly_list, &bin_poly_list);
-    /// assert_eq!(*output.get_monomial(MonomialDegree(0)).get_coefficient(), 231);
-    /// assert_eq!(*output.get_monomial(MonomialDegree(1)).get_coefficient(), 96);
-    /// assert_eq!(*output.get_monomial(MonomialDegree(2)).get_coefficient(), 120);
+    /// use concrete_core::math::polynomial::{
+    ///     MonomialDegree, Polynomial, PolynomialSize, ReductionMode,
+    /// };
+    /// let lhs = Polynomial::from_container(vec![4_u8, 5, 0]);
+    /// let rhs = Polynomial::from_container(vec![7_u8, 9, 0]);
+    /// let mut res = Polynomial::allocate(0 as u8, PolynomialSize(3));
+    /// res.fill_with_wrapping_mul_with_reduction(&lhs, &rhs, ReductionMode::Cyclic);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 28 as u8);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 71 as u8);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 45 as u8);
+    /// let lhs = Polynomial::from_container(vec![0_u8, 5, 4]);
+    /// let rhs = Polynomial::from_container(vec![0_u8, 9, 7]);
+    /// res.fill_with_wrapping_mul_with_reduction(&lhs, &rhs, ReductionMode::Cyclic);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 71 as u8);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 28 as u8);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 45 as u8);
     /// ```
-    pub fn update_with_wrapping_add_binary_multisum<Coef, InCont, BinCont>(
+    pub fn fill_with_wrapping_mul_with_reduction<Coef, LhsCont, RhsCont>(
         &mut self,
-        coef_list: &PolynomialList<InCont>,
-        bin_list: &PolynomialList<BinCont>,
+        lhs: &Polynomial<LhsCont>,
+        rhs: &Polynomial<RhsCont>,
+        reduction: ReductionMode,
     ) where
         Self: AsMutTensor<Element = Coef>,
-        PolynomialList<InCont>: AsRefTensor<Element = Coef>,
-        PolynomialList<BinCont>: AsRefTensor<Element = bool>,
-        for<'a> Polynomial<&'a [bool]>: AsRefTensor<Element = bool>,
-        for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
+        Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
         Coef: UnsignedInteger,
     {
-        for (poly, bin_poly) in coef_list.polynomial_iter().zip(bin_list.polynomial_iter()) {
-            self.update_with_wrapping_add_binary_mul(&poly, &bin_poly);
+        ck_dim_eq!(self.polynomial_size() => lhs.polynomial_size(), rhs.polynomial_size());
+        if <Coef::Wide as Numeric>::BITS > Coef::BITS {
+            self.fill_with_widening_mul_with_reduction(lhs, rhs, reduction);
+        } else {
+            self.fill_with_schoolbook_mul_with_reduction(lhs, rhs, reduction);
         }
     }
 
-    /// Subtracts the sum of the element-wise product between a list of integer polynomial, and a
-    /// list of binary polynomial, to the current polynomial.
-    ///
-    /// I.e., if the current polynomial is $C(X)$, for a list of polynomials $(P_i(X)))_i$ and a
+    // Computes the product monomial by monomial, with a wrapping operation in `Coef` for each
+    // pair of monomials.
+    pub(super) fn fill_with_schoolbook_mul_with_reduction<Coef, LhsCont, RhsCont>(
+        &mut self,
+        lhs: &Polynomial<LhsCont>,
+        rhs: &Polynomial<RhsCont>,
+        reduction: ReductionMode,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        self.coefficient_iter_mut().for_each(|a| *a = Coef::ZERO);
+        let degree = lhs.polynomial_size().0 - 1;
+        for lhsi in lhs.monomial_iter() {
+            for rhsi in rhs.monomial_iter() {
+                let target_degree = lhsi.degree().0 + rhsi.degree().0;
+                let new = lhsi.get_coefficient().wrapping_mul(*rhsi.get_coefficient());
+                if target_degree <= degree {
+                    let element = self.as_mut_tensor().get_element_mut(target_degree);
+                    *element = element.wrapping_add(new);
+                } else {
+                    let element = self
+                        .as_mut_tensor()
+                        .get_element_mut(target_degree % (degree + 1));
+                    *element = match reduction {
+                        ReductionMode::Negacyclic => element.wrapping_sub(new),
+                        ReductionMode::Cyclic => element.wrapping_add(new),
+                    };
+                }
+            }
+        }
+    }
+
+    // Computes the product coefficient by coefficient, accumulating the products of the
+    // monomials in `Coef::Wide`, and truncating the sums once.
+    pub(super) fn fill_with_widening_mul_with_reduction<Coef, Wide, LhsCont, RhsCont>(
+        &mut self,
+        lhs: &Polynomial<LhsCont>,
+        rhs: &Polynomial<RhsCont>,
+        reduction: ReductionMode,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger<Wide = Wide>,
+        Wide: UnsignedInteger + CastFrom<Coef> + CastInto<Coef>,
+    {
+        fn dot<'a, Coef, Wide>(
+            lhs: impl Iterator<Item = &'a Coef>,
+            rhs: impl Iterator<Item = &'a Coef>,
+        ) -> Wide
+        where
+            Coef: UnsignedInteger + 'a,
+            Wide: UnsignedInteger + CastFrom<Coef>,
+        {
+            lhs.zip(rhs).fold(Wide::ZERO, |acc, (l, r)| {
+                acc.wrapping_add(Wide::cast_from(*l).wrapping_mul(Wide::cast_from(*r)))
+            })
+        }
+        let lhs = lhs.as_tensor().as_slice();
+        let rhs = rhs.as_tensor().as_slice();
+        for (degree, output) in self.as_mut_tensor().iter_mut().enumerate() {
+            // The pairs of degrees summing to `degree`, and to `degree + N`.
+            let direct: Wide = dot(lhs[..=degree].iter(), rhs[..=degree].iter().rev());
+            let wrapped: Wide = dot(lhs[degree + 1..].iter(), rhs[degree + 1..].iter().rev());
+            let sum = match reduction {
+                ReductionMode::Negacyclic => direct.wrapping_sub(wrapped),
+                ReductionMode::Cyclic => direct.wrapping_add(wrapped),
+            };
+            *output = sum.cast_into();
+        }
+    }
+
+    /// Fills the current polynomial with the product of two polynomials reduced modulo
+    /// $(X^N + 1)$, computed with a fast fourier transform when the size allows it.
+    ///
+    /// When `view` is `None`, the powers of two between 256 and 16384 use the fourier domain,
+    /// with a transformer planned on every call, which is only worth it for a single product of
+    /// large polynomials. The other sizes fall back on the schoolbook product of
+    /// [`Polynomial::fill_with_wrapping_mul`]: the larger sizes are supported by [`Fft`], but the
+    /// rounding error of their products grows with the size, and they are only computed in the
+    /// fourier domain when a transformer is explicitly given. The `view` argument allows to reuse
+    /// the plans and the buffers of a transformer between calls.
+    ///
+    /// # Note
+    ///
+    /// In the fourier domain, the coefficients are viewed as signed integers, and the product is
+    /// computed with `f64`. The result matches the one of the schoolbook product only as long as
+    /// the coefficients of the exact product fit in the mantissa of a `f64` and in the signed
+    /// integer type of `Coef`, that is, if $N \cdot \max |a\_i| \cdot \max |b\_i|$ stays below
+    /// both $2^{53}$ and $2^{q-1}$. Otherwise, the result is only approximate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the transformer borrowed by `view` does not have the size of the polynomials, or
+    /// does not reduce the products modulo $(X^N + 1)$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::{Fft, FftView};
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial, PolynomialSize};
+    /// let mut lhs = Polynomial::allocate(0u32, PolynomialSize(256));
+    /// *lhs.get_mut_monomial(MonomialDegree(255)).get_mut_coefficient() = 3;
+    /// let mut rhs = Polynomial::allocate(0u32, PolynomialSize(256));
+    /// *rhs.get_mut_monomial(MonomialDegree(2)).get_mut_coefficient() = 5;
+    /// let mut res = Polynomial::allocate(0u32, PolynomialSize(256));
+    ///
+    /// // 3X^255 * 5X^2 = -15X modulo X^256 + 1
+    /// let mut fft = Fft::new(PolynomialSize(256));
+    /// let mut view = FftView::new(&mut fft);
+    /// res.fill_with_fast_mul(&lhs, &rhs, Some(&mut view));
+    /// assert_eq!(
+    ///     *res.get_monomial(MonomialDegree(1)).get_coefficient(),
+    ///     0u32.wrapping_sub(15)
+    /// );
+    ///
+    /// // Small sizes use the schoolbook product.
+    /// let lhs = Polynomial::from_container(vec![4_u8, 5, 0]);
+    /// let rhs = Polynomial::from_container(vec![7_u8, 9, 0]);
+    /// let mut res = Polynomial::allocate(0 as u8, PolynomialSize(3));
+    /// res.fill_with_fast_mul(&lhs, &rhs, None);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 71 as u8);
+    /// ```
+    pub fn fill_with_fast_mul<Coef, LhsCont, RhsCont>(
+        &mut self,
+        lhs: &Polynomial<LhsCont>,
+        rhs: &Polynomial<RhsCont>,
+        view: Option<&mut FftView<'_>>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.polynomial_size() => lhs.polynomial_size(), rhs.polynomial_size());
+        match view {
+            Some(view) => {
+                assert_eq!(
+                    view.polynomial_size(),
+                    self.polynomial_size(),
+                    "The transformer does not have the size of the polynomials."
+                );
+                assert_eq!(
+                    view.reduction_mode(),
+                    ReductionMode::Negacyclic,
+                    "The transformer does not reduce the products modulo X^N + 1."
+                );
+                self.as_mut_tensor().copy_from(lhs.as_tensor());
+                view.polynomial_mul_assign(self, rhs);
+            }
+            None if self.polynomial_size().0 > FAST_MUL_MAX_SIZE => {
+                self.fill_with_wrapping_mul(lhs, rhs)
+            }
+            None => match Fft::try_new(self.polynomial_size()) {
+                Ok(mut fft) => self.fill_with_fast_mul(lhs, rhs, Some(&mut FftView::new(&mut fft))),
+                Err(_) => self.fill_with_wrapping_mul(lhs, rhs),
+            },
+        }
+    }
+
+    /// Fills the current polynomial with the product of two polynomials reduced modulo
+    /// $(X^N + 1)$, computed in the fourier domain with the temporaries borrowed from `buffers`.
+    ///
+    /// Contrary to [`Polynomial::fill_with_fast_mul`], nothing is allocated, which makes this
+    /// method suited to the loops computing many products of the same size. The same precision
+    /// note applies.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fft` does not have the size of the polynomials, or does not reduce the products
+    /// modulo $(X^N + 1)$. In debug builds, also panics if `buffers` does not have the size of
+    /// the polynomials.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::fft::{Fft, FftBuffers};
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial, PolynomialSize};
+    /// let mut lhs = Polynomial::allocate(0u32, PolynomialSize(256));
+    /// *lhs.get_mut_monomial(MonomialDegree(255)).get_mut_coefficient() = 3;
+    /// let mut rhs = Polynomial::allocate(0u32, PolynomialSize(256));
+    /// *rhs.get_mut_monomial(MonomialDegree(2)).get_mut_coefficient() = 5;
+    /// let mut res = Polynomial::allocate(0u32, PolynomialSize(256));
+    ///
+    /// // 3X^255 * 5X^2 = -15X modulo X^256 + 1
+    /// let mut fft = Fft::new(PolynomialSize(256));
+    /// let mut buffers = FftBuffers::for_size(PolynomialSize(256));
+    /// res.fill_with_fast_mul_buffered(&lhs, &rhs, &mut fft, &mut buffers);
+    /// assert_eq!(
+    ///     *res.get_monomial(MonomialDegree(1)).get_coefficient(),
+    ///     0u32.wrapping_sub(15)
+    /// );
+    /// ```
+    pub fn fill_with_fast_mul_buffered<Coef, LhsCont, RhsCont>(
+        &mut self,
+        lhs: &Polynomial<LhsCont>,
+        rhs: &Polynomial<RhsCont>,
+        fft: &mut Fft,
+        buffers: &mut FftBuffers,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.polynomial_size() => lhs.polynomial_size(), rhs.polynomial_size());
+        assert_eq!(
+            fft.polynomial_size(),
+            self.polynomial_size(),
+            "The transformer does not have the size of the polynomials."
+        );
+        assert_eq!(
+            fft.reduction_mode(),
+            ReductionMode::Negacyclic,
+            "The transformer does not reduce the products modulo X^N + 1."
+        );
+        self.as_mut_tensor().copy_from(lhs.as_tensor());
+        fft.polynomial_mul_assign(self, rhs, buffers);
+    }
+
+    /// Fills the current polynomial with the product of two polynomials reduced modulo
+    /// $(X^N + 1)$, computed exactly with a number theoretic transform.
+    ///
+    /// Contrary to [`Polynomial::fill_with_fast_mul`], the result is always the same as the one
+    /// of [`Polynomial::fill_with_wrapping_mul`], for coefficients of up to 64 bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the transformer does not have the size of the polynomials.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::ntt::Ntt;
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial, PolynomialSize};
+    /// let mut lhs = Polynomial::allocate(0u64, PolynomialSize(256));
+    /// *lhs.get_mut_monomial(MonomialDegree(255)).get_mut_coefficient() = u64::MAX;
+    /// let mut rhs = Polynomial::allocate(0u64, PolynomialSize(256));
+    /// *rhs.get_mut_monomial(MonomialDegree(2)).get_mut_coefficient() = u64::MAX;
+    /// let mut res = Polynomial::allocate(0u64, PolynomialSize(256));
+    ///
+    /// // (-X^255) * (-X^2) = -X modulo X^256 + 1
+    /// let ntt = Ntt::new(PolynomialSize(256));
+    /// res.fill_with_ntt_mul(&lhs, &rhs, &ntt);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), u64::MAX);
+    /// assert_eq!(res.coefficient_iter().filter(|c| **c != 0).count(), 1);
+    /// ```
+    pub fn fill_with_ntt_mul<Coef, LhsCont, RhsCont>(
+        &mut self,
+        lhs: &Polynomial<LhsCont>,
+        rhs: &Polynomial<RhsCont>,
+        ntt: &Ntt,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger + CastInto<u64> + CastFrom<u64>,
+    {
+        ck_dim_eq!(self.polynomial_size() => lhs.polynomial_size(), rhs.polynomial_size());
+        assert_eq!(
+            ntt.polynomial_size(),
+            self.polynomial_size(),
+            "The transformer does not have the size of the polynomials."
+        );
+        let mut ntt_lhs = NttPolynomial::allocate(self.polynomial_size());
+        let mut ntt_rhs = NttPolynomial::allocate(self.polynomial_size());
+        ntt.forward_as_integer(&mut ntt_lhs, lhs);
+        ntt.forward_as_integer(&mut ntt_rhs, rhs);
+        let mut ntt_product = NttPolynomial::allocate(self.polynomial_size());
+        ntt_product.update_with_multiply_accumulate(&ntt_lhs, &ntt_rhs);
+        self.as_mut_tensor().fill_with_element(Coef::ZERO);
+        ntt.add_backward_as_integer(self, &mut ntt_product);
+    }
+
+    /// Adds the result of the (slow) product of two integer polynomials, reduced modulo
+    /// $(X^N+1)$, to the current polynomial.
+    ///
+    /// The result is the same as the one of [`Polynomial::fill_with_wrapping_mul`] into a
+    /// temporary polynomial, followed by [`Polynomial::update_with_wrapping_add`], without the
+    /// temporary polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial};
+    /// let lhs = Polynomial::from_container(vec![4_u8, 5, 0]);
+    /// let rhs = Polynomial::from_container(vec![7_u8, 9, 0]);
+    /// let mut res = Polynomial::from_container(vec![1_u8, 2, 3]);
+    /// res.update_with_wrapping_add_mul(&lhs, &rhs);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 29);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 73);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 48);
+    /// ```
+    pub fn update_with_wrapping_add_mul<Coef, LhsCont, RhsCont>(
+        &mut self,
+        lhs: &Polynomial<LhsCont>,
+        rhs: &Polynomial<RhsCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        self.update_with_wrapping_add_mul_with_reduction(lhs, rhs, ReductionMode::Negacyclic);
+    }
+
+    /// Adds the result of the (slow) product of two integer polynomials, reduced modulo
+    /// $(X^N-1)$, to the current polynomial.
+    ///
+    /// As for [`Polynomial::fill_with_wrapping_cyclic_mul`], the monomials of degree $N + i$ are
+    /// added to the coefficient of degree $i$, where [`Polynomial::update_with_wrapping_add_mul`]
+    /// subtracts them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::Polynomial;
+    /// let lhs = Polynomial::from_container(vec![4_u8, 5, 1]);
+    /// let rhs = Polynomial::from_container(vec![7_u8, 9, 0]);
+    /// let mut res = Polynomial::from_container(vec![1_u8, 2, 3]);
+    /// res.update_with_wrapping_add_cyclic_mul(&lhs, &rhs);
+    /// assert_eq!(res, Polynomial::from_container(vec![38_u8, 73, 55]));
+    /// ```
+    pub fn update_with_wrapping_add_cyclic_mul<Coef, LhsCont, RhsCont>(
+        &mut self,
+        lhs: &Polynomial<LhsCont>,
+        rhs: &Polynomial<RhsCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        self.update_with_wrapping_add_mul_with_reduction(lhs, rhs, ReductionMode::Cyclic);
+    }
+
+    /// Adds the result of the (slow) product of two integer polynomials, reduced modulo
+    /// $(X^N + 1)$ or $(X^N - 1)$ depending on the `reduction` mode, to the current polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{Polynomial, ReductionMode};
+    /// let lhs = Polynomial::from_container(vec![4_u8, 5, 1]);
+    /// let rhs = Polynomial::from_container(vec![7_u8, 9, 0]);
+    /// let mut res = Polynomial::from_container(vec![1_u8, 2, 3]);
+    /// res.update_with_wrapping_add_mul_with_reduction(&lhs, &rhs, ReductionMode::Negacyclic);
+    /// assert_eq!(res, Polynomial::from_container(vec![20_u8, 73, 55]));
+    /// ```
+    pub fn update_with_wrapping_add_mul_with_reduction<Coef, LhsCont, RhsCont>(
+        &mut self,
+        lhs: &Polynomial<LhsCont>,
+        rhs: &Polynomial<RhsCont>,
+        reduction: ReductionMode,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.polynomial_size() => lhs.polynomial_size(), rhs.polynomial_size());
+        let degree = lhs.polynomial_size().0 - 1;
+        for lhsi in lhs.monomial_iter() {
+            for rhsi in rhs.monomial_iter() {
+                let target_degree = lhsi.degree().0 + rhsi.degree().0;
+                let new = lhsi.get_coefficient().wrapping_mul(*rhsi.get_coefficient());
+                if target_degree <= degree {
+                    let element = self.as_mut_tensor().get_element_mut(target_degree);
+                    *element = element.wrapping_add(new);
+                } else {
+                    let element = self
+                        .as_mut_tensor()
+                        .get_element_mut(target_degree % (degree + 1));
+                    *element = match reduction {
+                        ReductionMode::Negacyclic => element.wrapping_sub(new),
+                        ReductionMode::Cyclic => element.wrapping_add(new),
+                    };
+                }
+            }
+        }
+    }
+
+    /// Subtracts the result of the (slow) product of two integer polynomials, reduced modulo
+    /// $(X^N+1)$, to the current polynomial.
+    ///
+    /// The result is the same as the one of [`Polynomial::fill_with_wrapping_mul`] into a
+    /// temporary polynomial, followed by [`Polynomial::update_with_wrapping_sub`], without the
+    /// temporary polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial};
+    /// let lhs = Polynomial::from_container(vec![4_u8, 5, 0]);
+    /// let rhs = Polynomial::from_container(vec![7_u8, 9, 0]);
+    /// let mut res = Polynomial::from_container(vec![1_u8, 2, 3]);
+    /// res.update_with_wrapping_sub_mul(&lhs, &rhs);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 229);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 187);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 214);
+    /// ```
+    pub fn update_with_wrapping_sub_mul<Coef, LhsCont, RhsCont>(
+        &mut self,
+        lhs: &Polynomial<LhsCont>,
+        rhs: &Polynomial<RhsCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.polynomial_size() => lhs.polynomial_size(), rhs.polynomial_size());
+        let degree = lhs.polynomial_size().0 - 1;
+        for lhsi in lhs.monomial_iter() {
+            for rhsi in rhs.monomial_iter() {
+                let target_degree = lhsi.degree().0 + rhsi.degree().0;
+                let new = lhsi.get_coefficient().wrapping_mul(*rhsi.get_coefficient());
+                if target_degree <= degree {
+                    let element = self.as_mut_tensor().get_element_mut(target_degree);
+                    *element = element.wrapping_sub(new);
+                } else {
+                    let element = self
+                        .as_mut_tensor()
+                        .get_element_mut(target_degree % (degree + 1));
+                    *element = element.wrapping_add(new);
+                }
+            }
+        }
+    }
+
+    /// Fills the current polynomial with the result of the product between an integer polynomial
+    /// and binary one, reduced modulo $(X^N + 1)$.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialSize, MonomialDegree};
+    /// let poly = Polynomial::from_container(vec![1_u8, 2, 3]);
+    /// let bin_poly = Polynomial::from_container(vec![false, true, true]);
+    /// let mut res = Polynomial::allocate(133 as u8, PolynomialSize(3));
+    /// res.fill_with_wrapping_binary_mul(&poly, &bin_poly);
+    /// dbg!(&res);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 251 as u8);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 254 as u8);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 3 as u8);
+    /// ```
+    pub fn fill_with_wrapping_binary_mul<Coef, PolyCont, BinCont>(
+        &mut self,
+        poly: &Polynomial<PolyCont>,
+        bin_poly: &Polynomial<BinCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
+        Polynomial<BinCont>: AsRefTensor<Element = bool>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(
+            self.polynomial_size() =>
+            poly.polynomial_size(),
+            bin_poly.polynomial_size()
+        );
+        self.coefficient_iter_mut().for_each(|a| *a = Coef::ZERO);
+        self.update_with_wrapping_add_binary_mul(poly, bin_poly)
+    }
+
+    /// Adds the sum of the element-wise product between a list of integer polynomial, and a
+    /// list of binary polynomial, to the current polynomial.
+    ///
+    /// I.e., if the current polynomial is $C(X)$, for a collection of polynomials $(P_i(X)))_i$
+    /// and a collection of binary polynomials $(B_i(X))_i$ we perform the operation:
+    /// $$
+    /// C(X) := C(X) + \sum_i P_i(X) \times B_i(X) mod (X^N + 1)
+    /// $$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{PolynomialList, PolynomialSize, Polynomial, MonomialDegree};
+    /// let poly_list = PolynomialList::from_container(
+    ///     vec![100 as u8,20,3,4,5,6],
+    ///     PolynomialSize(3)
+    /// );
+    /// let bin_poly_list = PolynomialList::from_container(
+    ///     vec![false, true, true, true, false, false],
+    ///     PolynomialSize(3)
+    /// );
+    /// let mut output = Polynomial::allocate(250 as u8, PolynomialSize(3));
+    /// output.update_with_wrapping_add_binary_multisum(&poly_list, &bin_poly_list);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(0)).get_coefficient(), 231);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(1)).get_coefficient(), 96);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(2)).get_coefficient(), 120);
+    /// ```
+    pub fn update_with_wrapping_add_binary_multisum<Coef, InCont, BinCont>(
+        &mut self,
+        coef_list: &PolynomialList<InCont>,
+        bin_list: &PolynomialList<BinCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        PolynomialList<InCont>: AsRefTensor<Element = Coef>,
+        PolynomialList<BinCont>: AsRefTensor<Element = bool>,
+        for<'a> Polynomial<&'a [bool]>: AsRefTensor<Element = bool>,
+        for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        for (poly, bin_poly) in coef_list.polynomial_iter().zip(bin_list.polynomial_iter()) {
+            self.update_with_wrapping_add_binary_mul(&poly, &bin_poly);
+        }
+    }
+
+    /// Subtracts the sum of the element-wise product between a list of integer polynomial, and a
+    /// list of binary polynomial, to the current polynomial.
+    ///
+    /// I.e., if the current polynomial is $C(X)$, for a list of polynomials $(P_i(X)))_i$ and a
     /// list of  binary polynomials $(B_i(X))_i$ we perform the operation:
     /// $$
     /// C(X) := C(X) + \sum_i P_i(X) \times B_i(X) mod (X^N + 1)
@@ -372,12 +1280,665 @@ impl<Cont> Polynomial<Cont> {
     ///     PolynomialSize(3)
     /// );
     /// let mut output = Polynomial::allocate(250 as u8, PolynomialSize(3));
-    /// output.update_with_wrapping_sub_binary_multisum(&poly_list, &bin_poly_list);
-    /// assert_eq!(*output.get_monomial(MonomialDegree(0)).get_coefficient(), 13);
-    /// assert_eq!(*output.get_monomial(MonomialDegree(1)).get_coefficient(), 148);
-    /// assert_eq!(*output.get_monomial(MonomialDegree(2)).get_coefficient(), 124);
+    /// output.update_with_wrapping_sub_binary_multisum(&poly_list, &bin_poly_list);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(0)).get_coefficient(), 13);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(1)).get_coefficient(), 148);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(2)).get_coefficient(), 124);
+    /// ```
+    pub fn update_with_wrapping_sub_binary_multisum<Coef, InCont, BinCont>(
+        &mut self,
+        coef_list: &PolynomialList<InCont>,
+        bin_list: &PolynomialList<BinCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        PolynomialList<InCont>: AsRefTensor<Element = Coef>,
+        PolynomialList<BinCont>: AsRefTensor<Element = bool>,
+        for<'a> Polynomial<&'a [bool]>: AsRefTensor<Element = bool>,
+        for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger + CastFrom<bool>,
+    {
+        for (poly, bin_poly) in coef_list.polynomial_iter().zip(bin_list.polynomial_iter()) {
+            self.update_with_wrapping_sub_binary_mul(&poly, &bin_poly);
+        }
+    }
+    /// Adds the result of the product between a integer polynomial and a binary one, reduced
+    /// modulo $(X^N+1)$, to the current polynomial.
+    ///
+    /// Only the coefficients of the binary polynomial which are set are visited, so the running
+    /// time depends on their number. See
+    /// [`Polynomial::ct_update_with_wrapping_add_binary_mul`] for a constant-time variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{Polynomial, MonomialDegree};
+    /// let poly = Polynomial::from_container(vec![1_u8,2,3]);
+    /// let bin_poly = Polynomial::from_container(vec![false, true, true]);
+    /// let mut res = Polynomial::from_container(vec![1_u8, 0, 253]);
+    /// res.update_with_wrapping_add_binary_mul(&poly, &bin_poly);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 252);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 254);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 0);
+    /// ```
+    pub fn update_with_wrapping_add_binary_mul<Coef, PolyCont, BinCont>(
+        &mut self,
+        polynomial: &Polynomial<PolyCont>,
+        bin_polynomial: &Polynomial<BinCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
+        Polynomial<BinCont>: AsRefTensor<Element = bool>,
+        Coef: UnsignedInteger + CastFrom<bool>,
+    {
+        ck_dim_eq!(
+            self.polynomial_size() =>
+            polynomial.polynomial_size(),
+            bin_polynomial.polynomial_size()
+        );
+        self.update_with_wrapping_signed_rotations(
+            polynomial,
+            bin_polynomial
+                .monomial_iter()
+                .filter(|monomial| *monomial.get_coefficient())
+                .map(|monomial| monomial.degree()),
+            false,
+        );
+    }
+
+    /// Subtracts the result of the product between an integer polynomial and a binary one, reduced
+    /// modulo $(X^N+1)$, to the current polynomial.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{Polynomial, MonomialDegree};
+    /// let poly = Polynomial::from_container(vec![1_u8,2,3]);
+    /// let bin_poly = Polynomial::from_container(vec![false, true, true]);
+    /// let mut res = Polynomial::from_container(vec![255_u8, 255, 1]);
+    /// res.update_with_wrapping_sub_binary_mul(&poly, &bin_poly);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 4);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 1);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 254);
+    /// ```
+    pub fn update_with_wrapping_sub_binary_mul<Coef, PolyCont, BinCont>(
+        &mut self,
+        polynomial: &Polynomial<PolyCont>,
+        bin_polynomial: &Polynomial<BinCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
+        Polynomial<BinCont>: AsRefTensor<Element = bool>,
+        Coef: UnsignedInteger + CastFrom<bool>,
+    {
+        ck_dim_eq!(
+            self.polynomial_size() =>
+            polynomial.polynomial_size(),
+            bin_polynomial.polynomial_size()
+        );
+        self.update_with_wrapping_signed_rotations(
+            polynomial,
+            bin_polynomial
+                .monomial_iter()
+                .filter(|monomial| *monomial.get_coefficient())
+                .map(|monomial| monomial.degree()),
+            true,
+        );
+    }
+
+    // Adds (or subtracts if `negate` is set) the polynomial multiplied by $X^d$, reduced modulo
+    // $(X^N+1)$, for every degree $d$ yielded by `degrees`, which must be increasing. This is the
+    // product with the binary polynomial whose ones are at those degrees: a single rotated copy
+    // of the polynomial is kept, and brought from one degree to the next by a monic monomial
+    // multiplication.
+    fn update_with_wrapping_signed_rotations<Coef, PolyCont>(
+        &mut self,
+        polynomial: &Polynomial<PolyCont>,
+        degrees: impl Iterator<Item = MonomialDegree>,
+        negate: bool,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        let mut rotated = Polynomial::from_container(polynomial.as_tensor().as_slice().to_vec());
+        let mut current_degree = 0;
+        for MonomialDegree(degree) in degrees {
+            rotated
+                .update_with_wrapping_monic_monomial_mul(MonomialDegree(degree - current_degree));
+            current_degree = degree;
+            if negate {
+                self.update_with_wrapping_sub(&rotated);
+            } else {
+                self.update_with_wrapping_add(&rotated);
+            }
+        }
+    }
+
+    /// Adds the sum of the element-wise product between a list of polynomials, and a list of
+    /// binary polynomials, to the current polynomial, using the arithmetic of the coefficient
+    /// type.
+    ///
+    /// Contrarily to [`Polynomial::update_with_wrapping_add_binary_multisum`], this method does
+    /// not assume that the coefficients are unsigned integers, and can be used with coefficients
+    /// implementing their own modular arithmetic, such as
+    /// [`ModQ`](crate::math::modular::ModQ).
+    ///
+    /// # Constant-time
+    ///
+    /// Each coefficient of the polynomials is selected with
+    /// [`ConstantTimeSelect::ct_select`](crate::numeric::ConstantTimeSelect::ct_select) between
+    /// itself and zero, depending on the binary coefficient, so that the control flow does not
+    /// depend on the binary polynomials.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::modular::ModQ;
+    /// use concrete_core::math::polynomial::{PolynomialList, PolynomialSize, Polynomial, MonomialDegree};
+    /// let poly_list = PolynomialList::from_container(
+    ///     vec![100, 20, 3, 4, 5, 6].into_iter().map(ModQ::<251>::new).collect::<Vec<_>>(),
+    ///     PolynomialSize(3)
+    /// );
+    /// let bin_poly_list = PolynomialList::from_container(
+    ///     vec![false, true, true, true, false, false],
+    ///     PolynomialSize(3)
+    /// );
+    /// let mut output = Polynomial::allocate(ModQ::<251>::new(250), PolynomialSize(3));
+    /// output.update_with_add_binary_multisum(&poly_list, &bin_poly_list);
+    /// assert_eq!(output.get_monomial(MonomialDegree(0)).get_coefficient().value(), 231);
+    /// assert_eq!(output.get_monomial(MonomialDegree(1)).get_coefficient().value(), 101);
+    /// assert_eq!(output.get_monomial(MonomialDegree(2)).get_coefficient().value(), 125);
+    /// ```
+    pub fn update_with_add_binary_multisum<Coef, InCont, BinCont>(
+        &mut self,
+        coef_list: &PolynomialList<InCont>,
+        bin_list: &PolynomialList<BinCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        PolynomialList<InCont>: AsRefTensor<Element = Coef>,
+        PolynomialList<BinCont>: AsRefTensor<Element = bool>,
+        for<'a> Polynomial<&'a [bool]>: AsRefTensor<Element = bool>,
+        for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
+        Coef: Copy + Default + AddAssign + SubAssign + ConstantTimeSelect,
+    {
+        for (poly, bin_poly) in coef_list.polynomial_iter().zip(bin_list.polynomial_iter()) {
+            self.update_with_signed_binary_mul(&poly, &bin_poly, false);
+        }
+    }
+
+    /// Subtracts the sum of the element-wise product between a list of polynomials, and a list
+    /// of binary polynomials, to the current polynomial, using the arithmetic of the coefficient
+    /// type.
+    ///
+    /// See [`Polynomial::update_with_add_binary_multisum`].
+    ///
+    /// # Constant-time
+    ///
+    /// See [`Polynomial::update_with_add_binary_multisum`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::modular::ModQ;
+    /// use concrete_core::math::polynomial::{PolynomialList, PolynomialSize, Polynomial, MonomialDegree};
+    /// let poly_list = PolynomialList::from_container(
+    ///     vec![100, 20, 3, 4, 5, 6].into_iter().map(ModQ::<251>::new).collect::<Vec<_>>(),
+    ///     PolynomialSize(3)
+    /// );
+    /// let bin_poly_list = PolynomialList::from_container(
+    ///     vec![false, true, true, true, false, false],
+    ///     PolynomialSize(3)
+    /// );
+    /// let mut output = Polynomial::allocate(ModQ::<251>::new(250), PolynomialSize(3));
+    /// output.update_with_sub_binary_multisum(&poly_list, &bin_poly_list);
+    /// assert_eq!(output.get_monomial(MonomialDegree(0)).get_coefficient().value(), 18);
+    /// assert_eq!(output.get_monomial(MonomialDegree(1)).get_coefficient().value(), 148);
+    /// assert_eq!(output.get_monomial(MonomialDegree(2)).get_coefficient().value(), 124);
+    /// ```
+    pub fn update_with_sub_binary_multisum<Coef, InCont, BinCont>(
+        &mut self,
+        coef_list: &PolynomialList<InCont>,
+        bin_list: &PolynomialList<BinCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        PolynomialList<InCont>: AsRefTensor<Element = Coef>,
+        PolynomialList<BinCont>: AsRefTensor<Element = bool>,
+        for<'a> Polynomial<&'a [bool]>: AsRefTensor<Element = bool>,
+        for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
+        Coef: Copy + Default + AddAssign + SubAssign + ConstantTimeSelect,
+    {
+        for (poly, bin_poly) in coef_list.polynomial_iter().zip(bin_list.polynomial_iter()) {
+            self.update_with_signed_binary_mul(&poly, &bin_poly, true);
+        }
+    }
+
+    // Adds (or subtracts if `negate` is set) the product of a polynomial and a binary polynomial,
+    // reduced modulo $(X^N+1)$, using the arithmetic of the coefficient type. The binary
+    // coefficients select the coefficients of the polynomial with a mask rather than a branch.
+    fn update_with_signed_binary_mul<Coef, PolyCont, BinCont>(
+        &mut self,
+        polynomial: &Polynomial<PolyCont>,
+        bin_polynomial: &Polynomial<BinCont>,
+        negate: bool,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
+        Polynomial<BinCont>: AsRefTensor<Element = bool>,
+        Coef: Copy + Default + AddAssign + SubAssign + ConstantTimeSelect,
+    {
+        ck_dim_eq!(
+            self.polynomial_size() =>
+            polynomial.polynomial_size(),
+            bin_polynomial.polynomial_size()
+        );
+        let degree = polynomial.polynomial_size().0 - 1;
+        for lhsi in polynomial.monomial_iter() {
+            for rhsi in bin_polynomial.monomial_iter() {
+                let target_degree = lhsi.degree().0 + rhsi.degree().0;
+                let product = Coef::ct_select(
+                    *rhsi.get_coefficient(),
+                    *lhsi.get_coefficient(),
+                    Coef::default(),
+                );
+                let element = self
+                    .as_mut_tensor()
+                    .get_element_mut(target_degree % (degree + 1));
+                // The wrap around the modulus $X^N+1$ negates the product. This branch only
+                // depends on the (public) degrees of the monomials.
+                if (target_degree > degree) != negate {
+                    *element -= product;
+                } else {
+                    *element += product;
+                }
+            }
+        }
+    }
+
+    /// Adds the result of the product between an integer polynomial and a ternary one, reduced
+    /// modulo $(X^N+1)$, to the current polynomial.
+    ///
+    /// The coefficients of the ternary polynomial are `i8` values in $\\{-1, 0, 1\\}$: the
+    /// integer polynomial, shifted by the degree of each coefficient, is added for $1$,
+    /// subtracted for $-1$, and skipped for $0$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coefficient of the ternary polynomial is not in $\\{-1, 0, 1\\}$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial};
+    /// let poly = Polynomial::from_container(vec![1_u8, 2, 3]);
+    /// let ternary_poly = Polynomial::from_container(vec![0_i8, 1, -1]);
+    /// let mut res = Polynomial::from_container(vec![1_u8, 0, 253]);
+    /// res.update_with_wrapping_add_ternary_mul(&poly, &ternary_poly);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 0);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 4);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 254);
+    /// ```
+    pub fn update_with_wrapping_add_ternary_mul<Coef, PolyCont, TernCont>(
+        &mut self,
+        polynomial: &Polynomial<PolyCont>,
+        ternary_polynomial: &Polynomial<TernCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
+        Polynomial<TernCont>: AsRefTensor<Element = i8>,
+        Coef: UnsignedInteger,
+    {
+        self.update_with_signed_ternary_mul(polynomial, ternary_polynomial, false);
+    }
+
+    /// Subtracts the result of the product between an integer polynomial and a ternary one,
+    /// reduced modulo $(X^N+1)$, to the current polynomial.
+    ///
+    /// See [`Polynomial::update_with_wrapping_add_ternary_mul`] for the representation of the
+    /// ternary coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coefficient of the ternary polynomial is not in $\\{-1, 0, 1\\}$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial};
+    /// let poly = Polynomial::from_container(vec![1_u8, 2, 3]);
+    /// let ternary_poly = Polynomial::from_container(vec![0_i8, 1, -1]);
+    /// let mut res = Polynomial::from_container(vec![1_u8, 0, 253]);
+    /// res.update_with_wrapping_sub_ternary_mul(&poly, &ternary_poly);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 2);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 252);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 252);
+    /// ```
+    pub fn update_with_wrapping_sub_ternary_mul<Coef, PolyCont, TernCont>(
+        &mut self,
+        polynomial: &Polynomial<PolyCont>,
+        ternary_polynomial: &Polynomial<TernCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
+        Polynomial<TernCont>: AsRefTensor<Element = i8>,
+        Coef: UnsignedInteger,
+    {
+        self.update_with_signed_ternary_mul(polynomial, ternary_polynomial, true);
+    }
+
+    /// Adds the sum of the element-wise product between a list of integer polynomial, and a
+    /// list of ternary polynomial, to the current polynomial.
+    ///
+    /// I.e., if the current polynomial is $C(X)$, for a list of polynomials $(P_i(X))_i$ and
+    /// a list of ternary polynomials $(T_i(X))_i$ we perform the operation:
+    /// $$
+    /// C(X) := C(X) + \sum_i P_i(X) \times T_i(X) mod (X^N + 1)
+    /// $$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{
+    ///     MonomialDegree, Polynomial, PolynomialList, PolynomialSize,
+    /// };
+    /// let poly_list =
+    ///     PolynomialList::from_container(vec![1_u8, 2, 3, 4, 5, 6], PolynomialSize(3));
+    /// let ternary_list =
+    ///     PolynomialList::from_container(vec![0_i8, 1, -1, -1, 0, 0], PolynomialSize(3));
+    /// let mut output = Polynomial::allocate(0_u8, PolynomialSize(3));
+    /// output.update_with_wrapping_add_ternary_multisum(&poly_list, &ternary_list);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(0)).get_coefficient(), 251);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(1)).get_coefficient(), 255);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(2)).get_coefficient(), 251);
+    /// ```
+    pub fn update_with_wrapping_add_ternary_multisum<Coef, InCont, TernCont>(
+        &mut self,
+        coef_list: &PolynomialList<InCont>,
+        ternary_list: &PolynomialList<TernCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        PolynomialList<InCont>: AsRefTensor<Element = Coef>,
+        PolynomialList<TernCont>: AsRefTensor<Element = i8>,
+        for<'a> Polynomial<&'a [i8]>: AsRefTensor<Element = i8>,
+        for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(coef_list.polynomial_count() => ternary_list.polynomial_count());
+        for (poly, ternary_poly) in coef_list
+            .polynomial_iter()
+            .zip(ternary_list.polynomial_iter())
+        {
+            self.update_with_signed_ternary_mul(&poly, &ternary_poly, false);
+        }
+    }
+
+    /// Subtracts the sum of the element-wise product between a list of integer polynomial, and
+    /// a list of ternary polynomial, to the current polynomial.
+    ///
+    /// I.e., if the current polynomial is $C(X)$, for a list of polynomials $(P_i(X))_i$ and
+    /// a list of ternary polynomials $(T_i(X))_i$ we perform the operation:
+    /// $$
+    /// C(X) := C(X) - \sum_i P_i(X) \times T_i(X) mod (X^N + 1)
+    /// $$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{
+    ///     MonomialDegree, Polynomial, PolynomialList, PolynomialSize,
+    /// };
+    /// let poly_list =
+    ///     PolynomialList::from_container(vec![1_u8, 2, 3, 4, 5, 6], PolynomialSize(3));
+    /// let ternary_list =
+    ///     PolynomialList::from_container(vec![0_i8, 1, -1, -1, 0, 0], PolynomialSize(3));
+    /// let mut output = Polynomial::allocate(0_u8, PolynomialSize(3));
+    /// output.update_with_wrapping_sub_ternary_multisum(&poly_list, &ternary_list);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(0)).get_coefficient(), 5);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(1)).get_coefficient(), 1);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(2)).get_coefficient(), 5);
+    /// ```
+    pub fn update_with_wrapping_sub_ternary_multisum<Coef, InCont, TernCont>(
+        &mut self,
+        coef_list: &PolynomialList<InCont>,
+        ternary_list: &PolynomialList<TernCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        PolynomialList<InCont>: AsRefTensor<Element = Coef>,
+        PolynomialList<TernCont>: AsRefTensor<Element = i8>,
+        for<'a> Polynomial<&'a [i8]>: AsRefTensor<Element = i8>,
+        for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(coef_list.polynomial_count() => ternary_list.polynomial_count());
+        for (poly, ternary_poly) in coef_list
+            .polynomial_iter()
+            .zip(ternary_list.polynomial_iter())
+        {
+            self.update_with_signed_ternary_mul(&poly, &ternary_poly, true);
+        }
+    }
+
+    // Adds (or subtracts if `negate` is set) the product of a polynomial and a ternary
+    // polynomial, reduced modulo $(X^N+1)$. Unlike the binary products, the zero coefficients of
+    // the ternary polynomial are skipped.
+    fn update_with_signed_ternary_mul<Coef, PolyCont, TernCont>(
+        &mut self,
+        polynomial: &Polynomial<PolyCont>,
+        ternary_polynomial: &Polynomial<TernCont>,
+        negate: bool,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
+        Polynomial<TernCont>: AsRefTensor<Element = i8>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(
+            self.polynomial_size() =>
+            polynomial.polynomial_size(),
+            ternary_polynomial.polynomial_size()
+        );
+        let degree = polynomial.polynomial_size().0 - 1;
+        for rhsi in ternary_polynomial.monomial_iter() {
+            let subtract = match *rhsi.get_coefficient() {
+                0 => continue,
+                1 => negate,
+                -1 => !negate,
+                coef => panic!("The coefficient {} is not ternary.", coef),
+            };
+            for lhsi in polynomial.monomial_iter() {
+                let target_degree = lhsi.degree().0 + rhsi.degree().0;
+                let element = self
+                    .as_mut_tensor()
+                    .get_element_mut(target_degree % (degree + 1));
+                // The wrap around the modulus $X^N+1$ negates the product.
+                if (target_degree > degree) != subtract {
+                    *element = element.wrapping_sub(*lhsi.get_coefficient());
+                } else {
+                    *element = element.wrapping_add(*lhsi.get_coefficient());
+                }
+            }
+        }
+    }
+
+    /// Adds the sum of the element-wise product between a list of integer polynomial, and a
+    /// list of bit-packed binary polynomial, to the current polynomial.
+    ///
+    /// I.e., if the current polynomial is $C(X)$, for a list of polynomials $(P_i(X))_i$ and
+    /// a list of binary polynomials $(B_i(X))_i$ we perform the operation:
+    /// $$
+    /// C(X) := C(X) + \sum_i P_i(X) \times B_i(X) mod (X^N + 1)
+    /// $$
+    ///
+    /// This computes the same result as [`Polynomial::update_with_wrapping_add_binary_multisum`],
+    /// but only visits the coefficients of the binary polynomials which are equal to one.
+    ///
+    /// # Constant-time
+    ///
+    /// The running time of this method depends on the number of ones in the binary polynomials,
+    /// and must not be used when those are secret and timing leaks matter. Use
+    /// [`Polynomial::ct_update_with_wrapping_add_binary_multisum`] in this case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{
+    ///     BinaryPolynomialList, MonomialDegree, Polynomial, PolynomialList, PolynomialSize,
+    /// };
+    /// let poly_list =
+    ///     PolynomialList::from_container(vec![100_u8, 20, 3, 4, 5, 6], PolynomialSize(3));
+    /// let bin_poly_list = PolynomialList::from_container(
+    ///     vec![false, true, true, true, false, false],
+    ///     PolynomialSize(3),
+    /// );
+    /// let packed_list = BinaryPolynomialList::from_boolean_list(&bin_poly_list);
+    /// let mut output = Polynomial::allocate(250 as u8, PolynomialSize(3));
+    /// output.update_with_wrapping_add_packed_binary_multisum(&poly_list, &packed_list);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(0)).get_coefficient(), 231);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(1)).get_coefficient(), 96);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(2)).get_coefficient(), 120);
+    /// ```
+    pub fn update_with_wrapping_add_packed_binary_multisum<Coef, InCont, BinCont>(
+        &mut self,
+        coef_list: &PolynomialList<InCont>,
+        bin_list: &BinaryPolynomialList<BinCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        PolynomialList<InCont>: AsRefTensor<Element = Coef>,
+        BinaryPolynomialList<BinCont>: AsRefTensor<Element = u64>,
+        for<'a> BinaryPolynomial<&'a [u64]>: AsRefTensor<Element = u64>,
+        for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(coef_list.polynomial_count() => bin_list.polynomial_count());
+        for (poly, bin_poly) in coef_list.polynomial_iter().zip(bin_list.polynomial_iter()) {
+            self.update_with_signed_packed_binary_mul(&poly, &bin_poly, false);
+        }
+    }
+
+    /// Subtracts the sum of the element-wise product between a list of integer polynomial, and
+    /// a list of bit-packed binary polynomial, to the current polynomial.
+    ///
+    /// I.e., if the current polynomial is $C(X)$, for a list of polynomials $(P_i(X))_i$ and
+    /// a list of binary polynomials $(B_i(X))_i$ we perform the operation:
+    /// $$
+    /// C(X) := C(X) - \sum_i P_i(X) \times B_i(X) mod (X^N + 1)
+    /// $$
+    ///
+    /// This computes the same result as [`Polynomial::update_with_wrapping_sub_binary_multisum`].
+    ///
+    /// # Constant-time
+    ///
+    /// See [`Polynomial::update_with_wrapping_add_packed_binary_multisum`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{
+    ///     BinaryPolynomialList, MonomialDegree, Polynomial, PolynomialList, PolynomialSize,
+    /// };
+    /// let poly_list =
+    ///     PolynomialList::from_container(vec![100_u8, 20, 3, 4, 5, 6], PolynomialSize(3));
+    /// let bin_poly_list = PolynomialList::from_container(
+    ///     vec![false, true, true, true, false, false],
+    ///     PolynomialSize(3),
+    /// );
+    /// let packed_list = BinaryPolynomialList::from_boolean_list(&bin_poly_list);
+    /// let mut output = Polynomial::allocate(250 as u8, PolynomialSize(3));
+    /// output.update_with_wrapping_sub_packed_binary_multisum(&poly_list, &packed_list);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(0)).get_coefficient(), 13);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(1)).get_coefficient(), 148);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(2)).get_coefficient(), 124);
+    /// ```
+    pub fn update_with_wrapping_sub_packed_binary_multisum<Coef, InCont, BinCont>(
+        &mut self,
+        coef_list: &PolynomialList<InCont>,
+        bin_list: &BinaryPolynomialList<BinCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        PolynomialList<InCont>: AsRefTensor<Element = Coef>,
+        BinaryPolynomialList<BinCont>: AsRefTensor<Element = u64>,
+        for<'a> BinaryPolynomial<&'a [u64]>: AsRefTensor<Element = u64>,
+        for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(coef_list.polynomial_count() => bin_list.polynomial_count());
+        for (poly, bin_poly) in coef_list.polynomial_iter().zip(bin_list.polynomial_iter()) {
+            self.update_with_signed_packed_binary_mul(&poly, &bin_poly, true);
+        }
+    }
+
+    // Adds (or subtracts if `negate` is set) the product of a polynomial and a bit-packed binary
+    // polynomial, reduced modulo $(X^N+1)$. For every coefficient equal to one, at degree $d$,
+    // the polynomial is rotated by $d$: its $N-d$ lowest coefficients are added at degrees
+    // $d..N$, and its $d$ highest ones are wrapped around with a sign flip.
+    fn update_with_signed_packed_binary_mul<Coef, PolyCont, BinCont>(
+        &mut self,
+        polynomial: &Polynomial<PolyCont>,
+        bin_polynomial: &BinaryPolynomial<BinCont>,
+        negate: bool,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
+        BinaryPolynomial<BinCont>: AsRefTensor<Element = u64>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(
+            self.polynomial_size() =>
+            polynomial.polynomial_size(),
+            bin_polynomial.polynomial_size()
+        );
+        let size = polynomial.polynomial_size().0;
+        let input = polynomial.as_tensor().as_slice();
+        let output = self.as_mut_tensor().as_mut_slice();
+        for MonomialDegree(shift) in bin_polynomial.set_degree_iter() {
+            let (wrapped, straight) = output.split_at_mut(shift);
+            let (straight_input, wrapped_input) = input.split_at(size - shift);
+            if negate {
+                for (out, inp) in straight.iter_mut().zip(straight_input.iter()) {
+                    *out = out.wrapping_sub(*inp);
+                }
+                for (out, inp) in wrapped.iter_mut().zip(wrapped_input.iter()) {
+                    *out = out.wrapping_add(*inp);
+                }
+            } else {
+                for (out, inp) in straight.iter_mut().zip(straight_input.iter()) {
+                    *out = out.wrapping_add(*inp);
+                }
+                for (out, inp) in wrapped.iter_mut().zip(wrapped_input.iter()) {
+                    *out = out.wrapping_sub(*inp);
+                }
+            }
+        }
+    }
+
+    /// Adds the sum of the element-wise product between a list of integer polynomial, and a
+    /// list of binary polynomial, to the current polynomial, in constant time.
+    ///
+    /// This computes the same result as
+    /// [`Polynomial::update_with_wrapping_add_binary_multisum`].
+    ///
+    /// # Constant-time
+    ///
+    /// See [`Polynomial::ct_update_with_wrapping_add_binary_mul`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{PolynomialList, PolynomialSize, Polynomial, MonomialDegree};
+    /// let poly_list = PolynomialList::from_container(
+    ///     vec![100 as u8,20,3,4,5,6],
+    ///     PolynomialSize(3)
+    /// );
+    /// let bin_poly_list = PolynomialList::from_container(
+    ///     vec![false, true, true, true, false, false],
+    ///     PolynomialSize(3)
+    /// );
+    /// let mut output = Polynomial::allocate(250 as u8, PolynomialSize(3));
+    /// output.ct_update_with_wrapping_add_binary_multisum(&poly_list, &bin_poly_list);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(0)).get_coefficient(), 231);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(1)).get_coefficient(), 96);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(2)).get_coefficient(), 120);
     /// ```
-    pub fn update_with_wrapping_sub_binary_multisum<Coef, InCont, BinCont>(
+    pub fn ct_update_with_wrapping_add_binary_multisum<Coef, InCont, BinCont>(
         &mut self,
         coef_list: &PolynomialList<InCont>,
         bin_list: &PolynomialList<BinCont>,
@@ -387,14 +1948,24 @@ impl<Cont> Polynomial<Cont> {
         PolynomialList<BinCont>: AsRefTensor<Element = bool>,
         for<'a> Polynomial<&'a [bool]>: AsRefTensor<Element = bool>,
         for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
-        Coef: UnsignedInteger + CastFrom<bool>,
+        Coef: UnsignedInteger,
     {
         for (poly, bin_poly) in coef_list.polynomial_iter().zip(bin_list.polynomial_iter()) {
-            self.update_with_wrapping_sub_binary_mul(&poly, &bin_poly);
+            self.ct_update_with_wrapping_add_binary_mul(&poly, &bin_poly);
         }
     }
-    /// Adds the result of the product between a integer polynomial and a binary one, reduced
-    /// modulo $(X^N+1)$, to the current polynomial.
+
+    /// Adds the result of the product between an integer polynomial and a binary one, reduced
+    /// modulo $(X^N+1)$, to the current polynomial, in constant time.
+    ///
+    /// This computes the same result as [`Polynomial::update_with_wrapping_add_binary_mul`].
+    ///
+    /// # Constant-time
+    ///
+    /// Each binary coefficient $b$ is turned into the mask $0 - b$ (all ones or all zeros), which
+    /// is and-ed with the integer coefficient. This replaces the multiplication by
+    /// `Coef::cast_from(bit)`, which some compilers lower to a branch per key bit, and leaves
+    /// straight-line code whose only branch depends on the (public) degrees of the monomials.
     ///
     /// # Example
     ///
@@ -403,12 +1974,12 @@ impl<Cont> Polynomial<Cont> {
     /// let poly = Polynomial::from_container(vec![1_u8,2,3]);
     /// let bin_poly = Polynomial::from_container(vec![false, true, true]);
     /// let mut res = Polynomial::from_container(vec![1_u8, 0, 253]);
-    /// res.update_with_wrapping_add_binary_mul(&poly, &bin_poly);
+    /// res.ct_update_with_wrapping_add_binary_mul(&poly, &bin_poly);
     /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 252);
     /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 254);
     /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 0);
     /// ```
-    pub fn update_with_wrapping_add_binary_mul<Coef, PolyCont, BinCont>(
+    pub fn ct_update_with_wrapping_add_binary_mul<Coef, PolyCont, BinCont>(
         &mut self,
         polynomial: &Polynomial<PolyCont>,
         bin_polynomial: &Polynomial<BinCont>,
@@ -416,7 +1987,7 @@ impl<Cont> Polynomial<Cont> {
         Self: AsMutTensor<Element = Coef>,
         Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
         Polynomial<BinCont>: AsRefTensor<Element = bool>,
-        Coef: UnsignedInteger + CastFrom<bool>,
+        Coef: UnsignedInteger,
     {
         ck_dim_eq!(
             self.polynomial_size() =>
@@ -426,29 +1997,78 @@ impl<Cont> Polynomial<Cont> {
         let degree = polynomial.polynomial_size().0 - 1;
         for lhsi in polynomial.monomial_iter() {
             for rhsi in bin_polynomial.monomial_iter() {
+                // The branch below only depends on the (public) degrees of the monomials.
                 let target_degree = lhsi.degree().0 + rhsi.degree().0;
-                let binary_bit = Coef::cast_from(*rhsi.get_coefficient());
+                let masked =
+                    *lhsi.get_coefficient() & Coef::ct_mask_from_bool(*rhsi.get_coefficient());
+                let output = self
+                    .as_mut_tensor()
+                    .get_element_mut(target_degree % (degree + 1));
                 if target_degree <= degree {
-                    let update = self
-                        .as_tensor()
-                        .get_element(target_degree)
-                        .wrapping_add(*lhsi.get_coefficient() * binary_bit);
-                    *self.as_mut_tensor().get_element_mut(target_degree) = update;
+                    *output = output.wrapping_add(masked);
                 } else {
-                    let update = self
-                        .as_tensor()
-                        .get_element(target_degree % (degree + 1))
-                        .wrapping_sub(*lhsi.get_coefficient() * binary_bit);
-                    *self
-                        .as_mut_tensor()
-                        .get_element_mut(target_degree % (degree + 1)) = update;
+                    *output = output.wrapping_sub(masked);
                 }
             }
         }
     }
 
+    /// Subtracts the sum of the element-wise product between a list of integer polynomial, and a
+    /// list of binary polynomial, to the current polynomial, in constant time.
+    ///
+    /// This computes the same result as
+    /// [`Polynomial::update_with_wrapping_sub_binary_multisum`].
+    ///
+    /// # Constant-time
+    ///
+    /// See [`Polynomial::ct_update_with_wrapping_sub_binary_mul`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{PolynomialList, PolynomialSize, Polynomial, MonomialDegree};
+    /// let poly_list = PolynomialList::from_container(
+    ///     vec![100 as u8,20,3,4,5,6],
+    ///     PolynomialSize(3)
+    /// );
+    /// let bin_poly_list = PolynomialList::from_container(
+    ///     vec![false, true, true, true, false, false],
+    ///     PolynomialSize(3)
+    /// );
+    /// let mut output = Polynomial::allocate(250 as u8, PolynomialSize(3));
+    /// output.ct_update_with_wrapping_sub_binary_multisum(&poly_list, &bin_poly_list);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(0)).get_coefficient(), 13);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(1)).get_coefficient(), 148);
+    /// assert_eq!(*output.get_monomial(MonomialDegree(2)).get_coefficient(), 124);
+    /// ```
+    pub fn ct_update_with_wrapping_sub_binary_multisum<Coef, InCont, BinCont>(
+        &mut self,
+        coef_list: &PolynomialList<InCont>,
+        bin_list: &PolynomialList<BinCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        PolynomialList<InCont>: AsRefTensor<Element = Coef>,
+        PolynomialList<BinCont>: AsRefTensor<Element = bool>,
+        for<'a> Polynomial<&'a [bool]>: AsRefTensor<Element = bool>,
+        for<'a> Polynomial<&'a [Coef]>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        for (poly, bin_poly) in coef_list.polynomial_iter().zip(bin_list.polynomial_iter()) {
+            self.ct_update_with_wrapping_sub_binary_mul(&poly, &bin_poly);
+        }
+    }
+
     /// Subtracts the result of the product between an integer polynomial and a binary one, reduced
-    /// modulo $(X^N+1)$, to the current polynomial.
+    /// modulo $(X^N+1)$, to the current polynomial, in constant time.
+    ///
+    /// This computes the same result as [`Polynomial::update_with_wrapping_sub_binary_mul`].
+    ///
+    /// # Constant-time
+    ///
+    /// The control flow and the memory accesses of this method only depend on the size of the
+    /// polynomials. As in [`Polynomial::ct_update_with_wrapping_add_binary_mul`], each binary
+    /// coefficient is turned into a mask which is applied to the integer coefficient, instead of
+    /// being converted to an integer with a branch.
     ///
     /// # Example
     ///
@@ -457,12 +2077,12 @@ impl<Cont> Polynomial<Cont> {
     /// let poly = Polynomial::from_container(vec![1_u8,2,3]);
     /// let bin_poly = Polynomial::from_container(vec![false, true, true]);
     /// let mut res = Polynomial::from_container(vec![255_u8, 255, 1]);
-    /// res.update_with_wrapping_sub_binary_mul(&poly, &bin_poly);
+    /// res.ct_update_with_wrapping_sub_binary_mul(&poly, &bin_poly);
     /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 4);
     /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 1);
     /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 254);
     /// ```
-    pub fn update_with_wrapping_sub_binary_mul<Coef, PolyCont, BinCont>(
+    pub fn ct_update_with_wrapping_sub_binary_mul<Coef, PolyCont, BinCont>(
         &mut self,
         polynomial: &Polynomial<PolyCont>,
         bin_polynomial: &Polynomial<BinCont>,
@@ -470,7 +2090,7 @@ impl<Cont> Polynomial<Cont> {
         Self: AsMutTensor<Element = Coef>,
         Polynomial<PolyCont>: AsRefTensor<Element = Coef>,
         Polynomial<BinCont>: AsRefTensor<Element = bool>,
-        Coef: UnsignedInteger + CastFrom<bool>,
+        Coef: UnsignedInteger,
     {
         ck_dim_eq!(
             self.polynomial_size() =>
@@ -480,24 +2100,17 @@ impl<Cont> Polynomial<Cont> {
         let degree = polynomial.polynomial_size().0 - 1;
         for lhsi in polynomial.monomial_iter() {
             for rhsi in bin_polynomial.monomial_iter() {
+                // The branch below only depends on the (public) degrees of the monomials.
                 let target_degree = lhsi.degree().0 + rhsi.degree().0;
-                let binary_bit = Coef::cast_from(*rhsi.get_coefficient());
+                let masked =
+                    *lhsi.get_coefficient() & Coef::ct_mask_from_bool(*rhsi.get_coefficient());
+                let output = self
+                    .as_mut_tensor()
+                    .get_element_mut(target_degree % (degree + 1));
                 if target_degree <= degree {
-                    let update = self
-                        .as_tensor()
-                        .get_element(target_degree)
-                        .wrapping_sub(*lhsi.get_coefficient() * binary_bit);
-                    *self.as_mut_tensor().get_element_mut(target_degree) = update;
+                    *output = output.wrapping_sub(masked);
                 } else {
-                    let update = self
-                        .as_tensor()
-                        .get_element(target_degree % (degree + 1))
-                        .wrapping_add(*lhsi.get_coefficient() * binary_bit);
-                    *self
-                        .as_mut_tensor()
-                        .as_mut_slice()
-                        .get_mut(target_degree % (degree + 1))
-                        .unwrap() = update;
+                    *output = output.wrapping_add(masked);
                 }
             }
         }
@@ -543,38 +2156,223 @@ impl<Cont> Polynomial<Cont> {
     /// assert_eq!(*first.get_monomial(MonomialDegree(1)).get_coefficient(), 253);
     /// assert_eq!(*first.get_monomial(MonomialDegree(2)).get_coefficient(), 253);
     /// ```
-    pub fn update_with_wrapping_sub<Coef, OtherCont>(&mut self, other: &Polynomial<OtherCont>)
-    where
+    pub fn update_with_wrapping_sub<Coef, OtherCont>(&mut self, other: &Polynomial<OtherCont>)
+    where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<OtherCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(
+            self.polynomial_size() =>
+            other.polynomial_size()
+        );
+        self.as_mut_tensor()
+            .update_with_wrapping_sub(other.as_tensor());
+    }
+
+    /// Multiplies every coefficient of the polynomial by a scalar, with wrapping.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial};
+    /// let mut poly = Polynomial::from_container(vec![1u8, 2, 100]);
+    /// poly.update_with_wrapping_scalar_mul(3);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 3);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 6);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 44);
+    /// ```
+    pub fn update_with_wrapping_scalar_mul<Coef>(&mut self, scalar: Coef)
+    where
+        Self: AsMutTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        self.as_mut_tensor()
+            .update_with_wrapping_scalar_mul(&scalar);
+    }
+
+    /// Fills the current polynomial with the coefficients of another one multiplied by a scalar,
+    /// with wrapping.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial, PolynomialSize};
+    /// let poly = Polynomial::from_container(vec![1u8, 2, 100]);
+    /// let mut res = Polynomial::allocate(0u8, PolynomialSize(3));
+    /// res.fill_with_wrapping_scalar_mul(&poly, 255);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(0)).get_coefficient(), 255);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(1)).get_coefficient(), 254);
+    /// assert_eq!(*res.get_monomial(MonomialDegree(2)).get_coefficient(), 156);
+    /// ```
+    pub fn fill_with_wrapping_scalar_mul<Coef, InCont>(
+        &mut self,
+        poly: &Polynomial<InCont>,
+        scalar: Coef,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<InCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.polynomial_size() => poly.polynomial_size());
+        self.as_mut_tensor()
+            .fill_with_wrapping_element_mul(poly.as_tensor(), scalar);
+    }
+
+    /// Fills the current polynomial with the coefficients of a source polynomial of another
+    /// integer width, rescaled to keep their torus interpretation.
+    ///
+    /// Narrowing keeps the most significant bits of the coefficients with a rounding to the
+    /// nearest value, and widening shifts them to the most significant bits. See
+    /// [`Tensor::fill_with_cast_rescale`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial, PolynomialSize};
+    /// let source = Polynomial::from_container(vec![1u64 << 63, (1 << 31) + 1, (1 << 31) - 1]);
+    /// let mut poly = Polynomial::allocate(0u32, PolynomialSize(3));
+    /// poly.fill_with_cast_rescale(&source);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 1 << 31);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 1);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 0);
+    /// ```
+    pub fn fill_with_cast_rescale<Coef, OtherCoef, InCont>(&mut self, source: &Polynomial<InCont>)
+    where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<InCont>: AsRefTensor<Element = OtherCoef>,
+        Coef: UnsignedInteger + CastFrom<OtherCoef>,
+        OtherCoef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.polynomial_size() => source.polynomial_size());
+        self.as_mut_tensor()
+            .fill_with_cast_rescale(source.as_tensor());
+    }
+
+    /// Fills the current polynomial with the coefficients of a smaller source polynomial, and
+    /// zeroes the coefficients of higher degrees.
+    ///
+    /// This embeds a polynomial of $\mathbb{Z}_{2^q}\[X\] / <X^n + 1>$ into the larger ring
+    /// $\mathbb{Z}_{2^q}\[X\] / <X^N + 1>$ by keeping the coefficient of every degree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the source polynomial is larger than the current one, as it would be truncated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialSize};
+    /// let source = Polynomial::from_container(vec![1u8, 2]);
+    /// let mut poly = Polynomial::allocate(7u8, PolynomialSize(4));
+    /// poly.fill_with_resized_copy(&source);
+    /// assert_eq!(poly, Polynomial::from_container(vec![1u8, 2, 0, 0]));
+    /// ```
+    pub fn fill_with_resized_copy<Coef, InCont>(&mut self, source: &Polynomial<InCont>)
+    where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<InCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        assert!(
+            source.polynomial_size().0 <= self.polynomial_size().0,
+            "Tried to copy a polynomial of size {} in a smaller polynomial of size {}.",
+            source.polynomial_size().0,
+            self.polynomial_size().0
+        );
+        let (low, high) = self
+            .as_mut_tensor()
+            .as_mut_slice()
+            .split_at_mut(source.polynomial_size().0);
+        low.copy_from_slice(source.as_tensor().as_slice());
+        high.iter_mut().for_each(|coef| *coef = Coef::ZERO);
+    }
+
+    /// Multiplies (mod $(X^N+1)$), the current polynomial with a monomial of a given degree, and
+    /// a coefficient of one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{Polynomial, MonomialDegree};
+    /// let mut poly = Polynomial::from_container(vec![1u8,2,3]);
+    /// poly.update_with_wrapping_monic_monomial_mul(MonomialDegree(2));
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 254);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 253);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 1);
+    /// ```
+    pub fn update_with_wrapping_monic_monomial_mul<Coef>(&mut self, monomial_degree: MonomialDegree)
+    where
+        Self: AsMutTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        self.update_with_wrapping_monic_monomial_mul_with_reduction(
+            monomial_degree,
+            ReductionMode::Negacyclic,
+        );
+    }
+
+    /// Multiplies (mod $(X^N-1)$), the current polynomial with a monomial of a given degree, and
+    /// a coefficient of one.
+    ///
+    /// This is a plain rotation of the coefficients towards the higher degrees: contrary to
+    /// [`Polynomial::update_with_wrapping_monic_monomial_mul`], the coefficients wrapping past
+    /// $X^N$ keep their sign.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial};
+    /// let mut poly = Polynomial::from_container(vec![1u8, 2, 3]);
+    /// poly.update_with_wrapping_cyclic_monomial_mul(MonomialDegree(2));
+    /// assert_eq!(poly, Polynomial::from_container(vec![2u8, 3, 1]));
+    /// ```
+    pub fn update_with_wrapping_cyclic_monomial_mul<Coef>(
+        &mut self,
+        monomial_degree: MonomialDegree,
+    ) where
         Self: AsMutTensor<Element = Coef>,
-        Polynomial<OtherCont>: AsRefTensor<Element = Coef>,
         Coef: UnsignedInteger,
     {
-        ck_dim_eq!(
-            self.polynomial_size() =>
-            other.polynomial_size()
+        self.update_with_wrapping_monic_monomial_mul_with_reduction(
+            monomial_degree,
+            ReductionMode::Cyclic,
         );
-        self.as_mut_tensor()
-            .update_with_wrapping_sub(other.as_tensor());
     }
 
-    /// Multiplies (mod $(X^N+1)$), the current polynomial with a monomial of a given degree, and
-    /// a coefficient of one.
+    /// Multiplies (mod $(X^N+1)$ or $(X^N-1)$ depending on the `reduction` mode), the current
+    /// polynomial with a monomial of a given degree, and a coefficient of one.
+    ///
+    /// In the cyclic mode, this is a rotation of the coefficients.
     ///
     /// # Examples
     ///
     /// ```
-    /// use concrete_core::math::polynomial::{Polynomial, MonomialDegree};
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial, ReductionMode};
     /// let mut poly = Polynomial::from_container(vec![1u8,2,3]);
-    /// poly.update_with_wrapping_monic_monomial_mul(MonomialDegree(2));
-    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 254);
-    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 253);
+    /// poly.update_with_wrapping_monic_monomial_mul_with_reduction(
+    ///     MonomialDegree(2),
+    ///     ReductionMode::Cyclic
+    /// );
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 2);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 3);
     /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 1);
     /// ```
-    pub fn update_with_wrapping_monic_monomial_mul<Coef>(&mut self, monomial_degree: MonomialDegree)
-    where
+    pub fn update_with_wrapping_monic_monomial_mul_with_reduction<Coef>(
+        &mut self,
+        monomial_degree: MonomialDegree,
+        reduction: ReductionMode,
+    ) where
         Self: AsMutTensor<Element = Coef>,
         Coef: UnsignedInteger,
     {
+        let remaining_degree = monomial_degree.0 % self.as_tensor().len();
+        if reduction == ReductionMode::Cyclic {
+            self.as_mut_tensor()
+                .as_mut_slice()
+                .rotate_right(remaining_degree);
+            return;
+        }
         let full_cycles_count = monomial_degree.0 / self.as_tensor().len();
         if full_cycles_count % 2 != 0 {
             self.as_mut_tensor()
@@ -582,7 +2380,6 @@ impl<Cont> Polynomial<Cont> {
                 .iter_mut()
                 .for_each(|a| *a = a.wrapping_neg());
         }
-        let remaining_degree = monomial_degree.0 % self.as_tensor().len();
         self.as_mut_tensor()
             .as_mut_slice()
             .rotate_right(remaining_degree);
@@ -611,6 +2408,45 @@ impl<Cont> Polynomial<Cont> {
         Self: AsMutTensor<Element = Coef>,
         Coef: UnsignedInteger,
     {
+        self.update_with_wrapping_unit_monomial_div_with_reduction(
+            monomial_degree,
+            ReductionMode::Negacyclic,
+        );
+    }
+
+    /// Divides (mod $(X^N+1)$ or $(X^N-1)$ depending on the `reduction` mode), the current
+    /// polynomial with a monomial of a given degree, and a coefficient of one.
+    ///
+    /// In the cyclic mode, this is a rotation of the coefficients.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial, ReductionMode};
+    /// let mut poly = Polynomial::from_container(vec![1u8,2,3]);
+    /// poly.update_with_wrapping_unit_monomial_div_with_reduction(
+    ///     MonomialDegree(2),
+    ///     ReductionMode::Cyclic
+    /// );
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 3);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 1);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 2);
+    /// ```
+    pub fn update_with_wrapping_unit_monomial_div_with_reduction<Coef>(
+        &mut self,
+        monomial_degree: MonomialDegree,
+        reduction: ReductionMode,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        let remaining_degree = monomial_degree.0 % self.as_tensor().len();
+        if reduction == ReductionMode::Cyclic {
+            self.as_mut_tensor()
+                .as_mut_slice()
+                .rotate_left(remaining_degree);
+            return;
+        }
         let full_cycles_count = monomial_degree.0 / self.as_tensor().len();
         if full_cycles_count % 2 != 0 {
             self.as_mut_tensor()
@@ -618,7 +2454,6 @@ impl<Cont> Polynomial<Cont> {
                 .iter_mut()
                 .for_each(|a| *a = a.wrapping_neg());
         }
-        let remaining_degree = monomial_degree.0 % self.as_tensor().len();
         self.as_mut_tensor()
             .as_mut_slice()
             .rotate_left(remaining_degree);
@@ -630,6 +2465,249 @@ impl<Cont> Polynomial<Cont> {
             .for_each(|a| *a = a.wrapping_neg());
     }
 
+    /// Fills the current polynomial with the image of a source polynomial by the galois
+    /// automorphism $X \mapsto X^k$, reduced modulo $(X^N+1)$.
+    ///
+    /// The coefficient of degree $i$ of the source is sent to the degree $ik \bmod 2N$, which is
+    /// then brought back below $N$ by negating the coefficient if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is even, as the map is only an automorphism when $k$ is coprime with $2N$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial, PolynomialSize};
+    /// let source = Polynomial::from_container(vec![1u8, 2, 3, 4]);
+    /// let mut poly = Polynomial::allocate(0u8, PolynomialSize(4));
+    /// poly.fill_with_galois_automorphism(&source, 3);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 1);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 4);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 253);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(3)).get_coefficient(), 2);
+    /// ```
+    pub fn fill_with_galois_automorphism<Coef, InCont>(
+        &mut self,
+        source: &Polynomial<InCont>,
+        k: usize,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<InCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.polynomial_size() => source.polynomial_size());
+        assert!(
+            k % 2 == 1,
+            "The galois automorphism X -> X^{} is not defined for an even exponent.",
+            k
+        );
+        let size = self.polynomial_size().0;
+        let output = self.as_mut_tensor().as_mut_slice();
+        for (degree, coef) in source.coefficient_iter().enumerate() {
+            // The product is reduced first to avoid overflowing on large exponents.
+            let target_degree = (degree * (k % (2 * size))) % (2 * size);
+            if target_degree < size {
+                output[target_degree] = *coef;
+            } else {
+                output[target_degree - size] = coef.wrapping_neg();
+            }
+        }
+    }
+
+    /// Applies the galois automorphism $X \mapsto X^k$ to the current polynomial, reduced modulo
+    /// $(X^N+1)$.
+    ///
+    /// See [`Polynomial::fill_with_galois_automorphism`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is even.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial};
+    /// let mut poly = Polynomial::from_container(vec![1u8, 2, 3, 4]);
+    /// poly.update_with_galois_automorphism(5);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 1);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 254);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 3);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(3)).get_coefficient(), 252);
+    /// ```
+    pub fn update_with_galois_automorphism<Coef>(&mut self, k: usize)
+    where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<Vec<Coef>>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        let source = Polynomial::from_container(self.as_tensor().as_slice().to_vec());
+        self.fill_with_galois_automorphism(&source, k);
+    }
+
+    /// Fills the current polynomial with the negacyclic reversal $P(X^{-1})$ of a source
+    /// polynomial, reduced modulo $(X^N+1)$.
+    ///
+    /// Since $X^{-i} = -X^{N-i}$ modulo $(X^N+1)$, the constant coefficient is kept, and the
+    /// coefficient of degree $i > 0$ is sent to the degree $N - i$ and negated. This is the
+    /// galois automorphism of exponent $2N - 1$. The constant coefficient of the product of the
+    /// reversal of $A$ by $B$ is the dot product of their coefficient vectors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial, PolynomialSize};
+    /// let source = Polynomial::from_container(vec![1u8, 2, 3, 4]);
+    /// let mut poly = Polynomial::allocate(0u8, PolynomialSize(4));
+    /// poly.fill_with_wrapping_reversal(&source);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 1);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 252);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 253);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(3)).get_coefficient(), 254);
+    /// ```
+    pub fn fill_with_wrapping_reversal<Coef, InCont>(&mut self, source: &Polynomial<InCont>)
+    where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<InCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(self.polynomial_size() => source.polynomial_size());
+        let output = self.as_mut_tensor().as_mut_slice();
+        let input = source.as_tensor().as_slice();
+        if let (Some((out_first, out_rest)), Some((in_first, in_rest))) =
+            (output.split_first_mut(), input.split_first())
+        {
+            *out_first = *in_first;
+            for (out, inp) in out_rest.iter_mut().zip(in_rest.iter().rev()) {
+                *out = inp.wrapping_neg();
+            }
+        }
+    }
+
+    /// Replaces the current polynomial by its negacyclic reversal $P(X^{-1})$, reduced modulo
+    /// $(X^N+1)$.
+    ///
+    /// See [`Polynomial::fill_with_wrapping_reversal`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, Polynomial};
+    /// let mut poly = Polynomial::from_container(vec![1u8, 2, 3, 4]);
+    /// poly.update_with_wrapping_reversal();
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(0)).get_coefficient(), 1);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(1)).get_coefficient(), 252);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(2)).get_coefficient(), 253);
+    /// assert_eq!(*poly.get_monomial(MonomialDegree(3)).get_coefficient(), 254);
+    /// ```
+    pub fn update_with_wrapping_reversal<Coef>(&mut self)
+    where
+        Self: AsMutTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        if let Some((_, rest)) = self.as_mut_tensor().as_mut_slice().split_first_mut() {
+            rest.reverse();
+            rest.iter_mut().for_each(|a| *a = a.wrapping_neg());
+        }
+    }
+
+    /// Splits the current polynomial into its even and odd degree parts.
+    ///
+    /// The polynomial is written $P(X) = P_{even}(X^2) + X \cdot P_{odd}(X^2)$, where both parts
+    /// have $N/2$ coefficients. Since $(X^2)^{N/2} = X^N$, the parts live in the ring
+    /// $\mathbb{Z}_{2^q}\[Y\] / <Y^{N/2} + 1>$, in which multiplying both parts by $Y$ amounts to
+    /// multiplying $P$ by $X^2$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the polynomial is odd.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialSize};
+    /// let poly = Polynomial::from_container(vec![1u8, 2, 3, 4, 5, 6]);
+    /// let mut even = Polynomial::allocate(0u8, PolynomialSize(3));
+    /// let mut odd = Polynomial::allocate(0u8, PolynomialSize(3));
+    /// poly.fill_even_odd_split(&mut even, &mut odd);
+    /// assert_eq!(even, Polynomial::from_container(vec![1u8, 3, 5]));
+    /// assert_eq!(odd, Polynomial::from_container(vec![2u8, 4, 6]));
+    /// ```
+    pub fn fill_even_odd_split<Coef, EvenCont, OddCont>(
+        &self,
+        even: &mut Polynomial<EvenCont>,
+        odd: &mut Polynomial<OddCont>,
+    ) where
+        Self: AsRefTensor<Element = Coef>,
+        Polynomial<EvenCont>: AsMutTensor<Element = Coef>,
+        Polynomial<OddCont>: AsMutTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        assert!(
+            self.polynomial_size().0.is_multiple_of(2),
+            "Tried to split a polynomial of odd size {}.",
+            self.polynomial_size().0
+        );
+        let half_size = PolynomialSize(self.polynomial_size().0 / 2);
+        ck_dim_eq!(half_size => even.polynomial_size(), odd.polynomial_size());
+        for ((pair, even_coef), odd_coef) in self
+            .as_tensor()
+            .as_slice()
+            .chunks_exact(2)
+            .zip(even.as_mut_tensor().iter_mut())
+            .zip(odd.as_mut_tensor().iter_mut())
+        {
+            *even_coef = pair[0];
+            *odd_coef = pair[1];
+        }
+    }
+
+    /// Fills the current polynomial with $P_{even}(X^2) + X \cdot P_{odd}(X^2)$, which inverts
+    /// [`Polynomial::fill_even_odd_split`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the polynomial is odd.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialSize};
+    /// let even = Polynomial::from_container(vec![1u8, 3, 5]);
+    /// let odd = Polynomial::from_container(vec![2u8, 4, 6]);
+    /// let mut poly = Polynomial::allocate(0u8, PolynomialSize(6));
+    /// poly.fill_from_even_odd(&even, &odd);
+    /// assert_eq!(poly, Polynomial::from_container(vec![1u8, 2, 3, 4, 5, 6]));
+    /// ```
+    pub fn fill_from_even_odd<Coef, EvenCont, OddCont>(
+        &mut self,
+        even: &Polynomial<EvenCont>,
+        odd: &Polynomial<OddCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<EvenCont>: AsRefTensor<Element = Coef>,
+        Polynomial<OddCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        assert!(
+            self.polynomial_size().0.is_multiple_of(2),
+            "Tried to merge the parts of a polynomial of odd size {}.",
+            self.polynomial_size().0
+        );
+        let half_size = PolynomialSize(self.polynomial_size().0 / 2);
+        ck_dim_eq!(half_size => even.polynomial_size(), odd.polynomial_size());
+        for ((pair, even_coef), odd_coef) in self
+            .as_mut_tensor()
+            .as_mut_slice()
+            .chunks_exact_mut(2)
+            .zip(even.coefficient_iter())
+            .zip(odd.coefficient_iter())
+        {
+            pair[0] = *even_coef;
+            pair[1] = *odd_coef;
+        }
+    }
+
     /// Adds multiple integer polynomials to the current one.
     ///
     /// # Examples
@@ -686,3 +2764,290 @@ impl<Cont> Polynomial<Cont> {
         }
     }
 }
+
+/// Adds two polynomials, with the wrapping semantics of
+/// [`Polynomial::update_with_wrapping_add`].
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::polynomial::Polynomial;
+/// let first = Polynomial::from_container(vec![1u8, 2, 3]);
+/// let second = Polynomial::from_container(vec![255u8, 255, 255]);
+/// assert_eq!(first + &second, Polynomial::from_container(vec![0, 1, 2]));
+/// ```
+impl<'a, Coef, OtherCont> Add<&'a Polynomial<OtherCont>> for Polynomial<Vec<Coef>>
+where
+    Polynomial<OtherCont>: AsRefTensor<Element = Coef>,
+    Coef: UnsignedInteger,
+{
+    type Output = Polynomial<Vec<Coef>>;
+
+    fn add(mut self, other: &'a Polynomial<OtherCont>) -> Self::Output {
+        self.update_with_wrapping_add(other);
+        self
+    }
+}
+
+/// Adds two owned polynomials, with the wrapping semantics of
+/// [`Polynomial::update_with_wrapping_add`].
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::polynomial::Polynomial;
+/// let first = Polynomial::from_container(vec![1u8, 2, 3]);
+/// let second = Polynomial::from_container(vec![255u8, 255, 255]);
+/// assert_eq!(first + second, Polynomial::from_container(vec![0, 1, 2]));
+/// ```
+impl<Coef> Add<Polynomial<Vec<Coef>>> for Polynomial<Vec<Coef>>
+where
+    Coef: UnsignedInteger,
+{
+    type Output = Polynomial<Vec<Coef>>;
+
+    fn add(self, other: Polynomial<Vec<Coef>>) -> Self::Output {
+        self + &other
+    }
+}
+
+/// Subtracts a polynomial from another one, with the wrapping semantics of
+/// [`Polynomial::update_with_wrapping_sub`].
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::polynomial::Polynomial;
+/// let first = Polynomial::from_container(vec![1u8, 2, 3]);
+/// let second = Polynomial::from_container(vec![2u8, 2, 2]);
+/// assert_eq!(first - &second, Polynomial::from_container(vec![255, 0, 1]));
+/// ```
+impl<'a, Coef, OtherCont> Sub<&'a Polynomial<OtherCont>> for Polynomial<Vec<Coef>>
+where
+    Polynomial<OtherCont>: AsRefTensor<Element = Coef>,
+    Coef: UnsignedInteger,
+{
+    type Output = Polynomial<Vec<Coef>>;
+
+    fn sub(mut self, other: &'a Polynomial<OtherCont>) -> Self::Output {
+        self.update_with_wrapping_sub(other);
+        self
+    }
+}
+
+/// Subtracts an owned polynomial from another one, with the wrapping semantics of
+/// [`Polynomial::update_with_wrapping_sub`].
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::polynomial::Polynomial;
+/// let first = Polynomial::from_container(vec![1u8, 2, 3]);
+/// let second = Polynomial::from_container(vec![2u8, 2, 2]);
+/// assert_eq!(first - second, Polynomial::from_container(vec![255, 0, 1]));
+/// ```
+impl<Coef> Sub<Polynomial<Vec<Coef>>> for Polynomial<Vec<Coef>>
+where
+    Coef: UnsignedInteger,
+{
+    type Output = Polynomial<Vec<Coef>>;
+
+    fn sub(self, other: Polynomial<Vec<Coef>>) -> Self::Output {
+        self - &other
+    }
+}
+
+/// Negates every coefficient of a polynomial, with wrapping.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::polynomial::Polynomial;
+/// let poly = Polynomial::from_container(vec![0u8, 1, 128]);
+/// assert_eq!(-poly, Polynomial::from_container(vec![0, 255, 128]));
+/// ```
+impl<Coef> Neg for Polynomial<Vec<Coef>>
+where
+    Coef: UnsignedInteger,
+{
+    type Output = Polynomial<Vec<Coef>>;
+
+    fn neg(mut self) -> Self::Output {
+        self.as_mut_tensor().update_with_wrapping_neg();
+        self
+    }
+}
+
+/// Adds a polynomial to the current one in place, with the wrapping semantics of
+/// [`Polynomial::update_with_wrapping_add`].
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::polynomial::Polynomial;
+/// let mut first = Polynomial::from_container(vec![1u8, 2, 3]);
+/// first += &Polynomial::from_container(vec![255u8, 255, 255]);
+/// assert_eq!(first, Polynomial::from_container(vec![0, 1, 2]));
+/// // Borrowed polynomials can be updated as well.
+/// let mut values = vec![1u8, 2, 3];
+/// let mut borrowed = Polynomial::from_container(values.as_mut_slice());
+/// borrowed += &Polynomial::from_container(vec![1u8, 1, 1]);
+/// assert_eq!(values, vec![2, 3, 4]);
+/// ```
+impl<'a, Coef, Cont, OtherCont> AddAssign<&'a Polynomial<OtherCont>> for Polynomial<Cont>
+where
+    Polynomial<Cont>: AsMutTensor<Element = Coef>,
+    Polynomial<OtherCont>: AsRefTensor<Element = Coef>,
+    Coef: UnsignedInteger,
+{
+    fn add_assign(&mut self, other: &'a Polynomial<OtherCont>) {
+        self.update_with_wrapping_add(other);
+    }
+}
+
+/// Subtracts a polynomial from the current one in place, with the wrapping semantics of
+/// [`Polynomial::update_with_wrapping_sub`].
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::polynomial::Polynomial;
+/// let mut first = Polynomial::from_container(vec![1u8, 2, 3]);
+/// first -= &Polynomial::from_container(vec![2u8, 2, 2]);
+/// assert_eq!(first, Polynomial::from_container(vec![255, 0, 1]));
+/// ```
+impl<'a, Coef, Cont, OtherCont> SubAssign<&'a Polynomial<OtherCont>> for Polynomial<Cont>
+where
+    Polynomial<Cont>: AsMutTensor<Element = Coef>,
+    Polynomial<OtherCont>: AsRefTensor<Element = Coef>,
+    Coef: UnsignedInteger,
+{
+    fn sub_assign(&mut self, other: &'a Polynomial<OtherCont>) {
+        self.update_with_wrapping_sub(other);
+    }
+}
+
+#[cfg(feature = "npy")]
+impl<Cont> Polynomial<Cont> {
+    /// Writes the coefficients of the polynomial to a file in the npy format, as a
+    /// one-dimensional array.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialSize};
+    /// use concrete_core::math::tensor::NpyError;
+    /// let poly = Polynomial::from_container(vec![1i64, -2, 3, -4]);
+    /// let path = std::env::temp_dir().join("concrete_polynomial_example.npy");
+    /// poly.write_npy(&path).unwrap();
+    /// let recovered = Polynomial::<Vec<i64>>::read_npy(&path, PolynomialSize(4)).unwrap();
+    /// assert_eq!(recovered, poly);
+    /// let error = Polynomial::<Vec<i64>>::read_npy(&path, PolynomialSize(8)).unwrap_err();
+    /// assert!(matches!(error, NpyError::ShapeMismatch { .. }));
+    /// ```
+    pub fn write_npy<Coef, P: AsRef<Path>>(&self, path: P) -> Result<(), NpyError>
+    where
+        Self: AsRefTensor<Element = Coef>,
+        Coef: NpyElement,
+    {
+        self.as_tensor().write_npy(path)
+    }
+}
+
+#[cfg(feature = "npy")]
+impl<Coef> Polynomial<Vec<Coef>>
+where
+    Coef: NpyElement,
+{
+    /// Reads a polynomial of a given size from a one-dimensional array stored in the npy format.
+    ///
+    /// An error is returned if the number of coefficients stored in the file does not match
+    /// `polynomial_size`. See [`Polynomial::write_npy`] for an example.
+    pub fn read_npy<P: AsRef<Path>>(
+        path: P,
+        polynomial_size: PolynomialSize,
+    ) -> Result<Self, NpyError> {
+        let tensor: Tensor<Vec<Coef>> = Tensor::read_npy(path.as_ref())?;
+        if tensor.len() != polynomial_size.0 {
+            return Err(NpyError::ShapeMismatch {
+                filename: path.as_ref().to_string_lossy().into_owned(),
+                expected: format!("({},)", polynomial_size.0),
+                found: vec![tensor.len()],
+            });
+        }
+        Ok(Polynomial::from_container(tensor.into_container()))
+    }
+}
+
+/// The number of leading and trailing coefficients displayed by default.
+const DISPLAYED_COEFFICIENTS: usize = 3;
+
+// Writes the coefficients as a sum of monomials, eliding the middle ones. The formatter
+// precision sets the number of leading and trailing coefficients to show, and the alternate flag
+// shows the coefficients as signed torus values.
+pub(super) fn fmt_coefficients<Coef>(
+    f: &mut fmt::Formatter<'_>,
+    coefficients: &[Coef],
+) -> fmt::Result
+where
+    Coef: UnsignedInteger + fmt::Display,
+{
+    let shown = f.precision().unwrap_or(DISPLAYED_COEFFICIENTS);
+    let size = coefficients.len();
+    let truncated = size > 2 * shown;
+    let (leading, trailing) = if truncated { (shown, shown) } else { (size, 0) };
+    if size == 0 || (truncated && shown == 0) {
+        write!(f, "…")?;
+    }
+    for (position, degree) in (0..leading).chain(size - trailing..size).enumerate() {
+        if position == leading {
+            write!(f, " + … + ")?;
+        } else if position > 0 {
+            write!(f, " + ")?;
+        }
+        let coefficient = coefficients[degree];
+        if f.alternate() {
+            let signed: f64 = coefficient.into_signed().cast_into();
+            write!(f, "{}", signed / 2f64.powi(Coef::BITS as i32))?;
+        } else {
+            write!(f, "{}", coefficient)?;
+        }
+        match degree {
+            0 => {}
+            1 => write!(f, "·X")?,
+            _ => write!(f, "·X^{}", degree)?,
+        }
+    }
+    if truncated {
+        write!(f, " ({} more)", size - leading - trailing)?;
+    }
+    Ok(())
+}
+
+/// Displays a polynomial as a sum of monomials.
+///
+/// Only the first and the last three coefficients of large polynomials are shown, which can be
+/// changed with the precision of the format. With the alternate flag, the coefficients are shown
+/// as signed torus values in $[-0.5, 0.5[$.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::polynomial::Polynomial;
+/// let poly = Polynomial::from_container(vec![1u8, 2, 3]);
+/// assert_eq!(format!("{}", poly), "P(X) = 1 + 2·X + 3·X^2");
+/// let poly = Polynomial::from_container((0..10u8).collect::<Vec<_>>());
+/// assert_eq!(format!("{:.1}", poly), "P(X) = 0 + … + 9·X^9 (8 more)");
+/// let poly = Polynomial::from_container(vec![64u8, 128, 192]);
+/// assert_eq!(format!("{:#}", poly), "P(X) = 0.25 + -0.5·X + -0.25·X^2");
+/// ```
+impl<Cont, Coef> fmt::Display for Polynomial<Cont>
+where
+    Polynomial<Cont>: AsRefTensor<Element = Coef>,
+    Coef: UnsignedInteger + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "P(X) = ")?;
+        fmt_coefficients(f, self.as_tensor().as_slice())
+    }
+}
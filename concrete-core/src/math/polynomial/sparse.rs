@@ -0,0 +1,206 @@
+use crate::ck_dim_eq;
+use crate::math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::numeric::UnsignedInteger;
+
+use super::*;
+
+/// A sparse polynomial, storing only its non-zero monomials.
+///
+/// The monomials are stored as `(degree, coefficient)` pairs sorted by increasing degree. This
+/// representation is cheaper than a dense [`Polynomial`] when only a handful of coefficients are
+/// non-zero, e.g. for some test vectors of the programmable bootstrapping.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::polynomial::{MonomialDegree, Polynomial, SparsePolynomial};
+/// let poly = Polynomial::from_container(vec![0u8, 3, 0, 0, 7]);
+/// let sparse = SparsePolynomial::from_polynomial(&poly);
+/// assert_eq!(
+///     sparse.terms(),
+///     &[(MonomialDegree(1), 3), (MonomialDegree(4), 7)]
+/// );
+/// assert_eq!(sparse.to_polynomial(), poly);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparsePolynomial<Scalar> {
+    terms: Vec<(MonomialDegree, Scalar)>,
+    poly_size: PolynomialSize,
+}
+
+impl<Scalar> SparsePolynomial<Scalar> {
+    /// Creates a sparse polynomial of `poly_size` coefficients from a list of monomials.
+    ///
+    /// The monomials can be given in any order, and the coefficients of the missing degrees are
+    /// zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a degree is not smaller than the polynomial size, or appears more than once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{
+    ///     MonomialDegree, Polynomial, PolynomialSize, SparsePolynomial,
+    /// };
+    /// let sparse = SparsePolynomial::from_terms(
+    ///     vec![(MonomialDegree(3), 5u8), (MonomialDegree(0), 1)],
+    ///     PolynomialSize(4),
+    /// );
+    /// assert_eq!(sparse.to_polynomial(), Polynomial::from_container(vec![1u8, 0, 0, 5]));
+    /// ```
+    pub fn from_terms(mut terms: Vec<(MonomialDegree, Scalar)>, poly_size: PolynomialSize) -> Self {
+        terms.sort_by_key(|(degree, _)| degree.0);
+        if let Some((degree, _)) = terms.last() {
+            assert!(
+                degree.0 < poly_size.0,
+                "The monomial degree {} is out of range for a polynomial of size {}.",
+                degree.0,
+                poly_size.0
+            );
+        }
+        for pair in terms.windows(2) {
+            assert!(
+                pair[0].0 != pair[1].0,
+                "The monomial degree {} appears more than once.",
+                pair[0].0 .0
+            );
+        }
+        SparsePolynomial { terms, poly_size }
+    }
+
+    /// Returns the number of coefficients of the polynomial, including the zero ones.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{PolynomialSize, SparsePolynomial};
+    /// let sparse = SparsePolynomial::<u8>::from_terms(vec![], PolynomialSize(1024));
+    /// assert_eq!(sparse.polynomial_size(), PolynomialSize(1024));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the stored monomials, sorted by increasing degree.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{MonomialDegree, PolynomialSize, SparsePolynomial};
+    /// let sparse = SparsePolynomial::from_terms(
+    ///     vec![(MonomialDegree(3), 5u8), (MonomialDegree(0), 1)],
+    ///     PolynomialSize(4),
+    /// );
+    /// assert_eq!(sparse.terms()[0], (MonomialDegree(0), 1));
+    /// ```
+    pub fn terms(&self) -> &[(MonomialDegree, Scalar)] {
+        self.terms.as_slice()
+    }
+}
+
+impl<Scalar> SparsePolynomial<Scalar>
+where
+    Scalar: Copy + PartialEq + Default,
+{
+    /// Creates a sparse polynomial from the non-zero coefficients of a dense polynomial.
+    ///
+    /// See [`Polynomial::count_nonzero`] for the definition of a non-zero coefficient.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{Polynomial, SparsePolynomial};
+    /// let poly = Polynomial::from_container(vec![0u8, 3, 0, 0, 7]);
+    /// let sparse = SparsePolynomial::from_polynomial(&poly);
+    /// assert_eq!(sparse.terms().len(), 2);
+    /// ```
+    pub fn from_polynomial<Cont>(poly: &Polynomial<Cont>) -> Self
+    where
+        Polynomial<Cont>: AsRefTensor<Element = Scalar>,
+    {
+        let zero = Scalar::default();
+        let terms = poly
+            .coefficient_iter()
+            .enumerate()
+            .filter(|(_, coef)| **coef != zero)
+            .map(|(degree, coef)| (MonomialDegree(degree), *coef))
+            .collect();
+        SparsePolynomial {
+            terms,
+            poly_size: poly.polynomial_size(),
+        }
+    }
+
+    /// Converts the sparse polynomial into a dense one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{
+    ///     MonomialDegree, Polynomial, PolynomialSize, SparsePolynomial,
+    /// };
+    /// let sparse =
+    ///     SparsePolynomial::from_terms(vec![(MonomialDegree(1), 9u8)], PolynomialSize(3));
+    /// assert_eq!(sparse.to_polynomial(), Polynomial::from_container(vec![0u8, 9, 0]));
+    /// ```
+    pub fn to_polynomial(&self) -> Polynomial<Vec<Scalar>> {
+        let mut poly = Polynomial::allocate(Scalar::default(), self.poly_size);
+        for (degree, coef) in self.terms.iter() {
+            *poly.as_mut_tensor().get_element_mut(degree.0) = *coef;
+        }
+        poly
+    }
+}
+
+impl<Cont> Polynomial<Cont> {
+    /// Adds the result of the product between a dense polynomial and a sparse one, reduced
+    /// modulo $(X^N+1)$, to the current polynomial.
+    ///
+    /// Only the stored monomials of the sparse polynomial are visited: each one adds a copy of the
+    /// dense polynomial multiplied by its coefficient and rotated by its degree, with the
+    /// coefficients wrapping past $X^N$ negated. The result is the same as the dense product.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{
+    ///     MonomialDegree, Polynomial, PolynomialSize, SparsePolynomial,
+    /// };
+    /// let dense = Polynomial::from_container(vec![1u8, 2, 3]);
+    /// let sparse =
+    ///     SparsePolynomial::from_terms(vec![(MonomialDegree(2), 2u8)], PolynomialSize(3));
+    /// let mut res = Polynomial::from_container(vec![1u8, 1, 1]);
+    /// res.update_with_wrapping_add_sparse_mul(&dense, &sparse);
+    /// assert_eq!(res, Polynomial::from_container(vec![253u8, 251, 3]));
+    /// ```
+    pub fn update_with_wrapping_add_sparse_mul<Coef, DenseCont>(
+        &mut self,
+        dense: &Polynomial<DenseCont>,
+        sparse: &SparsePolynomial<Coef>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<DenseCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger,
+    {
+        ck_dim_eq!(
+            self.polynomial_size() =>
+            dense.polynomial_size(),
+            sparse.polynomial_size()
+        );
+        let size = dense.polynomial_size().0;
+        let input = dense.as_tensor().as_slice();
+        let output = self.as_mut_tensor().as_mut_slice();
+        for (MonomialDegree(shift), coef) in sparse.terms().iter() {
+            let (wrapped, straight) = output.split_at_mut(*shift);
+            let (straight_input, wrapped_input) = input.split_at(size - shift);
+            for (out, inp) in straight.iter_mut().zip(straight_input.iter()) {
+                *out = out.wrapping_add(inp.wrapping_mul(*coef));
+            }
+            for (out, inp) in wrapped.iter_mut().zip(wrapped_input.iter()) {
+                *out = out.wrapping_sub(inp.wrapping_mul(*coef));
+            }
+        }
+    }
+}
@@ -1,7 +1,17 @@
 use crate::crypto::UnsignedTorus;
-use crate::math::polynomial::{MonomialDegree, Polynomial};
+use crate::math::fft::{Fft, FftView};
+use crate::math::polynomial::{
+    BinaryPolynomial, BinaryPolynomialList, MonomialDegree, Polynomial, PolynomialCount,
+    PolynomialList, PolynomialSize, ReductionMode, SparsePolynomial,
+};
 use crate::math::random;
+use crate::math::random::{RandomGenerable, Uniform, UniformWithZeros};
+use crate::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::math::DimensionError;
+use crate::numeric::{CastFrom, CastInto, UnsignedInteger};
+use crate::test_tools;
 use rand::Rng;
+use std::fmt::Debug;
 
 fn test_multiply_divide_unit_monomial<T: UnsignedTorus>() {
     //! tests if multiply_by_monomial and divide_by_monomial cancel each other
@@ -57,3 +67,1561 @@ pub fn test_multiply_divide_unit_monomial_u32() {
 pub fn test_multiply_divide_unit_monomial_u64() {
     test_multiply_divide_unit_monomial::<u64>()
 }
+
+fn test_ct_binary_multisum<T: UnsignedTorus>() {
+    //! tests that the constant-time binary multisum matches the regular one
+    let mut rng = rand::thread_rng();
+
+    // settings
+    let polynomial_size = PolynomialSize((rng.gen::<usize>() % 1024) + 1);
+    let count = (rng.gen::<usize>() % 5) + 1;
+
+    // generates random polynomials
+    let poly_list = PolynomialList::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size.0 * count).into_container(),
+        polynomial_size,
+    );
+    let bin_list = PolynomialList::from_container(
+        random::random_uniform_boolean_tensor(polynomial_size.0 * count).into_container(),
+        polynomial_size,
+    );
+    let mut output = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+    );
+    let mut ct_output = output.clone();
+
+    // computes the multisums
+    output.update_with_wrapping_sub_binary_multisum(&poly_list, &bin_list);
+    ct_output.ct_update_with_wrapping_sub_binary_multisum(&poly_list, &bin_list);
+
+    // test
+    assert_eq!(&output, &ct_output);
+
+    // computes the multisums
+    output.update_with_wrapping_add_binary_multisum(&poly_list, &bin_list);
+    ct_output.ct_update_with_wrapping_add_binary_multisum(&poly_list, &bin_list);
+
+    // test
+    assert_eq!(&output, &ct_output);
+}
+
+#[test]
+pub fn test_ct_binary_multisum_u32() {
+    test_ct_binary_multisum::<u32>()
+}
+
+#[test]
+pub fn test_ct_binary_multisum_u64() {
+    test_ct_binary_multisum::<u64>()
+}
+
+fn test_centered_l1_norm<T: UnsignedTorus + CastInto<u64> + CastFrom<u64>>() {
+    //! tests the centered l1 norm against the sum of the absolute values of the signed
+    //! coefficients
+    let mut rng = rand::thread_rng();
+
+    // generates a random polynomial with small coefficients
+    let polynomial_size = (rng.gen::<usize>() % 2048) + 1;
+    let mut poly = Polynomial::allocate(T::ZERO, PolynomialSize(polynomial_size));
+    let mut expected = 0u64;
+    for coef in poly.coefficient_iter_mut() {
+        let value: i64 = rng.gen_range(-1000, 1000);
+        expected += value.unsigned_abs();
+        *coef = if value < 0 {
+            T::cast_from((-value) as f64).wrapping_neg()
+        } else {
+            T::cast_from(value as f64)
+        };
+    }
+
+    // test
+    assert_eq!(poly.centered_l1_norm(), expected);
+}
+
+#[test]
+pub fn test_centered_l1_norm_u32() {
+    test_centered_l1_norm::<u32>()
+}
+
+#[test]
+pub fn test_centered_l1_norm_u64() {
+    test_centered_l1_norm::<u64>()
+}
+
+#[test]
+pub fn test_centered_l1_norm_u128() {
+    test_centered_l1_norm::<u128>();
+
+    // the magnitudes which do not fit in 64 bits saturate instead of being truncated
+    let poly = Polynomial::from_container(vec![1u128 << 64, 1]);
+    assert_eq!(poly.centered_l1_norm(), u64::MAX);
+    let poly = Polynomial::from_container(vec![(1u128 << 100).wrapping_neg()]);
+    assert_eq!(poly.centered_l1_norm(), u64::MAX);
+}
+
+fn test_centered_coefficients_iter<T: UnsignedTorus>() {
+    //! tests that the centered coefficients are the signed values the polynomial was filled with
+    let mut rng = rand::thread_rng();
+
+    let polynomial_size = (rng.gen::<usize>() % 2048) + 1;
+    let mut poly = Polynomial::allocate(T::ZERO, PolynomialSize(polynomial_size));
+    let mut expected = Vec::with_capacity(polynomial_size);
+    for coef in poly.coefficient_iter_mut() {
+        let value: i64 = rng.gen_range(-1000, 1000);
+        expected.push(value as f64);
+        *coef = if value < 0 {
+            T::cast_from((-value) as f64).wrapping_neg()
+        } else {
+            T::cast_from(value as f64)
+        };
+    }
+
+    let centered: Vec<f64> = poly
+        .centered_coefficients_iter()
+        .map(|coef| coef.cast_into())
+        .collect();
+    assert_eq!(centered, expected);
+}
+
+#[test]
+pub fn test_centered_coefficients_iter_u32() {
+    test_centered_coefficients_iter::<u32>()
+}
+
+#[test]
+pub fn test_centered_coefficients_iter_u64() {
+    test_centered_coefficients_iter::<u64>()
+}
+
+fn test_auto_correlation<T: UnsignedTorus>() {
+    //! tests that a random polynomial with coefficients in {-1, 1} is close to uncorrelated with
+    //! its shifts
+    let mut rng = rand::thread_rng();
+    let polynomial_size = 2048;
+    let mut poly = Polynomial::allocate(T::ZERO, PolynomialSize(polynomial_size));
+    for coef in poly.coefficient_iter_mut() {
+        *coef = if rng.gen::<bool>() {
+            T::ONE
+        } else {
+            T::ONE.wrapping_neg()
+        };
+    }
+
+    assert_eq!(poly.auto_correlation(0), 1.);
+    assert_eq!(poly.auto_correlation(polynomial_size), 1.);
+    // The auto-correlation at a non-zero shift has a standard deviation of 1 / sqrt(N).
+    let bound = 6. / (polynomial_size as f64).sqrt();
+    for shift in 1..polynomial_size {
+        let correlation = poly.auto_correlation(shift);
+        assert!(
+            correlation.abs() < bound,
+            "auto-correlation {} at shift {} is too large",
+            correlation,
+            shift
+        );
+    }
+}
+
+#[test]
+pub fn test_auto_correlation_u32() {
+    test_auto_correlation::<u32>()
+}
+
+#[test]
+pub fn test_auto_correlation_u64() {
+    test_auto_correlation::<u64>()
+}
+
+fn test_cyclic_mul<T: UnsignedTorus>() {
+    //! tests the cyclic product against a reference convolution
+    let mut rng = rand::thread_rng();
+    let polynomial_size = (rng.gen::<usize>() % 256) + 1;
+    let lhs = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size).into_container(),
+    );
+    let rhs = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size).into_container(),
+    );
+
+    // the reference convolution, with the indices taken modulo the size
+    let lhs_coefs = lhs.as_tensor().as_slice();
+    let rhs_coefs = rhs.as_tensor().as_slice();
+    let expected: Vec<T> = (0..polynomial_size)
+        .map(|k| {
+            (0..polynomial_size).fold(T::ZERO, |acc, i| {
+                let j = (k + polynomial_size - i) % polynomial_size;
+                acc.wrapping_add(lhs_coefs[i].wrapping_mul(rhs_coefs[j]))
+            })
+        })
+        .collect();
+
+    let mut res = Polynomial::allocate(T::ZERO, PolynomialSize(polynomial_size));
+    res.fill_with_wrapping_mul_with_reduction(&lhs, &rhs, ReductionMode::Cyclic);
+    assert_eq!(res.as_tensor().as_slice(), expected.as_slice());
+}
+
+#[test]
+pub fn test_cyclic_mul_u32() {
+    test_cyclic_mul::<u32>()
+}
+
+#[test]
+pub fn test_cyclic_mul_u64() {
+    test_cyclic_mul::<u64>()
+}
+
+fn test_negacyclic_ntt<T: UnsignedTorus + CastInto<u64>>() {
+    //! tests that the ntt maps the negacyclic product to the pointwise product
+    let mut rng = rand::thread_rng();
+    let prime = 12289u64;
+    let polynomial_size = 256;
+    let mut random_polynomial = || {
+        let coefs: Vec<u64> = (0..polynomial_size)
+            .map(|_| rng.gen::<u64>() % prime)
+            .collect();
+        let poly: Polynomial<Vec<T>> =
+            Polynomial::from_container(coefs.iter().map(|c| T::cast_from(*c as f64)).collect());
+        (coefs, poly)
+    };
+    let (lhs_coefs, lhs) = random_polynomial();
+    let (rhs_coefs, rhs) = random_polynomial();
+
+    // the reference negacyclic product, computed modulo the prime
+    let mut product_coefs = vec![0u64; polynomial_size];
+    for (i, lhs_i) in lhs_coefs.iter().enumerate() {
+        for (j, rhs_j) in rhs_coefs.iter().enumerate() {
+            let term = lhs_i * rhs_j % prime;
+            let k = (i + j) % polynomial_size;
+            product_coefs[k] = if i + j < polynomial_size {
+                (product_coefs[k] + term) % prime
+            } else {
+                (product_coefs[k] + prime - term) % prime
+            };
+        }
+    }
+    let product: Polynomial<Vec<T>> = Polynomial::from_container(
+        product_coefs
+            .iter()
+            .map(|c| T::cast_from(*c as f64))
+            .collect(),
+    );
+
+    let lhs_ntt = lhs.to_negacyclic_ntt(prime);
+    let rhs_ntt = rhs.to_negacyclic_ntt(prime);
+    let product_ntt = product.to_negacyclic_ntt(prime);
+    for ((l, r), p) in lhs_ntt.iter().zip(rhs_ntt.iter()).zip(product_ntt.iter()) {
+        assert_eq!(l * r % prime, *p);
+    }
+}
+
+#[test]
+pub fn test_negacyclic_ntt_u32() {
+    test_negacyclic_ntt::<u32>()
+}
+
+#[test]
+pub fn test_negacyclic_ntt_u64() {
+    test_negacyclic_ntt::<u64>()
+}
+
+fn test_cyclic_monomial_rotation<T: UnsignedTorus>() {
+    //! tests that the cyclic monomial multiplication and division are pure rotations
+    let mut rng = rand::thread_rng();
+    let polynomial_size = (rng.gen::<usize>() % 2048) + 1;
+    let poly = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size).into_container(),
+    );
+    let coefs = poly.as_tensor().as_slice();
+    let r: usize = rng.gen::<usize>() % (4 * polynomial_size);
+
+    // multiplying by X^r moves the i-th coefficient to the (i+r)-th position
+    let mut mul = poly.clone();
+    mul.update_with_wrapping_monic_monomial_mul_with_reduction(
+        MonomialDegree(r),
+        ReductionMode::Cyclic,
+    );
+    for (i, coef) in coefs.iter().enumerate() {
+        assert_eq!(
+            *mul.get_monomial(MonomialDegree((i + r) % polynomial_size))
+                .get_coefficient(),
+            *coef
+        );
+    }
+
+    // dividing by X^r moves the (i+r)-th coefficient to the i-th position
+    let mut div = poly.clone();
+    div.update_with_wrapping_unit_monomial_div_with_reduction(
+        MonomialDegree(r),
+        ReductionMode::Cyclic,
+    );
+    for (i, coef) in div.coefficient_iter().enumerate() {
+        assert_eq!(*coef, coefs[(i + r) % polynomial_size]);
+    }
+
+    // the rotation by X^N is the identity
+    let mut full = poly.clone();
+    full.update_with_wrapping_monic_monomial_mul_with_reduction(
+        MonomialDegree(polynomial_size),
+        ReductionMode::Cyclic,
+    );
+    assert_eq!(full, poly);
+}
+
+#[test]
+pub fn test_cyclic_monomial_rotation_u32() {
+    test_cyclic_monomial_rotation::<u32>()
+}
+
+#[test]
+pub fn test_cyclic_monomial_rotation_u64() {
+    test_cyclic_monomial_rotation::<u64>()
+}
+
+fn test_count_nonzero<T: UnsignedTorus + RandomGenerable<UniformWithZeros> + Default>() {
+    //! tests the number of non-zero coefficients of a sparse polynomial
+    let mut rng = rand::thread_rng();
+    let polynomial_size = (rng.gen::<usize>() % 2048) + 1;
+    let poly = Polynomial::from_container(
+        random::random_uniform_with_zeros_tensor::<T>(polynomial_size, 0.7).into_container(),
+    );
+    let expected = poly.coefficient_iter().filter(|c| **c != T::ZERO).count();
+    assert_eq!(poly.count_nonzero(), expected);
+    assert_eq!(poly.sparsity(), expected as f64 / polynomial_size as f64);
+
+    let zero = Polynomial::allocate(T::ZERO, PolynomialSize(polynomial_size));
+    assert_eq!(zero.count_nonzero(), 0);
+    assert_eq!(zero.sparsity(), 0.);
+}
+
+#[test]
+pub fn test_count_nonzero_u32() {
+    test_count_nonzero::<u32>()
+}
+
+#[test]
+pub fn test_count_nonzero_u64() {
+    test_count_nonzero::<u64>()
+}
+
+fn test_fold_to_polynomial<T: UnsignedTorus>() {
+    //! tests an inner product computed with a fold against the one computed coefficient-wise
+    let mut rng = rand::thread_rng();
+    let polynomial_size = (rng.gen::<usize>() % 256) + 1;
+    let polynomial_count = (rng.gen::<usize>() % 16) + 1;
+    let list = PolynomialList::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size * polynomial_count).into_container(),
+        PolynomialSize(polynomial_size),
+    );
+    let weights = random::random_uniform_tensor::<T>(polynomial_count).into_container();
+
+    let mut weight_iter = weights.iter();
+    let res = list.fold_to_polynomial(
+        Polynomial::allocate(T::ZERO, PolynomialSize(polynomial_size)),
+        |acc, poly| {
+            let weight = *weight_iter.next().unwrap();
+            for (a, p) in acc.coefficient_iter_mut().zip(poly.coefficient_iter()) {
+                *a = a.wrapping_add(p.wrapping_mul(weight));
+            }
+        },
+    );
+    assert!(weight_iter.next().is_none());
+
+    let coefs = list.as_tensor().as_slice();
+    let expected: Vec<T> = (0..polynomial_size)
+        .map(|k| {
+            (0..polynomial_count).fold(T::ZERO, |acc, i| {
+                acc.wrapping_add(coefs[i * polynomial_size + k].wrapping_mul(weights[i]))
+            })
+        })
+        .collect();
+    assert_eq!(res.as_tensor().as_slice(), expected.as_slice());
+}
+
+#[test]
+pub fn test_fold_to_polynomial_u32() {
+    test_fold_to_polynomial::<u32>()
+}
+
+#[test]
+pub fn test_fold_to_polynomial_u64() {
+    test_fold_to_polynomial::<u64>()
+}
+
+fn test_sum_of_coefficients<T: UnsignedTorus>() {
+    //! tests that the sum of the coefficients is multiplicative for the cyclic product, as an
+    //! evaluation at 1
+    let mut rng = rand::thread_rng();
+    let polynomial_size = (rng.gen::<usize>() % 256) + 1;
+    let lhs = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size).into_container(),
+    );
+    let rhs = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size).into_container(),
+    );
+    let mut res = Polynomial::allocate(T::ZERO, PolynomialSize(polynomial_size));
+    res.fill_with_wrapping_mul_with_reduction(&lhs, &rhs, ReductionMode::Cyclic);
+    assert_eq!(
+        res.sum_of_coefficients(),
+        lhs.sum_of_coefficients()
+            .wrapping_mul(rhs.sum_of_coefficients())
+    );
+}
+
+#[test]
+pub fn test_sum_of_coefficients_u32() {
+    test_sum_of_coefficients::<u32>()
+}
+
+#[test]
+pub fn test_sum_of_coefficients_u64() {
+    test_sum_of_coefficients::<u64>()
+}
+
+fn test_fast_mul<T: UnsignedTorus>() {
+    //! tests that the fast product matches the schoolbook product, on coefficients small enough
+    //! for the fourier product to be exact
+    let mut fft = Fft::new(PolynomialSize(1024));
+    let mut view = FftView::new(&mut fft);
+    for &size in [256, 1024, 100].iter() {
+        let mut lhs = Polynomial::allocate(T::ZERO, PolynomialSize(size));
+        random::fill_with_random_uniform(&mut lhs);
+        lhs.as_mut_tensor()
+            .update_with(|x| *x = (*x >> (T::BITS - 8)).wrapping_sub(T::ONE << 7));
+        let mut rhs = Polynomial::allocate(T::ZERO, PolynomialSize(size));
+        random::fill_with_random_uniform(&mut rhs);
+        rhs.as_mut_tensor()
+            .update_with(|x| *x = (*x >> (T::BITS - 12)).wrapping_sub(T::ONE << 11));
+        let mut expected = Polynomial::allocate(T::ZERO, PolynomialSize(size));
+        expected.fill_with_wrapping_mul(&lhs, &rhs);
+
+        let mut res = Polynomial::allocate(T::ONE, PolynomialSize(size));
+        res.fill_with_fast_mul(&lhs, &rhs, None);
+        assert_eq!(res, expected);
+        if size == 1024 {
+            res.fill_with_fast_mul(&lhs, &rhs, Some(&mut view));
+            assert_eq!(res, expected);
+        }
+    }
+}
+
+#[test]
+pub fn test_fast_mul_u32() {
+    test_fast_mul::<u32>()
+}
+
+#[test]
+pub fn test_fast_mul_u64() {
+    test_fast_mul::<u64>()
+}
+
+fn test_wrapping_add_sub_mul<T: UnsignedTorus>() {
+    //! tests the accumulated products against a product computed in a temporary polynomial
+    let mut rng = rand::thread_rng();
+    let polynomial_size = PolynomialSize((rng.gen::<usize>() % 256) + 1);
+    let random_polynomial = || {
+        Polynomial::from_container(
+            random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+        )
+    };
+    let (lhs, rhs, acc) = (
+        random_polynomial(),
+        random_polynomial(),
+        random_polynomial(),
+    );
+    let mut product = Polynomial::allocate(T::ZERO, polynomial_size);
+    product.fill_with_wrapping_mul(&lhs, &rhs);
+
+    let mut expected = acc.clone();
+    expected.update_with_wrapping_add(&product);
+    let mut res = acc.clone();
+    res.update_with_wrapping_add_mul(&lhs, &rhs);
+    assert_eq!(res, expected);
+
+    let mut expected = acc.clone();
+    expected.update_with_wrapping_sub(&product);
+    let mut res = acc;
+    res.update_with_wrapping_sub_mul(&lhs, &rhs);
+    assert_eq!(res, expected);
+}
+
+#[test]
+pub fn test_wrapping_add_sub_mul_u32() {
+    test_wrapping_add_sub_mul::<u32>()
+}
+
+#[test]
+pub fn test_wrapping_add_sub_mul_u64() {
+    test_wrapping_add_sub_mul::<u64>()
+}
+
+fn test_ternary_mul<T: UnsignedTorus>() {
+    //! tests the ternary products against the product with the ternary values lifted into the
+    //! torus
+    let mut rng = rand::thread_rng();
+    let polynomial_size = PolynomialSize((rng.gen::<usize>() % 256) + 1);
+    let count = PolynomialCount(3);
+    let coef_list = PolynomialList::from_container(
+        random::random_uniform_tensor::<T>(count.0 * polynomial_size.0).into_container(),
+        polynomial_size,
+    );
+    let ternary_values: Vec<i8> = (0..count.0 * polynomial_size.0)
+        .map(|_| (rng.gen::<u8>() % 3) as i8 - 1)
+        .collect();
+    let lifted_values: Vec<T> = ternary_values
+        .iter()
+        .map(|t| match t {
+            1 => T::ONE,
+            -1 => T::ONE.wrapping_neg(),
+            _ => T::ZERO,
+        })
+        .collect();
+    let ternary_list = PolynomialList::from_container(ternary_values, polynomial_size);
+    let lifted_list = PolynomialList::from_container(lifted_values, polynomial_size);
+
+    // the reference multisum, computed with the schoolbook product
+    let acc = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+    );
+    let mut sum = Polynomial::allocate(T::ZERO, polynomial_size);
+    let mut product = Polynomial::allocate(T::ZERO, polynomial_size);
+    for (poly, lifted) in coef_list
+        .polynomial_iter()
+        .zip(lifted_list.polynomial_iter())
+    {
+        product.fill_with_wrapping_mul(&poly, &lifted);
+        sum.update_with_wrapping_add(&product);
+    }
+
+    let mut expected = acc.clone();
+    expected.update_with_wrapping_add(&sum);
+    let mut res = acc.clone();
+    res.update_with_wrapping_add_ternary_multisum(&coef_list, &ternary_list);
+    assert_eq!(res, expected);
+
+    let mut expected = acc.clone();
+    expected.update_with_wrapping_sub(&sum);
+    let mut res = acc;
+    res.update_with_wrapping_sub_ternary_multisum(&coef_list, &ternary_list);
+    assert_eq!(res, expected);
+}
+
+#[test]
+pub fn test_ternary_mul_u32() {
+    test_ternary_mul::<u32>()
+}
+
+#[test]
+pub fn test_ternary_mul_u64() {
+    test_ternary_mul::<u64>()
+}
+
+fn test_packed_binary_multisum<T: UnsignedTorus>() {
+    //! tests that the bit-packed binary multisums match the boolean ones
+    let mut rng = rand::thread_rng();
+    let polynomial_size = PolynomialSize((rng.gen::<usize>() % 256) + 1);
+    let count = PolynomialCount(3);
+    let coef_list = PolynomialList::from_container(
+        random::random_uniform_tensor::<T>(count.0 * polynomial_size.0).into_container(),
+        polynomial_size,
+    );
+    let bin_list = PolynomialList::from_container(
+        (0..count.0 * polynomial_size.0)
+            .map(|_| rng.gen::<bool>())
+            .collect::<Vec<_>>(),
+        polynomial_size,
+    );
+    let packed_list = BinaryPolynomialList::from_boolean_list(&bin_list);
+    let acc = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+    );
+
+    let mut expected = acc.clone();
+    expected.update_with_wrapping_add_binary_multisum(&coef_list, &bin_list);
+    let mut res = acc.clone();
+    res.update_with_wrapping_add_packed_binary_multisum(&coef_list, &packed_list);
+    assert_eq!(res, expected);
+
+    let mut expected = acc.clone();
+    expected.update_with_wrapping_sub_binary_multisum(&coef_list, &bin_list);
+    let mut res = acc;
+    res.update_with_wrapping_sub_packed_binary_multisum(&coef_list, &packed_list);
+    assert_eq!(res, expected);
+}
+
+#[test]
+pub fn test_packed_binary_multisum_u32() {
+    test_packed_binary_multisum::<u32>()
+}
+
+#[test]
+pub fn test_packed_binary_multisum_u64() {
+    test_packed_binary_multisum::<u64>()
+}
+
+#[test]
+pub fn test_binary_polynomial_packing() {
+    //! tests that packing a boolean polynomial keeps its coefficients
+    let mut rng = rand::thread_rng();
+    let polynomial_size = PolynomialSize((rng.gen::<usize>() % 256) + 1);
+    let poly = Polynomial::from_container(
+        (0..polynomial_size.0)
+            .map(|_| rng.gen::<bool>())
+            .collect::<Vec<_>>(),
+    );
+    let packed = BinaryPolynomial::from_boolean_polynomial(&poly);
+    assert_eq!(packed.polynomial_size(), polynomial_size);
+    for (degree, coef) in poly.coefficient_iter().enumerate() {
+        assert_eq!(packed.get_coefficient(MonomialDegree(degree)), *coef);
+    }
+    let degrees: Vec<usize> = packed.set_degree_iter().map(|d| d.0).collect();
+    let expected: Vec<usize> = poly
+        .coefficient_iter()
+        .enumerate()
+        .filter(|(_, coef)| **coef)
+        .map(|(degree, _)| degree)
+        .collect();
+    assert_eq!(degrees, expected);
+}
+
+// The schoolbook binary product, which visits every pair of coefficients.
+fn schoolbook_binary_mul<T: UnsignedTorus>(
+    output: &mut Polynomial<Vec<T>>,
+    poly: &Polynomial<Vec<T>>,
+    bin_poly: &Polynomial<Vec<bool>>,
+    negate: bool,
+) {
+    let degree = poly.polynomial_size().0 - 1;
+    for lhsi in poly.monomial_iter() {
+        for rhsi in bin_poly.monomial_iter() {
+            if !*rhsi.get_coefficient() {
+                continue;
+            }
+            let target_degree = lhsi.degree().0 + rhsi.degree().0;
+            let element = output
+                .as_mut_tensor()
+                .get_element_mut(target_degree % (degree + 1));
+            if (target_degree > degree) != negate {
+                *element = element.wrapping_sub(*lhsi.get_coefficient());
+            } else {
+                *element = element.wrapping_add(*lhsi.get_coefficient());
+            }
+        }
+    }
+}
+
+fn test_binary_mul_rotations<T: UnsignedTorus>() {
+    //! tests the binary products against the schoolbook product
+    let mut rng = rand::thread_rng();
+    let polynomial_size = PolynomialSize((rng.gen::<usize>() % 512) + 1);
+    let poly = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+    );
+    let bin_poly = Polynomial::from_container(
+        (0..polynomial_size.0)
+            .map(|_| rng.gen::<bool>())
+            .collect::<Vec<_>>(),
+    );
+    let acc = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+    );
+
+    let mut expected = acc.clone();
+    schoolbook_binary_mul(&mut expected, &poly, &bin_poly, false);
+    let mut res = acc.clone();
+    res.update_with_wrapping_add_binary_mul(&poly, &bin_poly);
+    assert_eq!(res, expected);
+
+    let mut expected = acc.clone();
+    schoolbook_binary_mul(&mut expected, &poly, &bin_poly, true);
+    let mut res = acc;
+    res.update_with_wrapping_sub_binary_mul(&poly, &bin_poly);
+    assert_eq!(res, expected);
+}
+
+#[test]
+pub fn test_binary_mul_rotations_u32() {
+    test_binary_mul_rotations::<u32>()
+}
+
+#[test]
+pub fn test_binary_mul_rotations_u64() {
+    test_binary_mul_rotations::<u64>()
+}
+
+#[test]
+pub fn test_binary_mul_max_coefficients_u8() {
+    //! tests that the binary products do not overflow on maximal coefficients, which panics
+    //! when the overflow checks of the debug builds are enabled
+    let polynomial_size = PolynomialSize(16);
+    let poly = Polynomial::allocate(u8::MAX, polynomial_size);
+    let bin_poly = Polynomial::allocate(true, polynomial_size);
+    let lifted_poly = Polynomial::allocate(1u8, polynomial_size);
+    let mut product = Polynomial::allocate(0u8, polynomial_size);
+    product.fill_with_wrapping_mul(&poly, &lifted_poly);
+
+    let mut res = Polynomial::allocate(u8::MAX, polynomial_size);
+    res.update_with_wrapping_add_binary_mul(&poly, &bin_poly);
+    res.update_with_wrapping_sub_binary_mul(&poly, &bin_poly);
+    assert_eq!(res, Polynomial::allocate(u8::MAX, polynomial_size));
+
+    let mut res = Polynomial::allocate(0u8, polynomial_size);
+    res.update_with_wrapping_add_binary_mul(&poly, &bin_poly);
+    assert_eq!(res, product);
+    let mut res = Polynomial::allocate(0u8, polynomial_size);
+    res.ct_update_with_wrapping_add_binary_mul(&poly, &bin_poly);
+    assert_eq!(res, product);
+    let mut res = Polynomial::allocate(0u8, polynomial_size);
+    res.fill_with_wrapping_binary_mul(&poly, &bin_poly);
+    assert_eq!(res, product);
+}
+
+fn test_galois_automorphism<T: UnsignedTorus>() {
+    //! tests that the galois automorphisms are invertible and commute with the product
+    let mut rng = rand::thread_rng();
+    let polynomial_size = PolynomialSize(1 << (rng.gen::<usize>() % 10));
+    let double_size = 2 * polynomial_size.0;
+    let k = (rng.gen::<usize>() % polynomial_size.0) * 2 + 1;
+    let k_inverse = (1..double_size)
+        .step_by(2)
+        .find(|inv| (k * inv) % double_size == 1)
+        .unwrap();
+    let lhs = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+    );
+    let rhs = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+    );
+
+    // applying the inverse automorphism returns the original polynomial
+    let mut image = Polynomial::allocate(T::ZERO, polynomial_size);
+    image.fill_with_galois_automorphism(&lhs, k);
+    image.update_with_galois_automorphism(k_inverse);
+    assert_eq!(image, lhs);
+
+    // the automorphism of a product is the product of the automorphisms
+    let mut product = Polynomial::allocate(T::ZERO, polynomial_size);
+    product.fill_with_wrapping_mul(&lhs, &rhs);
+    product.update_with_galois_automorphism(k);
+    let mut lhs_image = Polynomial::allocate(T::ZERO, polynomial_size);
+    lhs_image.fill_with_galois_automorphism(&lhs, k);
+    let mut rhs_image = Polynomial::allocate(T::ZERO, polynomial_size);
+    rhs_image.fill_with_galois_automorphism(&rhs, k);
+    let mut image_product = Polynomial::allocate(T::ZERO, polynomial_size);
+    image_product.fill_with_wrapping_mul(&lhs_image, &rhs_image);
+    assert_eq!(product, image_product);
+}
+
+#[test]
+pub fn test_galois_automorphism_u32() {
+    test_galois_automorphism::<u32>()
+}
+
+#[test]
+pub fn test_galois_automorphism_u64() {
+    test_galois_automorphism::<u64>()
+}
+
+#[test]
+#[should_panic]
+pub fn test_galois_automorphism_even_exponent() {
+    let mut poly = Polynomial::from_container(vec![1u32, 2, 3, 4]);
+    poly.update_with_galois_automorphism(2);
+}
+
+fn test_wrapping_reversal<T: UnsignedTorus>() {
+    //! tests that the product by a reversal computes the dot product in its constant coefficient
+    let mut rng = rand::thread_rng();
+    let polynomial_size = PolynomialSize((rng.gen::<usize>() % 512) + 1);
+    let lhs = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+    );
+    let rhs = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+    );
+    let dot_product = lhs
+        .coefficient_iter()
+        .zip(rhs.coefficient_iter())
+        .fold(T::ZERO, |acc, (l, r)| acc.wrapping_add(l.wrapping_mul(*r)));
+
+    let mut reversal = Polynomial::allocate(T::ZERO, polynomial_size);
+    reversal.fill_with_wrapping_reversal(&lhs);
+    let mut product = Polynomial::allocate(T::ZERO, polynomial_size);
+    product.fill_with_wrapping_mul(&reversal, &rhs);
+    assert_eq!(
+        *product.get_monomial(MonomialDegree(0)).get_coefficient(),
+        dot_product
+    );
+
+    // the in-place variant gives the same result, and the reversal is an involution
+    let mut in_place = lhs.clone();
+    in_place.update_with_wrapping_reversal();
+    assert_eq!(in_place, reversal);
+    in_place.update_with_wrapping_reversal();
+    assert_eq!(in_place, lhs);
+}
+
+#[test]
+pub fn test_wrapping_reversal_u32() {
+    test_wrapping_reversal::<u32>()
+}
+
+#[test]
+pub fn test_wrapping_reversal_u64() {
+    test_wrapping_reversal::<u64>()
+}
+
+#[test]
+pub fn test_coefficient_statistics_u8() {
+    //! tests the coefficient statistics against hand-computed values
+    let list =
+        PolynomialList::from_container(vec![0u8, 1, 255, 127, 0, 0, 129, 200], PolynomialSize(4));
+    let first = list.get_polynomial(0);
+    let second = list.get_polynomial(1);
+
+    // 255 is at distance 1 from zero, and 129 at distance 127
+    assert_eq!(first.max_torus_distance_from_zero(), 127);
+    assert_eq!(second.max_torus_distance_from_zero(), 127);
+    assert_eq!(list.max_torus_distance_from_zero(), 127);
+    let poly = Polynomial::from_container(vec![0u8, 128, 1]);
+    assert_eq!(poly.max_torus_distance_from_zero(), 128);
+
+    // 1 + 255 + 127 = 383 = 127 mod 256, and 129 + 200 = 329 = 73 mod 256
+    assert_eq!(first.sum_of_coefficients(), 127);
+    assert_eq!(second.sum_of_coefficients(), 73);
+    assert_eq!(list.sum_of_coefficients(), 200);
+
+    assert_eq!(first.count_nonzero(), 3);
+    assert_eq!(second.count_nonzero(), 2);
+    assert_eq!(list.count_nonzero(), 5);
+}
+
+#[test]
+pub fn test_cast_rescale_round_trip() {
+    //! tests that widening then narrowing the coefficients is exact
+    let mut rng = rand::thread_rng();
+    let polynomial_size = PolynomialSize((rng.gen::<usize>() % 512) + 1);
+    let poly = Polynomial::from_container(
+        random::random_uniform_tensor::<u32>(polynomial_size.0).into_container(),
+    );
+    let mut wide = Polynomial::allocate(0u64, polynomial_size);
+    wide.fill_with_cast_rescale(&poly);
+    let mut narrow = Polynomial::allocate(0u32, polynomial_size);
+    narrow.fill_with_cast_rescale(&wide);
+    assert_eq!(narrow, poly);
+}
+
+#[test]
+pub fn test_cast_rescale_rounding() {
+    //! tests that narrowing the coefficients rounds to the nearest value, halfway up
+    let half = 1u64 << 31;
+    let poly = Polynomial::from_container(vec![
+        (5 << 32) + half - 1,
+        (5 << 32) + half,
+        (5 << 32) + half + 1,
+        u64::MAX - half + 1,
+        u64::MAX - half,
+    ]);
+    let mut narrow = Polynomial::allocate(0u32, poly.polynomial_size());
+    narrow.fill_with_cast_rescale(&poly);
+    assert_eq!(
+        narrow,
+        Polynomial::from_container(vec![5, 6, 6, 0, u32::MAX])
+    );
+}
+
+#[cfg(feature = "multithread")]
+fn test_par_polynomial_iter<T: UnsignedTorus + Send + Sync>() {
+    //! tests that the parallel iterators visit the same polynomials as the sequential ones
+    use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+    let mut rng = rand::thread_rng();
+    let polynomial_size = PolynomialSize((rng.gen::<usize>() % 256) + 1);
+    let count = PolynomialCount((rng.gen::<usize>() % 64) + 1);
+    let source = PolynomialList::from_container(
+        random::random_uniform_tensor::<T>(count.0 * polynomial_size.0).into_container(),
+        polynomial_size,
+    );
+
+    let mut sequential = PolynomialList::allocate(T::ZERO, count, polynomial_size);
+    for (mut poly, source_poly) in sequential
+        .polynomial_iter_mut()
+        .zip(source.polynomial_iter())
+    {
+        poly.as_mut_tensor().copy_from(source_poly.as_tensor());
+    }
+    let mut parallel = PolynomialList::allocate(T::ZERO, count, polynomial_size);
+    parallel
+        .par_polynomial_iter_mut()
+        .zip(source.par_polynomial_iter())
+        .for_each(|(mut poly, source_poly)| {
+            assert_eq!(poly.polynomial_size(), polynomial_size);
+            poly.as_mut_tensor().copy_from(source_poly.as_tensor());
+        });
+    assert_eq!(parallel.as_tensor(), sequential.as_tensor());
+
+    parallel.par_update_with_wrapping_monic_monomial_mul(MonomialDegree(3));
+    sequential.update_with_wrapping_monic_monomial_mul(MonomialDegree(3));
+    assert_eq!(parallel.as_tensor(), sequential.as_tensor());
+}
+
+#[cfg(feature = "multithread")]
+#[test]
+pub fn test_par_polynomial_iter_u32() {
+    test_par_polynomial_iter::<u32>()
+}
+
+#[cfg(feature = "multithread")]
+#[test]
+pub fn test_par_polynomial_iter_u64() {
+    test_par_polynomial_iter::<u64>()
+}
+
+#[test]
+pub fn test_polynomial_list_growth() {
+    //! tests that the pushed polynomials are iterated in insertion order
+    let polynomials: Vec<Polynomial<Vec<u32>>> = (0..5u32)
+        .map(|i| Polynomial::from_container(vec![i, 2 * i, 3 * i]))
+        .collect();
+    let mut pushed = PolynomialList::allocate(0u32, PolynomialCount(0), PolynomialSize(3));
+    for poly in polynomials.iter() {
+        pushed.push_polynomial(poly);
+    }
+    let mut extended = PolynomialList::allocate(0u32, PolynomialCount(0), PolynomialSize(3));
+    extended.extend_from_list(&pushed);
+    let collected: PolynomialList<Vec<u32>> = polynomials.iter().cloned().collect();
+
+    for list in [pushed, extended, collected].iter() {
+        assert_eq!(list.polynomial_count(), PolynomialCount(5));
+        for (poly, expected) in list.polynomial_iter().zip(polynomials.iter()) {
+            assert_eq!(poly.as_tensor().as_slice(), expected.as_tensor().as_slice());
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+pub fn test_polynomial_list_push_mismatched_size() {
+    let mut list = PolynomialList::allocate(0u32, PolynomialCount(1), PolynomialSize(3));
+    list.push_polynomial(&Polynomial::from_container(vec![1u32, 2]));
+}
+
+#[test]
+#[should_panic]
+pub fn test_polynomial_list_extend_mismatched_size() {
+    let mut list = PolynomialList::allocate(0u32, PolynomialCount(1), PolynomialSize(3));
+    list.extend_from_list(&PolynomialList::from_container(
+        vec![1u32, 2],
+        PolynomialSize(2),
+    ));
+}
+
+#[test]
+#[should_panic]
+pub fn test_polynomial_list_collect_mismatched_size() {
+    let polynomials = [
+        Polynomial::from_container(vec![1u32, 2, 3]),
+        Polynomial::from_container(vec![1u32, 2]),
+    ];
+    let _: PolynomialList<Vec<u32>> = polynomials.iter().cloned().collect();
+}
+
+#[test]
+pub fn test_checked_accessors_boundaries() {
+    //! tests that the last index is accepted, and the next one rejected with its details
+    let mut poly = Polynomial::from_container(vec![1u32, 2, 3, 4]);
+    assert_eq!(
+        *poly
+            .try_get_monomial(MonomialDegree(3))
+            .unwrap()
+            .get_coefficient(),
+        4
+    );
+    assert!(poly.try_get_mut_monomial(MonomialDegree(3)).is_ok());
+    let expected = DimensionError {
+        parameter: "monomial degree",
+        index: 4,
+        size: 4,
+    };
+    assert_eq!(
+        poly.try_get_monomial(MonomialDegree(4)).err(),
+        Some(expected.clone())
+    );
+    assert_eq!(
+        poly.try_get_mut_monomial(MonomialDegree(4)).err(),
+        Some(expected)
+    );
+
+    let mut list = PolynomialList::from_container(vec![1u32, 2, 3, 4, 5, 6], PolynomialSize(2));
+    assert_eq!(
+        list.try_get_polynomial(2).unwrap().as_tensor().as_slice(),
+        &[5, 6]
+    );
+    assert!(list.try_get_mut_polynomial(2).is_ok());
+    let expected = DimensionError {
+        parameter: "polynomial index",
+        index: 3,
+        size: 3,
+    };
+    assert_eq!(list.try_get_polynomial(3).unwrap_err(), expected);
+    assert_eq!(list.try_get_mut_polynomial(3).unwrap_err(), expected);
+}
+
+#[test]
+#[should_panic(expected = "The monomial degree 4 is out of range, the size is 4")]
+pub fn test_get_monomial_out_of_range() {
+    let poly = Polynomial::from_container(vec![1u32, 2, 3, 4]);
+    poly.get_monomial(MonomialDegree(4));
+}
+
+#[test]
+#[should_panic(expected = "The polynomial index 3 is out of range, the size is 3")]
+pub fn test_get_polynomial_out_of_range() {
+    let list = PolynomialList::from_container(vec![1u32, 2, 3, 4, 5, 6], PolynomialSize(2));
+    list.get_polynomial(3);
+}
+
+fn test_sparse_mul<T: UnsignedTorus + Default>() {
+    //! tests the sparse product against the dense one, with terms wrapping past X^N
+    let mut rng = rand::thread_rng();
+    let polynomial_size = PolynomialSize((rng.gen::<usize>() % 1024) + 2);
+    let dense = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+    );
+    let mut degrees = vec![
+        0,
+        polynomial_size.0 - 1,
+        polynomial_size.0 / 2,
+        rng.gen::<usize>() % polynomial_size.0,
+    ];
+    degrees.sort_unstable();
+    degrees.dedup();
+    let coefficients = random::random_uniform_tensor::<T>(degrees.len());
+    let terms = degrees
+        .iter()
+        .zip(coefficients.iter())
+        .map(|(degree, coef)| (MonomialDegree(*degree), *coef))
+        .collect();
+    let sparse = SparsePolynomial::from_terms(terms, polynomial_size);
+    let acc = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+    );
+
+    let mut expected = acc.clone();
+    expected.update_with_wrapping_add_mul(&dense, &sparse.to_polynomial());
+    let mut res = acc;
+    res.update_with_wrapping_add_sparse_mul(&dense, &sparse);
+    assert_eq!(res, expected);
+    assert_eq!(
+        SparsePolynomial::from_polynomial(&sparse.to_polynomial()),
+        SparsePolynomial::from_terms(
+            sparse
+                .terms()
+                .iter()
+                .filter(|(_, coef)| *coef != T::ZERO)
+                .cloned()
+                .collect(),
+            polynomial_size
+        )
+    );
+}
+
+#[test]
+pub fn test_sparse_mul_u32() {
+    test_sparse_mul::<u32>()
+}
+
+#[test]
+pub fn test_sparse_mul_u64() {
+    test_sparse_mul::<u64>()
+}
+
+#[test]
+#[should_panic(expected = "The monomial degree 2 appears more than once.")]
+pub fn test_sparse_polynomial_duplicate_degree() {
+    SparsePolynomial::from_terms(
+        vec![(MonomialDegree(2), 1u8), (MonomialDegree(2), 3)],
+        PolynomialSize(4),
+    );
+}
+
+fn test_resize<T: UnsignedTorus>() {
+    //! tests that resizing keeps the coefficients at their degrees
+    let mut rng = rand::thread_rng();
+    let small_size = PolynomialSize((rng.gen::<usize>() % 512) + 1);
+    let large_size = PolynomialSize(small_size.0 * 2);
+    let poly = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(small_size.0).into_container(),
+    );
+
+    let mut resized = poly.clone();
+    resized.resize(large_size, T::ZERO);
+    assert_eq!(resized.polynomial_size(), large_size);
+    let mut copied = Polynomial::allocate(T::ONE, large_size);
+    copied.fill_with_resized_copy(&poly);
+    assert_eq!(copied, resized);
+    for monomial in resized.monomial_iter() {
+        let degree = monomial.degree();
+        if degree.0 < small_size.0 {
+            assert_eq!(
+                *monomial.get_coefficient(),
+                *poly.get_monomial(degree).get_coefficient()
+            );
+        } else {
+            assert_eq!(*monomial.get_coefficient(), T::ZERO);
+        }
+    }
+    resized.truncate(small_size);
+    assert_eq!(resized, poly);
+
+    let list = PolynomialList::from_container(
+        random::random_uniform_tensor::<T>(small_size.0 * 3).into_container(),
+        small_size,
+    );
+    let mut resized_list =
+        PolynomialList::from_container(list.as_tensor().as_slice().to_vec(), small_size);
+    resized_list.resize(large_size, T::ZERO);
+    assert_eq!(resized_list.polynomial_size(), large_size);
+    assert_eq!(resized_list.polynomial_count(), PolynomialCount(3));
+    let mut copied_list = PolynomialList::allocate(T::ONE, PolynomialCount(3), large_size);
+    copied_list.fill_with_resized_copy(&list);
+    assert_eq!(copied_list.as_tensor(), resized_list.as_tensor());
+    for (resized_poly, poly) in resized_list.polynomial_iter().zip(list.polynomial_iter()) {
+        let mut expected = Polynomial::allocate(T::ZERO, large_size);
+        expected.fill_with_resized_copy(&poly);
+        assert_eq!(
+            resized_poly.as_tensor().as_slice(),
+            expected.as_tensor().as_slice()
+        );
+    }
+    resized_list.truncate(small_size);
+    assert_eq!(resized_list.as_tensor(), list.as_tensor());
+}
+
+#[test]
+pub fn test_resize_u32() {
+    test_resize::<u32>()
+}
+
+#[test]
+pub fn test_resize_u64() {
+    test_resize::<u64>()
+}
+
+#[test]
+#[should_panic(expected = "Tried to resize a polynomial of size 4 to the smaller size 2")]
+pub fn test_resize_refuses_to_shrink() {
+    let mut poly = Polynomial::allocate(0u8, PolynomialSize(4));
+    poly.resize(PolynomialSize(2), 0);
+}
+
+#[test]
+#[should_panic(expected = "Tried to copy a polynomial of size 4 in a smaller polynomial of size 2")]
+pub fn test_resized_copy_refuses_to_truncate() {
+    let source = Polynomial::allocate(0u8, PolynomialSize(4));
+    let mut poly = Polynomial::allocate(0u8, PolynomialSize(2));
+    poly.fill_with_resized_copy(&source);
+}
+
+#[test]
+pub fn test_even_odd_split_u64() {
+    //! tests that the split and the merge of the even and odd parts cancel each other, and that
+    //! a multiplication by X^2 is a multiplication of both parts by Y = X^2
+    let mut rng = rand::thread_rng();
+    let half_size = PolynomialSize((rng.gen::<usize>() % 1024) + 1);
+    let polynomial_size = PolynomialSize(half_size.0 * 2);
+    let poly = Polynomial::from_container(
+        random::random_uniform_tensor::<u64>(polynomial_size.0).into_container(),
+    );
+
+    let mut even = Polynomial::allocate(0u64, half_size);
+    let mut odd = Polynomial::allocate(0u64, half_size);
+    poly.fill_even_odd_split(&mut even, &mut odd);
+    let mut merged = Polynomial::allocate(0u64, polynomial_size);
+    merged.fill_from_even_odd(&even, &odd);
+    assert_eq!(merged, poly);
+
+    let mut shifted = poly;
+    shifted.update_with_wrapping_monic_monomial_mul(MonomialDegree(2));
+    let mut shifted_even = Polynomial::allocate(0u64, half_size);
+    let mut shifted_odd = Polynomial::allocate(0u64, half_size);
+    shifted.fill_even_odd_split(&mut shifted_even, &mut shifted_odd);
+    even.update_with_wrapping_monic_monomial_mul(MonomialDegree(1));
+    odd.update_with_wrapping_monic_monomial_mul(MonomialDegree(1));
+    assert_eq!(shifted_even, even);
+    assert_eq!(shifted_odd, odd);
+}
+
+#[test]
+#[should_panic(expected = "Tried to split a polynomial of odd size 3.")]
+pub fn test_even_odd_split_odd_size() {
+    let poly = Polynomial::allocate(0u64, PolynomialSize(3));
+    let mut even = Polynomial::allocate(0u64, PolynomialSize(1));
+    let mut odd = Polynomial::allocate(0u64, PolynomialSize(1));
+    poly.fill_even_odd_split(&mut even, &mut odd);
+}
+
+fn test_widening_mul<T: UnsignedInteger + RandomGenerable<Uniform> + Debug>() {
+    //! tests that the widening product is the same as the schoolbook one
+    let mut rng = rand::thread_rng();
+    let polynomial_size = PolynomialSize((rng.gen::<usize>() % 256) + 1);
+    let lhs = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+    );
+    let rhs = Polynomial::from_container(
+        random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+    );
+    for reduction in [ReductionMode::Negacyclic, ReductionMode::Cyclic].iter() {
+        let mut expected = Polynomial::allocate(T::ZERO, polynomial_size);
+        expected.fill_with_schoolbook_mul_with_reduction(&lhs, &rhs, *reduction);
+        let mut res = Polynomial::allocate(T::ONE, polynomial_size);
+        res.fill_with_widening_mul_with_reduction(&lhs, &rhs, *reduction);
+        assert_eq!(res, expected);
+    }
+}
+
+#[test]
+pub fn test_widening_mul_u8() {
+    test_widening_mul::<u8>()
+}
+
+#[test]
+pub fn test_widening_mul_u16() {
+    test_widening_mul::<u16>()
+}
+
+#[test]
+pub fn test_widening_mul_u32() {
+    test_widening_mul::<u32>()
+}
+
+#[test]
+pub fn test_widening_mul_u64() {
+    test_widening_mul::<u64>()
+}
+
+#[test]
+pub fn test_widening_mul_u128() {
+    test_widening_mul::<u128>()
+}
+
+fn test_toom3_mul_sizes<T>(sizes: impl Iterator<Item = usize>)
+where
+    T: UnsignedInteger + RandomGenerable<Uniform> + CastInto<u128> + CastFrom<u128> + Debug,
+{
+    for size in sizes {
+        let polynomial_size = PolynomialSize(size);
+        let lhs = Polynomial::from_container(
+            random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+        );
+        let rhs = Polynomial::from_container(
+            random::random_uniform_tensor::<T>(polynomial_size.0).into_container(),
+        );
+        let mut expected = Polynomial::allocate(T::ZERO, polynomial_size);
+        expected.fill_with_wrapping_mul(&lhs, &rhs);
+        let mut res = Polynomial::allocate(T::ONE, polynomial_size);
+        res.fill_with_toom3_mul(&lhs, &rhs);
+        assert_eq!(res, expected, "size {}", size);
+    }
+}
+
+#[test]
+pub fn test_toom3_mul_small_sizes() {
+    //! tests every size up to several levels of recursion
+    test_toom3_mul_sizes::<u8>(1..=150);
+    test_toom3_mul_sizes::<u16>(1..=150);
+    test_toom3_mul_sizes::<u32>(1..=150);
+    test_toom3_mul_sizes::<u64>(1..=150);
+}
+
+#[test]
+pub fn test_toom3_mul_exhaustive_u8() {
+    //! tests all the products of polynomials above the schoolbook threshold, whose three parts
+    //! are each filled with a coefficient in {0, 1, 127, 255}
+    let values = [0u8, 1, 127, 255];
+    let mut parts = Vec::new();
+    for a in values.iter() {
+        for b in values.iter() {
+            for c in values.iter() {
+                parts.push([*a, *b, *c]);
+            }
+        }
+    }
+    for size in [17usize, 32, 48].iter().copied() {
+        let part = size.div_ceil(3);
+        let polynomials: Vec<Polynomial<Vec<u8>>> = parts
+            .iter()
+            .map(|p| Polynomial::from_container((0..size).map(|i| p[i / part]).collect()))
+            .collect();
+        for lhs in polynomials.iter() {
+            for rhs in polynomials.iter() {
+                let mut expected = Polynomial::allocate(0u8, PolynomialSize(size));
+                expected.fill_with_wrapping_mul(lhs, rhs);
+                let mut res = Polynomial::allocate(0u8, PolynomialSize(size));
+                res.fill_with_toom3_mul(lhs, rhs);
+                assert_eq!(res, expected);
+            }
+        }
+    }
+}
+
+#[test]
+pub fn test_toom3_mul_u32() {
+    test_toom3_mul_sizes::<u32>([384, 512].iter().copied());
+}
+
+#[test]
+pub fn test_toom3_mul_u64() {
+    test_toom3_mul_sizes::<u64>([384, 512].iter().copied());
+}
+
+#[test]
+pub fn test_toom3_mul_max_coefficients_u64() {
+    let polynomial_size = PolynomialSize(512);
+    let poly = Polynomial::allocate(u64::MAX, polynomial_size);
+    let mut expected = Polynomial::allocate(0u64, polynomial_size);
+    expected.fill_with_wrapping_mul(&poly, &poly);
+    let mut res = Polynomial::allocate(0u64, polynomial_size);
+    res.fill_with_toom3_mul(&poly, &poly);
+    assert_eq!(res, expected);
+}
+
+fn reference_cyclic_mul(lhs: &[u8], rhs: &[u8]) -> Vec<u8> {
+    let size = lhs.len();
+    let mut output = vec![0u8; size];
+    for (i, l) in lhs.iter().enumerate() {
+        for (j, r) in rhs.iter().enumerate() {
+            let out = &mut output[(i + j) % size];
+            *out = out.wrapping_add(l.wrapping_mul(*r));
+        }
+    }
+    output
+}
+
+#[test]
+pub fn test_cyclic_mul_u8() {
+    //! tests the cyclic products and rotations against a direct convolution
+    let mut rng = rand::thread_rng();
+    let polynomial_size = PolynomialSize((rng.gen::<usize>() % 128) + 1);
+    let lhs = Polynomial::from_container(
+        random::random_uniform_tensor::<u8>(polynomial_size.0).into_container(),
+    );
+    let rhs = Polynomial::from_container(
+        random::random_uniform_tensor::<u8>(polynomial_size.0).into_container(),
+    );
+    let expected = reference_cyclic_mul(lhs.as_tensor().as_slice(), rhs.as_tensor().as_slice());
+
+    let mut res = Polynomial::allocate(1u8, polynomial_size);
+    res.fill_with_wrapping_cyclic_mul(&lhs, &rhs);
+    assert_eq!(res.as_tensor().as_slice(), expected.as_slice());
+
+    let acc = Polynomial::from_container(
+        random::random_uniform_tensor::<u8>(polynomial_size.0).into_container(),
+    );
+    let mut res = acc.clone();
+    res.update_with_wrapping_add_cyclic_mul(&lhs, &rhs);
+    let mut expected_sum = acc;
+    expected_sum.update_with_wrapping_add(&Polynomial::from_container(expected));
+    assert_eq!(res, expected_sum);
+
+    let degree = rng.gen::<usize>() % (3 * polynomial_size.0);
+    let mut monomial = vec![0u8; polynomial_size.0];
+    monomial[degree % polynomial_size.0] = 1;
+    let mut rotated = lhs.clone();
+    rotated.update_with_wrapping_cyclic_monomial_mul(MonomialDegree(degree));
+    assert_eq!(
+        rotated.as_tensor().as_slice(),
+        reference_cyclic_mul(lhs.as_tensor().as_slice(), &monomial).as_slice()
+    );
+}
+
+#[test]
+pub fn test_polynomial_display() {
+    let poly = Polynomial::from_container(vec![7u32]);
+    assert_eq!(format!("{}", poly), "P(X) = 7");
+    let poly = Polynomial::from_container((0..1024u32).collect::<Vec<_>>());
+    assert_eq!(
+        format!("{}", poly),
+        "P(X) = 0 + 1·X + 2·X^2 + … + 1021·X^1021 + 1022·X^1022 + 1023·X^1023 (1018 more)"
+    );
+    assert_eq!(format!("{:.0}", poly), "P(X) = … (1024 more)");
+    let poly = Polynomial::from_container(vec![0u32, 1 << 30, 3 << 30]);
+    assert_eq!(format!("{:#}", poly), "P(X) = 0 + 0.25·X + -0.25·X^2");
+    assert_eq!(
+        format!("{:?}", Polynomial::from_container(vec![1u8])),
+        "Polynomial { tensor: Tensor([1]) }"
+    );
+
+    let list = PolynomialList::from_container(vec![0u8, 64, 128, 192], PolynomialSize(2));
+    assert_eq!(
+        format!("{:#}", list),
+        "P_0(X) = 0 + 0.25·X\nP_1(X) = -0.5 + -0.25·X"
+    );
+}
+
+/// The number of random instances on which each identity is checked.
+const IDENTITY_TRIALS: usize = 10;
+
+fn test_mul_identities<T: UnsignedInteger + RandomGenerable<Uniform> + Debug>() {
+    //! tests that the product is commutative, associative, and distributes over the sum
+    let mut rng = rand::thread_rng();
+    for _ in 0..IDENTITY_TRIALS {
+        let polynomial_size = PolynomialSize((rng.gen::<usize>() % 64) + 1);
+        let a = test_tools::random_polynomial::<T>(polynomial_size);
+        let b = test_tools::random_polynomial::<T>(polynomial_size);
+        let c = test_tools::random_polynomial::<T>(polynomial_size);
+        let mut ab = Polynomial::allocate(T::ZERO, polynomial_size);
+        ab.fill_with_wrapping_mul(&a, &b);
+        let mut ba = Polynomial::allocate(T::ZERO, polynomial_size);
+        ba.fill_with_wrapping_mul(&b, &a);
+        let mut bc = Polynomial::allocate(T::ZERO, polynomial_size);
+        bc.fill_with_wrapping_mul(&b, &c);
+
+        // a * b = b * a
+        assert_eq!(ab, ba);
+
+        // (a * b) * c = a * (b * c)
+        let mut ab_c = Polynomial::allocate(T::ZERO, polynomial_size);
+        ab_c.fill_with_wrapping_mul(&ab, &c);
+        let mut a_bc = Polynomial::allocate(T::ZERO, polynomial_size);
+        a_bc.fill_with_wrapping_mul(&a, &bc);
+        assert_eq!(ab_c, a_bc);
+
+        // a * (b + c) = a * b + a * c
+        let mut b_plus_c = b.clone();
+        b_plus_c.update_with_wrapping_add(&c);
+        let mut a_b_plus_c = Polynomial::allocate(T::ZERO, polynomial_size);
+        a_b_plus_c.fill_with_wrapping_mul(&a, &b_plus_c);
+        let mut ab_plus_ac = ab.clone();
+        ab_plus_ac.update_with_wrapping_add_mul(&a, &c);
+        assert_eq!(a_b_plus_c, ab_plus_ac);
+    }
+}
+
+#[test]
+pub fn test_mul_identities_u8() {
+    test_mul_identities::<u8>()
+}
+
+#[test]
+pub fn test_mul_identities_u16() {
+    test_mul_identities::<u16>()
+}
+
+#[test]
+pub fn test_mul_identities_u32() {
+    test_mul_identities::<u32>()
+}
+
+#[test]
+pub fn test_mul_identities_u64() {
+    test_mul_identities::<u64>()
+}
+
+#[test]
+pub fn test_mul_identities_u128() {
+    test_mul_identities::<u128>()
+}
+
+fn test_monomial_mul_div_identity<T: UnsignedInteger + RandomGenerable<Uniform> + Debug>() {
+    //! tests that dividing by X^d cancels a multiplication by X^d, for degrees beyond 2N
+    let mut rng = rand::thread_rng();
+    for _ in 0..IDENTITY_TRIALS {
+        let polynomial_size = PolynomialSize((rng.gen::<usize>() % 2048) + 1);
+        let ground_truth = test_tools::random_polynomial::<T>(polynomial_size);
+        let n = polynomial_size.0;
+        let degrees = [
+            rng.gen::<usize>() % n,
+            n + rng.gen::<usize>() % n,
+            2 * n + rng.gen::<usize>() % n,
+            rng.gen::<usize>() % (64 * n),
+        ];
+        for degree in degrees.iter() {
+            let mut poly = ground_truth.clone();
+            poly.update_with_wrapping_monic_monomial_mul(MonomialDegree(*degree));
+            poly.update_with_wrapping_unit_monomial_div(MonomialDegree(*degree));
+            assert_eq!(poly, ground_truth);
+        }
+    }
+}
+
+#[test]
+pub fn test_monomial_mul_div_identity_u8() {
+    test_monomial_mul_div_identity::<u8>()
+}
+
+#[test]
+pub fn test_monomial_mul_div_identity_u16() {
+    test_monomial_mul_div_identity::<u16>()
+}
+
+#[test]
+pub fn test_monomial_mul_div_identity_u32() {
+    test_monomial_mul_div_identity::<u32>()
+}
+
+#[test]
+pub fn test_monomial_mul_div_identity_u64() {
+    test_monomial_mul_div_identity::<u64>()
+}
+
+#[test]
+pub fn test_monomial_mul_div_identity_u128() {
+    test_monomial_mul_div_identity::<u128>()
+}
+
+fn test_binary_multisum_identity<T>()
+where
+    T: UnsignedInteger + RandomGenerable<Uniform> + CastFrom<bool> + Debug,
+{
+    //! tests that the binary multisum is the sum of the binary products, which are the dense ones
+    let mut rng = rand::thread_rng();
+    for _ in 0..IDENTITY_TRIALS {
+        let polynomial_size = PolynomialSize((rng.gen::<usize>() % 128) + 1);
+        let count = PolynomialCount((rng.gen::<usize>() % 5) + 1);
+        let poly_list = test_tools::random_polynomial_list::<T>(count, polynomial_size);
+        let bin_list = test_tools::random_binary_polynomial_list(count, polynomial_size);
+        let output = test_tools::random_polynomial::<T>(polynomial_size);
+
+        let mut multisum = output.clone();
+        multisum.update_with_wrapping_add_binary_multisum(&poly_list, &bin_list);
+
+        let mut sum = output.clone();
+        let mut dense_sum = output;
+        for (poly, bin_poly) in poly_list.polynomial_iter().zip(bin_list.polynomial_iter()) {
+            sum.update_with_wrapping_add_binary_mul(&poly, &bin_poly);
+            let dense_bin_poly = Polynomial::from_container(
+                bin_poly
+                    .coefficient_iter()
+                    .map(|bit| T::cast_from(*bit))
+                    .collect::<Vec<_>>(),
+            );
+            dense_sum.update_with_wrapping_add_mul(&poly, &dense_bin_poly);
+        }
+        assert_eq!(multisum, sum);
+        assert_eq!(multisum, dense_sum);
+    }
+}
+
+#[test]
+pub fn test_binary_multisum_identity_u8() {
+    test_binary_multisum_identity::<u8>()
+}
+
+#[test]
+pub fn test_binary_multisum_identity_u16() {
+    test_binary_multisum_identity::<u16>()
+}
+
+#[test]
+pub fn test_binary_multisum_identity_u32() {
+    test_binary_multisum_identity::<u32>()
+}
+
+#[test]
+pub fn test_binary_multisum_identity_u64() {
+    test_binary_multisum_identity::<u64>()
+}
+
+#[test]
+pub fn test_binary_multisum_identity_u128() {
+    test_binary_multisum_identity::<u128>()
+}
@@ -0,0 +1,151 @@
+use crate::ck_dim_eq;
+use crate::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor};
+use crate::numeric::{CastFrom, CastInto, UnsignedInteger};
+
+use super::*;
+
+/// The size below which the products are computed with the schoolbook algorithm.
+const TOOM3_THRESHOLD: usize = 16;
+
+/// The inverse of 3 modulo $2^{128}$.
+const INVERSE_OF_THREE: u128 = 0xaaaa_aaaa_aaaa_aaaa_aaaa_aaaa_aaaa_aaab;
+
+// Computes the product of two polynomials of the same size, without reduction.
+fn schoolbook_mul(lhs: &[u128], rhs: &[u128]) -> Vec<u128> {
+    let mut output = vec![0u128; lhs.len() + rhs.len() - 1];
+    for (i, l) in lhs.iter().enumerate() {
+        for (out, r) in output[i..].iter_mut().zip(rhs.iter()) {
+            *out = out.wrapping_add(l.wrapping_mul(*r));
+        }
+    }
+    output
+}
+
+// Computes the product of two polynomials of the same size, without reduction, by splitting them
+// in three parts and interpolating the products of their evaluations in 0, 1, -1, -2 and the
+// infinity.
+//
+// The interpolation divides some exact multiples of 2 and 3. The divisions by 3 are performed
+// exactly modulo $2^{128}$ by multiplying with the inverse of 3. The divisions by 2 are shifts,
+// which lose the most significant bit of the result: each level of recursion leaves the product
+// correct modulo a power of two smaller by a factor of 2, so the 64 least significant bits are
+// correct for all practical sizes.
+fn toom3_mul(lhs: &[u128], rhs: &[u128]) -> Vec<u128> {
+    let size = lhs.len();
+    if size <= TOOM3_THRESHOLD {
+        return schoolbook_mul(lhs, rhs);
+    }
+    let part = size.div_ceil(3);
+    // Evaluates a polynomial split in three parts, the last one being padded with zeros.
+    let evaluate = |poly: &[u128]| {
+        let (p0, rest) = poly.split_at(part);
+        let (p1, p2) = rest.split_at(part);
+        let mut p2 = p2.to_vec();
+        p2.resize(part, 0);
+        let mut points: Vec<Vec<u128>> = (0..5).map(|_| Vec::with_capacity(part)).collect();
+        for ((c0, c1), c2) in p0.iter().zip(p1.iter()).zip(p2.iter()) {
+            let even = c0.wrapping_add(*c2);
+            points[0].push(*c0);
+            points[1].push(even.wrapping_add(*c1));
+            points[2].push(even.wrapping_sub(*c1));
+            points[3].push(c0.wrapping_sub(c1 << 1).wrapping_add(c2 << 2));
+            points[4].push(*c2);
+        }
+        points
+    };
+    let lhs_points = evaluate(lhs);
+    let rhs_points = evaluate(rhs);
+    let mut products = lhs_points
+        .iter()
+        .zip(rhs_points.iter())
+        .map(|(l, r)| toom3_mul(l, r));
+    let at_zero = products.next().unwrap();
+    let mut at_one = products.next().unwrap();
+    let mut at_minus_one = products.next().unwrap();
+    let mut at_minus_two = products.next().unwrap();
+    let at_infinity = products.next().unwrap();
+    // Bodrato's interpolation sequence, which turns the values at the points into the
+    // coefficients of the parts of degrees 1, 2 and 3.
+    for ((((r0, r1), r2), r3), r_inf) in at_zero
+        .iter()
+        .zip(at_one.iter_mut())
+        .zip(at_minus_one.iter_mut())
+        .zip(at_minus_two.iter_mut())
+        .zip(at_infinity.iter())
+    {
+        let t3 = r3.wrapping_sub(*r1).wrapping_mul(INVERSE_OF_THREE);
+        let t1 = r1.wrapping_sub(*r2) >> 1;
+        let t2 = r2.wrapping_sub(*r0);
+        *r3 = (t2.wrapping_sub(t3) >> 1).wrapping_add(r_inf << 1);
+        *r2 = t2.wrapping_add(t1).wrapping_sub(*r_inf);
+        *r1 = t1.wrapping_sub(*r3);
+    }
+    let mut output = vec![0u128; 6 * part - 1];
+    let parts = [
+        &at_zero,
+        &at_one,
+        &at_minus_one,
+        &at_minus_two,
+        &at_infinity,
+    ];
+    for (index, product) in parts.iter().enumerate() {
+        for (out, coef) in output[index * part..].iter_mut().zip(product.iter()) {
+            *out = out.wrapping_add(*coef);
+        }
+    }
+    output.truncate(2 * size - 1);
+    output
+}
+
+impl<Cont> Polynomial<Cont> {
+    /// Fills the current polynomial with the product of two polynomials, reduced modulo
+    /// $(X^N+1)$, computed with the Toom-Cook 3-way algorithm.
+    ///
+    /// The polynomials are recursively split in three parts, which replaces the 9 products of the
+    /// parts by 5 products of their evaluations. This is faster than the schoolbook product of
+    /// [`Polynomial::fill_with_wrapping_mul`] for medium sizes, and gives the same result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coefficients have more than 64 bits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::math::polynomial::{Polynomial, PolynomialSize};
+    /// let lhs = Polynomial::from_container((0..64u32).collect::<Vec<_>>());
+    /// let rhs = Polynomial::from_container((0..64u32).rev().collect::<Vec<_>>());
+    /// let mut expected = Polynomial::allocate(0u32, PolynomialSize(64));
+    /// expected.fill_with_wrapping_mul(&lhs, &rhs);
+    /// let mut res = Polynomial::allocate(0u32, PolynomialSize(64));
+    /// res.fill_with_toom3_mul(&lhs, &rhs);
+    /// assert_eq!(res, expected);
+    /// ```
+    pub fn fill_with_toom3_mul<Coef, LhsCont, RhsCont>(
+        &mut self,
+        lhs: &Polynomial<LhsCont>,
+        rhs: &Polynomial<RhsCont>,
+    ) where
+        Self: AsMutTensor<Element = Coef>,
+        Polynomial<LhsCont>: AsRefTensor<Element = Coef>,
+        Polynomial<RhsCont>: AsRefTensor<Element = Coef>,
+        Coef: UnsignedInteger + CastInto<u128> + CastFrom<u128>,
+    {
+        ck_dim_eq!(self.polynomial_size() => lhs.polynomial_size(), rhs.polynomial_size());
+        assert!(
+            Coef::BITS <= 64,
+            "The Toom-Cook product only supports coefficients of at most 64 bits."
+        );
+        let lift = |poly: &[Coef]| -> Vec<u128> { poly.iter().map(|c| (*c).cast_into()).collect() };
+        let product = toom3_mul(
+            &lift(lhs.as_tensor().as_slice()),
+            &lift(rhs.as_tensor().as_slice()),
+        );
+        let size = self.polynomial_size().0;
+        for (degree, output) in self.as_mut_tensor().iter_mut().enumerate() {
+            // The monomials of degree N + i wrap to the degree i with a negative sign.
+            let wrapped = product.get(degree + size).copied().unwrap_or(0);
+            *output = Coef::cast_from(product[degree].wrapping_sub(wrapped));
+        }
+    }
+}
@@ -18,10 +18,8 @@ macro_rules! implement_gaussian {
     ($T:ty, $S:ty) => {
         impl RandomGenerable<Gaussian<$T>> for ($T, $T) {
             fn sample(Gaussian { std, mean }: Gaussian<$T>) -> Self {
-                let output: ($T, $T);
                 let mut uniform_rand = vec![0 as $S; 2];
-                let mut gen = concrete_csprng::RandomGenerator::new(None, None);
-                loop {
+                with_generator(|gen| loop {
                     let n_bytes = (<$S as Numeric>::BITS * 2) / 8;
                     let uniform_rand_bytes = unsafe {
                         std::slice::from_raw_parts_mut(
@@ -40,11 +38,9 @@ macro_rules! implement_gaussian {
                     let s = u.powi(2) + v.powi(2);
                     if (s > <$T>::ZERO && s < <$T>::ONE) {
                         let cst = std * (-<$T>::TWO * s.ln() / s).sqrt();
-                        output = (u * cst + mean, v * cst + mean);
-                        break;
+                        break (u * cst + mean, v * cst + mean);
                     }
-                }
-                output
+                })
             }
         }
     };
@@ -155,6 +151,65 @@ pub fn fill_with_random_gaussian<Float, Scalar, Tensorable>(
         });
 }
 
+/// Fills an `AsMutTensor` value with samples of a discrete gaussian distribution, reduced modulo
+/// `modulus`.
+///
+/// The samples are drawn from a centered gaussian of standard deviation `std` (expressed in
+/// integer units, and not as a fraction of the torus), rounded to the nearest integer, and
+/// reduced in the range `0..modulus`. A sample $e$ of the discrete gaussian is stored as $e$ if
+/// it is positive, and as $q - |e|$ otherwise, where $q$ is the modulus.
+///
+/// # Panics
+///
+/// Panics if `modulus` is zero.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::random::fill_with_random_modular_gaussian;
+/// use concrete_core::math::tensor::Tensor;
+/// let modulus = 1u64 << 20;
+/// let mut tensor = Tensor::allocate(0u64, 1000);
+/// fill_with_random_modular_gaussian(&mut tensor, 3.2, modulus);
+/// tensor.iter().for_each(|e| {
+///     assert!(*e < modulus);
+///     // The samples are within 10 standard deviations of zero, modulo the modulus.
+///     assert!(*e <= 32 || *e >= modulus - 32);
+/// });
+/// ```
+pub fn fill_with_random_modular_gaussian<Scalar, Tensorable>(
+    output: &mut Tensorable,
+    std: f64,
+    modulus: Scalar,
+) where
+    Scalar: UnsignedTorus,
+    Tensorable: AsMutTensor<Element = Scalar>,
+{
+    assert!(modulus != Scalar::ZERO, "The modulus must not be zero.");
+    let reduce = |sample: f64| {
+        let rounded = sample.round();
+        let magnitude = Scalar::cast_from(rounded.abs()) % modulus;
+        if rounded < 0. && magnitude != Scalar::ZERO {
+            modulus - magnitude
+        } else {
+            magnitude
+        }
+    };
+    output
+        .as_mut_tensor()
+        .as_mut_slice()
+        .chunks_mut(2)
+        .for_each(|s| {
+            let (g1, g2) = random_gaussian::<f64, f64>(0., std);
+            if let Some(elem) = s.get_mut(0) {
+                *elem = reduce(g1);
+            }
+            if let Some(elem) = s.get_mut(1) {
+                *elem = reduce(g2);
+            }
+        });
+}
+
 /// Generates a new tensor of floating point values, randomly sampled from a gaussian distribution:
 ///
 /// # Example
@@ -4,7 +4,7 @@
 //! distribution, for instance:
 //!
 //! + [`random_uniform`] samples a random unsigned integer with uniform probability over
-//! the set of representable values.
+//!   the set of representable values.
 //! + [`random_gaussian`] samples a random float with using a gaussian distribution.
 //!
 //! The implementation relies on the [`RandomGenerable`] trait, which gives a type the ability to
@@ -16,6 +16,14 @@
 //! + [`random_uniform_n_msb`]
 //! + [`random_uniform_n_lsb`]
 //! + [`random_gaussian`]
+//!
+//! By default, the samples are drawn from a freshly seeded generator. The [`with_seed`] function
+//! makes the sampling deterministic for the duration of a closure, which is useful to reproduce
+//! keys and ciphertexts, and [`jump_ahead`] skips a known number of its samples.
+use std::cell::RefCell;
+
+use concrete_csprng::RandomGenerator;
+
 use crate::numeric::{FloatingPoint, Numeric};
 
 #[cfg(test)]
@@ -39,6 +47,91 @@ pub use uniform_with_zeros::*;
 mod uniform_boolean;
 pub use uniform_boolean::*;
 
+thread_local! {
+    static SEEDED_GENERATOR: RefCell<Option<RandomGenerator>> = const { RefCell::new(None) };
+}
+
+/// Restores the previously installed generator when dropped, even if the closure panicked.
+struct SeededGeneratorGuard(Option<RandomGenerator>);
+
+impl Drop for SeededGeneratorGuard {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        SEEDED_GENERATOR.with(|cell| *cell.borrow_mut() = previous);
+    }
+}
+
+/// Executes `f` with all the random samples of the current thread drawn from a generator seeded
+/// with `seed`.
+///
+/// Two executions of the same closure with the same seed produce the same samples. Calls can be
+/// nested, in which case the innermost seed is used.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::math::random::{random_uniform, with_seed};
+/// let first: u64 = with_seed(42, random_uniform);
+/// let second: u64 = with_seed(42, random_uniform);
+/// assert_eq!(first, second);
+/// ```
+pub fn with_seed<R>(seed: u128, f: impl FnOnce() -> R) -> R {
+    with_seeded_stream(seed, 0, f)
+}
+
+/// Executes `f` with the random samples of the current thread drawn from the `stream`-th stream
+/// of the generator seeded with `seed`.
+///
+/// The different streams of a same seed do not overlap, which allows to use the same seed on
+/// several threads.
+pub(crate) fn with_seeded_stream<R>(seed: u128, stream: u64, f: impl FnOnce() -> R) -> R {
+    let generator = RandomGenerator::new(Some(seed), Some((stream as u128) << 64));
+    let previous = SEEDED_GENERATOR.with(|cell| cell.borrow_mut().replace(generator));
+    let _guard = SeededGeneratorGuard(previous);
+    f()
+}
+
+/// Advances the generator installed by [`with_seed`] by `steps` batches of 128 bytes.
+///
+/// The next samples are the same as if `steps * 128` bytes had been drawn and discarded, but
+/// the generator only increments its counter. Knowing the number of bytes consumed by each
+/// segment of a computation, this allows to compute the segments in parallel from the same
+/// seed. Without a seeded generator, the samples are not reproducible anyway, and nothing is
+/// done.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::math::random::{jump_ahead, random_uniform, with_seed};
+/// let jumped: u64 = with_seed(42, || {
+///     jump_ahead(2);
+///     random_uniform()
+/// });
+/// let discarded: u64 = with_seed(42, || {
+///     for _ in 0..256 {
+///         random_uniform::<u8>();
+///     }
+///     random_uniform()
+/// });
+/// assert_eq!(jumped, discarded);
+/// ```
+pub fn jump_ahead(steps: u64) {
+    SEEDED_GENERATOR.with(|cell| {
+        if let Some(generator) = cell.borrow_mut().as_mut() {
+            generator.jump_ahead(steps);
+        }
+    })
+}
+
+/// Calls `f` with the generator installed by [`with_seed`] if any, or with a fresh generator
+/// otherwise.
+fn with_generator<R>(f: impl FnOnce(&mut RandomGenerator) -> R) -> R {
+    SEEDED_GENERATOR.with(|cell| match cell.borrow_mut().as_mut() {
+        Some(generator) => f(generator),
+        None => f(&mut RandomGenerator::new(None, None)),
+    })
+}
+
 /// A trait allowing a type to be randomly generated with a distribution represented by the generic
 /// `D` type.
 ///
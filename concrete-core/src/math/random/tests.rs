@@ -1,6 +1,9 @@
 use crate::crypto::UnsignedTorus;
 use crate::math::dispersion::LogStandardDev;
-use crate::math::random::fill_with_random_gaussian;
+use crate::math::random::{
+    fill_with_random_gaussian, fill_with_random_modular_gaussian, fill_with_random_sparse_mask,
+    jump_ahead, random_uniform, with_seed,
+};
 use crate::math::tensor::Tensor;
 use crate::test_tools::assert_noise_distribution;
 
@@ -80,3 +83,88 @@ fn test_distribution_u32() {
 fn test_distribution_u64() {
     test_distribution::<u64>();
 }
+
+fn test_sparse_mask_density<T: UnsignedTorus>() {
+    let size = 100_000;
+    let density = 0.3;
+    let mut mask = Tensor::allocate(T::ZERO, size);
+    fill_with_random_sparse_mask(&mut mask, density);
+    let non_zeros = mask.iter().filter(|a| **a != T::ZERO).count();
+    let measured = non_zeros as f64 / size as f64;
+    assert!(
+        (measured - density).abs() < 0.01,
+        "Measured density {} too far from {}.",
+        measured,
+        density
+    );
+}
+
+#[test]
+fn test_sparse_mask_density_u32() {
+    test_sparse_mask_density::<u32>();
+}
+
+#[test]
+fn test_sparse_mask_density_u64() {
+    test_sparse_mask_density::<u64>();
+}
+
+fn test_modular_gaussian<T: UnsignedTorus>() {
+    let size = 100_000;
+    let std_dev = 3.2;
+    // An odd modulus, so that the reduction is not a simple truncation of the bits.
+    let modulus = T::cast_from(12_289.);
+    let mut samples = Tensor::allocate(T::ZERO, size);
+    fill_with_random_modular_gaussian(&mut samples, std_dev, modulus);
+
+    // Lifts the samples back to the centered representatives.
+    let half: f64 = (modulus / T::TWO).cast_into();
+    let modulus: f64 = modulus.cast_into();
+    let mut sum = 0.;
+    let mut sum_squares = 0.;
+    for sample in samples.iter() {
+        let sample: f64 = (*sample).cast_into();
+        assert!(sample < modulus);
+        let centered = if sample > half {
+            sample - modulus
+        } else {
+            sample
+        };
+        assert_eq!(centered, centered.round());
+        sum += centered;
+        sum_squares += centered * centered;
+    }
+    let mean = sum / size as f64;
+    let measured_std_dev = (sum_squares / size as f64 - mean * mean).sqrt();
+    assert!(mean.abs() < 0.1, "Measured mean {} too far from 0.", mean);
+    assert!(
+        (measured_std_dev - std_dev).abs() < 0.1,
+        "Measured standard deviation {} too far from {}.",
+        measured_std_dev,
+        std_dev
+    );
+}
+
+#[test]
+fn test_modular_gaussian_u32() {
+    test_modular_gaussian::<u32>();
+}
+
+#[test]
+fn test_modular_gaussian_u64() {
+    test_modular_gaussian::<u64>();
+}
+
+#[test]
+fn test_jump_ahead_splits_seeded_samples() {
+    // Two segments of 3 batches each, generated from the same seed.
+    let seed = random_uniform::<u128>();
+    let whole: Vec<u32> = with_seed(seed, || (0..192).map(|_| random_uniform()).collect());
+    let first: Vec<u32> = with_seed(seed, || (0..96).map(|_| random_uniform()).collect());
+    let second: Vec<u32> = with_seed(seed, || {
+        jump_ahead(3);
+        (0..96).map(|_| random_uniform()).collect()
+    });
+    assert_eq!(whole[..96], first[..]);
+    assert_eq!(whole[96..], second[..]);
+}
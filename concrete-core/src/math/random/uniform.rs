@@ -11,10 +11,8 @@ macro_rules! implement_uniform {
         impl RandomGenerable<Uniform> for $T {
             #[allow(unused)]
             fn sample(distribution: Uniform) -> Self {
-                use concrete_csprng::RandomGenerator;
-                let mut gen = RandomGenerator::new(None, None);
                 let mut buf = [0; $bytes];
-                buf.iter_mut().for_each(|a| *a = gen.generate_next());
+                with_generator(|gen| buf.iter_mut().for_each(|a| *a = gen.generate_next()));
                 unsafe { *(buf.as_ptr() as *const $T) }
             }
         }
@@ -8,9 +8,7 @@ pub struct UniformBoolean;
 impl RandomGenerable<UniformBoolean> for bool {
     #[allow(unused)]
     fn sample(distribution: UniformBoolean) -> Self {
-        use concrete_csprng::RandomGenerator;
-        let mut gen = RandomGenerator::new(None, None);
-        gen.generate_next() & 1 == 1
+        with_generator(|gen| gen.generate_next() & 1 == 1)
     }
 }
 
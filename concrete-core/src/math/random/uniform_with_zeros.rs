@@ -1,4 +1,5 @@
 use super::*;
+use crate::crypto::UnsignedTorus;
 use crate::math::tensor::{AsMutTensor, Tensor};
 
 /// A distribution type taht samples a uniform value with probability `1 - prob_zero`, and a zero
@@ -101,3 +102,48 @@ pub fn random_uniform_with_zeros_tensor<T: RandomGenerable<UniformWithZeros>>(
         .map(|_| random_uniform_with_zeros::<T>(prob_zero))
         .collect()
 }
+
+/// Fills an `AsMutTensor` value with a sparse random mask.
+///
+/// Each coefficient is set to a uniformly random non-zero value with probability `density`, and
+/// to zero with probability `1 - density`.
+///
+/// # Panics
+///
+/// Panics if `density` is not in `[0, 1]`.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::math::tensor::Tensor;
+/// use concrete_core::math::random::fill_with_random_sparse_mask;
+/// let mut tensor = Tensor::allocate(0 as u32, 100);
+/// fill_with_random_sparse_mask(&mut tensor, 1.);
+/// tensor.iter().for_each(|a| assert_ne!(*a, 0));
+/// fill_with_random_sparse_mask(&mut tensor, 0.);
+/// tensor.iter().for_each(|a| assert_eq!(*a, 0));
+/// ```
+pub fn fill_with_random_sparse_mask<Scalar, Tensorable>(output: &mut Tensorable, density: f64)
+where
+    Scalar: UnsignedTorus,
+    Tensorable: AsMutTensor<Element = Scalar>,
+{
+    assert!(
+        (0. ..=1.).contains(&density),
+        "The density must be in [0, 1], got {}.",
+        density
+    );
+    output.as_mut_tensor().iter_mut().for_each(|s| {
+        let float_sample = random_uniform::<u64>() as f64 / u64::MAX as f64;
+        *s = if float_sample < density {
+            loop {
+                let value = random_uniform::<Scalar>();
+                if value != Scalar::ZERO {
+                    break value;
+                }
+            }
+        } else {
+            Scalar::ZERO
+        };
+    });
+}
@@ -1,5 +1,8 @@
 use fftw::array::AlignedVec;
 
+use super::bytes::{slice_as_bytes, slice_as_mut_bytes};
+use super::ByteElement;
+
 /// A trait allowing to extract a slice from a tensor.
 ///
 /// This trait is one of the two traits which allows to use [`Tensor`](super::Tensor) whith any data
@@ -39,6 +42,27 @@ pub trait AsRefSlice {
     type Element;
     /// Returns a slice from the container.
     fn as_slice(&self) -> &[Self::Element];
+
+    /// Returns the bytes of the elements of the container, without copying them.
+    ///
+    /// The elements are viewed in the native byte order of the platform. See
+    /// [`Tensor::to_le_bytes_vec`](super::Tensor::to_le_bytes_vec) for a portable output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::math::tensor::{AsRefSlice, Tensor};
+    /// let tensor = Tensor::from_container(vec![1u64, 2, 3]);
+    /// assert_eq!(tensor.as_bytes().len(), 24);
+    /// # #[cfg(target_endian = "little")]
+    /// assert_eq!(&tensor.as_bytes()[..9], &[1, 0, 0, 0, 0, 0, 0, 0, 2]);
+    /// ```
+    fn as_bytes(&self) -> &[u8]
+    where
+        Self::Element: ByteElement,
+    {
+        slice_as_bytes(self.as_slice())
+    }
 }
 
 impl<Element> AsRefSlice for Vec<Element> {
@@ -86,6 +110,25 @@ pub trait AsMutSlice: AsRefSlice<Element = <Self as AsMutSlice>::Element> {
     type Element;
     /// Returns a mutable slice from the container.
     fn as_mut_slice(&mut self) -> &mut [<Self as AsMutSlice>::Element];
+
+    /// Returns the mutable bytes of the elements of the container, without copying them.
+    ///
+    /// The elements are viewed in the native byte order of the platform.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::math::tensor::{AsMutSlice, AsRefSlice, Tensor};
+    /// let mut tensor = Tensor::from_container(vec![0u32; 2]);
+    /// tensor.as_mut_bytes().copy_from_slice(&[0xff; 8]);
+    /// assert_eq!(tensor.as_slice(), &[u32::MAX, u32::MAX]);
+    /// ```
+    fn as_mut_bytes(&mut self) -> &mut [u8]
+    where
+        <Self as AsMutSlice>::Element: ByteElement,
+    {
+        slice_as_mut_bytes(self.as_mut_slice())
+    }
 }
 
 impl<Element> AsMutSlice for Vec<Element> {
@@ -0,0 +1,197 @@
+use std::fmt;
+use std::io::{Read, Write};
+
+use crate::numeric::{CastFrom, CastInto, UnsignedInteger};
+
+use super::{AsRefSlice, Tensor};
+
+// The magic bytes starting every tensor stored in the binary format.
+const MAGIC: &[u8; 8] = b"CNCRTTSR";
+
+// The version of the format, to be incremented on every change of the layout.
+const VERSION: u16 = 1;
+
+// The endianness flag of the data. Only little endian data is written and read for now.
+const LITTLE_ENDIAN: u8 = 0;
+
+// The header is made of the magic bytes, the version, the endianness flag, the element width in
+// bytes and the element count, all integers being stored in little endian.
+const HEADER_LEN: usize = 8 + 2 + 1 + 1 + 8;
+
+// The number of bytes written or read at once.
+const CHUNK_LEN: usize = 1 << 16;
+
+/// A type that represents an error when writing or reading a tensor in the binary format.
+#[derive(Debug)]
+pub enum BinaryError {
+    /// The error occurred when writing to or reading from the stream.
+    Io { source: std::io::Error },
+    /// The stream does not start with the magic bytes of the format.
+    InvalidMagic,
+    /// The version of the format is not supported by this version of the library.
+    UnsupportedVersion { found: u16 },
+    /// The endianness flag is not supported by this version of the library.
+    UnsupportedEndianness { found: u8 },
+    /// The width of the stored elements does not match the element type of the tensor.
+    ElementWidthMismatch { expected: usize, found: usize },
+}
+
+impl fmt::Display for BinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { source } => write!(f, "Failed to access stream: {}", source),
+            Self::InvalidMagic => write!(f, "Invalid header: missing magic bytes"),
+            Self::UnsupportedVersion { found } => {
+                write!(f, "Invalid header: unsupported format version {}", found)
+            }
+            Self::UnsupportedEndianness { found } => {
+                write!(f, "Invalid header: unsupported endianness flag {}", found)
+            }
+            Self::ElementWidthMismatch { expected, found } => write!(
+                f,
+                "Unexpected element width: expected {} bytes, found {} bytes",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BinaryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for BinaryError {
+    fn from(source: std::io::Error) -> Self {
+        Self::Io { source }
+    }
+}
+
+impl<Cont> Tensor<Cont> {
+    /// Writes the tensor to a stream in a compact binary format.
+    ///
+    /// The tensor is written as a header of 20 bytes, storing a version of the format, the width
+    /// and the number of the elements, followed by the little endian representation of the
+    /// elements. Contrarily to [`Tensor::save_to_file`], no serialization format is involved,
+    /// which makes this format suited to large tensors.
+    ///
+    /// # Note
+    ///
+    /// The data is written in chunks, but the stream should be buffered for best performances.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container(vec![1u32, 2, 3]);
+    /// let mut bytes = Vec::new();
+    /// tensor.write_to(&mut bytes).unwrap();
+    /// assert_eq!(bytes.len(), 20 + 3 * 4);
+    /// let recovered: Tensor<Vec<u32>> = Tensor::read_from(bytes.as_slice()).unwrap();
+    /// assert_eq!(tensor, recovered);
+    /// ```
+    pub fn write_to<Element, W: Write>(&self, mut writer: W) -> Result<(), BinaryError>
+    where
+        Self: AsRefSlice<Element = Element>,
+        Element: UnsignedInteger + CastInto<u8>,
+    {
+        let slice = self.as_slice();
+        let width = Element::BITS / 8;
+        let mut header = Vec::with_capacity(HEADER_LEN);
+        header.extend_from_slice(MAGIC);
+        header.extend_from_slice(&VERSION.to_le_bytes());
+        header.push(LITTLE_ENDIAN);
+        header.push(width as u8);
+        header.extend_from_slice(&(slice.len() as u64).to_le_bytes());
+        writer.write_all(&header)?;
+
+        let mut buffer = Vec::with_capacity(CHUNK_LEN);
+        for chunk in slice.chunks(CHUNK_LEN / width) {
+            buffer.clear();
+            for element in chunk.iter() {
+                for byte in 0..width {
+                    buffer.push((*element >> (8 * byte)).cast_into());
+                }
+            }
+            writer.write_all(&buffer)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<Element> Tensor<Vec<Element>>
+where
+    Element: UnsignedInteger + CastFrom<u8>,
+{
+    /// Reads a tensor from a stream, written with [`Tensor::write_to`].
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the stream can not be read or ends before the last element, if
+    /// its header is not valid, or if the width of its elements does not match `Element`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::math::tensor::{BinaryError, Tensor};
+    /// let tensor = Tensor::from_container(vec![1u64, 2, 3]);
+    /// let mut bytes = Vec::new();
+    /// tensor.write_to(&mut bytes).unwrap();
+    /// let error = Tensor::<Vec<u32>>::read_from(bytes.as_slice()).unwrap_err();
+    /// assert!(matches!(
+    ///     error,
+    ///     BinaryError::ElementWidthMismatch {
+    ///         expected: 4,
+    ///         found: 8
+    ///     }
+    /// ));
+    /// ```
+    pub fn read_from<R: Read>(mut reader: R) -> Result<Self, BinaryError> {
+        let mut header = [0u8; HEADER_LEN];
+        reader.read_exact(&mut header)?;
+        if &header[..8] != MAGIC {
+            return Err(BinaryError::InvalidMagic);
+        }
+        let version = u16::from_le_bytes([header[8], header[9]]);
+        if version != VERSION {
+            return Err(BinaryError::UnsupportedVersion { found: version });
+        }
+        if header[10] != LITTLE_ENDIAN {
+            return Err(BinaryError::UnsupportedEndianness { found: header[10] });
+        }
+        let width = Element::BITS / 8;
+        if header[11] as usize != width {
+            return Err(BinaryError::ElementWidthMismatch {
+                expected: width,
+                found: header[11] as usize,
+            });
+        }
+        let mut count = [0u8; 8];
+        count.copy_from_slice(&header[12..]);
+        let count = u64::from_le_bytes(count) as usize;
+
+        // The count is not trusted to allocate the tensor at once, which grows as the data is
+        // actually read.
+        let mut container = Vec::with_capacity(count.min(CHUNK_LEN / width));
+        let mut buffer = vec![0u8; CHUNK_LEN];
+        while container.len() < count {
+            let chunk_len = (count - container.len()).min(CHUNK_LEN / width);
+            let bytes = &mut buffer[..chunk_len * width];
+            reader.read_exact(bytes)?;
+            container.extend(bytes.chunks_exact(width).map(|element| {
+                element
+                    .iter()
+                    .enumerate()
+                    .fold(Element::ZERO, |acc, (byte, value)| {
+                        acc | (Element::cast_from(*value) << (8 * byte))
+                    })
+            }));
+        }
+        Ok(Tensor::from_container(container))
+    }
+}
@@ -0,0 +1,202 @@
+use std::fmt;
+
+use super::{AsRefSlice, Tensor};
+
+/// A type that represents an error when viewing bytes as a tensor of integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastError {
+    /// The number of bytes is not a multiple of the size of an element.
+    LengthMismatch { len: usize, element_size: usize },
+    /// The bytes are not aligned on the alignment of an element.
+    Misaligned { alignment: usize },
+}
+
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthMismatch { len, element_size } => write!(
+                f,
+                "{} bytes can not be cast to elements of {} bytes",
+                len, element_size
+            ),
+            Self::Misaligned { alignment } => {
+                write!(f, "The bytes are not aligned on {} bytes", alignment)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CastError {}
+
+/// A marker trait for the element types whose tensors can be viewed as bytes.
+///
+/// This trait is implemented for the unsigned and signed integer types, which have no padding
+/// bytes, and for which every sequence of bytes is a valid value.
+pub trait ByteElement: seal::Sealed + Copy {
+    /// Appends the little endian representation of the element to a buffer.
+    fn extend_le_bytes(self, buffer: &mut Vec<u8>);
+}
+
+mod seal {
+    pub trait Sealed {}
+}
+
+macro_rules! implement_byte_element {
+    ($($Type: ty),*) => {
+        $(
+            impl seal::Sealed for $Type {}
+            impl ByteElement for $Type {
+                fn extend_le_bytes(self, buffer: &mut Vec<u8>) {
+                    buffer.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+implement_byte_element!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+// Returns the bytes of a slice of elements, in the native byte order.
+pub(super) fn slice_as_bytes<Element: ByteElement>(slice: &[Element]) -> &[u8] {
+    // SAFETY: The elements are integers without padding, so every byte of the slice is
+    // initialized, and the alignment of `u8` is satisfied by any pointer.
+    unsafe { std::slice::from_raw_parts(slice.as_ptr() as *const u8, std::mem::size_of_val(slice)) }
+}
+
+// Returns the bytes of a mutable slice of elements, in the native byte order.
+pub(super) fn slice_as_mut_bytes<Element: ByteElement>(slice: &mut [Element]) -> &mut [u8] {
+    // SAFETY: In addition to the above, every sequence of bytes written through the returned
+    // slice is a valid value of the integer elements.
+    unsafe {
+        std::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut u8, std::mem::size_of_val(slice))
+    }
+}
+
+// Returns the elements stored in a slice of bytes, after checking the length and the alignment.
+fn bytes_as_slice<Element: ByteElement>(bytes: &[u8]) -> Result<&[Element], CastError> {
+    let element_size = std::mem::size_of::<Element>();
+    let alignment = std::mem::align_of::<Element>();
+    if !bytes.len().is_multiple_of(element_size) {
+        return Err(CastError::LengthMismatch {
+            len: bytes.len(),
+            element_size,
+        });
+    }
+    if !(bytes.as_ptr() as usize).is_multiple_of(alignment) {
+        return Err(CastError::Misaligned { alignment });
+    }
+    // SAFETY: The pointer is aligned for `Element`, the slice covers exactly the bytes, and every
+    // sequence of bytes is a valid integer.
+    Ok(unsafe {
+        std::slice::from_raw_parts(bytes.as_ptr() as *const Element, bytes.len() / element_size)
+    })
+}
+
+impl<'a, Element> Tensor<&'a [Element]>
+where
+    Element: ByteElement,
+{
+    /// Creates a tensor viewing a slice of bytes as elements, without copying them.
+    ///
+    /// The bytes are interpreted in the native byte order of the platform.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the number of bytes is not a multiple of the size of `Element`,
+    /// or if the bytes are not aligned on the alignment of `Element`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::math::tensor::{AsRefSlice, CastError, Tensor};
+    /// let tensor = Tensor::from_container(vec![1u64, 2, 3]);
+    /// let view: Tensor<&[u64]> = Tensor::from_byte_slice(tensor.as_bytes()).unwrap();
+    /// assert_eq!(view.as_slice(), &[1, 2, 3]);
+    /// let error = Tensor::<&[u64]>::from_byte_slice(&tensor.as_bytes()[1..]).unwrap_err();
+    /// assert_eq!(
+    ///     error,
+    ///     CastError::LengthMismatch {
+    ///         len: 23,
+    ///         element_size: 8
+    ///     }
+    /// );
+    /// ```
+    pub fn from_byte_slice(bytes: &'a [u8]) -> Result<Self, CastError> {
+        bytes_as_slice(bytes).map(Tensor::from_container)
+    }
+}
+
+impl<Element> Tensor<Vec<Element>>
+where
+    Element: ByteElement,
+{
+    /// Creates a tensor from a vector of bytes, interpreted in the native byte order.
+    ///
+    /// # Note
+    ///
+    /// A vector of bytes can not give its allocation to a vector of larger elements, since it
+    /// must be freed with the alignment it was allocated with. The bytes are hence copied, and
+    /// [`Tensor::from_byte_slice`] should be used to avoid the copy.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the number of bytes is not a multiple of the size of `Element`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::math::tensor::{AsRefSlice, Tensor};
+    /// let bytes = Tensor::from_container(vec![1u32, 2, 3]).as_bytes().to_vec();
+    /// let tensor: Tensor<Vec<u32>> = Tensor::from_byte_container(bytes).unwrap();
+    /// assert_eq!(tensor.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn from_byte_container(bytes: Vec<u8>) -> Result<Self, CastError> {
+        let element_size = std::mem::size_of::<Element>();
+        if !bytes.len().is_multiple_of(element_size) {
+            return Err(CastError::LengthMismatch {
+                len: bytes.len(),
+                element_size,
+            });
+        }
+        let len = bytes.len() / element_size;
+        let mut container = Vec::<Element>::with_capacity(len);
+        // SAFETY: The capacity of the container holds exactly the bytes, which form valid
+        // integers once copied at an aligned address.
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                bytes.as_ptr(),
+                container.as_mut_ptr() as *mut u8,
+                bytes.len(),
+            );
+            container.set_len(len);
+        }
+        Ok(Tensor::from_container(container))
+    }
+}
+
+impl<Cont> Tensor<Cont> {
+    /// Returns the little endian representation of the elements of the tensor.
+    ///
+    /// Contrarily to [`AsRefSlice::as_bytes`], which returns the bytes in the native byte order,
+    /// the output is the same on every platform.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container(vec![0x0102u16, 0x0304]);
+    /// assert_eq!(tensor.to_le_bytes_vec(), vec![2, 1, 4, 3]);
+    /// ```
+    pub fn to_le_bytes_vec<Element>(&self) -> Vec<u8>
+    where
+        Self: AsRefSlice<Element = Element>,
+        Element: ByteElement,
+    {
+        let slice = self.as_slice();
+        let mut bytes = Vec::with_capacity(std::mem::size_of_val(slice));
+        for element in slice.iter() {
+            element.extend_le_bytes(&mut bytes);
+        }
+        bytes
+    }
+}
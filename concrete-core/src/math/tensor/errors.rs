@@ -13,6 +13,8 @@ pub enum SaveError {
         filename: String,
         source: bincode::Error,
     },
+    /// The error occurred when writing to a stream.
+    WritingStream { source: bincode::Error },
 }
 
 impl fmt::Display for SaveError {
@@ -24,6 +26,7 @@ impl fmt::Display for SaveError {
             Self::WritingFile { filename, source } => {
                 write!(f, "Failed to write file {}: {}", filename, source)
             }
+            Self::WritingStream { source } => write!(f, "Failed to write stream: {}", source),
         }
     }
 }
@@ -33,6 +36,7 @@ impl std::error::Error for SaveError {
         match self {
             Self::CreatingFile { source, .. } => Some(source),
             Self::WritingFile { source, .. } => Some(source),
+            Self::WritingStream { source } => Some(source),
         }
     }
 }
@@ -50,6 +54,8 @@ pub enum LoadError {
         filename: String,
         source: bincode::Error,
     },
+    /// The error occurred when reading from a stream.
+    ReadingStream { source: bincode::Error },
 }
 
 impl fmt::Display for LoadError {
@@ -61,6 +67,7 @@ impl fmt::Display for LoadError {
             Self::ReadingFile { filename, source } => {
                 write!(f, "Failed to read file {}: {}", filename, source)
             }
+            Self::ReadingStream { source } => write!(f, "Failed to read stream: {}", source),
         }
     }
 }
@@ -70,6 +77,7 @@ impl std::error::Error for LoadError {
         match self {
             Self::OpeningFile { source, .. } => Some(source),
             Self::ReadingFile { source, .. } => Some(source),
+            Self::ReadingStream { source } => Some(source),
         }
     }
 }
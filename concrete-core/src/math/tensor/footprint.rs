@@ -0,0 +1,58 @@
+use fftw::array::AlignedVec;
+
+use super::Tensor;
+
+/// A trait for types owning heap memory.
+///
+/// The reported size only accounts for the memory allocated on the heap by the value, and not
+/// for the size of the value itself. For a tensor-backed type, this is the length of the
+/// container multiplied by the size of its elements. Types made of several heap-allocated
+/// buffers report the sum of the sizes of those buffers.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::crypto::lwe::LweCiphertext;
+/// use concrete_core::crypto::LweSize;
+/// use concrete_core::math::tensor::MemoryFootprint;
+/// let ciphertext = LweCiphertext::allocate(0 as u64, LweSize(101));
+/// assert_eq!(ciphertext.heap_size(), 101 * 8);
+/// ```
+pub trait MemoryFootprint {
+    /// Returns the number of bytes allocated on the heap by the value.
+    fn heap_size(&self) -> usize;
+}
+
+impl<Element> MemoryFootprint for Tensor<Vec<Element>> {
+    fn heap_size(&self) -> usize {
+        self.len() * std::mem::size_of::<Element>()
+    }
+}
+
+impl<Element> MemoryFootprint for Tensor<AlignedVec<Element>> {
+    fn heap_size(&self) -> usize {
+        self.len() * std::mem::size_of::<Element>()
+    }
+}
+
+impl<T: MemoryFootprint> MemoryFootprint for Vec<T> {
+    fn heap_size(&self) -> usize {
+        self.len() * std::mem::size_of::<T>() + self.iter().map(T::heap_size).sum::<usize>()
+    }
+}
+
+/// Returns the total number of bytes allocated on the heap by a collection of values.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::crypto::lwe::{LweCiphertext, LweList};
+/// use concrete_core::crypto::{CiphertextCount, LweSize};
+/// use concrete_core::math::tensor::{total_memory_footprint, MemoryFootprint};
+/// let ciphertext = LweCiphertext::allocate(0 as u32, LweSize(11));
+/// let list = LweList::allocate(0 as u32, LweSize(11), CiphertextCount(3));
+/// assert_eq!(total_memory_footprint(&[&ciphertext, &list]), 4 * 11 * 4);
+/// ```
+pub fn total_memory_footprint(values: &[&dyn MemoryFootprint]) -> usize {
+    values.iter().map(|value| value.heap_size()).sum()
+}
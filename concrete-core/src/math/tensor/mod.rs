@@ -200,6 +200,28 @@ macro_rules! tensor_traits {
     };
 }
 
+// This macro implements the `MemoryFootprint` trait for the owned variants of a tensor-based
+// object, whose heap memory is entirely held by its `tensor` field.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! tensor_memory_footprint {
+    ($Type:ident) => {
+        impl<Element> $crate::math::tensor::MemoryFootprint for $Type<Vec<Element>> {
+            fn heap_size(&self) -> usize {
+                $crate::math::tensor::MemoryFootprint::heap_size(&self.tensor)
+            }
+        }
+
+        impl<Element> $crate::math::tensor::MemoryFootprint
+            for $Type<fftw::array::AlignedVec<Element>>
+        {
+            fn heap_size(&self) -> usize {
+                $crate::math::tensor::MemoryFootprint::heap_size(&self.tensor)
+            }
+        }
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! current_func_path {
@@ -275,3 +297,17 @@ pub use as_tensor::*;
 
 mod into_tensor;
 pub use into_tensor::*;
+
+mod footprint;
+pub use footprint::*;
+
+mod binary;
+pub use binary::*;
+
+mod bytes;
+pub use bytes::*;
+
+#[cfg(feature = "npy")]
+mod npy;
+#[cfg(feature = "npy")]
+pub use npy::*;
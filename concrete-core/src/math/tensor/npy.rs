@@ -0,0 +1,292 @@
+use std::fmt;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use super::{AsRefSlice, Tensor};
+
+// The magic string starting every npy file.
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+// The npy headers are padded so that the data starts at a multiple of this alignment.
+const ALIGNMENT: usize = 64;
+
+/// A type that represents an error when writing or reading a tensor in the npy format.
+#[derive(Debug)]
+pub enum NpyError {
+    /// The error occurred when creating, opening, writing or reading the file.
+    Io {
+        filename: String,
+        source: std::io::Error,
+    },
+    /// The file is not a valid npy file.
+    InvalidHeader { filename: String, reason: String },
+    /// The data type of the file does not match the element type of the tensor.
+    UnsupportedDtype {
+        filename: String,
+        expected: &'static str,
+        found: String,
+    },
+    /// The shape of the array stored in the file does not match the expected shape.
+    ShapeMismatch {
+        filename: String,
+        expected: String,
+        found: Vec<usize>,
+    },
+}
+
+impl fmt::Display for NpyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { filename, source } => {
+                write!(f, "Failed to access file {}: {}", filename, source)
+            }
+            Self::InvalidHeader { filename, reason } => {
+                write!(f, "Invalid npy header in file {}: {}", filename, reason)
+            }
+            Self::UnsupportedDtype {
+                filename,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Unsupported dtype in file {}: expected {}, found {}",
+                filename, expected, found
+            ),
+            Self::ShapeMismatch {
+                filename,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Unexpected shape in file {}: expected {}, found {:?}",
+                filename, expected, found
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NpyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// A trait for the element types that can be stored in an npy file.
+///
+/// This trait is implemented for `u32`, `u64`, `i64` and `f64`, which are stored in little endian
+/// with the `<u4`, `<u8`, `<i8` and `<f8` numpy dtypes respectively.
+pub trait NpyElement: Copy + Sized {
+    /// The numpy description of the type.
+    const DESCR: &'static str;
+
+    /// The number of bytes of an element.
+    const SIZE: usize;
+
+    /// Appends the little endian representation of the element to a buffer.
+    fn write_le(self, buffer: &mut Vec<u8>);
+
+    /// Reads an element from its little endian representation.
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! implement_npy_element {
+    ($Type: ty, $descr: expr) => {
+        impl NpyElement for $Type {
+            const DESCR: &'static str = $descr;
+            const SIZE: usize = std::mem::size_of::<$Type>();
+
+            fn write_le(self, buffer: &mut Vec<u8>) {
+                buffer.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn read_le(bytes: &[u8]) -> Self {
+                let mut array = [0u8; std::mem::size_of::<$Type>()];
+                array.copy_from_slice(bytes);
+                <$Type>::from_le_bytes(array)
+            }
+        }
+    };
+}
+
+implement_npy_element!(u32, "<u4");
+implement_npy_element!(u64, "<u8");
+implement_npy_element!(i64, "<i8");
+implement_npy_element!(f64, "<f8");
+
+impl<Cont> Tensor<Cont> {
+    /// Writes the tensor to a file in the npy format, as a one-dimensional array.
+    ///
+    /// The file uses the version 1.0 of the format, and can be read with `numpy.load`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container(vec![1u64, 2, 3]);
+    /// let path = std::env::temp_dir().join("concrete_write_npy_example.npy");
+    /// tensor.write_npy(&path).unwrap();
+    /// let recovered: Tensor<Vec<u64>> = Tensor::read_npy(&path).unwrap();
+    /// assert_eq!(tensor, recovered);
+    /// ```
+    pub fn write_npy<Element, P: AsRef<Path>>(&self, path: P) -> Result<(), NpyError>
+    where
+        Self: AsRefSlice<Element = Element>,
+        Element: NpyElement,
+    {
+        let filename = path.as_ref().to_string_lossy().into_owned();
+        let slice = self.as_slice();
+        let mut buffer = npy_header(Element::DESCR, slice.len());
+        buffer.reserve(slice.len() * Element::SIZE);
+        for &element in slice.iter() {
+            element.write_le(&mut buffer);
+        }
+        std::fs::File::create(path.as_ref())
+            .and_then(|mut file| file.write_all(&buffer))
+            .map_err(|source| NpyError::Io { filename, source })
+    }
+}
+
+impl<Element> Tensor<Vec<Element>>
+where
+    Element: NpyElement,
+{
+    /// Reads a tensor from a one-dimensional array stored in the npy format.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the file can not be read, if the array it contains does not have
+    /// the dtype matching `Element`, or if it has more than one dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::math::tensor::{NpyError, Tensor};
+    /// let tensor = Tensor::from_container(vec![1.5f64, -2.5]);
+    /// let path = std::env::temp_dir().join("concrete_read_npy_example.npy");
+    /// tensor.write_npy(&path).unwrap();
+    /// let recovered: Tensor<Vec<f64>> = Tensor::read_npy(&path).unwrap();
+    /// assert_eq!(tensor, recovered);
+    /// let error = Tensor::<Vec<u32>>::read_npy(&path).unwrap_err();
+    /// assert!(matches!(error, NpyError::UnsupportedDtype { .. }));
+    /// ```
+    pub fn read_npy<P: AsRef<Path>>(path: P) -> Result<Self, NpyError> {
+        let filename = path.as_ref().to_string_lossy().into_owned();
+        let mut bytes = Vec::new();
+        std::fs::File::open(path.as_ref())
+            .and_then(|mut file| file.read_to_end(&mut bytes))
+            .map_err(|source| NpyError::Io {
+                filename: filename.clone(),
+                source,
+            })?;
+        let invalid = |reason: &str| NpyError::InvalidHeader {
+            filename: filename.clone(),
+            reason: reason.into(),
+        };
+
+        // We parse the preamble, whose header length field depends on the version.
+        if bytes.len() < 10 || &bytes[..6] != MAGIC {
+            return Err(invalid("missing magic string"));
+        }
+        let (header_start, header_len) = match bytes[6] {
+            1 => (10, u16::from_le_bytes([bytes[8], bytes[9]]) as usize),
+            2 | 3 if bytes.len() >= 12 => (
+                12,
+                u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize,
+            ),
+            _ => return Err(invalid("unsupported format version")),
+        };
+        let data_start = header_start + header_len;
+        if bytes.len() < data_start {
+            return Err(invalid("truncated header"));
+        }
+        let header = std::str::from_utf8(&bytes[header_start..data_start])
+            .map_err(|_| invalid("header is not valid text"))?;
+        let (descr, shape) = parse_header(header).map_err(invalid)?;
+
+        if descr != Element::DESCR {
+            return Err(NpyError::UnsupportedDtype {
+                filename: filename.clone(),
+                expected: Element::DESCR,
+                found: descr,
+            });
+        }
+        if shape.len() != 1 {
+            return Err(NpyError::ShapeMismatch {
+                filename: filename.clone(),
+                expected: "a one-dimensional array".into(),
+                found: shape,
+            });
+        }
+        let data = &bytes[data_start..];
+        let expected_len = shape[0]
+            .checked_mul(Element::SIZE)
+            .ok_or_else(|| invalid("shape is too large"))?;
+        if data.len() != expected_len {
+            return Err(invalid("data length does not match the shape"));
+        }
+        Ok(Tensor::from_container(
+            data.chunks_exact(Element::SIZE)
+                .map(Element::read_le)
+                .collect(),
+        ))
+    }
+}
+
+// Returns the preamble and header of a version 1.0 npy file storing a one-dimensional array.
+fn npy_header(descr: &str, len: usize) -> Vec<u8> {
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': False, 'shape': ({},), }}",
+        descr, len
+    );
+    // The header is terminated by a newline, and padded with spaces so that the data is aligned.
+    let unpadded = MAGIC.len() + 4 + header.len() + 1;
+    let padding = (ALIGNMENT - unpadded % ALIGNMENT) % ALIGNMENT;
+    header.extend(std::iter::repeat_n(' ', padding));
+    header.push('\n');
+    let mut output = Vec::with_capacity(MAGIC.len() + 4 + header.len());
+    output.extend_from_slice(MAGIC);
+    output.extend_from_slice(&[1, 0]);
+    output.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    output.extend_from_slice(header.as_bytes());
+    output
+}
+
+// Extracts the dtype description and the shape from an npy header. The memory order is only
+// validated, since it has no effect on one-dimensional arrays.
+fn parse_header(header: &str) -> Result<(String, Vec<usize>), &'static str> {
+    let value_of = |key: &str| {
+        let pattern = format!("'{}':", key);
+        header
+            .find(&pattern)
+            .map(|index| header[index + pattern.len()..].trim_start())
+            .ok_or("missing key in header")
+    };
+
+    let descr = value_of("descr")?;
+    let descr = descr
+        .strip_prefix('\'')
+        .and_then(|rest| rest.split('\'').next())
+        .ok_or("invalid descr")?
+        .to_string();
+
+    let fortran_order = value_of("fortran_order")?;
+    if !fortran_order.starts_with("True") && !fortran_order.starts_with("False") {
+        return Err("invalid fortran_order");
+    }
+
+    let shape = value_of("shape")?;
+    let shape = shape
+        .strip_prefix('(')
+        .and_then(|rest| rest.split(')').next())
+        .ok_or("invalid shape")?
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| dim.parse::<usize>().map_err(|_| "invalid shape"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((descr, shape))
+}
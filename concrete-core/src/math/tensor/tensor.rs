@@ -8,7 +8,7 @@ use std::slice::SliceIndex;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
-use crate::numeric::{CastFrom, UnsignedInteger};
+use crate::numeric::{CastFrom, Numeric, UnsignedInteger};
 use crate::zip;
 
 use super::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, LoadError, SaveError};
@@ -134,7 +134,8 @@ macro_rules! fill_with {
 }
 
 macro_rules! fill_with_wrapping {
-    ($name: ident, $($func:tt)*) => {
+    ($(#[$attr:meta])* $name: ident, $($func:tt)*) => {
+            $(#[$attr])*
             pub fn $name<Lhs, Rhs, Element>(
                 &mut self,
                 lhs: &Tensor<Lhs>,
@@ -170,7 +171,8 @@ macro_rules! update_with {
 }
 
 macro_rules! update_with_wrapping {
-    ($name: ident, $($func:tt)*) => {
+    ($(#[$attr:meta])* $name: ident, $($func:tt)*) => {
+            $(#[$attr])*
             pub fn $name<Other, Element>(
                 &mut self,
                 other: &Tensor<Other>,
@@ -751,6 +753,11 @@ impl<Container> Tensor<Container> {
     /// for scalar in t1.iter(){
     ///     assert_eq!(*scalar, 3);
     /// }
+    /// // The closure can combine the elements with any other value.
+    /// t1.fill_with_two(&t2, &t3, |t2, t3| t2.wrapping_add(t3.wrapping_mul(200)));
+    /// for scalar in t1.iter(){
+    ///     assert_eq!(*scalar, 145);
+    /// }
     /// ```
     pub fn fill_with_two<Cont1, Cont2>(
         &mut self,
@@ -865,6 +872,108 @@ impl<Container> Tensor<Container> {
         self.fill_with_one(other, |a| <Self as AsMutSlice>::Element::cast_from(*a));
     }
 
+    /// Fills a mutable tensor by casting the elements of another one to a different integer
+    /// width, while keeping their torus interpretation.
+    ///
+    /// When narrowing, the most significant bits are kept, and the result is rounded to the
+    /// nearest value, with the halfway values rounded up. When widening, the elements are shifted
+    /// to the most significant bits of the output, which is exact.
+    ///
+    /// ```
+    /// use concrete_core::math::tensor::{AsRefSlice, Tensor};
+    /// let t1 = Tensor::from_container(vec![0x1280u16, 0x127f, 0xff80]);
+    /// let mut t2 = Tensor::allocate(0u8, 3);
+    /// t2.fill_with_cast_rescale(&t1);
+    /// assert_eq!(t2.as_slice(), &[0x13, 0x12, 0x00]);
+    /// let mut t3 = Tensor::allocate(0u16, 3);
+    /// t3.fill_with_cast_rescale(&t2);
+    /// assert_eq!(t3.as_slice(), &[0x1300, 0x1200, 0x0000]);
+    /// ```
+    pub fn fill_with_cast_rescale<Cont>(&mut self, other: &Tensor<Cont>)
+    where
+        Self: AsMutSlice,
+        Tensor<Cont>: AsRefSlice,
+        <Self as AsMutSlice>::Element:
+            UnsignedInteger + CastFrom<<Tensor<Cont> as AsRefSlice>::Element>,
+        <Tensor<Cont> as AsRefSlice>::Element: UnsignedInteger,
+    {
+        ck_dim_eq!(self.len() => other.len());
+        let input_bits = <Tensor<Cont> as AsRefSlice>::Element::BITS;
+        let output_bits = <Self as AsMutSlice>::Element::BITS;
+        if input_bits > output_bits {
+            let shift = input_bits - output_bits;
+            // A carry out of the most significant bit wraps around the torus when truncated.
+            self.fill_with_one(other, |a| {
+                let rounding = (*a >> (shift - 1)) & <Tensor<Cont> as AsRefSlice>::Element::ONE;
+                <Self as AsMutSlice>::Element::cast_from((*a >> shift).wrapping_add(rounding))
+            });
+        } else {
+            let shift = output_bits - input_bits;
+            self.fill_with_one(other, |a| {
+                <Self as AsMutSlice>::Element::cast_from(*a) << shift
+            });
+        }
+    }
+
+    /// Copies the values of another tensor into the current one, without allocating.
+    ///
+    /// This is the tensor counterpart of `slice::copy_from_slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two tensors do not have the same length.
+    ///
+    /// ```
+    /// use concrete_core::math::tensor::Tensor;
+    /// let mut t1 = Tensor::allocate(9 as u8, 1000);
+    /// let t2 = Tensor::from_container(vec![3 as u8; 1000]);
+    /// t1.copy_from(&t2);
+    /// assert_eq!(t1, t2);
+    /// ```
+    pub fn copy_from<OtherCont, Element>(&mut self, other: &Tensor<OtherCont>)
+    where
+        Self: AsMutSlice<Element = Element>,
+        Tensor<OtherCont>: AsRefSlice<Element = Element>,
+        Element: Copy,
+    {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "Tried to copy a tensor of length {} into a tensor of length {}.",
+            other.len(),
+            self.len()
+        );
+        self.as_mut_slice().copy_from_slice(other.as_slice());
+    }
+
+    /// Fills a mutable tensor with a copy of the values of another one.
+    ///
+    /// This is the same operation as [`Tensor::copy_from`], named after the other `fill_with_*`
+    /// methods. It is typically used to re-initialize a tensor allocated once, and reused for many
+    /// operations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two tensors do not have the same length.
+    ///
+    /// ```
+    /// use concrete_core::math::tensor::Tensor;
+    /// let mut t1 = Tensor::allocate(9 as u8, 1000);
+    /// for i in 0..10 {
+    ///     let t2 = Tensor::from_container(vec![i as u8; 1000]);
+    ///     t1.fill_with_copy_from(&t2);
+    ///     assert_eq!(t1, t2);
+    /// }
+    /// ```
+    pub fn fill_with_copy_from<OtherCont, Element>(&mut self, other: &Tensor<OtherCont>)
+    where
+        Self: AsMutSlice<Element = Element>,
+        Tensor<OtherCont>: AsRefSlice<Element = Element>,
+        Element: Copy,
+    {
+        self.copy_from(other);
+    }
+
     fill_with!(Add, fill_with_add, |l, r| *l + *r);
     fill_with!(Sub, fill_with_sub, |l, r| *l - *r);
     fill_with!(Mul, fill_with_mul, |l, r| *l * *r);
@@ -878,7 +987,25 @@ impl<Container> Tensor<Container> {
 
     fill_with_wrapping!(fill_with_wrapping_add, |l, r| l.wrapping_add(*r));
     fill_with_wrapping!(fill_with_wrapping_sub, |l, r| l.wrapping_sub(*r));
-    fill_with_wrapping!(fill_with_wrapping_mul, |l, r| l.wrapping_mul(*r));
+    fill_with_wrapping!(
+        /// Fills a mutable tensor with the element-wise wrapping product of two other tensors of
+        /// the same size.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use concrete_core::math::tensor::Tensor;
+        /// let mut t1 = Tensor::allocate(9 as u8, 1000);
+        /// let t2 = Tensor::allocate(16 as u8, 1000);
+        /// let t3 = Tensor::allocate(17 as u8, 1000);
+        /// t1.fill_with_wrapping_mul(&t2, &t3);
+        /// for scalar in t1.iter() {
+        ///     assert_eq!(*scalar, 16);
+        /// }
+        /// ```
+        fill_with_wrapping_mul,
+        |l, r| l.wrapping_mul(*r)
+    );
     fill_with_wrapping!(fill_with_wrapping_div, |l, r| l.wrapping_div(*r));
 
     /// Updates two tensors with the result of the operation with a single one.
@@ -1039,7 +1166,24 @@ impl<Container> Tensor<Container> {
 
     update_with_wrapping!(update_with_wrapping_add, |s, a| *s = s.wrapping_add(*a));
     update_with_wrapping!(update_with_wrapping_sub, |s, a| *s = s.wrapping_sub(*a));
-    update_with_wrapping!(update_with_wrapping_mul, |s, a| *s = s.wrapping_mul(*a));
+    update_with_wrapping!(
+        /// Updates a mutable tensor with the element-wise wrapping product with another tensor of
+        /// the same size.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use concrete_core::math::tensor::Tensor;
+        /// let mut t1 = Tensor::allocate(16 as u8, 1000);
+        /// let t2 = Tensor::allocate(17 as u8, 1000);
+        /// t1.update_with_wrapping_mul(&t2);
+        /// for scalar in t1.iter() {
+        ///     assert_eq!(*scalar, 16);
+        /// }
+        /// ```
+        update_with_wrapping_mul,
+        |s, a| *s = s.wrapping_mul(*a)
+    );
     update_with_wrapping!(update_with_wrapping_div, |s, a| *s = s.wrapping_div(*a));
 
     update_with_scalar!(AddAssign, update_with_scalar_add, |s, a| *s += *a);
@@ -1062,6 +1206,38 @@ impl<Container> Tensor<Container> {
     update_with_wrapping_scalar!(update_with_wrapping_scalar_div, |s, a| *s =
         s.wrapping_div(*a));
 
+    /// Returns a new tensor containing the values of `self` in reverse order.
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_core::math::tensor::Tensor;
+    /// let tensor = Tensor::from_container(vec![1u8, 2, 3]);
+    /// assert_eq!(tensor.reverse(), Tensor::from_container(vec![3, 2, 1]));
+    /// ```
+    pub fn reverse(&self) -> Tensor<Vec<<Self as AsRefSlice>::Element>>
+    where
+        Self: AsRefSlice,
+        <Self as AsRefSlice>::Element: Clone,
+    {
+        self.iter().rev().cloned().collect()
+    }
+
+    /// Reverses the order of the values of `self`, in place.
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_core::math::tensor::Tensor;
+    /// let mut tensor = Tensor::from_container(vec![1u8, 2, 3]);
+    /// tensor.reverse_inplace();
+    /// assert_eq!(tensor, Tensor::from_container(vec![3, 2, 1]));
+    /// ```
+    pub fn reverse_inplace(&mut self)
+    where
+        Self: AsMutSlice,
+    {
+        self.as_mut_slice().reverse();
+    }
+
     /// Sets each value of `self` to its own opposite.
     ///
     /// # Example
@@ -1151,6 +1327,32 @@ impl<Container> Tensor<Container> {
         self.fill_with_one(tensor, |t| t.wrapping_mul(element));
     }
 
+    /// Updates the values of a mutable tensor with the element-wise wrapping product with
+    /// another tensor of the same size.
+    ///
+    /// Unlike [`Tensor::fill_with_wrapping_element_mul`], which multiplies every value by the
+    /// same element, the values are multiplied pairwise. This is the same as
+    /// [`Tensor::update_with_wrapping_mul`].
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_core::math::tensor::Tensor;
+    /// let mut t1 = Tensor::allocate(9 as u8, 1000);
+    /// let t2 = Tensor::from_container((0..1000).map(|i| i as u8).collect::<Vec<_>>());
+    /// t1.update_with_wrapping_element_mul(&t2);
+    /// for (i, scalar) in t1.iter().enumerate() {
+    ///     assert_eq!(*scalar, (i as u8).wrapping_mul(9));
+    /// }
+    /// ```
+    pub fn update_with_wrapping_element_mul<Cont, Element>(&mut self, tensor: &Tensor<Cont>)
+    where
+        Self: AsMutSlice<Element = Element>,
+        Tensor<Cont>: AsRefSlice<Element = Element>,
+        Element: UnsignedInteger,
+    {
+        self.update_with_wrapping_mul(tensor);
+    }
+
     /// Updates the values of a mutable tensor by subtracting the product of the element of
     /// another tensor and an element.
     ///
@@ -1290,6 +1492,56 @@ impl<Container> Tensor<Container> {
             .zip(other.as_slice().iter())
             .fold(acc, |acc, (s_i, o_i)| ope(acc, s_i, o_i))
     }
+
+    /// Returns the largest distance between the elements of two tensors, taken modulo the size of
+    /// the element type.
+    ///
+    /// The distance between two elements is the smallest of their two wrapping differences, so
+    /// that values on both sides of zero are close, as are torus elements around the origin. The
+    /// distance of two empty tensors is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_core::math::tensor::Tensor;
+    /// let t1 = Tensor::from_container(vec![1 as u8, 254, 100]);
+    /// let t2 = Tensor::from_container(vec![255 as u8, 2, 97]);
+    /// assert_eq!(t1.max_wrapping_distance(&t2), 4);
+    /// ```
+    pub fn max_wrapping_distance<Cont, Element>(&self, other: &Tensor<Cont>) -> Element
+    where
+        Self: AsRefSlice<Element = Element>,
+        Tensor<Cont>: AsRefSlice<Element = Element>,
+        Element: UnsignedInteger,
+    {
+        self.fold_with_one(other, Element::ZERO, |max, s, o| {
+            max.max(s.wrapping_sub(*o).min(o.wrapping_sub(*s)))
+        })
+    }
+
+    /// Returns whether all the elements of two tensors are at most at a given wrapping distance.
+    ///
+    /// See [`Tensor::max_wrapping_distance`] for the definition of the distance.
+    ///
+    /// # Example
+    /// ```
+    /// use concrete_core::math::tensor::Tensor;
+    /// let t1 = Tensor::from_container(vec![1 as u8, 254, 100]);
+    /// let t2 = Tensor::from_container(vec![255 as u8, 2, 97]);
+    /// assert!(t1.all_within_wrapping_distance(&t2, 4));
+    /// assert!(!t1.all_within_wrapping_distance(&t2, 3));
+    /// ```
+    pub fn all_within_wrapping_distance<Cont, Element>(
+        &self,
+        other: &Tensor<Cont>,
+        bound: Element,
+    ) -> bool
+    where
+        Self: AsRefSlice<Element = Element>,
+        Tensor<Cont>: AsRefSlice<Element = Element>,
+        Element: UnsignedInteger,
+    {
+        self.max_wrapping_distance(other) <= bound
+    }
 }
 
 impl<Element> FromIterator<Element> for Tensor<Vec<Element>> {
@@ -1302,6 +1554,80 @@ impl<Element> FromIterator<Element> for Tensor<Vec<Element>> {
     }
 }
 
+/// Wraps a vector in a tensor, without copying its values.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::tensor::Tensor;
+/// let tensor: Tensor<Vec<u8>> = vec![1, 2, 3].into();
+/// assert_eq!(tensor, Tensor::from_container(vec![1, 2, 3]));
+/// ```
+impl<Element> From<Vec<Element>> for Tensor<Vec<Element>> {
+    fn from(vec: Vec<Element>) -> Self {
+        Tensor(vec)
+    }
+}
+
+/// Unwraps the vector of a tensor, without copying its values.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::tensor::Tensor;
+/// let vec: Vec<u8> = Tensor::allocate(9, 3).into();
+/// assert_eq!(vec, vec![9, 9, 9]);
+/// ```
+impl<Element> From<Tensor<Vec<Element>>> for Vec<Element> {
+    fn from(tensor: Tensor<Vec<Element>>) -> Self {
+        tensor.0
+    }
+}
+
+/// Consumes the tensor, and iterates over its values.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::tensor::Tensor;
+/// let tensor = Tensor::from_container(vec![String::from("a"), String::from("b")]);
+/// let mut joined = String::new();
+/// for value in tensor {
+///     joined.push_str(&value);
+/// }
+/// assert_eq!(joined, "ab");
+/// ```
+impl<Element> IntoIterator for Tensor<Vec<Element>> {
+    type Item = Element;
+    type IntoIter = std::vec::IntoIter<Element>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Iterates over references to the values of the tensor.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::math::tensor::Tensor;
+/// let tensor = Tensor::from_container(vec![1u8, 2, 3]);
+/// let mut sum = 0;
+/// for value in &tensor {
+///     sum += *value;
+/// }
+/// assert_eq!(sum, 6);
+/// ```
+impl<'a, Element> IntoIterator for &'a Tensor<Vec<Element>> {
+    type Item = &'a Element;
+    type IntoIter = std::slice::Iter<'a, Element>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
 impl<Cont> AsRefSlice for Tensor<Cont>
 where
     Cont: AsRefSlice,
@@ -28,6 +28,198 @@ save_load_unsigned!(u32, tests_u32);
 save_load_unsigned!(u64, tests_u64);
 save_load_unsigned!(u128, tests_u128);
 
+fn test_binary_round_trip<T>()
+where
+    T: crate::numeric::UnsignedInteger
+        + crate::numeric::CastInto<u8>
+        + crate::numeric::CastFrom<u8>
+        + crate::math::random::RandomGenerable<crate::math::random::Uniform>
+        + std::fmt::Debug,
+{
+    // We cover an empty tensor, and a tensor written in several chunks.
+    for len in &[0, 10, 100_000] {
+        let tensor = crate::math::random::random_uniform_tensor::<T>(*len);
+        let mut bytes = Vec::new();
+        tensor.write_to(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 20 + len * T::BITS / 8);
+        let recovered: Tensor<Vec<T>> = Tensor::read_from(bytes.as_slice()).unwrap();
+        assert_eq!(tensor, recovered);
+    }
+}
+
+#[test]
+fn test_binary_round_trip_u32() {
+    test_binary_round_trip::<u32>();
+}
+
+#[test]
+fn test_binary_round_trip_u64() {
+    test_binary_round_trip::<u64>();
+}
+
+#[test]
+fn test_binary_little_endian() {
+    let mut bytes = Vec::new();
+    Tensor::from_container(vec![0x0102_0304u32])
+        .write_to(&mut bytes)
+        .unwrap();
+    assert_eq!(&bytes[..8], b"CNCRTTSR");
+    assert_eq!(&bytes[8..12], &[1, 0, 0, 4]);
+    assert_eq!(&bytes[12..20], &1u64.to_le_bytes());
+    assert_eq!(&bytes[20..], &[4, 3, 2, 1]);
+}
+
+#[test]
+fn test_binary_corrupted_header() {
+    use super::BinaryError;
+
+    let mut bytes = Vec::new();
+    Tensor::from_container(vec![1u64, 2, 3])
+        .write_to(&mut bytes)
+        .unwrap();
+    let read = |bytes: &[u8]| Tensor::<Vec<u64>>::read_from(bytes).unwrap_err();
+
+    let mut corrupted = bytes.clone();
+    corrupted[0] = b'X';
+    assert!(matches!(read(&corrupted), BinaryError::InvalidMagic));
+
+    let mut corrupted = bytes.clone();
+    corrupted[8] = 2;
+    assert!(matches!(
+        read(&corrupted),
+        BinaryError::UnsupportedVersion { found: 2 }
+    ));
+
+    let mut corrupted = bytes.clone();
+    corrupted[10] = 1;
+    assert!(matches!(
+        read(&corrupted),
+        BinaryError::UnsupportedEndianness { found: 1 }
+    ));
+
+    let mut corrupted = bytes.clone();
+    corrupted[11] = 2;
+    assert!(matches!(
+        read(&corrupted),
+        BinaryError::ElementWidthMismatch {
+            expected: 8,
+            found: 2
+        }
+    ));
+    assert!(matches!(
+        Tensor::<Vec<u32>>::read_from(bytes.as_slice()).unwrap_err(),
+        BinaryError::ElementWidthMismatch {
+            expected: 4,
+            found: 8
+        }
+    ));
+
+    // A count larger than the data, and a truncated header.
+    let mut corrupted = bytes.clone();
+    corrupted[12..20].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(matches!(read(&corrupted), BinaryError::Io { .. }));
+    assert!(matches!(read(&bytes[..10]), BinaryError::Io { .. }));
+}
+
+fn test_byte_views<T>()
+where
+    T: crate::numeric::UnsignedInteger
+        + super::ByteElement
+        + crate::math::random::RandomGenerable<crate::math::random::Uniform>
+        + std::fmt::Debug,
+{
+    use super::{AsMutSlice, AsRefSlice};
+
+    let mut tensor = crate::math::random::random_uniform_tensor::<T>(100);
+    let size = T::BITS / 8;
+    assert_eq!(tensor.as_bytes().len(), 100 * size);
+    let view: Tensor<&[T]> = Tensor::from_byte_slice(tensor.as_bytes()).unwrap();
+    assert_eq!(view.as_slice(), tensor.as_slice());
+    let copy: Tensor<Vec<T>> = Tensor::from_byte_container(tensor.as_bytes().to_vec()).unwrap();
+    assert_eq!(copy, tensor);
+
+    // The little endian bytes match the native bytes up to the order of each element.
+    let le_bytes = tensor.to_le_bytes_vec();
+    for (native, le) in tensor.as_bytes().chunks(size).zip(le_bytes.chunks(size)) {
+        if cfg!(target_endian = "little") {
+            assert_eq!(native, le);
+        } else {
+            assert!(native.iter().eq(le.iter().rev()));
+        }
+    }
+
+    for byte in tensor.as_mut_bytes().iter_mut() {
+        *byte = 0xff;
+    }
+    assert!(tensor.iter().all(|element| *element == T::MAX));
+}
+
+#[test]
+fn test_byte_views_u32() {
+    test_byte_views::<u32>();
+}
+
+#[test]
+fn test_byte_views_u64() {
+    test_byte_views::<u64>();
+}
+
+#[test]
+fn test_byte_views_invalid_casts() {
+    use super::{AsRefSlice, CastError};
+
+    let tensor = Tensor::from_container(vec![1u64, 2, 3]);
+    let bytes = tensor.as_bytes();
+    assert_eq!(
+        Tensor::<&[u64]>::from_byte_slice(&bytes[1..9]).unwrap_err(),
+        CastError::Misaligned { alignment: 8 }
+    );
+    assert_eq!(
+        Tensor::<&[u64]>::from_byte_slice(&bytes[..12]).unwrap_err(),
+        CastError::LengthMismatch {
+            len: 12,
+            element_size: 8
+        }
+    );
+    assert_eq!(
+        Tensor::<Vec<u32>>::from_byte_container(bytes[..6].to_vec()).unwrap_err(),
+        CastError::LengthMismatch {
+            len: 6,
+            element_size: 4
+        }
+    );
+}
+
+fn test_wrapping_distance<T: crate::numeric::UnsignedInteger + std::fmt::Debug>() {
+    // The values straddle zero and the largest value, in both orders.
+    let t1 = Tensor::from_container(vec![T::ZERO, T::MAX, T::ONE, T::MAX - T::TWO, T::MAX]);
+    let t2 = Tensor::from_container(vec![T::MAX, T::ONE, T::MAX - T::ONE, T::TWO, T::MAX]);
+    assert_eq!(t1.max_wrapping_distance(&t2), T::TWO + T::TWO + T::ONE);
+    assert_eq!(t2.max_wrapping_distance(&t1), T::TWO + T::TWO + T::ONE);
+    assert!(t1.all_within_wrapping_distance(&t2, T::TWO + T::TWO + T::ONE));
+    assert!(!t1.all_within_wrapping_distance(&t2, T::TWO + T::TWO));
+
+    // The distance of two opposite values is half the modulus.
+    let half = T::ONE << (T::BITS - 1);
+    let t1 = Tensor::from_container(vec![T::ZERO, half]);
+    let t2 = Tensor::from_container(vec![half, T::ZERO]);
+    assert_eq!(t1.max_wrapping_distance(&t2), half);
+    assert_eq!(t1.max_wrapping_distance(&t1), T::ZERO);
+
+    let empty = Tensor::<Vec<T>>::from_container(vec![]);
+    assert_eq!(empty.max_wrapping_distance(&empty), T::ZERO);
+}
+
+#[test]
+fn test_wrapping_distance_u32() {
+    test_wrapping_distance::<u32>();
+}
+
+#[test]
+fn test_wrapping_distance_u64() {
+    test_wrapping_distance::<u64>();
+}
+
 #[test]
 fn test_add_u32() {
     let t_1 = Tensor::from_container(vec![
@@ -1283,3 +1475,259 @@ fn test_add_u64() {
 
     assert_eq!(t_3, ground_truth_t_3, "we are testing u64 add");
 }
+
+#[test]
+fn test_copy_from() {
+    let mut tensor = Tensor::allocate(0 as u32, 100);
+    let other = Tensor::from_container((0..100).collect::<Vec<u32>>());
+    tensor.copy_from(&other);
+    assert_eq!(tensor, other);
+}
+
+#[test]
+#[should_panic]
+fn test_copy_from_length_mismatch() {
+    let mut tensor = Tensor::allocate(0u32, 100);
+    let other = Tensor::allocate(1u32, 10);
+    tensor.copy_from(&other);
+}
+
+fn test_memory_footprint<T: crate::crypto::UnsignedTorus>() {
+    use super::{total_memory_footprint, AsRefTensor, MemoryFootprint};
+    use crate::crypto::bootstrap::BootstrapKey;
+    use crate::crypto::encoding::PlaintextList;
+    use crate::crypto::ggsw::GgswCiphertext;
+    use crate::crypto::glwe::{GlweCiphertext, GlweList};
+    use crate::crypto::lwe::{LweCiphertext, LweKeyswitchKey, LweList};
+    use crate::crypto::secret::{GlweSecretKey, LweSecretKey};
+    use crate::crypto::{CiphertextCount, GlweDimension, LweDimension, LweSize, PlaintextCount};
+    use crate::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+    use crate::math::fft::Complex64;
+    use crate::math::polynomial::PolynomialSize;
+
+    fn check<V>(value: &V)
+    where
+        V: MemoryFootprint + AsRefTensor,
+    {
+        assert_eq!(
+            value.heap_size(),
+            value.as_tensor().len() * std::mem::size_of::<V::Element>()
+        );
+    }
+
+    let size = std::mem::size_of::<T>();
+    let lwe_dimension = LweDimension(10);
+    let glwe_dimension = GlweDimension(2);
+    let poly_size = PolynomialSize(16);
+    let level = DecompositionLevelCount(3);
+    let base_log = DecompositionBaseLog(4);
+
+    check(&LweSecretKey::generate(lwe_dimension));
+    check(&GlweSecretKey::generate(glwe_dimension, poly_size));
+    let lwe = LweCiphertext::allocate(T::ZERO, LweSize(11));
+    assert_eq!(lwe.heap_size(), 11 * size);
+    check(&lwe);
+    let lwe_list = LweList::allocate(T::ZERO, LweSize(11), CiphertextCount(5));
+    assert_eq!(lwe_list.heap_size(), 5 * 11 * size);
+    check(&lwe_list);
+    check(&GlweCiphertext::allocate(
+        T::ZERO,
+        poly_size,
+        glwe_dimension.to_glwe_size(),
+    ));
+    check(&GlweList::allocate(
+        T::ZERO,
+        poly_size,
+        glwe_dimension,
+        CiphertextCount(4),
+    ));
+    check(&GgswCiphertext::allocate(
+        T::ZERO,
+        poly_size,
+        glwe_dimension.to_glwe_size(),
+        level,
+        base_log,
+    ));
+    check(&LweKeyswitchKey::allocate(
+        T::ZERO,
+        level,
+        base_log,
+        lwe_dimension,
+        LweDimension(5),
+    ));
+    check(&PlaintextList::allocate(T::ZERO, PlaintextCount(7)));
+    let bsk = BootstrapKey::allocate(
+        T::ZERO,
+        glwe_dimension.to_glwe_size(),
+        poly_size,
+        level,
+        base_log,
+        lwe_dimension,
+    );
+    check(&bsk);
+    let fourier_bsk = BootstrapKey::allocate_complex(
+        Complex64::new(0., 0.),
+        glwe_dimension.to_glwe_size(),
+        poly_size,
+        level,
+        base_log,
+        lwe_dimension,
+    );
+    assert_eq!(
+        fourier_bsk.heap_size(),
+        fourier_bsk.as_tensor().len() * std::mem::size_of::<Complex64>()
+    );
+
+    assert_eq!(
+        total_memory_footprint(&[&lwe, &lwe_list, &bsk]),
+        lwe.heap_size() + lwe_list.heap_size() + bsk.heap_size()
+    );
+}
+
+#[test]
+fn test_memory_footprint_u32() {
+    test_memory_footprint::<u32>();
+}
+
+#[test]
+fn test_memory_footprint_u64() {
+    test_memory_footprint::<u64>();
+}
+
+#[cfg(feature = "npy")]
+mod npy {
+    use std::path::PathBuf;
+
+    use super::super::{NpyElement, NpyError, Tensor};
+    use crate::math::random;
+
+    fn temporary_path() -> PathBuf {
+        std::env::temp_dir().join(format!("{}.npy", rand::random::<usize>()))
+    }
+
+    fn test_round_trip<T: NpyElement + PartialEq + std::fmt::Debug>(tensor: Tensor<Vec<T>>) {
+        let path = temporary_path();
+        tensor.write_npy(&path).unwrap();
+        let recovered: Tensor<Vec<T>> = Tensor::read_npy(&path).unwrap();
+        assert_eq!(tensor, recovered);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_round_trip_u32() {
+        test_round_trip(random::random_uniform_tensor::<u32>(1000));
+    }
+
+    #[test]
+    fn test_round_trip_u64() {
+        test_round_trip(random::random_uniform_tensor::<u64>(1000));
+    }
+
+    #[test]
+    fn test_round_trip_i64() {
+        test_round_trip(random::random_uniform_tensor::<i64>(1000));
+    }
+
+    #[test]
+    fn test_round_trip_f64() {
+        let tensor: Tensor<Vec<f64>> = random::random_uniform_tensor::<u64>(1000)
+            .iter()
+            .map(|x| *x as f64 / u64::MAX as f64 - 0.5)
+            .collect();
+        test_round_trip(tensor);
+    }
+
+    #[test]
+    fn test_round_trip_empty() {
+        test_round_trip(Tensor::<Vec<u64>>::from_container(vec![]));
+    }
+
+    // The bytes written by `numpy.save(path, numpy.array([1, 2, 3], dtype=numpy.uint64))`.
+    fn numpy_fixture() -> Vec<u8> {
+        let mut bytes =
+            b"\x93NUMPY\x01\x00\x76\x00{'descr': '<u8', 'fortran_order': False, 'shape': (3,), }"
+                .to_vec();
+        bytes.resize(127, b' ');
+        bytes.push(b'\n');
+        for value in &[1u64, 2, 3] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_numpy_interop() {
+        let path = temporary_path();
+        Tensor::from_container(vec![1u64, 2, 3])
+            .write_npy(&path)
+            .unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), numpy_fixture());
+
+        std::fs::write(&path, numpy_fixture()).unwrap();
+        let tensor: Tensor<Vec<u64>> = Tensor::read_npy(&path).unwrap();
+        assert_eq!(tensor, Tensor::from_container(vec![1u64, 2, 3]));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_unsupported_dtype() {
+        let path = temporary_path();
+        Tensor::from_container(vec![1u64, 2, 3])
+            .write_npy(&path)
+            .unwrap();
+        let error = Tensor::<Vec<u32>>::read_npy(&path).unwrap_err();
+        assert!(matches!(
+            error,
+            NpyError::UnsupportedDtype {
+                expected: "<u4",
+                ref found,
+                ..
+            } if found == "<u8"
+        ));
+        let error = Tensor::<Vec<f64>>::read_npy(&path).unwrap_err();
+        assert!(matches!(error, NpyError::UnsupportedDtype { .. }));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_shape_mismatch() {
+        // A 3x1 array, which can not be read as a tensor.
+        let path = temporary_path();
+        let mut bytes =
+            b"\x93NUMPY\x01\x00\x76\x00{'descr': '<u8', 'fortran_order': False, 'shape': (3, 1), }"
+                .to_vec();
+        bytes.resize(127, b' ');
+        bytes.push(b'\n');
+        bytes.extend_from_slice(&[0; 24]);
+        std::fs::write(&path, bytes).unwrap();
+        let error = Tensor::<Vec<u64>>::read_npy(&path).unwrap_err();
+        assert!(matches!(error, NpyError::ShapeMismatch { ref found, .. } if found == &[3, 1]));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_overflowing_shape() {
+        // 2^61 elements of 8 bytes, whose length overflows to zero.
+        let path = temporary_path();
+        let mut bytes = b"\x93NUMPY\x01\x00\x76\x00{'descr': '<u8', 'fortran_order': False, \
+            'shape': (2305843009213693952,), }"
+            .to_vec();
+        bytes.resize(127, b' ');
+        bytes.push(b'\n');
+        std::fs::write(&path, bytes).unwrap();
+        let error = Tensor::<Vec<u64>>::read_npy(&path).unwrap_err();
+        assert!(matches!(error, NpyError::InvalidHeader { .. }));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_invalid_file() {
+        let path = temporary_path();
+        std::fs::write(&path, b"not an npy file").unwrap();
+        let error = Tensor::<Vec<u64>>::read_npy(&path).unwrap_err();
+        assert!(matches!(error, NpyError::InvalidHeader { .. }));
+        std::fs::remove_file(&path).unwrap();
+        let error = Tensor::<Vec<u64>>::read_npy(&path).unwrap_err();
+        assert!(matches!(error, NpyError::Io { .. }));
+    }
+}
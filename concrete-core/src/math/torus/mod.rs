@@ -72,3 +72,41 @@ implement!(u16);
 implement!(u32);
 implement!(u64);
 implement!(u128);
+
+/// Rounds a torus element to the closest torus element representable with a smaller (or equal)
+/// number of bits.
+///
+/// This operation is the modulus switching from $\mathbb{Z}/2^{b_{in}}\mathbb{Z}$ to
+/// $\mathbb{Z}/2^{b_{out}}\mathbb{Z}$, where $b_{in}$ and $b_{out}$ are the number of bits of
+/// the input and output types.
+///
+/// # Panics
+///
+/// Panics if the output type has more bits than the input type.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_core::math::torus::switch_modulus;
+/// let input: u128 = (3 << 126) + (1 << 63);
+/// let output: u64 = switch_modulus(input);
+/// assert_eq!(output, (3 << 62) + 1);
+/// let output: u64 = switch_modulus(input - 1);
+/// assert_eq!(output, 3 << 62);
+/// ```
+pub fn switch_modulus<Input, Output>(input: Input) -> Output
+where
+    Input: UnsignedInteger + CastInto<Output>,
+    Output: UnsignedInteger,
+{
+    assert!(
+        <Output as Numeric>::BITS <= <Input as Numeric>::BITS,
+        "Can not switch to a larger modulus."
+    );
+    let shift = <Input as Numeric>::BITS - <Output as Numeric>::BITS;
+    if shift == 0 {
+        return input.cast_into();
+    }
+    let rounded = input.wrapping_add(Input::ONE << (shift - 1)) >> shift;
+    rounded.cast_into()
+}
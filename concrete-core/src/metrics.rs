@@ -0,0 +1,128 @@
+//! Operation counters and timers.
+//!
+//! When the `metrics` feature is enabled, the library counts the number of executions of its most
+//! expensive operations, and accumulates the time spent in them:
+//!
+//! + the fourier transforms performed by an [`Fft`](crate::math::fft::Fft),
+//! + the [`external_product`](crate::crypto::cross::external_product),
+//! + the keyswitches of single ciphertexts,
+//! + the [`bootstrap`](crate::crypto::cross::bootstrap).
+//!
+//! The counters are global to the process, and updated with atomic operations, which makes it
+//! possible to use them from several threads. Operations called by other instrumented operations
+//! are counted as well: a bootstrap increments the external products and fourier transforms
+//! counters too. When the feature is disabled, this module does not exist and the
+//! instrumentation is compiled out.
+//!
+//! # Example
+//!
+//! ```rust
+//! use concrete_core::math::fft::{Complex64, Fft, FourierPolynomial};
+//! use concrete_core::math::polynomial::{Polynomial, PolynomialSize};
+//! use concrete_core::metrics;
+//!
+//! metrics::reset();
+//! let mut fft = Fft::new(PolynomialSize(256));
+//! let mut fourier_poly = FourierPolynomial::allocate(Complex64::new(0., 0.), PolynomialSize(256));
+//! let poly = Polynomial::allocate(0u32, PolynomialSize(256));
+//! fft.forward_as_torus(&mut fourier_poly, &poly);
+//! let report = metrics::snapshot();
+//! assert!(report.fft.count >= 1);
+//! ```
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// The counter and timer of an operation.
+pub(crate) struct OperationCounter {
+    count: AtomicU64,
+    nanos: AtomicU64,
+}
+
+impl OperationCounter {
+    const fn new() -> Self {
+        OperationCounter {
+            count: AtomicU64::new(0),
+            nanos: AtomicU64::new(0),
+        }
+    }
+
+    /// Starts timing an execution of the operation, which is recorded when the returned timer is
+    /// dropped.
+    pub(crate) fn start(&'static self) -> OperationTimer {
+        OperationTimer {
+            counter: self,
+            start: Instant::now(),
+        }
+    }
+
+    fn read(&self) -> OperationMetrics {
+        OperationMetrics {
+            count: self.count.load(Ordering::Relaxed),
+            total_time: Duration::from_nanos(self.nanos.load(Ordering::Relaxed)),
+        }
+    }
+
+    fn reset(&self) {
+        self.count.store(0, Ordering::Relaxed);
+        self.nanos.store(0, Ordering::Relaxed);
+    }
+}
+
+/// A running execution of an operation.
+pub(crate) struct OperationTimer {
+    counter: &'static OperationCounter,
+    start: Instant,
+}
+
+impl Drop for OperationTimer {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed().as_nanos() as u64;
+        self.counter.count.fetch_add(1, Ordering::Relaxed);
+        self.counter.nanos.fetch_add(elapsed, Ordering::Relaxed);
+    }
+}
+
+pub(crate) static FFT: OperationCounter = OperationCounter::new();
+pub(crate) static EXTERNAL_PRODUCT: OperationCounter = OperationCounter::new();
+pub(crate) static KEYSWITCH: OperationCounter = OperationCounter::new();
+pub(crate) static BOOTSTRAP: OperationCounter = OperationCounter::new();
+
+/// The metrics recorded for a single operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OperationMetrics {
+    /// The number of executions of the operation.
+    pub count: u64,
+    /// The cumulative time spent in the operation.
+    pub total_time: Duration,
+}
+
+/// The metrics recorded since the start of the process, or the last call to [`reset`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MetricsReport {
+    /// The forward and backward fourier transforms.
+    pub fft: OperationMetrics,
+    /// The external products between GGSW and GLWE ciphertexts.
+    pub external_product: OperationMetrics,
+    /// The keyswitches of single LWE ciphertexts.
+    pub keyswitch: OperationMetrics,
+    /// The bootstraps of LWE ciphertexts.
+    pub bootstrap: OperationMetrics,
+}
+
+/// Returns the current value of the counters.
+pub fn snapshot() -> MetricsReport {
+    MetricsReport {
+        fft: FFT.read(),
+        external_product: EXTERNAL_PRODUCT.read(),
+        keyswitch: KEYSWITCH.read(),
+        bootstrap: BOOTSTRAP.read(),
+    }
+}
+
+/// Sets all the counters back to zero.
+pub fn reset() {
+    FFT.reset();
+    EXTERNAL_PRODUCT.reset();
+    KEYSWITCH.reset();
+    BOOTSTRAP.reset();
+}
@@ -38,6 +38,20 @@ pub trait Numeric: Sized + Copy + PartialEq + PartialOrd {
     const MAX: Self;
 }
 
+/// A trait implemented by the types whose values can be selected without branching.
+///
+/// The unsigned integers already provide such a selection with
+/// [`UnsignedInteger::ct_select`]. This trait allows the generic code to select values of the types
+/// implementing their own arithmetic, such as [`ModQ`](crate::math::modular::ModQ).
+pub trait ConstantTimeSelect: Sized {
+    /// Returns `first` if `choice` is `true`, and `second` otherwise.
+    ///
+    /// # Constant-time
+    ///
+    /// The control flow and the memory accesses do not depend on the value of `choice`.
+    fn ct_select(choice: bool, first: Self, second: Self) -> Self;
+}
+
 /// A trait that allows to generically cast one type from another.
 ///
 /// This type is similar to the [`std::convert::From`] trait, but the conversion between the two
@@ -71,6 +85,7 @@ macro_rules! implement_cast {
     ($Input:ty, {$($Output:ty),*}) => {
         $(
         impl CastFrom<$Input> for $Output {
+            #[inline]
             fn cast_from(input: $Input) -> $Output {
                 input as $Output
             }
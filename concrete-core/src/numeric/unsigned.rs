@@ -36,6 +36,12 @@ pub trait UnsignedInteger:
 {
     /// The signed type of the same precision.
     type Signed: SignedInteger<Unsigned = Self> + CastFrom<Self>;
+    /// The type used to accumulate products of values of this type.
+    ///
+    /// It is `u64` for the types of at most 32 bits, whose products fit in it, and the type
+    /// itself for the larger ones. Since its size is a multiple of the size of the type,
+    /// truncating a wrapping result computed with it gives the wrapping result of the type.
+    type Wide: UnsignedInteger + CastFrom<Self> + CastInto<Self>;
     /// Compute an addition, modulo the max of the type.
     fn wrapping_add(self, other: Self) -> Self;
     /// Compute a subtraction, modulo the max of the type.
@@ -57,10 +63,86 @@ pub trait UnsignedInteger:
     /// Returns a bit representation of the integer, where blocks of length `block_length` are
     /// separated by whitespaces to increase the readability.
     fn to_bits_string(&self, block_length: usize) -> String;
+
+    /// Returns `Self::MAX` if `bit` is `true`, and `Self::ZERO` otherwise.
+    ///
+    /// # Constant-time
+    ///
+    /// The mask is computed as `0 - bit`, where `bit` is zero-extended to the integer type, without
+    /// branching on the value of `bit`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::numeric::UnsignedInteger;
+    /// assert_eq!(u8::ct_mask_from_bool(true), 255);
+    /// assert_eq!(u8::ct_mask_from_bool(false), 0);
+    /// ```
+    fn ct_mask_from_bool(bit: bool) -> Self;
+
+    /// Returns `first` if `choice` is `true`, and `second` otherwise.
+    ///
+    /// # Constant-time
+    ///
+    /// The selection is performed with a mask, without branching on the value of `choice`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::numeric::UnsignedInteger;
+    /// assert_eq!(u8::ct_select(true, 3, 5), 3);
+    /// assert_eq!(u8::ct_select(false, 3, 5), 5);
+    /// ```
+    #[inline]
+    fn ct_select(choice: bool, first: Self, second: Self) -> Self {
+        let mask = Self::ct_mask_from_bool(choice);
+        (first & mask) | (second & !mask)
+    }
+
+    /// Returns whether `self` is equal to `other`.
+    ///
+    /// # Constant-time
+    ///
+    /// The comparison is performed with bitwise operations on the whole width of the integers,
+    /// and does not exit early on the first differing bit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::numeric::UnsignedInteger;
+    /// assert!(5u8.ct_eq(5));
+    /// assert!(!5u8.ct_eq(4));
+    /// ```
+    #[inline]
+    fn ct_eq(self, other: Self) -> bool {
+        let diff = self ^ other;
+        ((diff | diff.wrapping_neg()) >> (Self::BITS - 1)) == Self::ZERO
+    }
+
+    /// Returns whether `self` is strictly lower than `other`.
+    ///
+    /// # Constant-time
+    ///
+    /// The comparison is performed by computing the borrow of `self - other` with bitwise
+    /// operations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::numeric::UnsignedInteger;
+    /// assert!(4u8.ct_lt(5));
+    /// assert!(!5u8.ct_lt(5));
+    /// assert!(!255u8.ct_lt(0));
+    /// ```
+    #[inline]
+    fn ct_lt(self, other: Self) -> bool {
+        let borrow = (!self & other) | (!(self ^ other) & self.wrapping_sub(other));
+        (borrow >> (Self::BITS - 1)) == Self::ONE
+    }
 }
 
 macro_rules! implement {
-    ($Type: tt, $SignedType:ty, $bits:expr) => {
+    ($Type: tt, $SignedType:ty, $WideType:ty, $bits:expr) => {
         impl Numeric for $Type {
             const BITS: usize = $bits;
             const ZERO: Self = 0;
@@ -70,6 +152,7 @@ macro_rules! implement {
         }
         impl UnsignedInteger for $Type {
             type Signed = $SignedType;
+            type Wide = $WideType;
             fn into_signed(self) -> Self::Signed {
                 Self::Signed::cast_from(self)
             }
@@ -87,39 +170,51 @@ macro_rules! implement {
                 }
                 strn
             }
+            #[inline]
             fn wrapping_add(self, other: Self) -> Self {
                 self.wrapping_add(other)
             }
+            #[inline]
             fn wrapping_sub(self, other: Self) -> Self {
                 self.wrapping_sub(other)
             }
+            #[inline]
             fn wrapping_div(self, other: Self) -> Self {
                 self.wrapping_div(other)
             }
+            #[inline]
             fn wrapping_mul(self, other: Self) -> Self {
                 self.wrapping_mul(other)
             }
+            #[inline]
             fn wrapping_neg(self) -> Self {
                 self.wrapping_neg()
             }
+            #[inline]
             fn wrapping_shl(self, rhs: u32) -> Self {
                 self.wrapping_shl(rhs)
             }
+            #[inline]
             fn wrapping_shr(self, rhs: u32) -> Self {
                 self.wrapping_shr(rhs)
             }
+            #[inline]
             fn wrapping_pow(self, exp: u32) -> Self {
                 self.wrapping_pow(exp)
             }
+            #[inline]
+            fn ct_mask_from_bool(bit: bool) -> Self {
+                (0 as $Type).wrapping_sub(bit as $Type)
+            }
         }
     };
 }
 
-implement!(u8, i8, 8);
-implement!(u16, i16, 16);
-implement!(u32, i32, 32);
-implement!(u64, i64, 64);
-implement!(u128, i128, 128);
+implement!(u8, i8, u64, 8);
+implement!(u16, i16, u64, 16);
+implement!(u32, i32, u64, 32);
+implement!(u64, i64, u64, 64);
+implement!(u128, i128, u128, 128);
 
 #[cfg(test)]
 mod test {
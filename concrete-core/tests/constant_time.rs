@@ -0,0 +1,62 @@
+//! Checks the constant-time primitives.
+//!
+//! The primitives are checked to compute the same values as their naive counterparts. The machine
+//! code generated for them is checked separately by `scripts/check_constant_time.sh`, since the
+//! result depends on the toolchain and on the target.
+
+use concrete_core::crypto::encoding::Plaintext;
+use concrete_core::crypto::lwe::LweCiphertext;
+use concrete_core::crypto::secret::LweSecretKey;
+use concrete_core::math::modular::ModQ;
+use concrete_core::math::tensor::AsRefTensor;
+use concrete_core::numeric::UnsignedInteger;
+
+const MODULUS: u64 = 2305843009213693951;
+
+#[test]
+fn test_primitives_match_naive() {
+    for _ in 0..1000 {
+        let (lhs, rhs, bit) = (rand::random::<u64>(), rand::random::<u64>(), rand::random());
+        assert_eq!(u64::ct_mask_from_bool(bit), if bit { u64::MAX } else { 0 });
+        assert_eq!(u64::ct_select(bit, lhs, rhs), if bit { lhs } else { rhs });
+        assert_eq!(lhs.ct_lt(rhs), lhs < rhs);
+
+        let (a, b) = (lhs % MODULUS, rhs % MODULUS);
+        let (zq_a, zq_b) = (ModQ::<MODULUS>::new(a), ModQ::<MODULUS>::new(b));
+        let modulus = MODULUS as u128;
+        let expected_sum = ((a as u128 + b as u128) % modulus) as u64;
+        let expected_difference = ((a as u128 + modulus - b as u128) % modulus) as u64;
+        let expected_product = ((a as u128 * b as u128) % modulus) as u64;
+        assert_eq!((zq_a + zq_b).value(), expected_sum);
+        assert_eq!((zq_a - zq_b).value(), expected_difference);
+        assert_eq!((zq_a * zq_b).value(), expected_product);
+    }
+}
+
+#[test]
+fn test_binary_multisum_matches_naive() {
+    for dimension in 1..100 {
+        let key: Vec<bool> = (0..dimension).map(|_| rand::random()).collect();
+        let ciphertext: Vec<u64> = (0..=dimension).map(|_| rand::random()).collect();
+        let key = LweSecretKey::from_container(key);
+        let ciphertext = LweCiphertext::from_container(ciphertext);
+
+        let naive = ciphertext
+            .get_mask()
+            .as_tensor()
+            .iter()
+            .zip(key.as_tensor().iter())
+            .filter(|(_, bit)| **bit)
+            .fold(0u64, |sum, (coef, _)| sum.wrapping_add(*coef));
+        let mask = ciphertext.get_mask();
+        assert_eq!(mask.ct_compute_binary_multisum(&key), naive);
+        assert_eq!(mask.compute_binary_multisum(&key), naive);
+
+        let mut decrypted = Plaintext(0);
+        key.decrypt_lwe(&mut decrypted, &ciphertext);
+        assert_eq!(decrypted.0, ciphertext.get_body().0.wrapping_sub(naive));
+        let mut ct_decrypted = Plaintext(0);
+        key.ct_decrypt_lwe(&mut ct_decrypted, &ciphertext);
+        assert_eq!(ct_decrypted, decrypted);
+    }
+}
@@ -0,0 +1,94 @@
+//! The metrics are global to the process, so this test lives in its own binary to avoid counting
+//! the operations of other tests.
+#![cfg(feature = "metrics")]
+
+use concrete_core::crypto::bootstrap::BootstrapKey;
+use concrete_core::crypto::cross::bootstrap;
+use concrete_core::crypto::glwe::GlweCiphertext;
+use concrete_core::crypto::lwe::{LweCiphertext, LweKeyswitchKey, LweList};
+use concrete_core::crypto::{CiphertextCount, GlweDimension, LweDimension, LweSize};
+use concrete_core::math::decomposition::{DecompositionBaseLog, DecompositionLevelCount};
+use concrete_core::math::fft::{Complex64, Fft, FourierPolynomial};
+use concrete_core::math::polynomial::{Polynomial, PolynomialSize};
+use concrete_core::math::tensor::AsMutTensor;
+use concrete_core::metrics::{self, OperationMetrics};
+
+#[test]
+fn test_metrics_count_operations() {
+    metrics::reset();
+    assert_eq!(metrics::snapshot(), Default::default());
+
+    // Fourier transforms.
+    let polynomial_size = PolynomialSize(1024);
+    let mut fft = Fft::new(polynomial_size);
+    let mut fourier_poly = FourierPolynomial::allocate(Complex64::new(0., 0.), polynomial_size);
+    let mut poly = Polynomial::allocate(0u64, polynomial_size);
+    for _ in 0..3 {
+        fft.forward_as_torus(&mut fourier_poly, &poly);
+    }
+    for _ in 0..2 {
+        fft.add_backward_as_torus(&mut poly, &mut fourier_poly);
+    }
+    let report = metrics::snapshot();
+    assert_eq!(report.fft.count, 5);
+    assert!(report.fft.total_time.as_nanos() > 0);
+    assert_eq!(report.external_product, OperationMetrics::default());
+    assert_eq!(report.keyswitch, OperationMetrics::default());
+    assert_eq!(report.bootstrap, OperationMetrics::default());
+
+    // Keyswitches, both on single ciphertexts and on lists.
+    metrics::reset();
+    let (input_dimension, output_dimension) = (LweDimension(20), LweDimension(10));
+    let ksk = LweKeyswitchKey::allocate(
+        0u64,
+        DecompositionLevelCount(3),
+        DecompositionBaseLog(4),
+        input_dimension,
+        output_dimension,
+    );
+    let input = LweCiphertext::allocate(0u64, input_dimension.to_lwe_size());
+    let mut output = LweCiphertext::allocate(0u64, output_dimension.to_lwe_size());
+    for _ in 0..2 {
+        ksk.keyswitch_ciphertext(&mut output, &input);
+    }
+    let input_list = LweList::allocate(0u64, input_dimension.to_lwe_size(), CiphertextCount(3));
+    let mut output_list =
+        LweList::allocate(0u64, output_dimension.to_lwe_size(), CiphertextCount(3));
+    ksk.keyswitch_list(&mut output_list, &input_list);
+    let report = metrics::snapshot();
+    assert_eq!(report.keyswitch.count, 5);
+    assert!(report.keyswitch.total_time.as_nanos() > 0);
+    assert_eq!(report.fft, OperationMetrics::default());
+
+    // A bootstrap performs one external product per non-zero rotation of the mask.
+    metrics::reset();
+    let lwe_dimension = LweDimension(8);
+    let glwe_dimension = GlweDimension(1);
+    let polynomial_size = PolynomialSize(512);
+    let bsk = BootstrapKey::allocate_complex(
+        Complex64::new(0., 0.),
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        DecompositionLevelCount(3),
+        DecompositionBaseLog(7),
+        lwe_dimension,
+    );
+    let mut lwe_in = LweCiphertext::allocate(1u64 << 62, lwe_dimension.to_lwe_size());
+    lwe_in.get_mut_body().0 = 0;
+    let mut lwe_out =
+        LweCiphertext::allocate(0u64, LweSize(glwe_dimension.0 * polynomial_size.0 + 1));
+    let mut accumulator =
+        GlweCiphertext::allocate(0u64, polynomial_size, glwe_dimension.to_glwe_size());
+    accumulator
+        .get_mut_body()
+        .as_mut_tensor()
+        .fill_with_element(1 << 60);
+    bootstrap(&mut lwe_out, &lwe_in, &bsk, &mut accumulator);
+    let report = metrics::snapshot();
+    assert_eq!(report.bootstrap.count, 1);
+    assert_eq!(report.external_product.count, lwe_dimension.0 as u64);
+    assert_eq!(report.keyswitch, OperationMetrics::default());
+    assert!(report.fft.count > 0);
+    assert!(report.bootstrap.total_time >= report.external_product.total_time);
+    assert!(report.external_product.total_time.as_nanos() > 0);
+}
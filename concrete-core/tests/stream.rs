@@ -0,0 +1,95 @@
+//! The memory usage is measured with a global allocator counting the allocations of the whole
+//! process, so this test lives in its own binary to avoid counting the memory of other tests.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io::Cursor;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use concrete_core::crypto::secret::LweSecretKey;
+use concrete_core::crypto::{CiphertextCount, LweDimension};
+use concrete_core::math::dispersion::LogStandardDev;
+
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+            PEAK.fetch_max(allocated, Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+// Resets the peak to the memory currently allocated, and returns it.
+fn reset_peak() -> usize {
+    let allocated = ALLOCATED.load(Ordering::SeqCst);
+    PEAK.store(allocated, Ordering::SeqCst);
+    allocated
+}
+
+#[test]
+fn test_stream_round_trip_bounded_memory() {
+    let count: usize = 300_000;
+    let lwe_dimension = LweDimension(16);
+    let batch_size = CiphertextCount(512);
+    let noise = LogStandardDev::from_log_standard_dev(-40.);
+    let secret_key = LweSecretKey::generate(lwe_dimension);
+
+    // The messages are computed on the fly from their index, so that they never have to be
+    // stored in memory.
+    let plaintext = |index: u64| index << 44;
+
+    // The cursor is allocated up front with the exact size of the stream, so that its growth is
+    // not accounted for in the peak: the size, then the batches with their length, and the
+    // empty batch ending the stream.
+    let lwe_size = lwe_dimension.0 + 1;
+    let batch_count = count.div_ceil(batch_size.0);
+    let stream_size = 8 + batch_count * 8 + count * lwe_size * 8 + 8;
+    let mut cursor = Cursor::new(Vec::with_capacity(stream_size));
+    let batch_memory = batch_size.0 * lwe_size * 8;
+    let full_list_memory = count * lwe_size * 8;
+
+    // Encrypts the stream.
+    let baseline = reset_peak();
+    let written = secret_key
+        .encrypt_stream_with_batch_size(
+            (0..count as u64).map(plaintext),
+            noise,
+            &mut cursor,
+            batch_size,
+        )
+        .unwrap();
+    let encryption_peak = PEAK.load(Ordering::SeqCst) - baseline;
+    assert_eq!(written, count as u64);
+    assert_eq!(cursor.get_ref().len(), stream_size);
+    assert!(encryption_peak < 4 * batch_memory);
+    assert!(encryption_peak < full_list_memory / 100);
+
+    // Decrypts the stream.
+    cursor.set_position(0);
+    let baseline = reset_peak();
+    let mut index = 0;
+    let read = secret_key
+        .decrypt_stream(&mut cursor, |decrypted: u64| {
+            assert_eq!(decrypted.wrapping_add(1 << 43) >> 44, index);
+            index += 1;
+        })
+        .unwrap();
+    let decryption_peak = PEAK.load(Ordering::SeqCst) - baseline;
+    assert_eq!(read, count as u64);
+    assert_eq!(index, count as u64);
+    assert!(decryption_peak < 4 * batch_memory);
+    assert!(decryption_peak < full_list_memory / 100);
+}
@@ -0,0 +1,95 @@
+//! Runs the test vectors generator as a separate process, and checks that its outputs are
+//! deterministic.
+#![cfg(feature = "test-vectors")]
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const PARAMETERS: &str = r#"{
+    "lwe_dimension": 64,
+    "glwe_dimension": 1,
+    "polynomial_size": 256,
+    "lwe_log_std_dev": -30.0,
+    "glwe_log_std_dev": -40.0,
+    "ks_base_log": 4,
+    "ks_level_count": 6,
+    "ggsw_base_log": 7,
+    "ggsw_level_count": 3,
+    "plaintexts": [0, 4611686018427387904, 9223372036854775808, 13835058055282163712]
+}"#;
+
+const ARTIFACTS: [&str; 9] = [
+    "lwe_secret_key.bin",
+    "glwe_secret_key.bin",
+    "lwe_ciphertexts.bin",
+    "keyswitch_key.bin",
+    "keyswitch_output.bin",
+    "ggsw.bin",
+    "glwe_ciphertext.bin",
+    "external_product_output.bin",
+    "manifest.json",
+];
+
+fn scratch_directory(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir()
+        .join(format!("concrete-test-vectors-{}", std::process::id()))
+        .join(name);
+    if directory.exists() {
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+    std::fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+fn run_generator(seed: &str, parameters: &Path, output: &Path) {
+    let status = Command::new(env!("CARGO_BIN_EXE_concrete-test-vectors"))
+        .arg(seed)
+        .arg(parameters)
+        .arg(output)
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
+
+#[test]
+fn test_vectors_are_deterministic() {
+    let root = scratch_directory("root");
+    let parameters = root.join("parameters.json");
+    std::fs::write(&parameters, PARAMETERS).unwrap();
+    let first = root.join("first");
+    let second = root.join("second");
+    let other = root.join("other");
+    run_generator("0x0123_4567_89ab_cdef", &parameters, &first);
+    run_generator("81985529216486895", &parameters, &second);
+    run_generator("42", &parameters, &other);
+
+    for artifact in ARTIFACTS.iter() {
+        let first = std::fs::read(first.join(artifact)).unwrap();
+        let second = std::fs::read(second.join(artifact)).unwrap();
+        let other = std::fs::read(other.join(artifact)).unwrap();
+        assert_eq!(first, second, "{} differs between two runs", artifact);
+        assert_ne!(first, other, "{} does not depend on the seed", artifact);
+    }
+    let manifest = std::fs::read_to_string(first.join("manifest.json")).unwrap();
+    assert!(manifest.contains("\"seed\": \"0x00000000000000000123456789abcdef\""));
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn test_vectors_reject_invalid_parameters() {
+    let root = scratch_directory("invalid");
+    let parameters = root.join("parameters.json");
+    std::fs::write(
+        &parameters,
+        PARAMETERS.replace("\"ks_level_count\": 6", "\"ks_level_count\": 20"),
+    )
+    .unwrap();
+    let status = Command::new(env!("CARGO_BIN_EXE_concrete-test-vectors"))
+        .arg("42")
+        .arg(&parameters)
+        .arg(root.join("output"))
+        .status()
+        .unwrap();
+    assert!(!status.success());
+    std::fs::remove_dir_all(&root).unwrap();
+}
@@ -97,23 +97,76 @@ impl RandomGenerator {
         } else {
             // All the values of the buffer were yielded. We generate new ones, and resets the
             // index.
-            self.update_state();
-            self.generated = si128arr_to_u8arr(aes_encrypt_many(
-                &u128_to_si128(self.state),
-                &u128_to_si128(self.state + 1),
-                &u128_to_si128(self.state + 2),
-                &u128_to_si128(self.state + 3),
-                &u128_to_si128(self.state + 4),
-                &u128_to_si128(self.state + 5),
-                &u128_to_si128(self.state + 6),
-                &u128_to_si128(self.state + 7),
-                &self.round_keys,
-            ));
+            self.generate_batch();
             self.generated_idx = 0;
         }
         self.generated[self.generated_idx]
     }
 
+    /// Returns the next 16 bytes of the stream, as a single aes block.
+    ///
+    /// The output is the same as the one of 16 successive calls to
+    /// [`RandomGenerator::generate_next`]. When the bytes already yielded by the generator form a
+    /// whole number of blocks (which is always the case when only this method is used), the
+    /// block is the encryption of a single value of the counter, and is copied at once instead
+    /// of byte by byte.
+    pub fn generate_block(&mut self) -> [u8; 16] {
+        let start = if self.generated_idx < 127 {
+            self.generated_idx + 1
+        } else {
+            self.generate_batch();
+            0
+        };
+        let mut block = [0u8; 16];
+        if start % 16 == 0 {
+            // The next bytes are aligned on a block of the buffer.
+            block.copy_from_slice(&self.generated[start..start + 16]);
+            self.generated_idx = start + 15;
+        } else {
+            // The block overlaps two blocks of the buffer, and possibly two batches.
+            block.iter_mut().for_each(|b| *b = self.generate_next());
+        }
+        block
+    }
+
+    /// Advances the generator by `steps` batches of eight aes blocks, i.e. `steps * 128` bytes.
+    ///
+    /// The output of the generator afterwards is the same as if `steps * 128` bytes had been
+    /// generated and discarded, but only the counter is incremented, and at most one batch is
+    /// encrypted. Knowing the number of bytes consumed by each segment of a computation, this
+    /// allows several generators created with the same key and state to produce the segments in
+    /// parallel.
+    pub fn jump_ahead(&mut self, steps: u64) {
+        if steps == 0 {
+            return;
+        }
+        if self.generated_idx < 127 {
+            // Some values of the current batch were not yielded. We generate the batch located
+            // `steps` batches after the current one, and keep the same position in it.
+            self.state = self.state.wrapping_add(8 * (steps as u128 - 1));
+            self.generate_batch();
+        } else {
+            // The next call generates a new batch, which only depends on the counter.
+            self.state = self.state.wrapping_add(8 * steps as u128);
+        }
+    }
+
+    // Encrypts the eight next values of the counter into the buffer.
+    fn generate_batch(&mut self) {
+        self.update_state();
+        self.generated = si128arr_to_u8arr(aes_encrypt_many(
+            &u128_to_si128(self.state),
+            &u128_to_si128(self.state + 1),
+            &u128_to_si128(self.state + 2),
+            &u128_to_si128(self.state + 3),
+            &u128_to_si128(self.state + 4),
+            &u128_to_si128(self.state + 5),
+            &u128_to_si128(self.state + 6),
+            &u128_to_si128(self.state + 7),
+            &self.round_keys,
+        ));
+    }
+
     fn update_state(&mut self) {
         self.state = self.state.wrapping_add(8);
     }
@@ -378,4 +431,70 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_generate_block_matches_generate_next() {
+        let key = generate_initialization_vector();
+        let state = generate_initialization_vector();
+        let mut block_generator = RandomGenerator::new(Some(key), Some(state));
+        let mut byte_generator = RandomGenerator::new(Some(key), Some(state));
+        // The blocks are aligned at first, and unaligned after a single byte was yielded.
+        for round in 0..3 {
+            for _ in 0..20 {
+                let block = block_generator.generate_block();
+                for byte in block.iter() {
+                    assert_eq!(*byte, byte_generator.generate_next());
+                }
+            }
+            for _ in 0..round {
+                assert_eq!(
+                    block_generator.generate_next(),
+                    byte_generator.generate_next()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_jump_ahead_matches_discarded_bytes() {
+        let key = generate_initialization_vector();
+        let state = generate_initialization_vector();
+        // The jumps start at the beginning of a batch, in the middle, and on its last byte.
+        for prefix in &[0, 1, 50, 127, 128] {
+            for steps in 0..4u64 {
+                let mut jumping_generator = RandomGenerator::new(Some(key), Some(state));
+                let mut discarding_generator = RandomGenerator::new(Some(key), Some(state));
+                for _ in 0..*prefix {
+                    assert_eq!(
+                        jumping_generator.generate_next(),
+                        discarding_generator.generate_next()
+                    );
+                }
+                jumping_generator.jump_ahead(steps);
+                for _ in 0..steps * 128 {
+                    discarding_generator.generate_next();
+                }
+                for _ in 0..300 {
+                    assert_eq!(
+                        jumping_generator.generate_next(),
+                        discarding_generator.generate_next()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_generate_block_encrypts_counter() {
+        // Checks that the aligned blocks are the encryptions of the successive counter values.
+        let mut generator = RandomGenerator::new(Some(CIPHER_KEY), Some(PLAINTEXT));
+        let mut keys: [__m128i; 11] = [u128_to_si128(0); 11];
+        aes_128_key_expansion(u128_to_si128(CIPHER_KEY), &mut keys);
+        // The counter is incremented before the first batch is generated.
+        for counter in 8..40 {
+            let expected = aes_encrypt(&u128_to_si128(PLAINTEXT.wrapping_add(counter)), &keys);
+            let expected: [u8; 16] = unsafe { transmute(expected) };
+            assert_eq!(generator.generate_block(), expected);
+        }
+    }
 }
@@ -8,11 +8,7 @@ use concrete_csprng::RandomGenerator;
 pub fn main() {
     let mut generator = RandomGenerator::new(None, None);
     let mut stdout = stdout();
-    let mut buffer = [0u8; 16];
     loop {
-        buffer
-            .iter_mut()
-            .for_each(|a| *a = generator.generate_next());
-        stdout.write_all(&buffer).unwrap();
+        stdout.write_all(&generator.generate_block()).unwrap();
     }
 }
@@ -15,4 +15,14 @@ impl RandomGenerator {
     pub fn generate_next(&mut self) -> u8 {
         unimplemented!()
     }
+    pub fn generate_block(&mut self) -> [u8; 16] {
+        let mut block = [0u8; 16];
+        block.iter_mut().for_each(|b| *b = self.generate_next());
+        block
+    }
+    pub fn jump_ahead(&mut self, steps: u64) {
+        for _ in 0..steps * 128 {
+            self.generate_next();
+        }
+    }
 }
@@ -162,6 +162,7 @@ macro_rules! impl_trait_npe_lwe {
 
 impl_trait_npe_lwe!(u32, i32, "type Torus = u32;");
 impl_trait_npe_lwe!(u64, i64, "type Torus = u64;");
+impl_trait_npe_lwe!(u128, i128, "type Torus = u128;");
 
 /// Computes the variance of the error distribution after an addition of two uncorrelated ciphertexts
 /// sigma_out^2 <- sigma0^2 + sigma1^2
@@ -31,3 +31,4 @@ macro_rules! impl_trait_npe_rlwe {
 
 impl_trait_npe_rlwe!(u32, i32, "type Torus = u32;");
 impl_trait_npe_rlwe!(u64, i64, "type Torus = u64;");
+impl_trait_npe_rlwe!(u128, i128, "type Torus = u128;");